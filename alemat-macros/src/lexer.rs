@@ -0,0 +1,210 @@
+use proc_macro::{Delimiter, Spacing, Span, TokenStream, TokenTree};
+
+use crate::Error;
+
+/// A token of the `math!` input.
+#[derive(Debug, Clone)]
+pub(crate) enum Token {
+    /// An identifier, e.g. `x` or `frac`.
+    Ident(String, Span),
+
+    /// A numeric literal, e.g. `2` or `1.5`.
+    Number(String, Span),
+
+    /// A string literal, rendered as text.
+    Text(String, Span),
+
+    /// An operator, e.g. `+` or `<=`.
+    Operator(String, Span),
+
+    /// The subscript marker `_`.
+    Sub(Span),
+
+    /// The superscript marker `^`.
+    Sup(Span),
+
+    /// Tokens enclosed in `()`, `[]` or `{}`.
+    Group(Delimiter, Vec<Token>, Span),
+
+    /// The interpolated Rust expression of `#{...}`.
+    Interpolation(TokenStream, Span),
+}
+
+impl Token {
+    pub(crate) fn span(&self) -> Span {
+        match self {
+            Token::Ident(_, span)
+            | Token::Number(_, span)
+            | Token::Text(_, span)
+            | Token::Operator(_, span)
+            | Token::Sub(span)
+            | Token::Sup(span)
+            | Token::Group(_, _, span)
+            | Token::Interpolation(_, span) => *span,
+        }
+    }
+}
+
+/// Operators spelled with two characters and their Unicode symbol.
+const COMPOUND_OPERATORS: &[(&str, &str)] = &[
+    ("<=", "\u{2264}"),
+    (">=", "\u{2265}"),
+    ("!=", "\u{2260}"),
+    ("->", "\u{2192}"),
+    ("=>", "\u{21D2}"),
+    ("+-", "\u{00B1}"),
+];
+
+pub(crate) fn lex(input: TokenStream) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let mut trees = input.into_iter().peekable();
+
+    while let Some(tree) = trees.next() {
+        match tree {
+            TokenTree::Ident(ident) => split_scripts(&ident.to_string(), ident.span(), &mut tokens),
+            TokenTree::Literal(literal) => {
+                tokens.extend(literal_tokens(&literal.to_string(), literal.span())?)
+            }
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                tokens.extend(lex(group.stream())?)
+            }
+            TokenTree::Group(group) => tokens.push(Token::Group(
+                group.delimiter(),
+                lex(group.stream())?,
+                group.span(),
+            )),
+            TokenTree::Punct(punct) if punct.as_char() == '#' => match trees.next() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                    if group.stream().is_empty() {
+                        return Err(Error::new(
+                            group.span(),
+                            "expected a Rust expression in `#{}`",
+                        ));
+                    }
+
+                    tokens.push(Token::Interpolation(group.stream(), group.span()));
+                }
+                _ => {
+                    return Err(Error::new(
+                        punct.span(),
+                        "expected `{` after `#`, interpolations are written as `#{value}`",
+                    ))
+                }
+            },
+            TokenTree::Punct(punct) => {
+                let span = punct.span();
+                let mut chars = String::from(punct.as_char());
+                let mut spacing = punct.spacing();
+
+                while spacing == Spacing::Joint {
+                    match trees.peek() {
+                        Some(TokenTree::Punct(next)) if next.as_char() != '#' => {
+                            chars.push(next.as_char());
+                            spacing = next.spacing();
+                            trees.next();
+                        }
+                        _ => break,
+                    }
+                }
+
+                operator_tokens(&chars, span, &mut tokens);
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Split joined punctuation into operators, preferring the compound operators.
+fn operator_tokens(mut chars: &str, span: Span, tokens: &mut Vec<Token>) {
+    while let Some(first) = chars.chars().next() {
+        if let Some((op, symbol)) = COMPOUND_OPERATORS
+            .iter()
+            .find(|(op, _)| chars.starts_with(op))
+        {
+            tokens.push(Token::Operator(String::from(*symbol), span));
+            chars = &chars[op.len()..];
+            continue;
+        }
+
+        tokens.push(match first {
+            '^' => Token::Sup(span),
+            '*' => Token::Operator(String::from("\u{22C5}"), span),
+            _ => Token::Operator(String::from(first), span),
+        });
+
+        chars = &chars[first.len_utf8()..];
+    }
+}
+
+/// Split an identifier such as `a_i` or `sum_` at its underscores, which mark subscripts.
+fn split_scripts(ident: &str, span: Span, tokens: &mut Vec<Token>) {
+    for (idx, part) in ident.split('_').enumerate() {
+        if idx > 0 {
+            tokens.push(Token::Sub(span));
+        }
+
+        match part.chars().next() {
+            None => {}
+            Some(c) if c.is_ascii_digit() => tokens.push(Token::Number(String::from(part), span)),
+            Some(_) => tokens.push(Token::Ident(String::from(part), span)),
+        }
+    }
+}
+
+fn literal_tokens(literal: &str, span: Span) -> Result<Vec<Token>, Error> {
+    if literal.starts_with(|c: char| c.is_ascii_digit()) {
+        if !literal
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '.' || c == '_')
+        {
+            return Err(Error::new(
+                span,
+                format!("unsupported number `{literal}`, only decimal numbers are allowed"),
+            ));
+        }
+
+        // underscores in numbers mark subscripts just like in identifiers, e.g. `2_1`
+        let mut tokens = Vec::new();
+        split_scripts(literal, span, &mut tokens);
+        return Ok(tokens);
+    }
+
+    let text = if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        raw.get(hashes + 1..raw.len() - hashes - 1)
+            .map(String::from)
+    } else {
+        literal
+            .strip_prefix('"')
+            .and_then(|text| text.strip_suffix('"'))
+            .map(unescape)
+    };
+
+    match text {
+        Some(text) => Ok(vec![Token::Text(text, span)]),
+        None => Err(Error::new(
+            span,
+            format!("unsupported literal `{literal}`, expected a number or a string"),
+        )),
+    }
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('t') => unescaped.push('\t'),
+                Some(other) => unescaped.push(other),
+                None => {}
+            },
+            c => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
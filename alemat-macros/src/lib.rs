@@ -0,0 +1,118 @@
+//! Procedural macros for the [`alemat`](https://docs.rs/alemat) MathML builder. The macros are
+//! re-exported by `alemat` with the `macros` feature, which is enabled by default.
+
+#![warn(missing_docs)]
+
+mod lexer;
+mod parser;
+
+use proc_macro::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
+
+/// Build MathML elements from math notation at compile time.
+///
+/// The macro expands into the builder calls of `alemat` and evaluates to an array
+/// `[alemat::Element; N]`, which can be passed wherever elements are expected, e.g. to
+/// `MathMl::with_content`. Malformed input is reported as compile error.
+///
+/// # Syntax
+///
+/// | Input                              | Element                                                 |
+/// |------------------------------------|---------------------------------------------------------|
+/// | `x`, `sin`                         | `Ident`                                                 |
+/// | `alpha`, `Omega`, `infinity`       | `Ident` from its constructor, e.g. `Ident::alpha()`     |
+/// | `2`, `1.5`                         | `Num`                                                   |
+/// | `"if"`                             | `Text`                                                  |
+/// | `+`, `-`, `=`, `<`, `,`            | `Operator`                                              |
+/// | `*`                                | `Operator` `⋅`                                          |
+/// | `<=`, `>=`, `!=`, `->`, `=>`, `+-` | `Operator` `≤`, `≥`, `≠`, `→`, `⇒`, `±`                 |
+/// | `(a)`, `[a]`                       | `Row` with visible parentheses or brackets              |
+/// | `{a}`                              | `Row` without fences                                    |
+/// | `x_i`, `x^2`, `x_(i+1)^n`          | `SubSup`                                                |
+/// | `sum`, `prod`, `lim`               | `UnderOver` if scripts are given, e.g. `sum_(i=0)^n`    |
+/// | `int`                              | `Operator` `∫`, scripts create a `SubSup`               |
+/// | `frac(a, b)`                       | `Frac`                                                  |
+/// | `sqrt(x)`, `sqrt[n](x)`            | `Radical`                                               |
+/// | `table[[a, b], [c, d]]`            | `Table`                                                 |
+/// | `#{value}`                         | The Rust expression `value` converted into an `Element` |
+///
+/// Since Rust reads `a_i` and `sum_` as single identifiers, underscores inside identifiers and
+/// numbers mark subscripts as well. Parentheses and braces around script and function
+/// arguments only group them and are not rendered, so `x^(n+1)` has the superscript `n+1`.
+///
+/// # Example
+///
+/// ```
+/// use alemat::elements::{radicals::Radical, scripted::{SubSup, UnderOver}, Frac, Ident, Num, Operator};
+/// use alemat::{children, math, Element};
+///
+/// let n = Ident::from("n");
+/// let elements = math! { frac(x, y) + sqrt[3](z) * sum_(i=0)^#{n} a_i };
+///
+/// let expected = children![
+///     Frac::builder().num(Ident::from("x")).denom(Ident::from("y")).build(),
+///     Operator::plus(),
+///     Radical::builder().index(Num::from(3)).content(Ident::from("z")).build(),
+///     Operator::dot(),
+///     UnderOver::builder()
+///         .expr(Operator::sum())
+///         .under(children![Ident::from("i"), Operator::eq(), Num::from(0)])
+///         .over(Ident::from("n"))
+///         .build(),
+///     SubSup::builder().base(Ident::from("a")).subscript(Ident::from("i")).build(),
+/// ];
+///
+/// assert_eq!(elements, expected);
+/// ```
+///
+/// Malformed input does not compile:
+///
+/// ```compile_fail
+/// let elements = alemat::math! { frac(x) };
+/// ```
+///
+/// ```compile_fail
+/// let elements = alemat::math! { x^ };
+/// ```
+#[proc_macro]
+pub fn math(input: TokenStream) -> TokenStream {
+    match lexer::lex(input).and_then(parser::elements) {
+        Ok(elements) => parser::array(elements),
+        Err(error) => error.into_compile_error(),
+    }
+}
+
+/// An error in the input of a macro, reported at the span of the offending tokens.
+#[derive(Debug)]
+struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+        }
+    }
+
+    /// Create the `compile_error!` invocation reporting this error.
+    fn into_compile_error(self) -> TokenStream {
+        let mut message = Literal::string(&self.message);
+        message.set_span(self.span);
+
+        let mut args = Group::new(Delimiter::Parenthesis, TokenTree::from(message).into());
+        args.set_span(self.span);
+
+        "::core::compile_error!"
+            .parse::<TokenStream>()
+            .expect("compile_error! invocation is valid")
+            .into_iter()
+            .map(|mut tree| {
+                tree.set_span(self.span);
+                tree
+            })
+            .chain([TokenTree::from(args)])
+            .collect()
+    }
+}
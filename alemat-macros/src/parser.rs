@@ -0,0 +1,362 @@
+use proc_macro::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
+
+use crate::{lexer::Token, Error};
+
+/// Identifiers written as a single symbol, with the constructor of `alemat::elements::Ident`
+/// creating it, e.g. `Ident::alpha()` for `alpha`.
+const SYMBOLS: &[(&str, &str)] = &[
+    ("alpha", "alpha"),
+    ("beta", "beta"),
+    ("gamma", "gamma"),
+    ("delta", "delta"),
+    ("epsilon", "epsilon"),
+    ("zeta", "zeta"),
+    ("eta", "eta"),
+    ("theta", "theta"),
+    ("iota", "iota"),
+    ("kappa", "kappa"),
+    ("lambda", "lambda"),
+    ("mu", "mu"),
+    ("nu", "nu"),
+    ("xi", "xi"),
+    ("pi", "pi"),
+    ("rho", "rho"),
+    ("sigma", "sigma"),
+    ("tau", "tau"),
+    ("upsilon", "upsilon"),
+    ("phi", "phi"),
+    ("chi", "chi"),
+    ("psi", "psi"),
+    ("omega", "omega"),
+    ("Gamma", "big_gamma"),
+    ("Delta", "big_delta"),
+    ("Theta", "big_theta"),
+    ("Lambda", "big_lambda"),
+    ("Xi", "big_xi"),
+    ("Pi", "big_pi"),
+    ("Sigma", "big_sigma"),
+    ("Phi", "big_phi"),
+    ("Psi", "big_psi"),
+    ("Omega", "big_omega"),
+    ("infinity", "infinity"),
+];
+
+/// Parse the tokens into expressions, each evaluating to an `alemat::Element`.
+pub(crate) fn elements(tokens: Vec<Token>) -> Result<Vec<TokenStream>, Error> {
+    let mut parser = Parser {
+        tokens: tokens.into_iter().peekable(),
+    };
+
+    let mut elements = Vec::new();
+
+    while let Some(token) = parser.tokens.next() {
+        elements.push(parser.item(token)?);
+    }
+
+    Ok(elements)
+}
+
+/// Create the array expression `[e1, e2, ...]` of type `[alemat::Element; N]`.
+pub(crate) fn array(elements: Vec<TokenStream>) -> TokenStream {
+    match elements.is_empty() {
+        true => code("[] as [::alemat::Element; 0]"),
+        false => list(elements),
+    }
+}
+
+struct Parser {
+    tokens: std::iter::Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl Parser {
+    /// Parse the token with its scripts.
+    fn item(&mut self, token: Token) -> Result<TokenStream, Error> {
+        let (base, is_big_operator) = self.atom(token)?;
+
+        let mut sub = None;
+        let mut sup = None;
+
+        loop {
+            let (script, name) = match self.tokens.peek() {
+                Some(Token::Sub(_)) => (&mut sub, "subscript"),
+                Some(Token::Sup(_)) => (&mut sup, "superscript"),
+                _ => break,
+            };
+
+            let marker = self.tokens.next().expect("script marker is peeked");
+
+            if script.is_some() {
+                return Err(Error::new(marker.span(), format!("duplicate {name}")));
+            }
+
+            *script = Some(self.script(marker.span(), name)?);
+        }
+
+        if sub.is_none() && sup.is_none() {
+            return Ok(base);
+        }
+
+        // big operators take their limits below and above, e.g. sum_(i=0)^n
+        let (builder, base_method, sub_method, sup_method) = match is_big_operator {
+            true => ("UnderOver", ".expr", ".under", ".over"),
+            false => ("SubSup", ".base", ".subscript", ".supscript"),
+        };
+
+        let mut built = code(&format!(
+            "::alemat::elements::scripted::{builder}::builder()"
+        ));
+        built.extend(call(base_method, array(vec![base])));
+
+        if let Some(sub) = sub {
+            built.extend(call(sub_method, array(sub)));
+        }
+
+        if let Some(sup) = sup {
+            built.extend(call(sup_method, array(sup)));
+        }
+
+        built.extend(code(".build()"));
+        Ok(element(built))
+    }
+
+    /// Parse the argument of a subscript or superscript. Parentheses and braces around the
+    /// argument only group it and are not rendered.
+    fn script(&mut self, span: Span, name: &str) -> Result<Vec<TokenStream>, Error> {
+        let marker = if name == "subscript" { '_' } else { '^' };
+        let missing = || Error::new(span, format!("expected a {name} after `{marker}`"));
+
+        match self.tokens.next() {
+            None | Some(Token::Sub(_)) | Some(Token::Sup(_)) => Err(missing()),
+            Some(Token::Group(Delimiter::Parenthesis | Delimiter::Brace, tokens, _)) => {
+                match tokens.is_empty() {
+                    true => Err(missing()),
+                    false => elements(tokens),
+                }
+            }
+            Some(token) => Ok(vec![self.atom(token)?.0]),
+        }
+    }
+
+    /// Parse a single element. Also returns whether the element is a big operator that takes its
+    /// scripts as limits below and above.
+    fn atom(&mut self, token: Token) -> Result<(TokenStream, bool), Error> {
+        let atom = match token {
+            Token::Ident(name, span) => return self.ident(&name, span),
+            Token::Number(num, _) => element(call("::alemat::elements::Num::from", string(&num))),
+            Token::Text(text, _) => element(call("::alemat::elements::Text::from", string(&text))),
+            Token::Operator(op, _) => operator(&op),
+            Token::Group(Delimiter::Parenthesis, tokens, _) => {
+                fenced("\u{0028}", elements(tokens)?, "\u{0029}")
+            }
+            Token::Group(Delimiter::Bracket, tokens, _) => {
+                fenced("\u{005B}", elements(tokens)?, "\u{005D}")
+            }
+            Token::Group(_, tokens, _) => row(elements(tokens)?),
+            Token::Interpolation(value, _) => {
+                call("::core::convert::Into::<::alemat::Element>::into", value)
+            }
+            Token::Sub(span) => return Err(Error::new(span, "expected an expression before `_`")),
+            Token::Sup(span) => return Err(Error::new(span, "expected an expression before `^`")),
+        };
+
+        Ok((atom, false))
+    }
+
+    fn ident(&mut self, name: &str, span: Span) -> Result<(TokenStream, bool), Error> {
+        let atom = match name {
+            "frac" => {
+                let [num, denom] = self.arguments(name, span, "frac(a, b)")?;
+
+                let mut frac = code("::alemat::elements::Frac::builder()");
+                frac.extend(call(".num", array(num)));
+                frac.extend(call(".denom", array(denom)));
+                frac.extend(code(".build()"));
+                element(frac)
+            }
+            "sqrt" => {
+                let index = match self.tokens.peek() {
+                    Some(Token::Group(Delimiter::Bracket, ..)) => match self.tokens.next() {
+                        Some(Token::Group(_, tokens, span)) if tokens.is_empty() => {
+                            return Err(Error::new(span, "expected the index of the root"))
+                        }
+                        Some(Token::Group(_, tokens, _)) => array(elements(tokens)?),
+                        _ => unreachable!("bracket group is peeked"),
+                    },
+                    _ => array(vec![element(call(
+                        "::alemat::elements::Num::from",
+                        string("2"),
+                    ))]),
+                };
+
+                let [content] = self.arguments(name, span, "sqrt(x) or sqrt[n](x)")?;
+
+                let mut radical = code("::alemat::elements::radicals::Radical::builder()");
+                radical.extend(call(".index", index));
+                radical.extend(call(".content", array(content)));
+                radical.extend(code(".build()"));
+                element(radical)
+            }
+            "table" => self.table(span)?,
+            "sum" => return Ok((operator("\u{2211}"), true)),
+            "prod" => return Ok((operator("\u{220F}"), true)),
+            "lim" => return Ok((ident("lim"), true)),
+            "int" => operator("\u{222B}"),
+            name => match SYMBOLS.iter().find(|(symbol, _)| *symbol == name) {
+                Some((_, constructor)) => {
+                    element(code(&format!("::alemat::elements::Ident::{constructor}()")))
+                }
+                None => ident(name),
+            },
+        };
+
+        Ok((atom, false))
+    }
+
+    /// Parse the comma separated arguments in parentheses following the function `name`.
+    fn arguments<const N: usize>(
+        &mut self,
+        name: &str,
+        span: Span,
+        usage: &str,
+    ) -> Result<[Vec<TokenStream>; N], Error> {
+        let expected = || {
+            let count = match N {
+                1 => String::from("one argument"),
+                n => format!("{n} arguments"),
+            };
+
+            format!("`{name}` expects {count}, e.g. `{usage}`")
+        };
+
+        let (tokens, span) = match self.tokens.next() {
+            Some(Token::Group(Delimiter::Parenthesis, tokens, span)) => (tokens, span),
+            Some(token) => return Err(Error::new(token.span(), expected())),
+            None => return Err(Error::new(span, expected())),
+        };
+
+        let arguments = split(tokens)
+            .into_iter()
+            .map(|argument| match argument.is_empty() {
+                true => Err(Error::new(span, expected())),
+                false => elements(argument),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        arguments
+            .try_into()
+            .map_err(|_| Error::new(span, expected()))
+    }
+
+    /// Parse the rows of `table[[a, b], [c, d]]`.
+    fn table(&mut self, span: Span) -> Result<TokenStream, Error> {
+        let usage = "expected rows in brackets, e.g. `table[[a, b], [c, d]]`";
+
+        let rows = match self.tokens.next() {
+            Some(Token::Group(Delimiter::Bracket, tokens, _)) => tokens,
+            Some(token) => return Err(Error::new(token.span(), usage)),
+            None => return Err(Error::new(span, usage)),
+        };
+
+        let mut table_rows = Vec::new();
+
+        for mut row in split(rows) {
+            let cells = match (row.pop(), row.is_empty()) {
+                (Some(Token::Group(Delimiter::Bracket, cells, _)), true) => cells,
+                (Some(token), _) => return Err(Error::new(token.span(), usage)),
+                (None, _) => return Err(Error::new(span, usage)),
+            };
+
+            let mut table_cells = Vec::new();
+
+            for cell in split(cells) {
+                table_cells.push(call(
+                    "::alemat::elements::TableCell::with_content",
+                    array(elements(cell)?),
+                ));
+            }
+
+            table_rows.push(call(
+                "::alemat::elements::TableRow::from_iter",
+                list(table_cells),
+            ));
+        }
+
+        Ok(element(call(
+            "::alemat::elements::Table::from_iter",
+            list(table_rows),
+        )))
+    }
+}
+
+/// Split the tokens at top level commas, ignoring a trailing comma.
+fn split(tokens: Vec<Token>) -> Vec<Vec<Token>> {
+    let mut parts = vec![Vec::new()];
+
+    for token in tokens {
+        match token {
+            Token::Operator(op, _) if op == "," => parts.push(Vec::new()),
+            token => parts.last_mut().expect("parts are never empty").push(token),
+        }
+    }
+
+    if parts.len() > 1 && parts.last().is_some_and(Vec::is_empty) {
+        parts.pop();
+    }
+
+    parts
+}
+
+fn code(src: &str) -> TokenStream {
+    src.parse().expect("generated code is valid")
+}
+
+fn string(value: &str) -> TokenStream {
+    TokenStream::from(TokenTree::Literal(Literal::string(value)))
+}
+
+/// Create the call `func(args)`.
+fn call(func: &str, args: TokenStream) -> TokenStream {
+    let mut tokens = code(func);
+    tokens.extend([TokenTree::Group(Group::new(Delimiter::Parenthesis, args))]);
+    tokens
+}
+
+/// Create the array expression of the items, which may be of any type.
+fn list(items: Vec<TokenStream>) -> TokenStream {
+    let mut inner = TokenStream::new();
+
+    for item in items {
+        inner.extend(item);
+        inner.extend(code(","));
+    }
+
+    TokenStream::from(TokenTree::Group(Group::new(Delimiter::Bracket, inner)))
+}
+
+fn element(value: TokenStream) -> TokenStream {
+    call("::alemat::Element::from", value)
+}
+
+fn ident(name: &str) -> TokenStream {
+    element(call("::alemat::elements::Ident::from", string(name)))
+}
+
+fn operator(op: &str) -> TokenStream {
+    element(call("::alemat::elements::Operator::from", string(op)))
+}
+
+fn row(elements: Vec<TokenStream>) -> TokenStream {
+    element(call(
+        "::alemat::elements::grouping::Row::from",
+        array(elements),
+    ))
+}
+
+fn fenced(open: &str, content: Vec<TokenStream>, close: &str) -> TokenStream {
+    let elements = std::iter::once(operator(open))
+        .chain(content)
+        .chain(std::iter::once(operator(close)))
+        .collect();
+
+    row(elements)
+}
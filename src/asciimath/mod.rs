@@ -0,0 +1,101 @@
+//! Conversion of [AsciiMath](https://asciimath.org) markup into MathML elements.
+//!
+//! The parser follows the AsciiMath grammar: fractions (`a/b`) bind the expressions around them,
+//! sub- and superscripts are attached with `_` and `^`, and brackets used for grouping are removed
+//! from fractions, scripts and command arguments, e.g. `(a+b)/2` or `sqrt(x)`. Bracketed lists of
+//! equally long bracketed rows, such as `[[a,b],[c,d]]`, are turned into matrices.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{radicals::Radical, Ident, Num};
+//! use alemat::{asciimath, MathMl};
+//!
+//! let math = asciimath::parse("sqrt(x)").unwrap();
+//!
+//! let expected = MathMl::with_content([Radical::builder()
+//!     .index(Num::from(2))
+//!     .content(Ident::from("x"))
+//!     .build()]);
+//!
+//! assert_eq!(math, expected);
+//! ```
+
+mod parser;
+mod symbols;
+
+use std::ops::Range;
+
+use crate::{Elements, MathMl};
+
+/// Parse the given AsciiMath input into a [`MathMl`] element.
+pub fn parse(input: &str) -> Result<MathMl, AsciiMathError> {
+    parse_elements(input).map(MathMl::with_content)
+}
+
+/// Parse the given AsciiMath input into [`Elements`].
+pub fn parse_elements(input: &str) -> Result<Elements, AsciiMathError> {
+    parser::parse(input).map(Elements)
+}
+
+/// The kind of error that occurred while parsing AsciiMath input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AsciiMathErrorKind {
+    /// The command or script is missing its argument, e.g. `sqrt` at the end of the input.
+    MissingArgument(String),
+
+    /// Text started with `"` is never closed.
+    UnclosedText,
+
+    /// The bracket opening the argument of a command such as `text` is never closed.
+    UnclosedBracket,
+
+    /// The input is nested deeper than the given maximum depth.
+    NestingTooDeep(usize),
+}
+
+impl std::fmt::Display for AsciiMathErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AsciiMathErrorKind::MissingArgument(name) => {
+                write!(f, "missing argument for `{name}`")
+            }
+            AsciiMathErrorKind::UnclosedText => f.write_str("text is never closed"),
+            AsciiMathErrorKind::UnclosedBracket => f.write_str("bracket is never closed"),
+            AsciiMathErrorKind::NestingTooDeep(max) => {
+                write!(f, "nesting is deeper than the maximum of {max} levels")
+            }
+        }
+    }
+}
+
+/// Error that occurred while parsing AsciiMath input, pointing at the offending part of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsciiMathError {
+    kind: AsciiMathErrorKind,
+    span: Range<usize>,
+}
+
+impl AsciiMathError {
+    pub(crate) fn new(kind: AsciiMathErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// Get the kind of the error.
+    pub fn kind(&self) -> &AsciiMathErrorKind {
+        &self.kind
+    }
+
+    /// Get the byte range of the input that caused the error.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl std::fmt::Display for AsciiMathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for AsciiMathError {}
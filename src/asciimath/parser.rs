@@ -0,0 +1,695 @@
+//! Parser turning AsciiMath input into MathML elements.
+//!
+//! The grammar of AsciiMath is:
+//!
+//! ```text
+//! S ::= v | lEr | uS | bSS    simple expression
+//! I ::= S_S | S^S | S_S^S | S intermediate expression
+//! E ::= IE | I/I              expression
+//! ```
+//!
+//! where `v` are symbols, `l` and `r` are brackets, `u` are unary and `b` are binary commands.
+
+use std::ops::Range;
+
+use crate::{
+    attributes::{Attribute, MathVariant},
+    elements::{
+        grouping::{Row, Style},
+        radicals::Radical,
+        scripted::{SubSup, UnderOver, UnderOverAttr},
+        Frac, Ident, Num, Operator, OperatorAttr, Space, SpaceAttr, Table, TableCell, TableRow,
+        Text,
+    },
+    Element, Elements,
+};
+
+use super::{
+    symbols::{self, Binary, Token, Unary, MAX_SYMBOL_LEN},
+    AsciiMathError, AsciiMathErrorKind,
+};
+
+/// Parse the given AsciiMath input into a list of elements.
+pub(super) fn parse(input: &str) -> Result<Vec<Element>, AsciiMathError> {
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+        nesting: 0,
+        variant: None,
+    };
+
+    let nodes = parser.expression()?;
+    Ok(nodes.into_iter().map(Node::into_element).collect())
+}
+
+/// A parsed expression. Brackets are kept apart from the content, since they are removed when the
+/// expression is used as an argument, e.g. in `(a+b)/2`.
+enum Node {
+    Element(Element),
+    Group {
+        open: Option<Operator>,
+        content: Vec<Node>,
+        close: Option<Operator>,
+    },
+}
+
+impl Node {
+    fn into_element(self) -> Element {
+        match self {
+            Node::Element(element) => element,
+            Node::Group {
+                open,
+                content,
+                close,
+            } => {
+                let content = content.into_iter().map(Node::into_element);
+                let open = open.map(Element::from);
+                let close = close.map(Element::from);
+
+                Row::from(
+                    open.into_iter()
+                        .chain(content)
+                        .chain(close)
+                        .collect::<Elements>(),
+                )
+                .into()
+            }
+        }
+    }
+
+    /// Turn the node into an argument of a command or script, removing the grouping brackets.
+    fn into_argument(self) -> Vec<Element> {
+        let elements = match self {
+            Node::Element(element) => vec![element],
+            Node::Group { content, .. } => content.into_iter().map(Node::into_element).collect(),
+        };
+
+        if elements.is_empty() {
+            vec![Row::default().into()]
+        } else {
+            elements
+        }
+    }
+
+    fn is_comma(&self) -> bool {
+        matches!(self, Node::Element(Element::Operator(op)) if op.op() == ",")
+    }
+}
+
+impl<T: Into<Element>> From<T> for Node {
+    fn from(value: T) -> Self {
+        Node::Element(value.into())
+    }
+}
+
+/// A parsed simple expression together with information on how scripts are attached to it.
+struct Simple {
+    node: Node,
+
+    /// Scripts are placed under and over the expression instead of after it, e.g. for `sum`.
+    limits: bool,
+
+    /// The expression is a function name such as `sin`, which is followed by a function
+    /// application.
+    function: bool,
+}
+
+impl Simple {
+    fn new(node: impl Into<Node>) -> Self {
+        Self {
+            node: node.into(),
+            limits: false,
+            function: false,
+        }
+    }
+}
+
+/// Maximum number of simple expressions nested into each other, e.g. brackets and fraction parts.
+const MAX_DEPTH: usize = 256;
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+
+    /// Number of brackets currently open.
+    depth: usize,
+
+    /// Number of simple expressions currently being parsed, limited by [`MAX_DEPTH`].
+    nesting: usize,
+
+    /// Math variant set by the innermost font command, e.g. `bb`.
+    variant: Option<MathVariant>,
+}
+
+impl<'a> Parser<'a> {
+    /// Lex the next token without consuming it.
+    fn peek(&self) -> Option<(Token, Range<usize>)> {
+        let rest = &self.input[self.pos..];
+        let start = self.pos + (rest.len() - rest.trim_start().len());
+        let rest = &self.input[start..];
+
+        let first = rest.chars().next()?;
+
+        if first.is_ascii_digit() {
+            let digits = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+
+            let mut len = digits(rest);
+            if let Some(fraction) = rest[len..].strip_prefix('.') {
+                let fraction_len = digits(fraction);
+                if fraction_len > 0 {
+                    len += 1 + fraction_len;
+                }
+            }
+
+            let number = Token::Number(rest[..len].to_string());
+            return Some((number, start..start + len));
+        }
+
+        for len in (1..=rest.len().min(MAX_SYMBOL_LEN)).rev() {
+            if !rest.is_char_boundary(len) {
+                continue;
+            }
+
+            if let Some(token) = symbols::symbol(&rest[..len]) {
+                return Some((token, start..start + len));
+            }
+        }
+
+        let token = if first.is_alphabetic() {
+            Token::Ident(Ident::from(first.to_string()))
+        } else if first.is_numeric() {
+            Token::Number(first.to_string())
+        } else {
+            Token::Operator(Operator::from(first.to_string()))
+        };
+
+        Some((token, start..start + first.len_utf8()))
+    }
+
+    fn bump(&mut self) -> Option<(Token, Range<usize>)> {
+        let (token, span) = self.peek()?;
+        self.pos = span.end;
+        Some((token, span))
+    }
+
+    /// Whether the next token ends the current expression.
+    fn at_expression_end(&self) -> bool {
+        match self.peek() {
+            None => true,
+            Some((Token::RightBracket(_), _)) => self.depth > 0,
+            Some(_) => false,
+        }
+    }
+
+    fn missing_argument(&self, span: Range<usize>) -> AsciiMathError {
+        AsciiMathError::new(
+            AsciiMathErrorKind::MissingArgument(self.input[span.clone()].to_string()),
+            span,
+        )
+    }
+
+    /// Parse `E ::= IE | I/I` until the end of input or the closing bracket of the current group.
+    fn expression(&mut self) -> Result<Vec<Node>, AsciiMathError> {
+        let mut nodes = Vec::new();
+
+        while !self.at_expression_end() {
+            let (node, function) = self.intermediate()?;
+            nodes.push(self.fractions(node)?);
+
+            if function && !self.at_expression_end() {
+                nodes.push(Operator::from("\u{2061}").into());
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    /// Parse the denominators following the numerator, e.g. `b` and `c` in `a/b/c`.
+    fn fractions(&mut self, mut node: Node) -> Result<Node, AsciiMathError> {
+        while let Some((Token::Frac, span)) = self.peek() {
+            self.bump();
+
+            if self.at_expression_end() {
+                return Err(self.missing_argument(span));
+            }
+
+            let (denom, _) = self.intermediate()?;
+
+            node = Frac::builder()
+                .num(node.into_argument())
+                .denom(denom.into_argument())
+                .build()
+                .into();
+        }
+
+        Ok(node)
+    }
+
+    /// Parse `I ::= S_S | S^S | S_S^S | S`. Returns whether the expression is a function.
+    fn intermediate(&mut self) -> Result<(Node, bool), AsciiMathError> {
+        let simple = self.simple()?;
+        let sub = self.script(Token::Sub)?;
+        let sup = self.script(Token::Sup)?;
+        let function = simple.function;
+
+        Ok((scripted(simple, sub, sup), function))
+    }
+
+    /// Parse the argument of the script if the next token is the given script marker.
+    fn script(&mut self, marker: Token) -> Result<Option<Vec<Element>>, AsciiMathError> {
+        match self.peek() {
+            Some((token, span)) if token == marker => {
+                self.bump();
+                Ok(Some(self.argument(span)?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Parse the argument of the command or script at the given span. Grouping brackets of the
+    /// argument are removed.
+    fn argument(&mut self, span: Range<usize>) -> Result<Vec<Element>, AsciiMathError> {
+        if self.at_expression_end() {
+            return Err(self.missing_argument(span));
+        }
+
+        Ok(self.simple()?.node.into_argument())
+    }
+
+    /// Parse the bracketed argument of the command at the given span as raw text, e.g. `a b` in
+    /// `text(a b)`.
+    fn raw_argument(&mut self, span: Range<usize>) -> Result<&'a str, AsciiMathError> {
+        let rest = &self.input[self.pos..];
+        let start = self.pos + (rest.len() - rest.trim_start().len());
+
+        let (open, close) = match self.input[start..].chars().next() {
+            Some('(') => ('(', ')'),
+            Some('[') => ('[', ']'),
+            Some('{') => ('{', '}'),
+            _ => return Err(self.missing_argument(span)),
+        };
+
+        let mut depth = 0;
+
+        for (idx, c) in self.input[start..].char_indices() {
+            if c == open {
+                depth += 1;
+            } else if c == close {
+                depth -= 1;
+
+                if depth == 0 {
+                    self.pos = start + idx + 1;
+                    return Ok(&self.input[start + 1..start + idx]);
+                }
+            }
+        }
+
+        Err(AsciiMathError::new(
+            AsciiMathErrorKind::UnclosedBracket,
+            start..start + 1,
+        ))
+    }
+
+    /// Parse `S ::= v | lEr | uS | bSS`, failing if the input is nested too deeply.
+    fn simple(&mut self) -> Result<Simple, AsciiMathError> {
+        if self.nesting >= MAX_DEPTH {
+            let len = self.input.len();
+            let span = self.peek().map_or(len..len, |(_, span)| span);
+
+            return Err(AsciiMathError::new(
+                AsciiMathErrorKind::NestingTooDeep(MAX_DEPTH),
+                span,
+            ));
+        }
+
+        self.nesting += 1;
+        let simple = self.parse_simple();
+        self.nesting -= 1;
+        simple
+    }
+
+    fn parse_simple(&mut self) -> Result<Simple, AsciiMathError> {
+        let Some((token, span)) = self.bump() else {
+            let len = self.input.len();
+            return Err(AsciiMathError::new(
+                AsciiMathErrorKind::MissingArgument(String::new()),
+                len..len,
+            ));
+        };
+
+        // tokens with arguments are parsed in their own methods to keep the stack frame of this
+        // function small, as it is part of the recursion for nested arguments
+        match token {
+            Token::LeftBracket(open) => self.bracketed(open),
+            Token::Unary(unary) => self.unary(unary, span),
+            Token::Binary(binary) => self.binary(binary, span),
+            Token::Quote => self.quoted(span),
+            token => Ok(self.symbol(token)),
+        }
+    }
+
+    /// Create the simple expression of a token without arguments.
+    fn symbol(&self, token: Token) -> Simple {
+        match token {
+            Token::Number(num) => Simple::new(self.num(&num)),
+            Token::Ident(ident) => Simple::new(self.ident(ident)),
+            Token::Operator(op) => Simple::new(op),
+            Token::LargeOperator(op) => Simple {
+                limits: true,
+                ..Simple::new(op)
+            },
+            Token::Function(name, limits) => Simple {
+                limits,
+                function: true,
+                ..Simple::new(Ident::from(name))
+            },
+            Token::Text(text) => Simple::new(Text::from(text)),
+            Token::Space(width) => {
+                Simple::new(Space::default().with_attr([SpaceAttr::Width(width.into())]))
+            }
+            Token::RightBracket(close) => match close {
+                Some(op) => Simple::new(op),
+                None => Simple::new(Row::default()),
+            },
+            Token::Frac => Simple::new(Operator::solidus()),
+            Token::Sub => Simple::new(Operator::from("_")),
+            Token::Sup => Simple::new(Operator::hat()),
+            Token::LeftBracket(_) | Token::Unary(_) | Token::Binary(_) | Token::Quote => {
+                unreachable!("Tokens with arguments are parsed by the caller.")
+            }
+        }
+    }
+
+    /// Parse the content of the group opened by the given bracket, including the closing bracket.
+    fn bracketed(&mut self, open: Option<Operator>) -> Result<Simple, AsciiMathError> {
+        self.depth += 1;
+        let content = self.expression();
+        self.depth -= 1;
+        let content = content?;
+
+        let close = match self.peek() {
+            Some((Token::RightBracket(close), _)) => {
+                self.bump();
+                close
+            }
+            _ => None,
+        };
+
+        Ok(Simple::new(group(open, content, close)))
+    }
+
+    /// Parse the text following the opening quote at the given span.
+    fn quoted(&mut self, span: Range<usize>) -> Result<Simple, AsciiMathError> {
+        let Some(len) = self.input[span.end..].find('"') else {
+            return Err(AsciiMathError::new(AsciiMathErrorKind::UnclosedText, span));
+        };
+
+        let text = &self.input[span.end..span.end + len];
+        self.pos = span.end + len + 1;
+
+        Ok(Simple::new(self.text(text)))
+    }
+
+    fn unary(&mut self, unary: Unary, span: Range<usize>) -> Result<Simple, AsciiMathError> {
+        match unary {
+            Unary::Sqrt => {
+                let content = self.argument(span)?;
+                Ok(Simple::new(
+                    Radical::builder()
+                        .index(Num::from(2))
+                        .content(content)
+                        .build(),
+                ))
+            }
+            Unary::Over(op, stretchy) => self.accent(op, stretchy, false, span),
+            Unary::Under(op, stretchy) => self.accent(op, stretchy, true, span),
+            Unary::Font(variant) => self.font(variant, span),
+            Unary::Fenced(open, close) => {
+                let content = self.argument(span)?;
+                Ok(Simple::new(fenced(open, content, close)))
+            }
+            Unary::Text => {
+                let text = self.raw_argument(span)?;
+                Ok(Simple::new(self.text(text)))
+            }
+        }
+    }
+
+    fn accent(
+        &mut self,
+        op: Operator,
+        stretchy: bool,
+        under: bool,
+        span: Range<usize>,
+    ) -> Result<Simple, AsciiMathError> {
+        let name = &self.input[span.clone()];
+
+        // braces take their label as limits, e.g. `ubrace(a+b)_n`
+        let limits = matches!(name, "obrace" | "overbrace" | "ubrace" | "underbrace");
+
+        let content = self.argument(span)?;
+
+        let op = if stretchy {
+            Operator::builder()
+                .op(op.op())
+                .attr([OperatorAttr::Stretchy])
+                .build()
+        } else {
+            op
+        };
+
+        let builder = UnderOver::builder().expr(content);
+
+        let accent = if under {
+            builder
+                .under([op])
+                .attr([UnderOverAttr::AccentUnder])
+                .build()
+        } else {
+            builder.over([op]).attr([UnderOverAttr::AccentOver]).build()
+        };
+
+        Ok(Simple {
+            limits,
+            ..Simple::new(accent)
+        })
+    }
+
+    fn font(&mut self, variant: MathVariant, span: Range<usize>) -> Result<Simple, AsciiMathError> {
+        let outer = self.variant.replace(variant);
+        let content = self.argument(span);
+        self.variant = outer;
+
+        let mut content = content?;
+        if content.len() == 1 {
+            Ok(Simple::new(content.remove(0)))
+        } else {
+            Ok(Simple::new(Row::from(Elements(content))))
+        }
+    }
+
+    fn binary(&mut self, binary: Binary, span: Range<usize>) -> Result<Simple, AsciiMathError> {
+        if binary == Binary::Color {
+            return self.color(span);
+        }
+
+        let first = self.argument(span.clone())?;
+        let second = self.argument(span)?;
+
+        Ok(Simple::new(binary_element(binary, first, second)))
+    }
+
+    fn color(&mut self, span: Range<usize>) -> Result<Simple, AsciiMathError> {
+        let color = self.raw_argument(span.clone())?;
+        let content = self.argument(span)?;
+
+        Ok(Simple::new(
+            Style::from(Elements(content)).with_attr([Attribute::MathColor(color.trim().into())]),
+        ))
+    }
+
+    /// Apply the current math variant to the given identifier.
+    fn ident(&self, ident: Ident) -> Ident {
+        match &self.variant {
+            Some(variant) => Ident::builder()
+                .ident(ident.ident())
+                .attr([Attribute::MathVariant(variant.clone())])
+                .build(),
+            None => ident,
+        }
+    }
+
+    /// Create a number with the current math variant applied.
+    fn num(&self, num: &str) -> Num {
+        let num = Num::from(num);
+
+        match &self.variant {
+            Some(variant) => num.with_attr([Attribute::MathVariant(variant.clone())]),
+            None => num,
+        }
+    }
+
+    /// Create a text with the current math variant applied.
+    fn text(&self, text: &str) -> Text {
+        let text = Text::from(text);
+
+        match &self.variant {
+            Some(variant) => text.with_attr([Attribute::MathVariant(variant.clone())]),
+            None => text,
+        }
+    }
+}
+
+/// Attach the scripts to the simple expression, either as limits or after it.
+fn scripted(simple: Simple, sub: Option<Vec<Element>>, sup: Option<Vec<Element>>) -> Node {
+    if sub.is_none() && sup.is_none() {
+        return simple.node;
+    }
+
+    let base = [simple.node.into_element()];
+
+    match (sub, sup) {
+        (None, None) => unreachable!("Expressions without scripts are returned above."),
+        (Some(sub), None) if simple.limits => {
+            UnderOver::builder().expr(base).under(sub).build().into()
+        }
+        (None, Some(sup)) if simple.limits => {
+            UnderOver::builder().expr(base).over(sup).build().into()
+        }
+        (Some(sub), Some(sup)) if simple.limits => UnderOver::builder()
+            .expr(base)
+            .under(sub)
+            .over(sup)
+            .build()
+            .into(),
+        (Some(sub), None) => SubSup::builder().base(base).subscript(sub).build().into(),
+        (None, Some(sup)) => SubSup::builder().base(base).supscript(sup).build().into(),
+        (Some(sub), Some(sup)) => SubSup::builder()
+            .base(base)
+            .subscript(sub)
+            .supscript(sup)
+            .build()
+            .into(),
+    }
+}
+
+/// Create the element of a command taking two arguments other than `color`.
+fn binary_element(binary: Binary, first: Vec<Element>, second: Vec<Element>) -> Element {
+    match binary {
+        Binary::Frac => Frac::builder().num(first).denom(second).build().into(),
+        Binary::Root => Radical::builder()
+            .index(first)
+            .content(second)
+            .build()
+            .into(),
+        Binary::Overset => UnderOver::builder().expr(second).over(first).build().into(),
+        Binary::Underset => UnderOver::builder()
+            .expr(second)
+            .under(first)
+            .build()
+            .into(),
+        Binary::Color => unreachable!("Color is handled by the caller."),
+    }
+}
+
+/// Create the row of the content between the brackets of a command such as `abs`.
+fn fenced(open: Operator, content: Vec<Element>, close: Operator) -> Row {
+    Row::from(
+        std::iter::once(open.into())
+            .chain(content)
+            .chain(std::iter::once(close.into()))
+            .collect::<Elements>(),
+    )
+}
+
+/// Create the node for the bracketed content, detecting matrices such as `[[a,b],[c,d]]`.
+fn group(open: Option<Operator>, content: Vec<Node>, close: Option<Operator>) -> Node {
+    if !is_matrix(&content) {
+        return Node::Group {
+            open,
+            content,
+            close,
+        };
+    }
+
+    let table = content
+        .into_iter()
+        .step_by(2)
+        .map(|row| {
+            let Node::Group { content, .. } = row else {
+                unreachable!("Matrix rows are groups.");
+            };
+
+            let mut cells = vec![Vec::new()];
+
+            for node in content {
+                if node.is_comma() {
+                    cells.push(Vec::new());
+                } else if let Some(cell) = cells.last_mut() {
+                    cell.push(node.into_element());
+                }
+            }
+
+            cells
+                .into_iter()
+                .map(|cell| TableCell::from(Elements(cell)))
+                .collect::<TableRow>()
+        })
+        .collect::<Table>();
+
+    if open.is_none() && close.is_none() {
+        return table.into();
+    }
+
+    let open = open.map(Element::from);
+    let close = close.map(Element::from);
+
+    Row::from(
+        open.into_iter()
+            .chain(std::iter::once(table.into()))
+            .chain(close)
+            .collect::<Elements>(),
+    )
+    .into()
+}
+
+/// A matrix is a comma separated list of at least two rows, where each row is a group in `()` or
+/// `[]` brackets with the same number of comma separated cells.
+fn is_matrix(content: &[Node]) -> bool {
+    if content.len() < 3 || content.len().is_multiple_of(2) {
+        return false;
+    }
+
+    let mut columns = None;
+
+    for (idx, node) in content.iter().enumerate() {
+        if idx % 2 == 1 {
+            if !node.is_comma() {
+                return false;
+            }
+
+            continue;
+        }
+
+        let Node::Group {
+            open: Some(open),
+            content: row,
+            close: Some(_),
+        } = node
+        else {
+            return false;
+        };
+
+        if !matches!(open.op(), "(" | "[") {
+            return false;
+        }
+
+        let cells = row.iter().filter(|node| node.is_comma()).count() + 1;
+        if *columns.get_or_insert(cells) != cells {
+            return false;
+        }
+    }
+
+    true
+}
@@ -0,0 +1,343 @@
+//! Table mapping AsciiMath symbols to tokens.
+
+use crate::{
+    attributes::MathVariant,
+    elements::{Ident, Operator},
+};
+
+/// Length (in bytes) of the longest symbol in the table.
+pub(super) const MAX_SYMBOL_LEN: usize = 17;
+
+/// A token of AsciiMath input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Token {
+    /// A number, e.g. `12.5`.
+    Number(String),
+
+    /// An identifier, e.g. `x` or `alpha`.
+    Ident(Ident),
+
+    /// An operator, relation or arrow, e.g. `+` or `->`.
+    Operator(Operator),
+
+    /// An operator whose scripts are placed under and over it, e.g. `sum`.
+    LargeOperator(Operator),
+
+    /// A function name, e.g. `sin`. The `bool` is `true` if the scripts of the function are placed
+    /// under and over it, e.g. for `lim`.
+    Function(&'static str, bool),
+
+    /// Text that is rendered as is, e.g. `and`.
+    Text(&'static str),
+
+    /// A space with the given width, e.g. `quad`.
+    Space(&'static str),
+
+    /// An opening bracket, `None` for the invisible bracket `{:`.
+    LeftBracket(Option<Operator>),
+
+    /// A closing bracket, `None` for the invisible bracket `:}`.
+    RightBracket(Option<Operator>),
+
+    /// A command taking one argument, e.g. `sqrt`.
+    Unary(Unary),
+
+    /// A command taking two arguments, e.g. `frac`.
+    Binary(Binary),
+
+    /// `/`
+    Frac,
+
+    /// `_`
+    Sub,
+
+    /// `^`
+    Sup,
+
+    /// `"`, starting or ending text.
+    Quote,
+}
+
+/// Commands taking one argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Unary {
+    /// `sqrt`
+    Sqrt,
+
+    /// Accent placed over the argument, e.g. `hat`. The `bool` is `true` if the accent stretches
+    /// over the whole argument.
+    Over(Operator, bool),
+
+    /// Accent placed under the argument, e.g. `ul`. The `bool` is `true` if the accent stretches
+    /// over the whole argument.
+    Under(Operator, bool),
+
+    /// Font commands, e.g. `bb`.
+    Font(MathVariant),
+
+    /// Argument surrounded with the given operators, e.g. `abs`.
+    Fenced(Operator, Operator),
+
+    /// `text` and `mbox`, the argument is taken as is.
+    Text,
+}
+
+/// Commands taking two arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Binary {
+    /// `frac`
+    Frac,
+
+    /// `root`, the first argument is the index.
+    Root,
+
+    /// `overset` and `stackrel`, the first argument is placed over the second one.
+    Overset,
+
+    /// `underset`, the first argument is placed under the second one.
+    Underset,
+
+    /// `color`, the first argument is the color of the second one.
+    Color,
+}
+
+/// Look up the given symbol.
+pub(super) fn symbol(name: &str) -> Option<Token> {
+    let token = match name {
+        // operators
+        "+" => Token::Operator(Operator::plus()),
+        "-" => Token::Operator(Operator::minus()),
+        "*" | "cdot" => Token::Operator(Operator::dot()),
+        "**" | "ast" => Token::Operator(Operator::asterisk()),
+        "***" | "star" => Token::Operator(Operator::star()),
+        "//" => Token::Operator(Operator::solidus()),
+        "\\\\" | "backslash" | "setminus" => Token::Operator(Operator::set_minus()),
+        "xx" | "times" => Token::Operator(Operator::mult()),
+        "|><" | "ltimes" => Token::Operator(Operator::lfactor()),
+        "><|" | "rtimes" => Token::Operator(Operator::rfactor()),
+        "|><|" | "bowtie" => Token::Operator(Operator::bowtie()),
+        "-:" | "div" => Token::Operator(Operator::div()),
+        "@" | "circ" => Token::Operator(Operator::ring()),
+        "o+" | "oplus" => Token::Operator(Operator::circle_plus()),
+        "ox" | "otimes" => Token::Operator(Operator::circle_times()),
+        "o." | "odot" => Token::Operator(Operator::circle_dot()),
+        "^^" | "wedge" => Token::Operator(Operator::wedge()),
+        "vv" | "vee" => Token::Operator(Operator::vee()),
+        "nn" | "cap" => Token::Operator(Operator::cap()),
+        "uu" | "cup" => Token::Operator(Operator::cup()),
+        "+-" | "pm" => Token::Operator(Operator::plus_minus()),
+        "-+" | "mp" => Token::Operator(Operator::from("\u{2213}")),
+        "int" => Token::Operator(Operator::integral()),
+        "oint" => Token::Operator(Operator::circle_integral()),
+        "del" | "partial" => Token::Operator(Operator::partial_diff()),
+        "grad" | "nabla" => Token::Operator(Operator::nabla()),
+        ":." | "therefore" => Token::Operator(Operator::therefore()),
+        ":'" | "because" => Token::Operator(Operator::because()),
+        "/_" | "angle" => Token::Operator(Operator::angle()),
+        "/_\\" | "triangle" => Token::Operator(Operator::from("\u{25B3}")),
+        "'" => Token::Operator(Operator::from("\u{2032}")),
+        "..." | "ldots" => Token::Operator(Operator::from("\u{2026}")),
+        "cdots" => Token::Operator(Operator::from("\u{22EF}")),
+        "vdots" => Token::Operator(Operator::from("\u{22EE}")),
+        "ddots" => Token::Operator(Operator::from("\u{22F1}")),
+        "diamond" => Token::Operator(Operator::from("\u{22C4}")),
+        "square" => Token::Operator(Operator::from("\u{25A1}")),
+        "|__" | "lfloor" => Token::Operator(Operator::lfloor()),
+        "__|" | "rfloor" => Token::Operator(Operator::rfloor()),
+        "|~" | "lceiling" => Token::Operator(Operator::lceiling()),
+        "~|" | "rceiling" => Token::Operator(Operator::rceiling()),
+        "|" => Token::Operator(Operator::vert_bar()),
+        "||" => Token::Operator(Operator::norm()),
+        "," => Token::Operator(Operator::from(",")),
+
+        // relations
+        "=" => Token::Operator(Operator::eq()),
+        "!=" | "ne" => Token::Operator(Operator::not_eq()),
+        ":=" => Token::Operator(Operator::assign()),
+        "<" | "lt" => Token::Operator(Operator::lt()),
+        ">" | "gt" => Token::Operator(Operator::gt()),
+        "<=" | "le" => Token::Operator(Operator::le()),
+        ">=" | "ge" => Token::Operator(Operator::ge()),
+        "mlt" => Token::Operator(Operator::from("\u{226A}")),
+        "mgt" => Token::Operator(Operator::from("\u{226B}")),
+        "-<" | "prec" => Token::Operator(Operator::prec()),
+        ">-" | "succ" => Token::Operator(Operator::succ()),
+        "-<=" | "preceq" => Token::Operator(Operator::preceq()),
+        ">-=" | "succeq" => Token::Operator(Operator::succeq()),
+        "in" => Token::Operator(Operator::in_set()),
+        "!in" | "notin" => Token::Operator(Operator::not_in_set()),
+        "sub" | "subset" => Token::Operator(Operator::subset()),
+        "sup" | "supset" => Token::Operator(Operator::supset()),
+        "sube" | "subseteq" => Token::Operator(Operator::subseteq()),
+        "supe" | "supseteq" => Token::Operator(Operator::supseteq()),
+        "-=" | "equiv" => Token::Operator(Operator::equivalent()),
+        "~=" | "cong" => Token::Operator(Operator::congruent()),
+        "~~" | "approx" => Token::Operator(Operator::approx()),
+        "~" | "sim" => Token::Operator(Operator::from("\u{223C}")),
+        "prop" | "propto" => Token::Operator(Operator::propto()),
+
+        // logic
+        "and" => Token::Text(" and "),
+        "or" => Token::Text(" or "),
+        "if" => Token::Text(" if "),
+        "not" | "neg" => Token::Operator(Operator::not()),
+        "=>" | "implies" | "rArr" => Token::Operator(Operator::implies()),
+        "<=>" | "iff" | "hArr" => Token::Operator(Operator::iff()),
+        "lArr" => Token::Operator(Operator::log_if()),
+        "AA" | "forall" => Token::Operator(Operator::forall()),
+        "EE" | "exists" => Token::Operator(Operator::exists()),
+        "_|_" | "bot" => Token::Operator(Operator::bottom()),
+        "TT" | "top" => Token::Operator(Operator::top()),
+        "|--" | "vdash" => Token::Operator(Operator::vdash()),
+        "|==" | "models" => Token::Operator(Operator::models()),
+
+        // arrows
+        "uarr" | "uparrow" => Token::Operator(Operator::from("\u{2191}")),
+        "darr" | "downarrow" => Token::Operator(Operator::from("\u{2193}")),
+        "rarr" | "->" | "to" | "rightarrow" => Token::Operator(Operator::rarrow()),
+        "larr" | "leftarrow" => Token::Operator(Operator::larrow()),
+        "harr" | "leftrightarrow" => Token::Operator(Operator::from("\u{2194}")),
+        "|->" | "mapsto" => Token::Operator(Operator::from("\u{21A6}")),
+        ">->" | "rightarrowtail" => Token::Operator(Operator::from("\u{21A3}")),
+        "->>" | "twoheadrightarrow" => Token::Operator(Operator::from("\u{21A0}")),
+        ">->>" => Token::Operator(Operator::from("\u{2916}")),
+
+        // large operators
+        "sum" => Token::LargeOperator(Operator::sum()),
+        "prod" => Token::LargeOperator(Operator::prod()),
+        "^^^" | "bigwedge" => Token::LargeOperator(Operator::big_wedge()),
+        "vvv" | "bigvee" => Token::LargeOperator(Operator::big_vee()),
+        "nnn" | "bigcap" => Token::LargeOperator(Operator::big_cap()),
+        "uuu" | "bigcup" => Token::LargeOperator(Operator::big_cup()),
+
+        // brackets
+        "(" => Token::LeftBracket(Some(Operator::lparens())),
+        ")" => Token::RightBracket(Some(Operator::rparens())),
+        "[" => Token::LeftBracket(Some(Operator::lbracket())),
+        "]" => Token::RightBracket(Some(Operator::rbracket())),
+        "{" => Token::LeftBracket(Some(Operator::lbrace())),
+        "}" => Token::RightBracket(Some(Operator::rbrace())),
+        "(:" | "<<" | "langle" => Token::LeftBracket(Some(Operator::langle())),
+        ":)" | ">>" | "rangle" => Token::RightBracket(Some(Operator::rangle())),
+        "{:" => Token::LeftBracket(None),
+        ":}" => Token::RightBracket(None),
+
+        // identifiers
+        "alpha" => Token::Ident(Ident::alpha()),
+        "beta" => Token::Ident(Ident::beta()),
+        "gamma" => Token::Ident(Ident::gamma()),
+        "Gamma" => Token::Ident(Ident::big_gamma()),
+        "delta" => Token::Ident(Ident::delta()),
+        "Delta" => Token::Ident(Ident::big_delta()),
+        "epsilon" | "epsi" => Token::Ident(Ident::epsilon()),
+        "varepsilon" => Token::Ident(Ident::varepsilon()),
+        "zeta" => Token::Ident(Ident::zeta()),
+        "eta" => Token::Ident(Ident::eta()),
+        "theta" => Token::Ident(Ident::theta()),
+        "vartheta" => Token::Ident(Ident::vartheta()),
+        "Theta" => Token::Ident(Ident::big_theta()),
+        "iota" => Token::Ident(Ident::iota()),
+        "kappa" => Token::Ident(Ident::kappa()),
+        "lambda" | "lamda" => Token::Ident(Ident::lambda()),
+        "Lambda" | "Lamda" => Token::Ident(Ident::big_lambda()),
+        "mu" => Token::Ident(Ident::mu()),
+        "nu" => Token::Ident(Ident::nu()),
+        "xi" => Token::Ident(Ident::xi()),
+        "Xi" => Token::Ident(Ident::big_xi()),
+        "pi" => Token::Ident(Ident::pi()),
+        "Pi" => Token::Ident(Ident::big_pi()),
+        "rho" => Token::Ident(Ident::rho()),
+        "sigma" => Token::Ident(Ident::sigma()),
+        "Sigma" => Token::Ident(Ident::big_sigma()),
+        "tau" => Token::Ident(Ident::tau()),
+        "upsilon" => Token::Ident(Ident::upsilon()),
+        "phi" => Token::Ident(Ident::phi()),
+        "varphi" => Token::Ident(Ident::varphi()),
+        "Phi" => Token::Ident(Ident::big_phi()),
+        "chi" => Token::Ident(Ident::chi()),
+        "psi" => Token::Ident(Ident::psi()),
+        "Psi" => Token::Ident(Ident::big_psi()),
+        "omega" => Token::Ident(Ident::omega()),
+        "Omega" => Token::Ident(Ident::big_omega()),
+        "oo" | "infty" => Token::Ident(Ident::infinity()),
+        "O/" | "emptyset" => Token::Ident(Ident::empty_set()),
+        "aleph" => Token::Ident(Ident::aleph()),
+        "NN" => Token::Ident(Ident::set_natural()),
+        "ZZ" => Token::Ident(Ident::set_integer()),
+        "QQ" => Token::Ident(Ident::set_rational()),
+        "RR" => Token::Ident(Ident::set_irrational()),
+        "CC" => Token::Ident(Ident::set_complex()),
+
+        // functions
+        "sin" => Token::Function("sin", false),
+        "cos" => Token::Function("cos", false),
+        "tan" => Token::Function("tan", false),
+        "sec" => Token::Function("sec", false),
+        "csc" => Token::Function("csc", false),
+        "cot" => Token::Function("cot", false),
+        "arcsin" => Token::Function("arcsin", false),
+        "arccos" => Token::Function("arccos", false),
+        "arctan" => Token::Function("arctan", false),
+        "sinh" => Token::Function("sinh", false),
+        "cosh" => Token::Function("cosh", false),
+        "tanh" => Token::Function("tanh", false),
+        "sech" => Token::Function("sech", false),
+        "csch" => Token::Function("csch", false),
+        "coth" => Token::Function("coth", false),
+        "exp" => Token::Function("exp", false),
+        "log" => Token::Function("log", false),
+        "ln" => Token::Function("ln", false),
+        "det" => Token::Function("det", false),
+        "dim" => Token::Function("dim", false),
+        "mod" => Token::Function("mod", false),
+        "gcd" => Token::Function("gcd", false),
+        "lcm" => Token::Function("lcm", false),
+        "lub" => Token::Function("lub", false),
+        "glb" => Token::Function("glb", false),
+        "min" => Token::Function("min", true),
+        "max" => Token::Function("max", true),
+        "lim" => Token::Function("lim", true),
+        "Lim" => Token::Function("Lim", true),
+
+        // spaces
+        "\\ " => Token::Space("0.25em"),
+        "quad" => Token::Space("1em"),
+        "qquad" => Token::Space("2em"),
+
+        // commands
+        "sqrt" => Token::Unary(Unary::Sqrt),
+        "hat" => Token::Unary(Unary::Over(Operator::hat(), false)),
+        "bar" | "overline" => Token::Unary(Unary::Over(Operator::bar(), true)),
+        "vec" => Token::Unary(Unary::Over(Operator::rarrow(), false)),
+        "tilde" => Token::Unary(Unary::Over(Operator::tilde(), false)),
+        "dot" => Token::Unary(Unary::Over(Operator::from("\u{02D9}"), false)),
+        "ddot" => Token::Unary(Unary::Over(Operator::double_dot(), false)),
+        "obrace" | "overbrace" => Token::Unary(Unary::Over(Operator::obrace(), true)),
+        "ul" | "underline" => Token::Unary(Unary::Under(Operator::bar(), true)),
+        "ubrace" | "underbrace" => Token::Unary(Unary::Under(Operator::ubrace(), true)),
+        "abs" => Token::Unary(Unary::Fenced(Operator::vert_bar(), Operator::vert_bar())),
+        "norm" => Token::Unary(Unary::Fenced(Operator::norm(), Operator::norm())),
+        "floor" => Token::Unary(Unary::Fenced(Operator::lfloor(), Operator::rfloor())),
+        "ceil" => Token::Unary(Unary::Fenced(Operator::lceiling(), Operator::rceiling())),
+        "bb" | "mathbf" => Token::Unary(Unary::Font(MathVariant::Bold)),
+        "bbb" | "mathbb" => Token::Unary(Unary::Font(MathVariant::DoubleStruck)),
+        "cc" | "mathcal" => Token::Unary(Unary::Font(MathVariant::Script)),
+        "tt" | "mathtt" => Token::Unary(Unary::Font(MathVariant::Monospace)),
+        "fr" | "mathfrak" => Token::Unary(Unary::Font(MathVariant::Fraktur)),
+        "sf" | "mathsf" => Token::Unary(Unary::Font(MathVariant::SansSerif)),
+        "text" | "mbox" => Token::Unary(Unary::Text),
+        "frac" => Token::Binary(Binary::Frac),
+        "root" => Token::Binary(Binary::Root),
+        "stackrel" | "overset" => Token::Binary(Binary::Overset),
+        "underset" => Token::Binary(Binary::Underset),
+        "color" => Token::Binary(Binary::Color),
+
+        "/" => Token::Frac,
+        "_" => Token::Sub,
+        "^" => Token::Sup,
+        "\"" => Token::Quote,
+        _ => return None,
+    };
+
+    Some(token)
+}
@@ -2,6 +2,12 @@
 
 use std::fmt::Display;
 
+mod color;
+mod length;
+
+pub use color::*;
+pub use length::*;
+
 /// Direction for [`Attribute::Dir`].
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Dir {
@@ -23,7 +29,7 @@ pub enum ScriptLevel {
 }
 
 /// MathVariant for [`Attribute::MathVariant`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum MathVariant {
     /// Normal variant.
     Normal,
@@ -125,7 +131,8 @@ pub enum Attribute {
     MathColor(String),
 
     /// Presentational hint for the font size of the element. Must be a value that is
-    /// [length-percentage](https://www.w3.org/TR/css-values-4/#typedef-length-percentage).
+    /// [length-percentage](https://www.w3.org/TR/css-values-4/#typedef-length-percentage), see
+    /// [`Length`].
     MathSize(String),
 
     /// The `mathvariant` attribute, if present, must be an ASCII case-insensitive match to one of
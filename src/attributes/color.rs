@@ -0,0 +1,761 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+/// Names and `0xRRGGBB` values of the CSS named colors.
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+/// One of the CSS named colors, e.g. `red` or `rebeccapurple`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NamedColor(usize);
+
+impl NamedColor {
+    /// Find the named color with the given name. Names are matched ASCII case-insensitively.
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COLORS
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(Self)
+    }
+
+    /// Get the lowercase name of the color.
+    pub fn name(&self) -> &'static str {
+        NAMED_COLORS[self.0].0
+    }
+
+    /// Get the red, green and blue components of the color.
+    pub fn rgb(&self) -> [u8; 3] {
+        let [_, r, g, b] = NAMED_COLORS[self.0].1.to_be_bytes();
+        [r, g, b]
+    }
+}
+
+impl Display for NamedColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Color with red, green, blue and alpha components, each in range `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba {
+    /// Red component.
+    pub red: f64,
+    /// Green component.
+    pub green: f64,
+    /// Blue component.
+    pub blue: f64,
+    /// Alpha component, `1.0` is fully opaque.
+    pub alpha: f64,
+}
+
+impl Rgba {
+    /// Relative luminance of the color as defined by
+    /// [WCAG](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance), ignoring the alpha component.
+    pub fn relative_luminance(&self) -> f64 {
+        let linear = |c: f64| {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linear(self.red) + 0.7152 * linear(self.green) + 0.0722 * linear(self.blue)
+    }
+
+    /// Composite the color over the given opaque background.
+    fn over(&self, background: &Rgba) -> Rgba {
+        let mix = |fg: f64, bg: f64| fg * self.alpha + bg * (1.0 - self.alpha);
+
+        Rgba {
+            red: mix(self.red, background.red),
+            green: mix(self.green, background.green),
+            blue: mix(self.blue, background.blue),
+            alpha: 1.0,
+        }
+    }
+}
+
+/// Color value of the [`Attribute::MathColor`] and [`Attribute::MathBackground`] attributes.
+///
+/// Colors can be created using the constructors (e.g. [`Color::rgb`]), parsed using [`FromStr`]
+/// or converted from strings. Parsing is the checked path and fails for invalid colors like
+/// `"#ff000"`. Converting a string with [`From`] never fails: values that are not understood are
+/// kept as [`Color::Other`], the escape hatch for CSS the parser doesn't support, e.g.
+/// `var(--color)`. Such values are reported by [`validate`](crate::validate) as
+/// [`DiagnosticKind::InvalidColor`]. Colors are converted into the string values of the
+/// attributes with [`Into`].
+///
+/// # Example
+///
+/// ```
+/// use alemat::attributes::{Attribute, Color};
+///
+/// assert_eq!("#ff0000".parse(), Ok(Color::hex(0xff0000)));
+/// assert_eq!("rgb(0, 128, 0)".parse(), Ok(Color::rgb(0, 128, 0)));
+/// assert!("#ff000".parse::<Color>().is_err());
+///
+/// let ratio = Color::named("black").unwrap().contrast_ratio(&Color::rgb(255, 255, 255));
+/// assert_eq!(ratio, Some(21.0));
+///
+/// let color = Attribute::MathColor(Color::rgb(255, 0, 0).into());
+/// assert_eq!(color, Attribute::MathColor("rgb(255, 0, 0)".to_string()));
+/// ```
+///
+/// [`Attribute::MathColor`]: crate::attributes::Attribute::MathColor
+/// [`Attribute::MathBackground`]: crate::attributes::Attribute::MathBackground
+/// [`DiagnosticKind::InvalidColor`]: crate::validate::DiagnosticKind::InvalidColor
+#[derive(Debug, Clone)]
+pub enum Color {
+    /// One of the CSS named colors, e.g. `red`.
+    Named(NamedColor),
+
+    /// Hexadecimal color, e.g. `#ff8800` or `#ff880080`. Written with 6 or 8 digits.
+    Hex {
+        /// Red component.
+        red: u8,
+        /// Green component.
+        green: u8,
+        /// Blue component.
+        blue: u8,
+        /// Alpha component, if present.
+        alpha: Option<u8>,
+    },
+
+    /// Color created by the `rgb()` or `rgba()` function, e.g. `rgb(255, 136, 0)`.
+    Rgb {
+        /// Red component in range `0.0..=255.0`.
+        red: f64,
+        /// Green component in range `0.0..=255.0`.
+        green: f64,
+        /// Blue component in range `0.0..=255.0`.
+        blue: f64,
+        /// Alpha component in range `0.0..=1.0`, if present.
+        alpha: Option<f64>,
+    },
+
+    /// Color created by the `hsl()` or `hsla()` function, e.g. `hsl(120, 100%, 50%)`.
+    Hsl {
+        /// Hue in degrees.
+        hue: f64,
+        /// Saturation in percent.
+        saturation: f64,
+        /// Lightness in percent.
+        lightness: f64,
+        /// Alpha component in range `0.0..=1.0`, if present.
+        alpha: Option<f64>,
+    },
+
+    /// The `currentColor` keyword.
+    CurrentColor,
+
+    /// The `transparent` keyword.
+    Transparent,
+
+    /// Any other value, which is written as it is. Only created by the unchecked conversion from
+    /// strings or directly, never by parsing.
+    Other(String),
+}
+
+impl Color {
+    /// Create a new [`Color::Named`] from the name of a CSS named color. Returns `None` if
+    /// there is no color with the given name.
+    pub fn named(name: &str) -> Option<Self> {
+        NamedColor::from_name(name).map(Self::Named)
+    }
+
+    /// Create a new [`Color::Hex`] from a `0xRRGGBB` value.
+    pub fn hex(rgb: u32) -> Self {
+        let [_, red, green, blue] = rgb.to_be_bytes();
+
+        Self::Hex {
+            red,
+            green,
+            blue,
+            alpha: None,
+        }
+    }
+
+    /// Create a new [`Color::Rgb`] without alpha component.
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::Rgb {
+            red: red.into(),
+            green: green.into(),
+            blue: blue.into(),
+            alpha: None,
+        }
+    }
+
+    /// Create a new [`Color::Rgb`] with alpha component in range `0.0..=1.0`.
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f64) -> Self {
+        Self::Rgb {
+            red: red.into(),
+            green: green.into(),
+            blue: blue.into(),
+            alpha: Some(alpha),
+        }
+    }
+
+    /// Create a new [`Color::Hsl`] without alpha component, with hue in degrees and saturation
+    /// and lightness in percent.
+    pub fn hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self::Hsl {
+            hue,
+            saturation,
+            lightness,
+            alpha: None,
+        }
+    }
+
+    /// Convert the color into its [`Rgba`] components. Returns `None` for
+    /// [`Color::CurrentColor`] and [`Color::Other`], whose components are unknown.
+    pub fn to_rgba(&self) -> Option<Rgba> {
+        let clamp = |c: f64| c.clamp(0.0, 1.0);
+
+        let rgba = match self {
+            Color::Named(named) => {
+                let [red, green, blue] = named.rgb();
+                Rgba {
+                    red: f64::from(red) / 255.0,
+                    green: f64::from(green) / 255.0,
+                    blue: f64::from(blue) / 255.0,
+                    alpha: 1.0,
+                }
+            }
+            Color::Hex {
+                red,
+                green,
+                blue,
+                alpha,
+            } => Rgba {
+                red: f64::from(*red) / 255.0,
+                green: f64::from(*green) / 255.0,
+                blue: f64::from(*blue) / 255.0,
+                alpha: alpha.map_or(1.0, |a| f64::from(a) / 255.0),
+            },
+            Color::Rgb {
+                red,
+                green,
+                blue,
+                alpha,
+            } => Rgba {
+                red: clamp(red / 255.0),
+                green: clamp(green / 255.0),
+                blue: clamp(blue / 255.0),
+                alpha: clamp(alpha.unwrap_or(1.0)),
+            },
+            Color::Hsl {
+                hue,
+                saturation,
+                lightness,
+                alpha,
+            } => {
+                let (red, green, blue) = hsl_to_rgb(
+                    hue.rem_euclid(360.0),
+                    clamp(saturation / 100.0),
+                    clamp(lightness / 100.0),
+                );
+
+                Rgba {
+                    red,
+                    green,
+                    blue,
+                    alpha: clamp(alpha.unwrap_or(1.0)),
+                }
+            }
+            Color::Transparent => Rgba {
+                red: 0.0,
+                green: 0.0,
+                blue: 0.0,
+                alpha: 0.0,
+            },
+            Color::CurrentColor | Color::Other(_) => return None,
+        };
+
+        Some(rgba)
+    }
+
+    /// Contrast ratio between this color as foreground and the given background color as
+    /// defined by [WCAG](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio), in range
+    /// `1.0..=21.0`. WCAG requires a ratio of at least `4.5` for normal text.
+    ///
+    /// A translucent foreground is composited over the background, and a translucent background
+    /// over white. Returns `None` if the components of either color are unknown.
+    pub fn contrast_ratio(&self, background: &Color) -> Option<f64> {
+        let white = Rgba {
+            red: 1.0,
+            green: 1.0,
+            blue: 1.0,
+            alpha: 1.0,
+        };
+
+        let background = background.to_rgba()?.over(&white);
+        let foreground = self.to_rgba()?.over(&background);
+
+        let fg = foreground.relative_luminance();
+        let bg = background.relative_luminance();
+
+        Some((fg.max(bg) + 0.05) / (fg.min(bg) + 0.05))
+    }
+
+    fn variant(&self) -> u8 {
+        match self {
+            Color::Named(_) => 0,
+            Color::Hex { .. } => 1,
+            Color::Rgb { .. } => 2,
+            Color::Hsl { .. } => 3,
+            Color::CurrentColor => 4,
+            Color::Transparent => 5,
+            Color::Other(_) => 6,
+        }
+    }
+
+    /// Components of the functional notations, used for comparisons.
+    fn components(&self) -> Option<([f64; 3], Option<f64>)> {
+        match self {
+            Color::Rgb {
+                red,
+                green,
+                blue,
+                alpha,
+            } => Some(([*red, *green, *blue], *alpha)),
+            Color::Hsl {
+                hue,
+                saturation,
+                lightness,
+                alpha,
+            } => Some(([*hue, *saturation, *lightness], *alpha)),
+            _ => None,
+        }
+    }
+}
+
+/// Convert HSL with hue in degrees and saturation and lightness in range `0.0..=1.0` to RGB.
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (f64, f64, f64) {
+    let f = |n: f64| {
+        let k = (n + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    (f(0.0), f(8.0), f(4.0))
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::Named(named) => write!(f, "{named}"),
+            Color::Hex {
+                red,
+                green,
+                blue,
+                alpha,
+            } => {
+                write!(f, "#{red:02x}{green:02x}{blue:02x}")?;
+
+                match alpha {
+                    Some(alpha) => write!(f, "{alpha:02x}"),
+                    None => Ok(()),
+                }
+            }
+            Color::Rgb {
+                red,
+                green,
+                blue,
+                alpha: Some(alpha),
+            } => write!(f, "rgba({red}, {green}, {blue}, {alpha})"),
+            Color::Rgb {
+                red, green, blue, ..
+            } => write!(f, "rgb({red}, {green}, {blue})"),
+            Color::Hsl {
+                hue,
+                saturation,
+                lightness,
+                alpha: Some(alpha),
+            } => write!(f, "hsla({hue}, {saturation}%, {lightness}%, {alpha})"),
+            Color::Hsl {
+                hue,
+                saturation,
+                lightness,
+                ..
+            } => write!(f, "hsl({hue}, {saturation}%, {lightness}%)"),
+            Color::CurrentColor => f.write_str("currentColor"),
+            Color::Transparent => f.write_str("transparent"),
+            Color::Other(other) => f.write_str(other),
+        }
+    }
+}
+
+impl PartialEq for Color {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Color {}
+
+impl PartialOrd for Color {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Color {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let variants = self.variant().cmp(&other.variant());
+        if variants.is_ne() {
+            return variants;
+        }
+
+        match (self, other) {
+            (Color::Named(a), Color::Named(b)) => a.cmp(b),
+            (
+                Color::Hex {
+                    red,
+                    green,
+                    blue,
+                    alpha,
+                },
+                Color::Hex {
+                    red: r,
+                    green: g,
+                    blue: b,
+                    alpha: a,
+                },
+            ) => (red, green, blue, alpha).cmp(&(r, g, b, a)),
+            (Color::Other(a), Color::Other(b)) => a.cmp(b),
+            _ => match (self.components(), other.components()) {
+                (Some((a, a_alpha)), Some((b, b_alpha))) => a
+                    .iter()
+                    .zip(&b)
+                    .map(|(a, b)| a.total_cmp(b))
+                    .chain([match (a_alpha, b_alpha) {
+                        (Some(a), Some(b)) => a.total_cmp(&b),
+                        (a, b) => a.is_some().cmp(&b.is_some()),
+                    }])
+                    .find(|o| o.is_ne())
+                    .unwrap_or(Ordering::Equal),
+                _ => Ordering::Equal,
+            },
+        }
+    }
+}
+
+/// Error returned when parsing a [`Color`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid color `{}`", self.input)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Parse a plain number, rejecting e.g. `inf` or `1e3`.
+fn number(s: &str) -> Option<f64> {
+    let valid = s.bytes().any(|b| b.is_ascii_digit())
+        && s.bytes().all(|b| b.is_ascii_digit() || b"+-.".contains(&b));
+
+    valid.then(|| s.parse().ok()).flatten()
+}
+
+fn percentage(s: &str) -> Option<f64> {
+    number(s.strip_suffix('%')?)
+}
+
+fn alpha(s: &str) -> Option<f64> {
+    percentage(s).map(|p| p / 100.0).or_else(|| number(s))
+}
+
+fn hex(s: &str) -> Option<Color> {
+    if !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digit = |i: usize| u8::from_str_radix(&s[i..=i], 16).ok();
+    let byte = |i: usize| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).ok();
+
+    let [red, green, blue, alpha] = match s.len() {
+        3 | 4 => [0, 1, 2, 3].map(|i| (i < s.len()).then(|| digit(i)).flatten().map(|d| d * 17)),
+        6 | 8 => [0, 1, 2, 3].map(|i| (2 * i < s.len()).then(|| byte(i)).flatten()),
+        _ => return None,
+    };
+
+    Some(Color::Hex {
+        red: red?,
+        green: green?,
+        blue: blue?,
+        alpha,
+    })
+}
+
+/// Split the arguments of a color function, either separated by commas or by whitespace with
+/// the alpha component separated by `/`.
+fn arguments(args: &str) -> Option<Vec<&str>> {
+    if args.contains(',') {
+        return Some(args.split(',').map(str::trim).collect());
+    }
+
+    let (components, alpha) = match args.split_once('/') {
+        Some((components, alpha)) => (components, Some(alpha.trim())),
+        None => (args, None),
+    };
+
+    let mut args: Vec<_> = components.split_whitespace().collect();
+    args.extend(alpha);
+
+    Some(args)
+}
+
+fn function(s: &str) -> Option<Color> {
+    let (name, rest) = s.split_once('(')?;
+    let args = arguments(rest.strip_suffix(')')?)?;
+
+    let (components, alpha) = match args.as_slice() {
+        [a, b, c] => ([*a, *b, *c], None),
+        [a, b, c, alpha] => ([*a, *b, *c], Some(*alpha)),
+        _ => return None,
+    };
+
+    let alpha = match alpha {
+        Some(a) => Some(self::alpha(a)?),
+        None => None,
+    };
+
+    match name.to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => {
+            let [red, green, blue] = components.map(|c| {
+                percentage(c)
+                    .map(|p| p * 255.0 / 100.0)
+                    .or_else(|| number(c))
+                    .filter(|c| (0.0..=255.0).contains(c))
+            });
+
+            Some(Color::Rgb {
+                red: red?,
+                green: green?,
+                blue: blue?,
+                alpha,
+            })
+        }
+        "hsl" | "hsla" => {
+            let [hue, saturation, lightness] = components;
+            let percent = |c| percentage(c).filter(|p| (0.0..=100.0).contains(p));
+
+            Some(Color::Hsl {
+                hue: number(hue.strip_suffix("deg").unwrap_or(hue))?,
+                saturation: percent(saturation)?,
+                lightness: percent(lightness)?,
+                alpha,
+            })
+        }
+        _ => None,
+    }
+    .filter(|_| alpha.is_none_or(|a| (0.0..=1.0).contains(&a)))
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let color = if let Some(digits) = s.strip_prefix('#') {
+            hex(digits)
+        } else if s.eq_ignore_ascii_case("currentcolor") {
+            Some(Color::CurrentColor)
+        } else if s.eq_ignore_ascii_case("transparent") {
+            Some(Color::Transparent)
+        } else if s.contains('(') {
+            function(s)
+        } else {
+            Color::named(s)
+        };
+
+        color.ok_or_else(|| ParseColorError {
+            input: s.to_string(),
+        })
+    }
+}
+
+impl From<NamedColor> for Color {
+    fn from(value: NamedColor) -> Self {
+        Self::Named(value)
+    }
+}
+
+impl From<&str> for Color {
+    fn from(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Other(value.to_string()))
+    }
+}
+
+impl From<String> for Color {
+    fn from(value: String) -> Self {
+        value.parse().unwrap_or(Self::Other(value))
+    }
+}
+
+impl From<Color> for String {
+    fn from(value: Color) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<&String> for Color {
+    fn from(value: &String) -> Self {
+        value.as_str().into()
+    }
+}
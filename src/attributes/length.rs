@@ -0,0 +1,449 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+/// Unit of a [`Length`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    /// Font size of the element, `em`.
+    Em,
+    /// x-height of the element's font, `ex`.
+    Ex,
+    /// Pixels, `px`.
+    Px,
+    /// Inches, `in`.
+    In,
+    /// Centimeters, `cm`.
+    Cm,
+    /// Millimeters, `mm`.
+    Mm,
+    /// Points, `pt`.
+    Pt,
+    /// Picas, `pc`.
+    Pc,
+    /// Percentage, `%`.
+    Percent,
+    /// Width of the content of `mpadded`, `width`. Only valid for [`PaddedAttr`] values.
+    ///
+    /// [`PaddedAttr`]: crate::elements::PaddedAttr
+    Width,
+    /// Height of the content of `mpadded`, `height`. Only valid for [`PaddedAttr`] values.
+    ///
+    /// [`PaddedAttr`]: crate::elements::PaddedAttr
+    Height,
+    /// Depth of the content of `mpadded`, `depth`. Only valid for [`PaddedAttr`] values.
+    ///
+    /// [`PaddedAttr`]: crate::elements::PaddedAttr
+    Depth,
+    /// Left space of the content of `mpadded`, `lspace`. Only valid for [`PaddedAttr`] values.
+    ///
+    /// [`PaddedAttr`]: crate::elements::PaddedAttr
+    LeftSpace,
+    /// No unit, e.g. `0`.
+    Unitless,
+}
+
+impl Unit {
+    const ALL: [Unit; 14] = [
+        Unit::Em,
+        Unit::Ex,
+        Unit::Px,
+        Unit::In,
+        Unit::Cm,
+        Unit::Mm,
+        Unit::Pt,
+        Unit::Pc,
+        Unit::Percent,
+        Unit::Width,
+        Unit::Height,
+        Unit::Depth,
+        Unit::LeftSpace,
+        Unit::Unitless,
+    ];
+
+    /// Get the unit as written in MathML.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Unit::Em => "em",
+            Unit::Ex => "ex",
+            Unit::Px => "px",
+            Unit::In => "in",
+            Unit::Cm => "cm",
+            Unit::Mm => "mm",
+            Unit::Pt => "pt",
+            Unit::Pc => "pc",
+            Unit::Percent => "%",
+            Unit::Width => "width",
+            Unit::Height => "height",
+            Unit::Depth => "depth",
+            Unit::LeftSpace => "lspace",
+            Unit::Unitless => "",
+        }
+    }
+
+    /// Check whether the unit is one of the pseudo-units of `mpadded`, e.g. `width`.
+    pub fn is_pseudo(&self) -> bool {
+        matches!(
+            self,
+            Unit::Width | Unit::Height | Unit::Depth | Unit::LeftSpace
+        )
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Named space of MathML 3, e.g. `thinmathspace`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NamedSpace {
+    /// `veryverythinmathspace`, 1/18em.
+    VeryVeryThin,
+    /// `verythinmathspace`, 2/18em.
+    VeryThin,
+    /// `thinmathspace`, 3/18em.
+    Thin,
+    /// `mediummathspace`, 4/18em.
+    Medium,
+    /// `thickmathspace`, 5/18em.
+    Thick,
+    /// `verythickmathspace`, 6/18em.
+    VeryThick,
+    /// `veryverythickmathspace`, 7/18em.
+    VeryVeryThick,
+    /// `negativeveryverythinmathspace`, -1/18em.
+    NegativeVeryVeryThin,
+    /// `negativeverythinmathspace`, -2/18em.
+    NegativeVeryThin,
+    /// `negativethinmathspace`, -3/18em.
+    NegativeThin,
+    /// `negativemediummathspace`, -4/18em.
+    NegativeMedium,
+    /// `negativethickmathspace`, -5/18em.
+    NegativeThick,
+    /// `negativeverythickmathspace`, -6/18em.
+    NegativeVeryThick,
+    /// `negativeveryverythickmathspace`, -7/18em.
+    NegativeVeryVeryThick,
+}
+
+impl NamedSpace {
+    const ALL: [NamedSpace; 14] = [
+        NamedSpace::VeryVeryThin,
+        NamedSpace::VeryThin,
+        NamedSpace::Thin,
+        NamedSpace::Medium,
+        NamedSpace::Thick,
+        NamedSpace::VeryThick,
+        NamedSpace::VeryVeryThick,
+        NamedSpace::NegativeVeryVeryThin,
+        NamedSpace::NegativeVeryThin,
+        NamedSpace::NegativeThin,
+        NamedSpace::NegativeMedium,
+        NamedSpace::NegativeThick,
+        NamedSpace::NegativeVeryThick,
+        NamedSpace::NegativeVeryVeryThick,
+    ];
+
+    /// Get the named space as written in MathML.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NamedSpace::VeryVeryThin => "veryverythinmathspace",
+            NamedSpace::VeryThin => "verythinmathspace",
+            NamedSpace::Thin => "thinmathspace",
+            NamedSpace::Medium => "mediummathspace",
+            NamedSpace::Thick => "thickmathspace",
+            NamedSpace::VeryThick => "verythickmathspace",
+            NamedSpace::VeryVeryThick => "veryverythickmathspace",
+            NamedSpace::NegativeVeryVeryThin => "negativeveryverythinmathspace",
+            NamedSpace::NegativeVeryThin => "negativeverythinmathspace",
+            NamedSpace::NegativeThin => "negativethinmathspace",
+            NamedSpace::NegativeMedium => "negativemediummathspace",
+            NamedSpace::NegativeThick => "negativethickmathspace",
+            NamedSpace::NegativeVeryThick => "negativeverythickmathspace",
+            NamedSpace::NegativeVeryVeryThick => "negativeveryverythickmathspace",
+        }
+    }
+
+    /// Get the size of the named space in `em`.
+    pub fn em(&self) -> f64 {
+        let eighteenths = match self {
+            NamedSpace::VeryVeryThin => 1.0,
+            NamedSpace::VeryThin => 2.0,
+            NamedSpace::Thin => 3.0,
+            NamedSpace::Medium => 4.0,
+            NamedSpace::Thick => 5.0,
+            NamedSpace::VeryThick => 6.0,
+            NamedSpace::VeryVeryThick => 7.0,
+            NamedSpace::NegativeVeryVeryThin => -1.0,
+            NamedSpace::NegativeVeryThin => -2.0,
+            NamedSpace::NegativeThin => -3.0,
+            NamedSpace::NegativeMedium => -4.0,
+            NamedSpace::NegativeThick => -5.0,
+            NamedSpace::NegativeVeryThick => -6.0,
+            NamedSpace::NegativeVeryVeryThick => -7.0,
+        };
+
+        eighteenths / 18.0
+    }
+}
+
+impl Display for NamedSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Length value of an attribute, e.g. `0.5em`, `2px`, `50%` or `thinmathspace`.
+///
+/// Length attributes store their values as strings, so that existing code passing strings keeps
+/// compiling. A [`Length`] can be created using the constructors (e.g. [`Length::em`]) and
+/// converted into such a string, or parsed from one using [`FromStr`]. Parsing fails for invalid
+/// lengths like `"2 em"`, which are reported by [`validate`](crate::validate) as
+/// [`DiagnosticKind::InvalidLength`].
+///
+/// The constructors panic for values that are not finite, which have no representation in
+/// MathML.
+///
+/// [`DiagnosticKind::InvalidLength`]: crate::validate::DiagnosticKind::InvalidLength
+///
+/// # Example
+///
+/// ```
+/// use alemat::attributes::{Length, Unit};
+///
+/// assert_eq!("0.5em".parse(), Ok(Length::em(0.5)));
+/// assert_eq!("+1width".parse(), Ok(Length::relative(1.0, Unit::Width)));
+/// assert_eq!("-1width".parse(), Ok(Length::relative(-1.0, Unit::Width)));
+/// assert_eq!("-2px".parse(), Ok(Length::px(-2.0)));
+/// assert!("2 em".parse::<Length>().is_err());
+///
+/// assert_eq!(Length::percent(50.0).to_string(), "50%");
+/// assert_eq!(String::from(Length::em(-0.5)), "-0.5em");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Length(Kind);
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    /// A number followed by a unit, e.g. `0.5em`, `-2px` or `50%`.
+    Value(f64, Unit),
+
+    /// A number with an explicit sign followed by a unit, e.g. `+0.5em` or `-1width`.
+    Relative(f64, Unit),
+
+    /// Named space, e.g. `thinmathspace`.
+    Named(NamedSpace),
+}
+
+impl Length {
+    /// Create a new [`Length`] with the given value and unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not finite.
+    pub fn new(value: f64, unit: Unit) -> Self {
+        assert!(value.is_finite(), "length `{value}` is not finite");
+        Self(Kind::Value(value, unit))
+    }
+
+    /// Create a new [`Length`] in `em`.
+    pub fn em(value: f64) -> Self {
+        Self::new(value, Unit::Em)
+    }
+
+    /// Create a new [`Length`] in `ex`.
+    pub fn ex(value: f64) -> Self {
+        Self::new(value, Unit::Ex)
+    }
+
+    /// Create a new [`Length`] in `px`.
+    pub fn px(value: f64) -> Self {
+        Self::new(value, Unit::Px)
+    }
+
+    /// Create a new [`Length`] in `pt`.
+    pub fn pt(value: f64) -> Self {
+        Self::new(value, Unit::Pt)
+    }
+
+    /// Create a new [`Length`] in `%`.
+    pub fn percent(value: f64) -> Self {
+        Self::new(value, Unit::Percent)
+    }
+
+    /// Create a new [`Length`] without unit.
+    pub fn unitless(value: f64) -> Self {
+        Self::new(value, Unit::Unitless)
+    }
+
+    /// Create a new relative [`Length`] with the given value and unit, which is written with an
+    /// explicit sign, e.g. `+0.5em` or `-1width`. Used by `mpadded` to increment or decrement the
+    /// dimensions of its content.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not finite.
+    pub fn relative(value: f64, unit: Unit) -> Self {
+        assert!(value.is_finite(), "length `{value}` is not finite");
+        Self(Kind::Relative(value, unit))
+    }
+
+    /// Create a new [`Length`] of a named space.
+    pub fn named(space: NamedSpace) -> Self {
+        Self(Kind::Named(space))
+    }
+
+    /// Get the number of the length, or `None` for named spaces.
+    pub fn value(&self) -> Option<f64> {
+        match self.0 {
+            Kind::Value(value, _) | Kind::Relative(value, _) => Some(value),
+            Kind::Named(_) => None,
+        }
+    }
+
+    /// Get the unit of the length, or `None` for named spaces.
+    pub fn unit(&self) -> Option<Unit> {
+        match self.0 {
+            Kind::Value(_, unit) | Kind::Relative(_, unit) => Some(unit),
+            Kind::Named(_) => None,
+        }
+    }
+
+    /// Get the named space, or `None` for lengths with a number.
+    pub fn named_space(&self) -> Option<NamedSpace> {
+        match self.0 {
+            Kind::Named(space) => Some(space),
+            _ => None,
+        }
+    }
+
+    /// Whether the length is relative, see [`Length::relative`]. A `-` sign only makes a length
+    /// relative before one of the [pseudo-units](Unit::is_pseudo), since `-2px` is a value on
+    /// its own.
+    pub fn is_relative(&self) -> bool {
+        matches!(self.0, Kind::Relative(..))
+    }
+
+    /// Whether this is a length of zero that is not relative, e.g. `0`, `0em` or `-0px`.
+    pub fn is_zero(&self) -> bool {
+        matches!(self.0, Kind::Value(value, _) if value == 0.0)
+    }
+
+    fn variant(&self) -> u8 {
+        match self.0 {
+            Kind::Value(..) => 0,
+            Kind::Relative(..) => 1,
+            Kind::Named(_) => 2,
+        }
+    }
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Kind::Value(value, unit) => write!(f, "{value}{unit}"),
+            Kind::Relative(value, unit) => write!(f, "{value:+}{unit}"),
+            Kind::Named(space) => write!(f, "{space}"),
+        }
+    }
+}
+
+impl PartialEq for Length {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Length {}
+
+impl PartialOrd for Length {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Length {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0, other.0) {
+            (Kind::Value(a, a_unit), Kind::Value(b, b_unit))
+            | (Kind::Relative(a, a_unit), Kind::Relative(b, b_unit)) => {
+                a.total_cmp(&b).then(a_unit.cmp(&b_unit))
+            }
+            (Kind::Named(a), Kind::Named(b)) => a.cmp(&b),
+            _ => self.variant().cmp(&other.variant()),
+        }
+    }
+}
+
+/// Error returned when parsing a [`Length`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLengthError {
+    input: String,
+}
+
+impl Display for ParseLengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid length `{}`", self.input)
+    }
+}
+
+impl std::error::Error for ParseLengthError {}
+
+impl FromStr for Length {
+    type Err = ParseLengthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(space) = NamedSpace::ALL.iter().find(|space| space.as_str() == s) {
+            return Ok(Self::named(*space));
+        }
+
+        let err = || ParseLengthError {
+            input: s.to_string(),
+        };
+
+        let (sign, rest) = match s.strip_prefix(['+', '-']) {
+            Some(rest) => (s.chars().next(), rest),
+            None => (None, s),
+        };
+
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (number, unit) = rest.split_at(number_len);
+
+        if !number.bytes().any(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+
+        let unit = Unit::ALL
+            .into_iter()
+            .find(|u| u.as_str() == unit)
+            .ok_or_else(err)?;
+
+        let value: f64 = s[..s.len() - unit.as_str().len()]
+            .trim_start_matches('+')
+            .parse()
+            .ok()
+            .filter(|value: &f64| value.is_finite())
+            .ok_or_else(err)?;
+
+        if sign == Some('+') || (sign == Some('-') && unit.is_pseudo()) {
+            Ok(Self::relative(value, unit))
+        } else {
+            Ok(Self::new(value, unit))
+        }
+    }
+}
+
+impl From<NamedSpace> for Length {
+    fn from(value: NamedSpace) -> Self {
+        Self::named(value)
+    }
+}
+
+impl From<Length> for String {
+    fn from(value: Length) -> Self {
+        value.to_string()
+    }
+}
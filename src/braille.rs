@@ -0,0 +1,1116 @@
+//! Rendering of MathML elements into braille, written as Unicode braille patterns.
+//!
+//! Two braille codes are supported, selected with [`BrailleCode`]:
+//!
+//! * [`BrailleCode::Nemeth`], the Nemeth Code for mathematics. Numerals use the lower cells,
+//!   scripts are written with level indicators (`⠘`, `⠰`) and a baseline indicator (`⠐`),
+//!   fractions are enclosed in `⠹ … ⠌ … ⠼` and radicals in `⠜ … ⠻`.
+//! * [`BrailleCode::Ueb`], Unified English Braille with its technical extension. Numerals use
+//!   the numeric indicator `⠼` with the upper cells, scripts apply to the next item (`⠔`, `⠢`)
+//!   with grouping indicators `⠣ … ⠜` around longer items, fractions are enclosed in
+//!   `⠷ … ⠨⠌ … ⠾` and radicals in `⠩ … ⠬`.
+//!
+//! Bold, fraktur and script letters are marked with the typeform indicators of the code. Text is
+//! transcribed like identifiers and numbers, with the punctuation of the code. Blank cells are
+//! written as `⠀` (U+2800). Tables are laid out in columns with one line per row, so the output
+//! of formulas with tables spans multiple lines.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::braille::{BrailleCode, BrailleFormatter};
+//! use alemat::elements::{scripted::SubSup, Ident, Num, Operator};
+//! use alemat::{children, MathMl};
+//!
+//! let math = MathMl::with_content(children![
+//!     SubSup::builder()
+//!         .base(Ident::from("x"))
+//!         .supscript(Num::from(2))
+//!         .build(),
+//!     Operator::plus(),
+//!     Num::from(1),
+//! ]);
+//!
+//! let nemeth = math.render_with(&mut BrailleFormatter::default()).unwrap();
+//! assert_eq!(nemeth, "⠭⠘⠆⠐⠬⠂");
+//!
+//! let ueb = math
+//!     .render_with(&mut BrailleFormatter::new(BrailleCode::Ueb))
+//!     .unwrap();
+//! assert_eq!(ueb, "⠭⠔⠼⠃⠐⠖⠼⠁");
+//! ```
+
+use std::fmt::Display;
+
+use crate::{
+    attributes::{Attribute, MathVariant},
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver},
+        Annotation, Frac, Ident, Num, Operator, Padded, Semantics, Space, StrLiteral, Table, Text,
+    },
+    greek, Element, MathMl, Renderer,
+};
+
+/// A blank braille cell.
+const BLANK: char = '⠀';
+
+/// The braille code used for the output.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BrailleCode {
+    /// The Nemeth Code for mathematics and science notation.
+    #[default]
+    Nemeth,
+
+    /// Unified English Braille, using its technical material rules.
+    Ueb,
+}
+
+/// Error returned when rendering into braille fails.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BrailleError {
+    /// The character has no representation in the braille code.
+    UnsupportedChar(char),
+
+    /// The math variant has no typeform indicator in the braille code.
+    UnsupportedVariant(MathVariant),
+}
+
+impl Display for BrailleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BrailleError::UnsupportedChar(c) => write!(f, "no braille representation of `{c}`"),
+            BrailleError::UnsupportedVariant(variant) => {
+                write!(f, "no braille representation of the {variant} variant")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BrailleError {}
+
+/// [`Renderer`] implementation that turns elements into braille.
+///
+/// Spaces, phantoms and annotations have no braille cells. Rendering a [`MathMl`] fails with a
+/// [`BrailleError`] if it contains a character or math variant without a representation in the
+/// selected code.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct BrailleFormatter {
+    code: BrailleCode,
+
+    /// The first character or math variant found without a braille representation.
+    unsupported: Option<BrailleError>,
+
+    /// Whether a numeral written next needs the numeric indicator in Nemeth, i.e. at the start
+    /// of the expression or after a space.
+    numeric: bool,
+
+    /// Whether the last written symbol is a digit, so that letters `a` to `j` need the grade 1
+    /// indicator in UEB.
+    digits: bool,
+
+    /// The level indicators of the script the renderer is currently in, used by Nemeth.
+    level: String,
+
+    /// The level indicator written before the next symbol when returning from a script in
+    /// Nemeth.
+    pending: Option<String>,
+
+    /// The number of radicals the renderer is currently in, used by Nemeth.
+    radicals: usize,
+}
+
+impl BrailleFormatter {
+    /// Create a [`BrailleFormatter`] producing the given braille code.
+    pub fn new(code: BrailleCode) -> Self {
+        Self {
+            code,
+            ..Self::default()
+        }
+    }
+
+    /// The braille code used for the output.
+    pub fn code(&self) -> BrailleCode {
+        self.code
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.code);
+        self.numeric = true;
+    }
+
+    /// Write symbols, preceded by a pending level indicator. All output goes through this
+    /// function in reading order, so that the indicators depending on the previous symbols are
+    /// written correctly.
+    fn emit(&mut self, symbols: &str) -> String {
+        if symbols.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::new();
+
+        if let Some(level) = self.pending.take() {
+            // a space or a comma returns to the baseline by itself
+            if !symbols.starts_with(BLANK) && !symbols.starts_with("⠠⠀") {
+                out.push_str(&level);
+            }
+        }
+
+        out.push_str(symbols);
+
+        let minus = symbols == nemeth_operator("-").unwrap_or_default();
+        self.numeric = symbols.ends_with(BLANK) || (self.numeric && minus);
+        self.digits = false;
+
+        out
+    }
+
+    fn render(&mut self, element: &Element) -> String {
+        // unsupported symbols are reported by `render_mathml`
+        self.render_element(element).unwrap_or_default()
+    }
+
+    fn row(&mut self, elements: &[Element]) -> String {
+        let mut out = String::new();
+        let mut idx = 0;
+
+        while idx < elements.len() {
+            let rest = &elements[idx..];
+
+            // tables are written with their fences on every line
+            if let [Element::Operator(open), Element::Table(table), after @ ..] = rest {
+                if is_fence(open.op()) {
+                    let close = match after.first() {
+                        Some(Element::Operator(close)) if is_fence(close.op()) => Some(close.op()),
+                        _ => None,
+                    };
+
+                    let table = self.table(table, Some(open.op()), close);
+                    concat(&mut out, &table);
+
+                    idx += if close.is_some() { 3 } else { 2 };
+                    continue;
+                }
+            }
+
+            let braille = match &rest[0] {
+                Element::Operator(op) if op.op() == "\u{2061}" => {
+                    // function names are followed by a space
+                    let function = matches!(
+                        elements[..idx].last(),
+                        Some(Element::Ident(ident)) if ident.ident().chars().count() > 1
+                    );
+
+                    match function {
+                        true => self.emit(&BLANK.to_string()),
+                        false => String::new(),
+                    }
+                }
+                element => self.render(element),
+            };
+
+            concat(&mut out, &braille);
+            idx += 1;
+        }
+
+        out
+    }
+
+    /// Write the elements as a single item in UEB, enclosed in grouping indicators if they
+    /// consist of more than one symbol.
+    fn item(&mut self, elements: &[Element]) -> String {
+        if is_item(elements) {
+            return self.row(elements);
+        }
+
+        let mut out = self.emit("⠣");
+        out.push_str(&self.row(elements));
+        out.push_str(&self.emit("⠜"));
+        out
+    }
+
+    /// Remember the first character or math variant without a braille representation, which is
+    /// reported once the whole formula is rendered.
+    fn unsupported(&mut self, error: BrailleError) {
+        self.unsupported.get_or_insert(error);
+    }
+
+    /// The typeform indicator of the math variant in the attributes.
+    fn typeform(&mut self, attributes: &[Attribute], word: bool) -> (&'static str, bool) {
+        let variant = attributes.iter().rev().find_map(|attr| match attr {
+            Attribute::MathVariant(variant) => Some(variant),
+            _ => None,
+        });
+
+        let Some(variant) = variant else {
+            return ("", false);
+        };
+
+        // german letters have their own alphabetic indicator in Nemeth, other latin letters
+        // need the english letter indicator after a typeform indicator
+        let german = matches!(variant, MathVariant::Fraktur | MathVariant::BoldFraktur);
+
+        match typeform(variant, self.code, word) {
+            Some(indicator) => (indicator, !german && !indicator.is_empty()),
+            None => {
+                self.unsupported(BrailleError::UnsupportedVariant(variant.clone()));
+                ("", false)
+            }
+        }
+    }
+
+    fn identifier(&mut self, ident: &str, attributes: &[Attribute]) -> String {
+        let (typeform, english) = self.typeform(attributes, false);
+        let mut out = String::new();
+
+        for c in ident.chars() {
+            let mut symbols = String::new();
+
+            if let Some(letter) = letter(c, self.code) {
+                symbols.push_str(typeform);
+
+                if english && self.code == BrailleCode::Nemeth && c.is_ascii_alphabetic() {
+                    symbols.push('⠰');
+                }
+
+                if self.code == BrailleCode::Ueb && self.digits && matches!(c, 'a'..='j') {
+                    symbols.push('⠰');
+                }
+
+                symbols.push_str(&letter);
+            } else if c == BLANK {
+                symbols.push(c);
+            } else if let Some(punctuation) = punctuation(c, self.code) {
+                symbols.push_str(punctuation);
+            } else {
+                self.unsupported(BrailleError::UnsupportedChar(c));
+            }
+
+            out.push_str(&self.emit(&symbols));
+        }
+
+        out
+    }
+
+    fn number(&mut self, num: &str, attributes: &[Attribute]) -> String {
+        let num = num.trim();
+        let (typeform, _) = self.typeform(attributes, true);
+        let mut symbols = String::from(typeform);
+
+        let (digits, point, comma) = match self.code {
+            BrailleCode::Nemeth => (NEMETH_DIGITS, '⠨', '⠠'),
+            BrailleCode::Ueb => (UEB_DIGITS, '⠲', '⠂'),
+        };
+
+        // numerals after a typeform indicator need the numeric indicator in Nemeth as well
+        if self.code == BrailleCode::Ueb || self.numeric || !typeform.is_empty() {
+            symbols.push('⠼');
+        }
+
+        for c in num.chars() {
+            match c {
+                '0'..='9' => symbols.push(digits[c as usize - '0' as usize]),
+                '.' => symbols.push(point),
+                ',' => symbols.push(comma),
+                c => self.unsupported(BrailleError::UnsupportedChar(c)),
+            }
+        }
+
+        let out = self.emit(&symbols);
+        self.digits = true;
+        out
+    }
+
+    /// Write text, with numerals in runs of digits and spaces as blank cells.
+    fn text(&mut self, text: &str, attributes: &[Attribute]) -> String {
+        let mut out = String::new();
+        let mut rest = text;
+
+        while let Some(c) = rest.chars().next() {
+            let end = match c.is_ascii_digit() {
+                true => rest.find(|c: char| !c.is_ascii_digit()),
+                false => rest.find(|c: char| c.is_ascii_digit()),
+            };
+            let (part, next) = rest.split_at(end.unwrap_or(rest.len()));
+
+            let braille = match c.is_ascii_digit() {
+                true => self.number(part, attributes),
+                false => self.identifier(&part.replace(' ', "⠀"), attributes),
+            };
+
+            out.push_str(&braille);
+            rest = next;
+        }
+
+        out
+    }
+
+    fn operator(&mut self, op: &str) -> String {
+        let symbols = match op {
+            // invisible operators
+            "\u{2061}" | "\u{2062}" | "\u{2063}" | "\u{2064}" => return String::new(),
+            op => match self.code {
+                BrailleCode::Nemeth => nemeth_operator(op),
+                BrailleCode::Ueb => ueb_operator(op),
+            },
+        };
+
+        match symbols {
+            Some(symbols) => self.emit(symbols),
+            // symbols without a braille representation, e.g. greek letters used as operators
+            None => self.identifier(op, &[]),
+        }
+    }
+
+    /// Write a script at the next level in Nemeth, returning to the current level afterwards.
+    fn nemeth_script(&mut self, indicator: &str, elements: &[Element]) -> String {
+        // the new level indicator replaces a pending return to the current level
+        self.pending = None;
+
+        let parent = self.level.clone();
+        self.level.push_str(indicator);
+
+        let level = self.level.clone();
+        let mut out = self.emit(&level);
+        out.push_str(&self.row(elements));
+
+        self.pending = Some(match parent.is_empty() {
+            true => String::from("⠐"),
+            false => parent.clone(),
+        });
+        self.level = parent;
+
+        out
+    }
+
+    /// Write a subscript (`0`) or superscript (`1`) of multiscripts.
+    fn script(&mut self, script: usize, elements: &[Element]) -> String {
+        match (self.code, script) {
+            (BrailleCode::Nemeth, 0) => self.nemeth_script("⠰", elements),
+            (BrailleCode::Nemeth, _) => self.nemeth_script("⠘", elements),
+            (BrailleCode::Ueb, 0) => self.emit("⠢") + &self.item(elements),
+            (BrailleCode::Ueb, _) => self.emit("⠔") + &self.item(elements),
+        }
+    }
+
+    fn scripts(
+        &mut self,
+        base: &[Element],
+        sub: Option<&[Element]>,
+        sup: Option<&[Element]>,
+    ) -> String {
+        let mut out = self.row(base);
+
+        // primes are written directly after the base
+        let mut sup = sup;
+        if let Some([Element::Operator(op), rest @ ..]) = sup {
+            if matches!(op.op(), "′" | "″" | "‴") {
+                out.push_str(&self.operator(op.op()));
+                sup = (!rest.is_empty()).then_some(rest);
+            }
+        }
+
+        match self.code {
+            BrailleCode::Nemeth => {
+                if let Some(sub) = sub {
+                    // numeric subscripts of letters are written without a level indicator
+                    let numeric = matches!(base, [Element::Ident(ident)] if ident.ident().chars().count() == 1)
+                        && matches!(sub, [Element::Num(num)] if num.num().chars().all(|c| c.is_ascii_digit()))
+                        && self.level.is_empty();
+
+                    if numeric {
+                        self.numeric = false;
+                        out.push_str(&self.row(sub));
+                    } else {
+                        out.push_str(&self.nemeth_script("⠰", sub));
+                    }
+                }
+
+                if let Some(sup) = sup {
+                    out.push_str(&self.nemeth_script("⠘", sup));
+                }
+            }
+            BrailleCode::Ueb => {
+                if let Some(sub) = sub {
+                    out.push_str(&self.emit("⠢"));
+                    out.push_str(&self.item(sub));
+                }
+
+                if let Some(sup) = sup {
+                    out.push_str(&self.emit("⠔"));
+                    out.push_str(&self.item(sup));
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Write a single operator placed above or below an expression, e.g. a bar.
+    fn modifier(&mut self, elements: &[Element]) -> String {
+        let modifier = match elements {
+            [Element::Operator(op)] => match (self.code, op.op()) {
+                (_, "¯" | "‾" | "_") => Some("⠱"),
+                (BrailleCode::Nemeth, "~" | "˜") => Some("⠈⠱"),
+                (BrailleCode::Ueb, "~" | "˜") => Some("⠈⠔"),
+                (BrailleCode::Nemeth, "→") => Some("⠫⠕"),
+                (BrailleCode::Ueb, "→") => Some("⠳⠕"),
+                (BrailleCode::Nemeth, "^" | "ˆ") => Some("⠸⠣"),
+                (BrailleCode::Ueb, "^" | "ˆ") => Some("⠈⠢"),
+                (BrailleCode::Nemeth, "˙") => Some("⠡"),
+                (BrailleCode::Nemeth, "¨") => Some("⠡⠡"),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match (modifier, self.code) {
+            (Some(modifier), _) => self.emit(modifier),
+            (None, BrailleCode::Nemeth) => self.row(elements),
+            (None, BrailleCode::Ueb) => self.item(elements),
+        }
+    }
+
+    fn table(&mut self, table: &Table, open: Option<&str>, close: Option<&str>) -> String {
+        let fence = |fence: Option<&str>, code: BrailleCode| {
+            let symbols = match code {
+                BrailleCode::Nemeth => nemeth_operator(fence?)?,
+                BrailleCode::Ueb => ueb_operator(fence?)?,
+            };
+
+            // fences spanning multiple lines are enlarged in Nemeth
+            Some(match code {
+                BrailleCode::Nemeth => format!("⠠{symbols}"),
+                BrailleCode::Ueb => symbols.to_string(),
+            })
+        };
+
+        let open = fence(open, self.code).unwrap_or_default();
+        let close = fence(close, self.code).unwrap_or_default();
+
+        let rows: Vec<Vec<String>> = table
+            .rows()
+            .iter()
+            .map(|row| {
+                // numerals at the beginning of a line need the numeric indicator, but not after
+                // an enlarged fence
+                self.numeric = open.is_empty();
+                row.cells()
+                    .iter()
+                    .map(|cell| {
+                        let braille = self.row(cell.children());
+                        self.pending = None;
+                        self.emit(&BLANK.to_string());
+                        braille
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+        let widths: Vec<usize> = (0..columns)
+            .map(|idx| {
+                rows.iter()
+                    .filter_map(|row| row.get(idx))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        let blanks = |n: usize| BLANK.to_string().repeat(n);
+
+        let lines: Vec<String> = rows
+            .into_iter()
+            .map(|row| {
+                let cells: Vec<String> = row
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, cell)| {
+                        let missing = widths[idx] - cell.chars().count();
+                        format!("{cell}{}", blanks(missing))
+                    })
+                    .collect();
+
+                let cells = cells.join(&blanks(2));
+                let cells = match close.is_empty() {
+                    true => cells.trim_end_matches(BLANK).to_string(),
+                    false => cells,
+                };
+
+                format!("{open}{cells}{close}")
+            })
+            .collect();
+
+        self.numeric = false;
+        lines.join("\n")
+    }
+}
+
+impl Renderer for BrailleFormatter {
+    type Output = String;
+    type Error = BrailleError;
+
+    fn render_action(&mut self, action: &Action) -> Result<Self::Output, Self::Error> {
+        let selection = action.attributes().iter().find_map(|attr| match attr {
+            ActionAttr::Selection(selection) => selection.trim().parse::<usize>().ok(),
+            _ => None,
+        });
+
+        let content = action.content();
+        let selected = selection
+            .and_then(|idx| content.get(idx.checked_sub(1)?))
+            .or_else(|| content.first());
+
+        match selected {
+            Some(element) => self.render_element(element),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_annotation(&mut self, _annotation: &Annotation) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_error(&mut self, error: &Error) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(error.content()))
+    }
+
+    fn render_frac(&mut self, frac: &Frac) -> Result<Self::Output, Self::Error> {
+        let num = frac.num();
+        let denom = frac.denom();
+
+        // complex fractions contain other fractions, which is marked on their indicators
+        let prefix = "⠠".repeat(nested_fracs(num).max(nested_fracs(denom)));
+
+        let (open, line, close) = match self.code {
+            BrailleCode::Nemeth => ("⠹", "⠌", "⠼"),
+            BrailleCode::Ueb => {
+                // simple numeric fractions are written in numeric mode, e.g. `⠼⠁⠌⠃`
+                if let ([Element::Num(n)], [Element::Num(d)]) = (num, denom) {
+                    let digits = |num: &Num| num.num().trim().chars().all(|c| c.is_ascii_digit());
+
+                    if digits(n) && digits(d) {
+                        let mut out = self.number(n.num(), n.attributes());
+                        let denom: String = d
+                            .num()
+                            .trim()
+                            .chars()
+                            .map(|c| UEB_DIGITS[c as usize - '0' as usize])
+                            .collect();
+
+                        out.push_str(&self.emit(&format!("⠌{denom}")));
+                        self.digits = true;
+                        return Ok(out);
+                    }
+                }
+
+                ("⠷", "⠨⠌", "⠾")
+            }
+        };
+
+        let mut out = self.emit(&format!("{prefix}{open}"));
+        out.push_str(&self.row(num));
+        out.push_str(&self.emit(&format!("{prefix}{line}")));
+        out.push_str(&self.row(denom));
+        out.push_str(&self.emit(&format!("{prefix}{close}")));
+
+        Ok(out)
+    }
+
+    fn render_ident(&mut self, ident: &Ident) -> Result<Self::Output, Self::Error> {
+        Ok(self.identifier(ident.ident(), ident.attributes()))
+    }
+
+    fn render_multiscripts(
+        &mut self,
+        multiscripts: &Multiscripts,
+    ) -> Result<Self::Output, Self::Error> {
+        let content = multiscripts.content();
+
+        let Some((base, scripts)) = content.split_first() else {
+            return Ok(String::new());
+        };
+
+        let (post, pre) = match scripts
+            .iter()
+            .position(|element| matches!(element, Element::Prescripts(_)))
+        {
+            Some(idx) => (&scripts[..idx], &scripts[idx + 1..]),
+            None => (scripts, &[][..]),
+        };
+
+        // prescripts are written before the base, with the same indicators as other scripts
+        let mut out = String::new();
+        for (script, elements) in pairs(pre) {
+            out.push_str(&self.script(script, elements));
+        }
+
+        out.push_str(&self.render(base));
+
+        for (script, elements) in pairs(post) {
+            out.push_str(&self.script(script, elements));
+        }
+
+        Ok(out)
+    }
+
+    fn render_prescripts(&mut self, _prescripts: &Prescripts) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_num(&mut self, num: &Num) -> Result<Self::Output, Self::Error> {
+        Ok(self.number(num.num(), num.attributes()))
+    }
+
+    fn render_operator(&mut self, operator: &Operator) -> Result<Self::Output, Self::Error> {
+        Ok(self.operator(operator.op()))
+    }
+
+    fn render_padded(&mut self, padded: &Padded) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(padded.children()))
+    }
+
+    fn render_phantom(&mut self, _phantom: &Phantom) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_radical(&mut self, radical: &Radical) -> Result<Self::Output, Self::Error> {
+        let index = (!radical.is_square() && !radical.index().is_empty()).then(|| radical.index());
+
+        let mut out = String::new();
+
+        match self.code {
+            BrailleCode::Nemeth => {
+                // radicals nested in other radicals are marked on their indicators
+                let nesting = "⠨".repeat(self.radicals);
+
+                if let Some(index) = index {
+                    out.push_str(&self.emit("⠣"));
+                    out.push_str(&self.row(index));
+                }
+
+                out.push_str(&self.emit(&format!("{nesting}⠜")));
+
+                self.radicals += 1;
+                out.push_str(&self.row(radical.content()));
+                self.radicals -= 1;
+
+                out.push_str(&self.emit(&format!("{nesting}⠻")));
+            }
+            BrailleCode::Ueb => {
+                out.push_str(&self.emit("⠩"));
+
+                if let Some(index) = index {
+                    out.push_str(&self.emit("⠔"));
+                    out.push_str(&self.item(index));
+                }
+
+                out.push_str(&self.row(radical.content()));
+                out.push_str(&self.emit("⠬"));
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn render_row(&mut self, row: &Row) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(row.children()))
+    }
+
+    fn render_semantics(&mut self, semantics: &Semantics) -> Result<Self::Output, Self::Error> {
+        match semantics.children().first() {
+            Some(presentation) => self.render_element(presentation),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_space(&mut self, _space: &Space) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_str_literal(
+        &mut self,
+        str_literal: &StrLiteral,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(self.text(str_literal.content(), &[]))
+    }
+
+    fn render_style(&mut self, style: &Style) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(style.children()))
+    }
+
+    fn render_subsup(&mut self, sub_sup: &SubSup) -> Result<Self::Output, Self::Error> {
+        Ok(self.scripts(sub_sup.base(), sub_sup.sub(), sub_sup.sup()))
+    }
+
+    fn render_table(&mut self, table: &Table) -> Result<Self::Output, Self::Error> {
+        Ok(self.table(table, None, None))
+    }
+
+    fn render_text(&mut self, text: &Text) -> Result<Self::Output, Self::Error> {
+        Ok(self.text(text.text(), text.attributes()))
+    }
+
+    fn render_underover(&mut self, under_over: &UnderOver) -> Result<Self::Output, Self::Error> {
+        // horizontal braces only group the expression, which the scripts placed under or over
+        // them already do
+        if let Some(expr) = braced(under_over) {
+            return Ok(self.row(expr));
+        }
+
+        let expr = match under_over.expr() {
+            [Element::UnderOver(inner)] => braced(inner).unwrap_or(under_over.expr()),
+            expr => expr,
+        };
+        let mut out = String::new();
+
+        match self.code {
+            BrailleCode::Nemeth => {
+                // the five-step rule: multipurpose indicator, expression, directly under,
+                // directly over and termination indicator
+                out.push_str(&self.emit("⠐"));
+                out.push_str(&self.row(expr));
+
+                if let Some(under) = under_over.under() {
+                    out.push_str(&self.emit("⠩"));
+                    out.push_str(&self.modifier(under));
+                }
+
+                if let Some(over) = under_over.over() {
+                    out.push_str(&self.emit("⠣"));
+                    out.push_str(&self.modifier(over));
+                }
+
+                out.push_str(&self.emit("⠻"));
+            }
+            BrailleCode::Ueb => {
+                out.push_str(&self.item(expr));
+
+                if let Some(under) = under_over.under() {
+                    out.push_str(&self.emit("⠨⠢"));
+                    out.push_str(&self.modifier(under));
+                }
+
+                if let Some(over) = under_over.over() {
+                    out.push_str(&self.emit("⠨⠔"));
+                    out.push_str(&self.modifier(over));
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn render_attr(&mut self, _attr: &Attribute) -> Result<Self::Output, Self::Error> {
+        // attributes have no representation in braille
+        Ok(String::new())
+    }
+
+    fn render_mathml(&mut self, mathml: &MathMl) -> Result<Self::Output, Self::Error> {
+        self.reset();
+        let out = self.row(mathml.content());
+
+        match self.unsupported.take() {
+            Some(error) => Err(error),
+            None => Ok(out),
+        }
+    }
+}
+
+const NEMETH_DIGITS: [char; 10] = ['⠴', '⠂', '⠆', '⠒', '⠲', '⠢', '⠖', '⠶', '⠦', '⠔'];
+
+const UEB_DIGITS: [char; 10] = ['⠚', '⠁', '⠃', '⠉', '⠙', '⠑', '⠋', '⠛', '⠓', '⠊'];
+
+/// Braille cells of the letters `a` to `z`.
+const LETTERS: [char; 26] = [
+    '⠁', '⠃', '⠉', '⠙', '⠑', '⠋', '⠛', '⠓', '⠊', '⠚', '⠅', '⠇', '⠍', '⠝', '⠕', '⠏', '⠟', '⠗', '⠎',
+    '⠞', '⠥', '⠧', '⠺', '⠭', '⠽', '⠵',
+];
+
+/// The braille symbols of a latin or greek letter, including the capital and greek indicators.
+fn letter(c: char, code: BrailleCode) -> Option<String> {
+    if c.is_ascii_alphabetic() {
+        let cell = LETTERS[(c.to_ascii_lowercase() as u8 - b'a') as usize];
+
+        return Some(match c.is_ascii_uppercase() {
+            true => format!("⠠{cell}"),
+            false => cell.to_string(),
+        });
+    }
+
+    if c == BLANK {
+        return Some(c.to_string());
+    }
+
+    let name = greek::name(c)?;
+    let capital = name.starts_with(char::is_uppercase);
+
+    let cell = match name.to_lowercase().as_str() {
+        "alpha" => '⠁',
+        "beta" => '⠃',
+        "gamma" => '⠛',
+        "delta" => '⠙',
+        "epsilon" => '⠑',
+        "zeta" => '⠵',
+        "eta" => '⠱',
+        "theta" => '⠹',
+        "iota" => '⠊',
+        "kappa" => '⠅',
+        "lambda" => '⠇',
+        "mu" => '⠍',
+        "nu" => '⠝',
+        "xi" => '⠭',
+        "omicron" => '⠕',
+        "pi" => '⠏',
+        "rho" => '⠗',
+        "sigma" => '⠎',
+        "tau" => '⠞',
+        "upsilon" => '⠥',
+        "phi" => '⠋',
+        "chi" => '⠯',
+        "psi" => '⠽',
+        "omega" => '⠺',
+        _ => return None,
+    };
+
+    let symbols = match (capital, code) {
+        (false, _) => format!("⠨{cell}"),
+        (true, BrailleCode::Nemeth) => format!("⠨⠠{cell}"),
+        (true, BrailleCode::Ueb) => format!("⠠⠨{cell}"),
+    };
+
+    Some(symbols)
+}
+
+/// Nemeth symbols of common operators. Comparison signs are surrounded by spaces.
+fn nemeth_operator(op: &str) -> Option<&'static str> {
+    let symbols = match op {
+        "+" => "⠬",
+        "-" | "−" => "⠤",
+        "×" => "⠈⠡",
+        "⋅" | "·" => "⠡",
+        "∗" | "*" => "⠈⠼",
+        "÷" => "⠨⠌",
+        "/" => "⠸⠌",
+        "±" => "⠬⠤",
+        "∓" => "⠤⠬",
+        "=" => "⠀⠨⠅⠀",
+        "<" => "⠀⠐⠅⠀",
+        ">" => "⠀⠨⠂⠀",
+        "≤" => "⠀⠐⠅⠱⠀",
+        "≥" => "⠀⠨⠂⠱⠀",
+        "≠" => "⠀⠌⠨⠅⠀",
+        "≈" => "⠀⠈⠱⠈⠱⠀",
+        "→" => "⠀⠫⠕⠀",
+        "∈" => "⠀⠈⠑⠀",
+        "∞" => "⠠⠿",
+        "∑" => "⠨⠠⠎",
+        "∏" => "⠨⠠⠏",
+        "∫" => "⠮",
+        "∂" => "⠈⠙",
+        "′" => "⠄",
+        "″" => "⠄⠄",
+        "!" => "⠯",
+        "," => "⠠⠀",
+        "(" => "⠷",
+        ")" => "⠾",
+        "[" => "⠈⠷",
+        "]" => "⠈⠾",
+        "{" => "⠨⠷",
+        "}" => "⠨⠾",
+        "|" | "∣" => "⠳",
+        _ => return None,
+    };
+
+    Some(symbols)
+}
+
+/// UEB symbols of common operators. Comparison signs are surrounded by spaces.
+fn ueb_operator(op: &str) -> Option<&'static str> {
+    let symbols = match op {
+        "+" => "⠐⠖",
+        "-" | "−" => "⠐⠤",
+        "×" => "⠐⠦",
+        "⋅" | "·" => "⠐⠲",
+        "∗" | "*" => "⠐⠔",
+        "÷" => "⠐⠌",
+        "/" => "⠸⠌",
+        "±" => "⠸⠖",
+        "∓" => "⠸⠤",
+        "=" => "⠀⠐⠶⠀",
+        "<" => "⠀⠈⠣⠀",
+        ">" => "⠀⠈⠜⠀",
+        "≤" => "⠀⠸⠈⠣⠀",
+        "≥" => "⠀⠸⠈⠜⠀",
+        "≠" => "⠀⠐⠶⠈⠱⠀",
+        "≈" => "⠀⠘⠶⠀",
+        "→" => "⠀⠳⠕⠀",
+        "∈" => "⠀⠘⠑⠀",
+        "∞" => "⠼⠿",
+        "∑" => "⠠⠨⠎",
+        "∏" => "⠠⠨⠏",
+        "∫" => "⠮",
+        "∂" => "⠈⠙",
+        "′" => "⠶",
+        "″" => "⠶⠶",
+        "!" => "⠖",
+        "," => "⠂",
+        "(" => "⠐⠣",
+        ")" => "⠐⠜",
+        "[" => "⠨⠣",
+        "]" => "⠨⠜",
+        "{" => "⠸⠣",
+        "}" => "⠸⠜",
+        "|" | "∣" => "⠸⠳",
+        _ => return None,
+    };
+
+    Some(symbols)
+}
+
+/// The typeform indicator of a math variant in front of a symbol, or of a whole `word` in UEB.
+/// Italic is the default style of letters and not marked, double-struck letters are transcribed
+/// as bold ones.
+fn typeform(variant: &MathVariant, code: BrailleCode, word: bool) -> Option<&'static str> {
+    let variant = match variant {
+        MathVariant::BoldItalic | MathVariant::DoubleStruck => &MathVariant::Bold,
+        variant => variant,
+    };
+
+    let indicator = match (code, variant, word) {
+        (_, MathVariant::Normal | MathVariant::Italic, _) => "",
+        (BrailleCode::Nemeth, MathVariant::Bold | MathVariant::Fraktur, _) => "⠸",
+        (BrailleCode::Nemeth, MathVariant::BoldFraktur, _) => "⠸⠸",
+        (BrailleCode::Nemeth, MathVariant::Script, _) => "⠈",
+        (BrailleCode::Ueb, MathVariant::Bold, true) => "⠘⠂",
+        (BrailleCode::Ueb, MathVariant::Bold, false) => "⠘⠆",
+        (BrailleCode::Ueb, MathVariant::Script, true) => "⠈⠂",
+        (BrailleCode::Ueb, MathVariant::Script, false) => "⠈⠆",
+        _ => return None,
+    };
+
+    Some(indicator)
+}
+
+/// The braille symbols of punctuation in text.
+fn punctuation(c: char, code: BrailleCode) -> Option<&'static str> {
+    let symbols = match (code, c) {
+        (BrailleCode::Nemeth, '%') => "⠈⠴",
+        (BrailleCode::Nemeth, '&') => "⠸⠯",
+        (BrailleCode::Nemeth, '#') => "⠨⠼",
+        (BrailleCode::Nemeth, '$') => "⠈⠎",
+        (BrailleCode::Nemeth, '.') => "⠸⠲",
+        (BrailleCode::Nemeth, ',') => "⠠",
+        (BrailleCode::Nemeth, ':') => "⠸⠒",
+        (BrailleCode::Nemeth, ';') => "⠸⠆",
+        (BrailleCode::Nemeth, '?') => "⠸⠦",
+        (BrailleCode::Nemeth, '!') => "⠸⠖",
+        (BrailleCode::Ueb, '%') => "⠨⠴",
+        (BrailleCode::Ueb, '&') => "⠈⠯",
+        (BrailleCode::Ueb, '#') => "⠸⠹",
+        (BrailleCode::Ueb, '$') => "⠈⠎",
+        (BrailleCode::Ueb, '.') => "⠲",
+        (BrailleCode::Ueb, ',') => "⠂",
+        (BrailleCode::Ueb, ':') => "⠒",
+        (BrailleCode::Ueb, ';') => "⠆",
+        (BrailleCode::Ueb, '?') => "⠦",
+        (BrailleCode::Ueb, '!') => "⠖",
+        (_, '\'') => "⠄",
+        (_, '-') => "⠤",
+        // other symbols are written like operators, without the spaces around comparison signs
+        (code, c) => match code {
+            BrailleCode::Nemeth => nemeth_operator(c.encode_utf8(&mut [0; 4]))?,
+            BrailleCode::Ueb => ueb_operator(c.encode_utf8(&mut [0; 4]))?,
+        }
+        .trim_matches(BLANK),
+    };
+
+    Some(symbols)
+}
+
+/// The expression of a horizontal brace or bracket placed under or over it, e.g. by
+/// `\underbrace`.
+fn braced(under_over: &UnderOver) -> Option<&[Element]> {
+    let brace = |elements: Option<&[Element]>| match elements {
+        Some([Element::Operator(op)]) => matches!(op.op(), "⏞" | "⏟" | "⎴" | "⎵" | "⏜" | "⏝"),
+        _ => false,
+    };
+
+    match (under_over.under(), under_over.over()) {
+        (under, None) if brace(under) => Some(under_over.expr()),
+        (None, over) if brace(over) => Some(under_over.expr()),
+        _ => None,
+    }
+}
+
+fn is_fence(op: &str) -> bool {
+    matches!(op, "(" | ")" | "[" | "]" | "{" | "}" | "|" | "∣")
+}
+
+/// Whether the elements form a single item in UEB, which needs no grouping indicators.
+fn is_item(elements: &[Element]) -> bool {
+    match elements {
+        [Element::Ident(ident)] => ident.ident().chars().count() == 1,
+        [Element::Num(_) | Element::Operator(_) | Element::Frac(_) | Element::Radical(_)] => true,
+        [Element::Row(row)] => is_item(row.children()),
+        _ => false,
+    }
+}
+
+/// The number of fractions nested in the elements.
+fn nested_fracs(elements: &[Element]) -> usize {
+    elements
+        .iter()
+        .map(|element| match element {
+            Element::Frac(frac) => 1 + nested_fracs(frac.num()).max(nested_fracs(frac.denom())),
+            Element::Row(row) => nested_fracs(row.children()),
+            Element::Style(style) => nested_fracs(style.children()),
+            Element::Padded(padded) => nested_fracs(padded.children()),
+            _ => 0,
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+/// Split the scripts of multiscripts into subscripts (`0`) and superscripts (`1`), skipping
+/// empty rows.
+fn pairs(scripts: &[Element]) -> impl Iterator<Item = (usize, &[Element])> {
+    scripts
+        .chunks(2)
+        .flat_map(|pair| pair.iter().enumerate())
+        .filter(|(_, element)| !matches!(element, Element::Row(row) if row.children().is_empty()))
+        .map(|(script, element)| (script, std::slice::from_ref(element)))
+}
+
+/// Append braille to the output. Multi-line braille, e.g. of tables, is placed beside the
+/// output, aligned at the first line.
+fn concat(out: &mut String, braille: &str) {
+    if !out.contains('\n') && !braille.contains('\n') {
+        out.push_str(braille);
+        return;
+    }
+
+    let left: Vec<&str> = out.lines().collect();
+    let right: Vec<&str> = braille.lines().collect();
+    let width = left
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let lines: Vec<String> = (0..left.len().max(right.len()))
+        .map(|idx| {
+            let left = left.get(idx).copied().unwrap_or_default();
+            let missing = width - left.chars().count();
+            let right = right.get(idx).copied().unwrap_or_default();
+
+            format!("{left}{}{right}", BLANK.to_string().repeat(missing))
+                .trim_end_matches(BLANK)
+                .to_string()
+        })
+        .collect();
+
+    *out = lines.join("\n");
+}
@@ -2,29 +2,46 @@ use std::borrow::Borrow;
 use std::fmt::Write;
 
 use crate::{
-    attributes::{Attribute, Dir, ScriptLevel},
+    attributes::Attribute,
     elements::{
-        grouping::{ActionAttr, Prescripts},
-        scripted::UnderOverAttr,
-        AnnotationAttr, AnnotationContent, FracAttr, Num, OperatorAttr, PaddedAttr, SpaceAttr,
-        TableAttr, TableCellAttr,
+        grouping::{Action, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver},
+        Annotation, Frac, Ident, Num, Operator, Padded, Semantics, Space, StrLiteral, Table, Text,
     },
-    DisplayAttr, Element, MathMlAttr, Renderer, Writer,
+    markup::{self, Markup},
+    Element, GeneratedAltText, MathMl, Renderer, Writer,
 };
 
 /// Default implementation of MathMl [`Writer`].
+///
+/// Element content and attribute values are escaped, so that any text contained in the elements
+/// produces well-formed markup. Use [`BufMathMlWriter::raw`] if the content is already escaped.
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct BufMathMlWriter {
     buf: String,
+    raw: bool,
+    alttext: GeneratedAltText,
 }
 
 impl BufMathMlWriter {
-    fn write_elements(&mut self, elements: &[Element]) -> Result<(), <Self as Writer>::Error> {
-        for e in elements {
-            self.write_element(e)?;
+    /// Create a [`BufMathMlWriter`] that writes element content and attribute values as they
+    /// are, without escaping them.
+    ///
+    /// Only use this writer if all content has already been escaped, otherwise the produced
+    /// markup might be malformed.
+    pub fn raw() -> Self {
+        Self {
+            raw: true,
+            ..Default::default()
         }
+    }
 
-        Ok(())
+    /// Set which text alternatives are generated for `math` elements that do not have them
+    /// already, see [`MathMl::generate_alttext`].
+    pub fn with_generated_alttext(mut self, generated: GeneratedAltText) -> Self {
+        self.alttext = generated;
+        self
     }
 }
 
@@ -34,567 +51,114 @@ impl Write for BufMathMlWriter {
     }
 }
 
+impl Markup for BufMathMlWriter {
+    fn is_raw(&self) -> bool {
+        self.raw
+    }
+
+    fn generated_alttext(&self) -> GeneratedAltText {
+        self.alttext
+    }
+}
+
 impl Writer for BufMathMlWriter {
     type Buffer = String;
     type Error = std::fmt::Error;
 
-    fn write_action(
-        &mut self,
-        action: &crate::elements::grouping::Action,
-    ) -> Result<(), Self::Error> {
-        self.write_str("<maction")?;
-
-        for attr in action.attributes().iter() {
-            self.write_str(" ")?;
-
-            match attr {
-                ActionAttr::Global(g_attr) => {
-                    self.write_attr(g_attr)?;
-                }
-                ActionAttr::Selection(sel) => {
-                    self.write_str("selection=\"")?;
-                    self.write_str(sel)?;
-                    self.write_str("\"")?;
-                }
-                ActionAttr::ActionType(at) => {
-                    self.write_str("actiontype=\"")?;
-                    self.write_str(at)?;
-                    self.write_str("\"")?;
-                }
-            }
-        }
-
-        self.write_str(">")?;
-
-        self.write_elements(action.content())?;
-
-        self.write_str("</maction>")
+    fn write_action(&mut self, action: &Action) -> Result<(), Self::Error> {
+        markup::action(self, action)
     }
 
-    fn write_annotation(
-        &mut self,
-        annotation: &crate::elements::Annotation,
-    ) -> Result<(), Self::Error> {
-        let write_attr = |w: &mut BufMathMlWriter| -> Result<(), Self::Error> {
-            for attr in annotation.attributes() {
-                w.write_str(" ")?;
-
-                match attr {
-                    AnnotationAttr::Global(ref g_attr) => w.write_attr(g_attr)?,
-                    AnnotationAttr::Encoding(ref enc) => {
-                        w.write_str("encoding=\"")?;
-                        w.write_str(enc)?;
-                        w.write_str("\"")?;
-                    }
-                }
-            }
-
-            Ok(())
-        };
-
-        match annotation.content() {
-            AnnotationContent::Text(text) => {
-                self.write_str("<annotation")?;
-                write_attr(self)?;
-                self.write_str(">")?;
-                self.write_str(text)?;
-                self.write_str("</annotation>")
-            }
-            AnnotationContent::Nested(elements) => {
-                self.write_str("<annotation-xml")?;
-                write_attr(self)?;
-                self.write_str(">")?;
-                self.write_elements(elements)?;
-                self.write_str("</annotation-xml>")
-            }
-        }
+    fn write_annotation(&mut self, annotation: &Annotation) -> Result<(), Self::Error> {
+        markup::annotation(self, annotation)
     }
 
-    fn write_error(&mut self, error: &crate::elements::grouping::Error) -> Result<(), Self::Error> {
-        self.write_str("<merror")?;
-
-        for attr in error.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_elements(error.content())?;
-        self.write_str("</merror>")
+    fn write_error(&mut self, error: &Error) -> Result<(), Self::Error> {
+        markup::error(self, error)
     }
 
-    fn write_frac(&mut self, frac: &crate::elements::Frac) -> Result<(), Self::Error> {
-        self.write_str("<mfrac")?;
-
-        for attr in frac.attributes().iter() {
-            self.write_str(" ")?;
-
-            match attr {
-                FracAttr::Global(ga) => self.write_attr(ga)?,
-                FracAttr::LineThickness(lt) => {
-                    self.write_str("linethickness=\"")?;
-                    self.write_str(lt)?;
-                    self.write_str("\"")?;
-                }
-            }
-        }
-
-        self.write_str(">")?;
-        self.write_elements(frac.num())?;
-        self.write_elements(frac.denom())?;
-        self.write_str("</mfrac>")
+    fn write_frac(&mut self, frac: &Frac) -> Result<(), Self::Error> {
+        markup::frac(self, frac)
     }
 
-    fn write_ident(&mut self, ident: &crate::elements::Ident) -> Result<(), Self::Error> {
-        self.write_str("<mi")?;
-
-        for attr in ident.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_str(ident.ident())?;
-
-        self.write_str("</mi>")?;
-
-        Ok(())
+    fn write_ident(&mut self, ident: &Ident) -> Result<(), Self::Error> {
+        markup::ident(self, ident)
     }
 
-    fn write_multiscripts(
-        &mut self,
-        multiscripts: &crate::elements::scripted::Multiscripts,
-    ) -> Result<(), Self::Error> {
-        self.write_str("<mmultiscripts")?;
-
-        for attr in multiscripts.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_elements(multiscripts.content())?;
-        self.write_str("</mmultiscripts>")?;
-
-        Ok(())
+    fn write_multiscripts(&mut self, multiscripts: &Multiscripts) -> Result<(), Self::Error> {
+        markup::multiscripts(self, multiscripts)
     }
 
     fn write_prescripts(&mut self, prescripts: &Prescripts) -> Result<(), Self::Error> {
-        self.write_str("<mprescripts")?;
-
-        for attr in prescripts.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str("/>")?;
-
-        Ok(())
+        markup::prescripts(self, prescripts)
     }
 
-    fn write_num(&mut self, num: &crate::elements::Num) -> Result<(), Self::Error> {
-        self.write_str("<mn")?;
-
-        for attr in num.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_str(num.num())?;
-        self.write_str("</mn>")?;
-
-        Ok(())
-    }
-
-    fn write_operator(&mut self, operator: &crate::elements::Operator) -> Result<(), Self::Error> {
-        self.write_str("<mo")?;
-
-        for attr in operator.attributes().iter() {
-            self.write_str(" ")?;
-
-            match attr {
-                OperatorAttr::Global(ga) => self.write_attr(ga)?,
-                OperatorAttr::Form(form) => write!(self, r#"form="{form}""#)?,
-                OperatorAttr::Fence => write!(self, "fence=\"true\"")?,
-                OperatorAttr::Separator => write!(self, "separator=\"true\"")?,
-                OperatorAttr::LeftSpace(sp) => write!(self, r#"lspace="{sp}""#)?,
-                OperatorAttr::RightSpace(sp) => write!(self, r#"rspace="{sp}""#)?,
-                OperatorAttr::MaxSize(s) => write!(self, r#"maxsize="{s}""#)?,
-                OperatorAttr::MinSize(s) => write!(self, r#"minsize="{s}""#)?,
-                OperatorAttr::Stretchy => self.write_str("stretchy=\"true\"")?,
-                OperatorAttr::Symmetric => self.write_str("symmetric=\"true\"")?,
-                OperatorAttr::LargeOp => self.write_str("largeop=\"true\"")?,
-                OperatorAttr::MovableLimits => self.write_str("movablelimits=\"true\"")?,
-            }
-        }
-
-        self.write_str(">")?;
-        self.write_str(operator.op())?;
-        self.write_str("</mo>")?;
-
-        Ok(())
+    fn write_num(&mut self, num: &Num) -> Result<(), Self::Error> {
+        markup::num(self, num)
     }
 
-    fn write_padded(&mut self, padded: &crate::elements::Padded) -> Result<(), Self::Error> {
-        self.write_str("<mpadded")?;
-
-        for attr in padded.attributes().iter() {
-            self.write_str(" ")?;
-
-            match attr {
-                PaddedAttr::Width(w) => write!(self, r#"width="{w}""#)?,
-                PaddedAttr::Height(h) => write!(self, r#"height="{h}""#)?,
-                PaddedAttr::Depth(d) => write!(self, r#"depth="{d}""#)?,
-                PaddedAttr::LeftSpace(ls) => write!(self, r#"lspace="{ls}""#)?,
-                PaddedAttr::VerticalOffset(voffs) => write!(self, r#"voffset="{voffs}""#)?,
-                PaddedAttr::Global(ga) => self.write_attr(ga)?,
-            }
-        }
-
-        self.write_str(">")?;
-        self.write_elements(padded.children())?;
-        self.write_str("</mpadded>")
+    fn write_operator(&mut self, operator: &Operator) -> Result<(), Self::Error> {
+        markup::operator(self, operator)
     }
 
-    fn write_phantom(
-        &mut self,
-        phantom: &crate::elements::grouping::Phantom,
-    ) -> Result<(), Self::Error> {
-        self.write_str("<mphantom")?;
-
-        for attr in phantom.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_elements(phantom.children())?;
-        self.write_str("</mphantom>")
+    fn write_padded(&mut self, padded: &Padded) -> Result<(), Self::Error> {
+        markup::padded(self, padded)
     }
 
-    fn write_radical(
-        &mut self,
-        radical: &crate::elements::radicals::Radical,
-    ) -> Result<(), Self::Error> {
-        if radical.is_square() {
-            self.write_str("<msqrt")?;
-        } else {
-            self.write_str("<mroot")?;
-        }
-
-        for attr in radical.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_elements(radical.content())?;
-
-        if radical.is_square() {
-            self.write_str("</msqrt>")
-        } else {
-            self.write_elements(radical.index())?;
-            self.write_str("</mroot>")
-        }
+    fn write_phantom(&mut self, phantom: &Phantom) -> Result<(), Self::Error> {
+        markup::phantom(self, phantom)
     }
 
-    fn write_row(&mut self, row: &crate::elements::grouping::Row) -> Result<(), Self::Error> {
-        self.write_str("<mrow")?;
-
-        for attr in row.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_elements(row.children())?;
-        self.write_str("</mrow>")
+    fn write_radical(&mut self, radical: &Radical) -> Result<(), Self::Error> {
+        markup::radical(self, radical)
     }
 
-    fn write_semantics(
-        &mut self,
-        semantics: &crate::elements::Semantics,
-    ) -> Result<(), Self::Error> {
-        self.write_str("<semantics")?;
-
-        for attr in semantics.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_elements(semantics.children())?;
-        self.write_str("</semantics>")
+    fn write_row(&mut self, row: &Row) -> Result<(), Self::Error> {
+        markup::row(self, row)
     }
 
-    fn write_space(&mut self, space: &crate::elements::Space) -> Result<(), Self::Error> {
-        self.write_str("<mspace")?;
-
-        for attr in space.attributes().iter() {
-            self.write_str(" ")?;
-
-            match attr {
-                SpaceAttr::Width(w) => write!(self, r#"width="{w}""#)?,
-                SpaceAttr::Height(h) => write!(self, r#"height="{h}""#)?,
-                SpaceAttr::Depth(d) => write!(self, r#"depth="{d}""#)?,
-                SpaceAttr::Global(ref ga) => self.write_attr(ga)?,
-            }
-        }
-
-        self.write_str("/>")
+    fn write_semantics(&mut self, semantics: &Semantics) -> Result<(), Self::Error> {
+        markup::semantics(self, semantics)
     }
 
-    fn write_str_literal(
-        &mut self,
-        str_literal: &crate::elements::StrLiteral,
-    ) -> Result<(), Self::Error> {
-        self.write_str("<ms")?;
-
-        for attr in str_literal.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_str(str_literal.content())?;
-        self.write_str("</ms>")
+    fn write_space(&mut self, space: &Space) -> Result<(), Self::Error> {
+        markup::space(self, space)
     }
 
-    fn write_style(&mut self, style: &crate::elements::grouping::Style) -> Result<(), Self::Error> {
-        self.write_str("<mstyle")?;
-
-        for attr in style.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_elements(style.children())?;
-        self.write_str("</mstyle>")
+    fn write_str_literal(&mut self, str_literal: &StrLiteral) -> Result<(), Self::Error> {
+        markup::str_literal(self, str_literal)
     }
 
-    fn write_subsup(
-        &mut self,
-        sub_sup: &crate::elements::scripted::SubSup,
-    ) -> Result<(), Self::Error> {
-        let sub = sub_sup.sub();
-        let sup = sub_sup.sup();
-
-        let write_attr = |w: &mut BufMathMlWriter| -> Result<(), Self::Error> {
-            for attr in sub_sup.attributes().iter() {
-                w.write_str(" ")?;
-                w.write_attr(attr)?;
-            }
-
-            Ok(())
-        };
-
-        match (sub, sup) {
-            (None, None) => unreachable!("SubSup element must have at least one of sub or sup."),
-            (None, Some(sup)) => {
-                self.write_str("<msup")?;
-                write_attr(self)?;
-                self.write_str(">")?;
-                self.write_elements(sub_sup.base())?;
-                self.write_elements(sup)?;
-                self.write_str("</msup>")
-            }
-            (Some(sub), None) => {
-                self.write_str("<msub")?;
-                write_attr(self)?;
-                self.write_str(">")?;
-                self.write_elements(sub_sup.base())?;
-                self.write_elements(sub)?;
-                self.write_str("</msub>")
-            }
-            (Some(sub), Some(sup)) => {
-                self.write_str("<msubsup")?;
-                write_attr(self)?;
-                self.write_str(">")?;
-                self.write_elements(sub_sup.base())?;
-                self.write_elements(sub)?;
-                self.write_elements(sup)?;
-                self.write_str("</msubsup>")
-            }
-        }
+    fn write_style(&mut self, style: &Style) -> Result<(), Self::Error> {
+        markup::style(self, style)
     }
 
-    fn write_table(&mut self, table: &crate::elements::Table) -> Result<(), Self::Error> {
-        self.write_str("<mtable")?;
-
-        for table_attr in table.attributes().iter() {
-            self.write_str(" ")?;
-
-            match table_attr {
-                TableAttr::ColumnLines(cl) => {
-                    self.write_str("columnlines=\"")?;
-                    for line in cl.iter() {
-                        self.write_str(line.as_ref())?;
-                        self.write_str(" ")?;
-                    }
-
-                    self.write_str("\"")?;
-                }
-                TableAttr::Global(ref ga) => self.write_attr(ga)?,
-            }
-        }
-
-        self.write_str(">")?;
-
-        for row in table.rows() {
-            self.write_str("<mtr")?;
-
-            for row_attr in row.attributes().iter() {
-                self.write_str(" ")?;
-                self.write_attr(row_attr)?;
-            }
-
-            self.write_str(">")?;
-
-            for cell in row.cells() {
-                self.write_str("<mtd")?;
-
-                for cell_attr in cell.attributes().iter() {
-                    self.write_str(" ")?;
-
-                    match cell_attr {
-                        TableCellAttr::ColumnSpan(cs) => write!(self, r#"columnspan="{cs}""#)?,
-                        TableCellAttr::RowSpan(rs) => write!(self, r#"rowspan="{rs}""#)?,
-                        TableCellAttr::Global(ref ga) => self.write_attr(ga)?,
-                    }
-                }
-
-                self.write_str(">")?;
-                self.write_elements(cell.children())?;
-                self.write_str("</mtd>")?;
-            }
-
-            self.write_str("</mtr>")?;
-        }
-
-        self.write_str("</mtable>")
+    fn write_subsup(&mut self, subsup: &SubSup) -> Result<(), Self::Error> {
+        markup::subsup(self, subsup)
     }
 
-    fn write_text(&mut self, text: &crate::elements::Text) -> Result<(), Self::Error> {
-        self.write_str("<mtext")?;
-
-        for attr in text.attributes().iter() {
-            self.write_str(" ")?;
-            self.write_attr(attr)?;
-        }
-
-        self.write_str(">")?;
-        self.write_str(text.text())?;
-        self.write_str("</mtext>")
+    fn write_table(&mut self, table: &Table) -> Result<(), Self::Error> {
+        markup::table(self, table)
     }
 
-    fn write_underover(
-        &mut self,
-        under_over: &crate::elements::scripted::UnderOver,
-    ) -> Result<(), Self::Error> {
-        let under = under_over.under();
-        let over = under_over.over();
-
-        let write_attr = |w: &mut BufMathMlWriter| -> Result<(), Self::Error> {
-            w.write_str(" ")?;
-
-            for attr in under_over.attributes().iter() {
-                match attr {
-                    UnderOverAttr::AccentUnder => w.write_str(r#"accentunder="true""#)?,
-                    UnderOverAttr::AccentOver => w.write_str(r#"accent="true""#)?,
-                    UnderOverAttr::Global(ref ga) => w.write_attr(ga)?,
-                }
-            }
-
-            Ok(())
-        };
-
-        match (under, over) {
-            (None, None) => unreachable!("SubSup element must have at least one of sub or sup."),
-            (None, Some(over)) => {
-                self.write_str("<mover")?;
-                write_attr(self)?;
-                self.write_str(">")?;
-                self.write_elements(under_over.expr())?;
-                self.write_elements(over)?;
-                self.write_str("</mover>")
-            }
-            (Some(under), None) => {
-                self.write_str("<munder")?;
-                write_attr(self)?;
-                self.write_str(">")?;
-                self.write_elements(under_over.expr())?;
-                self.write_elements(under)?;
-                self.write_str("</munder>")
-            }
-            (Some(under), Some(over)) => {
-                self.write_str("<munderover")?;
-                write_attr(self)?;
-                self.write_str(">")?;
-                self.write_elements(under_over.expr())?;
-                self.write_elements(under)?;
-                self.write_elements(over)?;
-                self.write_str("</munderover>")
-            }
-        }
+    fn write_text(&mut self, text: &Text) -> Result<(), Self::Error> {
+        markup::text(self, text)
     }
 
-    fn write_attr(&mut self, attr: &crate::attributes::Attribute) -> Result<(), Self::Error> {
-        match attr {
-            Attribute::Class(c) => write!(self, "class=\"{}\"", c),
-            Attribute::Data { name, value } => write!(self, r#"data-{name}="{value}""#),
-            Attribute::Dir(dir) => match dir {
-                Dir::RightToLeft => self.write_str(r#"dir="rtl""#),
-                Dir::LeftToRight => self.write_str(r#"dir="ltr""#),
-            },
-            Attribute::DisplayStyle(d) => {
-                if *d {
-                    self.write_str(r#"display="normal"#)
-                } else {
-                    self.write_str(r#"display="compact""#)
-                }
-            }
-            Attribute::Id(id) => write!(self, r#"id="{id}""#),
-            Attribute::MathBackground(c) => write!(self, r#"mathbackground="{c}""#),
-            Attribute::MathColor(c) => write!(self, r#"mathcolor="{c}""#),
-            Attribute::MathSize(s) => write!(self, r#"mathsize="{s}""#),
-            Attribute::Nonce(n) => write!(self, r#"nonce="{n}""#),
-            Attribute::ScriptLevel(sl) => match sl {
-                ScriptLevel::Add(num) => write!(self, r#"scriptlevel="+{}""#, num),
-                ScriptLevel::Sub(num) => write!(self, r#"scriptlevel="-{}""#, num),
-                ScriptLevel::Num(num) => write!(self, r#"scriptlevel="{}""#, num),
-            },
-            Attribute::Style(st) => write!(self, r#"style="{st}""#),
-            Attribute::TabIndex(ti) => write!(self, r#"tabindex="{ti}""#),
-            Attribute::OnHandler { name, handler } => {
-                write!(self, r#"on{name}="{handler}""#)
-            }
-            Attribute::MathVariant(mv) => write!(self, r#"mathvariant="{mv}""#),
-        }
+    fn write_underover(&mut self, underover: &UnderOver) -> Result<(), Self::Error> {
+        markup::underover(self, underover)
     }
 
-    fn write_mathml(&mut self, mathml: &crate::MathMl) -> Result<(), Self::Error> {
-        self.write_str("<math")?;
+    fn write_attr(&mut self, attr: &Attribute) -> Result<(), Self::Error> {
+        markup::attribute(self, attr)
+    }
 
-        for attr in mathml.attr.iter() {
-            self.write_str(" ")?;
+    fn write_element(&mut self, element: &Element) -> Result<(), Self::Error> {
+        markup::element(self, element)
+    }
 
-            match attr {
-                MathMlAttr::Display(d) => {
-                    write!(self, r#"display=""#)?;
-                    match d {
-                        DisplayAttr::Block => write!(self, r#"block""#)?,
-                        DisplayAttr::Inline => write!(self, r#"inline""#)?,
-                    }
-                }
-                MathMlAttr::AltText(alt_t) => write!(self, r#"alttext="{alt_t}""#)?,
-                MathMlAttr::Global(a) => self.write_attr(a)?,
-            }
-        }
-        self.write_str(">")?;
-        self.write_elements(mathml.content())?;
-        self.write_str("</math>")
+    fn write_mathml(&mut self, mathml: &MathMl) -> Result<(), Self::Error> {
+        markup::mathml(self, mathml)
     }
 
     fn buffer<T>(&self) -> &T
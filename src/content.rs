@@ -0,0 +1,585 @@
+//! Content MathML, markup describing the meaning of a formula instead of its notation.
+//!
+//! A [`Content`] tree is made of identifiers (`ci`), numbers (`cn`) and applications (`apply`) of
+//! operators such as `plus` or `int` to their arguments. [`Content::to_presentation`] converts the
+//! tree into presentation [`Elements`], placing parentheses where the precedence of the operators
+//! requires them. [`Content::to_semantics`] keeps both: the presentation markup is annotated with
+//! the content tree in an `annotation-xml` element with `encoding="MathML-Content"`.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::content::{Apply, Content, ContentOp};
+//! use alemat::elements::{grouping::Row, scripted::SubSup, Ident, Num, Operator};
+//! use alemat::Element;
+//!
+//! // (x+1)^2
+//! let content = Content::from(
+//!     Apply::builder()
+//!         .op(ContentOp::Power)
+//!         .args([
+//!             Content::from(
+//!                 Apply::builder()
+//!                     .op(ContentOp::Plus)
+//!                     .args([Content::ci("x"), Content::cn("1")])
+//!                     .build(),
+//!             ),
+//!             Content::cn("2"),
+//!         ])
+//!         .build(),
+//! );
+//!
+//! let expected = SubSup::builder()
+//!     .base(Row::from([
+//!         Element::from(Operator::lparens()),
+//!         Ident::from("x").into(),
+//!         Operator::plus().into(),
+//!         Num::from(1).into(),
+//!         Operator::rparens().into(),
+//!     ]))
+//!     .supscript(Num::from(2))
+//!     .build();
+//!
+//! assert_eq!(content.to_presentation().into_inner(), [Element::from(expected)]);
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::{
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver},
+        Annotation, AnnotationAttr, Frac, Ident, Num, Operator, Semantics,
+    },
+    expr::starts_with_number,
+    markers::{Init, Uninit},
+    Element, Elements,
+};
+
+/// The encoding of `annotation-xml` elements containing Content MathML.
+pub const ENCODING: &str = "MathML-Content";
+
+/// A node of a Content MathML tree.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Content {
+    /// The `ci` element, an identifier such as a variable.
+    Ident(String),
+
+    /// The `cn` element, a number.
+    Number(String),
+
+    /// The `apply` element, an operator applied to its arguments.
+    Apply(Apply),
+}
+
+impl From<Apply> for Content {
+    fn from(value: Apply) -> Self {
+        Self::Apply(value)
+    }
+}
+
+impl Content {
+    /// Create the `ci` element with the given name.
+    pub fn ci(name: impl Into<String>) -> Self {
+        Self::Ident(name.into())
+    }
+
+    /// Create the `cn` element with the given number.
+    pub fn cn(number: impl Into<String>) -> Self {
+        Self::Number(number.into())
+    }
+
+    /// Convert the content tree into presentation elements.
+    pub fn to_presentation(&self) -> Elements {
+        Elements(presentation(self))
+    }
+
+    /// Create the `semantics` element containing the presentation of the content tree, annotated
+    /// with the content tree itself.
+    pub fn to_semantics(&self) -> Semantics {
+        let mut presentation = presentation(self);
+
+        let presentation = match presentation.len() {
+            1 => presentation.remove(0),
+            _ => Row::from(Elements(presentation)).into(),
+        };
+
+        let annotation = Annotation::builder()
+            .content(self.clone())
+            .attr([AnnotationAttr::Encoding(String::from(ENCODING))])
+            .build();
+
+        Semantics::builder()
+            .content([presentation, annotation.into()])
+            .build()
+    }
+}
+
+/// The operator of an `apply` element.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ContentOp {
+    /// The `plus` element, the sum of all arguments.
+    Plus,
+
+    /// The `minus` element, the difference of two arguments, or the negation of one.
+    Minus,
+
+    /// The `times` element, the product of all arguments.
+    Times,
+
+    /// The `divide` element, the quotient of two arguments.
+    Divide,
+
+    /// The `power` element, the first argument raised to the power of the second.
+    Power,
+
+    /// The `root` element, the root of the argument. Its degree is given with `degree`, otherwise
+    /// it is the square root.
+    Root,
+
+    /// The `int` element, the integral of the argument over the `bvar`, within `lowlimit` and
+    /// `uplimit`.
+    Int,
+
+    /// The `sum` element, the sum of the argument over the `bvar`, from `lowlimit` to `uplimit`.
+    Sum,
+
+    /// The `eq` element, the equality of all arguments.
+    Eq,
+
+    /// A function given as `ci` element, applied to the arguments, e.g. `f(x)`.
+    Function(String),
+}
+
+impl ContentOp {
+    /// Get the name of the element of the operator, or [`None`] for functions given as `ci`.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self {
+            ContentOp::Plus => "plus",
+            ContentOp::Minus => "minus",
+            ContentOp::Times => "times",
+            ContentOp::Divide => "divide",
+            ContentOp::Power => "power",
+            ContentOp::Root => "root",
+            ContentOp::Int => "int",
+            ContentOp::Sum => "sum",
+            ContentOp::Eq => "eq",
+            ContentOp::Function(_) => return None,
+        };
+
+        Some(name)
+    }
+
+    /// Get the operator of the element with the given name, e.g. `plus`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let op = match name {
+            "plus" => ContentOp::Plus,
+            "minus" => ContentOp::Minus,
+            "times" => ContentOp::Times,
+            "divide" => ContentOp::Divide,
+            "power" => ContentOp::Power,
+            "root" => ContentOp::Root,
+            "int" => ContentOp::Int,
+            "sum" => ContentOp::Sum,
+            "eq" => ContentOp::Eq,
+            _ => return None,
+        };
+
+        Some(op)
+    }
+}
+
+/// The `apply` element, applying an operator to its arguments. Integrals, sums and roots are
+/// qualified with the bound variables, limits and degree.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Apply {
+    op: ContentOp,
+    args: Vec<Content>,
+    bvar: Vec<Content>,
+    lowlimit: Option<Box<Content>>,
+    uplimit: Option<Box<Content>>,
+    degree: Option<Box<Content>>,
+}
+
+impl Apply {
+    /// Create a builder for [`Apply`] element.
+    pub fn builder() -> ApplyBuilder<Uninit> {
+        ApplyBuilder::default()
+    }
+
+    /// Get a reference to the operator of the [`Apply`] element.
+    pub fn op(&self) -> &ContentOp {
+        &self.op
+    }
+
+    /// Get a reference to the arguments of the [`Apply`] element.
+    pub fn args(&self) -> &[Content] {
+        &self.args
+    }
+
+    /// Get a reference to the bound variables (`bvar`) of the [`Apply`] element.
+    pub fn bvar(&self) -> &[Content] {
+        &self.bvar
+    }
+
+    /// Get a reference to the lower limit (`lowlimit`) of the [`Apply`] element.
+    pub fn lowlimit(&self) -> Option<&Content> {
+        self.lowlimit.as_deref()
+    }
+
+    /// Get a reference to the upper limit (`uplimit`) of the [`Apply`] element.
+    pub fn uplimit(&self) -> Option<&Content> {
+        self.uplimit.as_deref()
+    }
+
+    /// Get a reference to the degree (`degree`) of the [`Apply`] element.
+    pub fn degree(&self) -> Option<&Content> {
+        self.degree.as_deref()
+    }
+}
+
+/// Builder of the [`Apply`] element.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ApplyBuilder<T> {
+    op: Option<ContentOp>,
+    args: Vec<Content>,
+    bvar: Vec<Content>,
+    lowlimit: Option<Box<Content>>,
+    uplimit: Option<Box<Content>>,
+    degree: Option<Box<Content>>,
+
+    _marker: PhantomData<(T,)>,
+}
+
+impl<T> ApplyBuilder<T> {
+    /// Set the operator of the [`Apply`] element.
+    pub fn op(self, op: ContentOp) -> ApplyBuilder<Init> {
+        ApplyBuilder {
+            op: Some(op),
+            args: self.args,
+            bvar: self.bvar,
+            lowlimit: self.lowlimit,
+            uplimit: self.uplimit,
+            degree: self.degree,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Add arguments.
+    pub fn args<I, C>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Content>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add a bound variable.
+    pub fn bvar(mut self, bvar: impl Into<Content>) -> Self {
+        self.bvar.push(bvar.into());
+        self
+    }
+
+    /// Set the lower limit.
+    pub fn lowlimit(mut self, lowlimit: impl Into<Content>) -> Self {
+        self.lowlimit = Some(Box::new(lowlimit.into()));
+        self
+    }
+
+    /// Set the upper limit.
+    pub fn uplimit(mut self, uplimit: impl Into<Content>) -> Self {
+        self.uplimit = Some(Box::new(uplimit.into()));
+        self
+    }
+
+    /// Set the degree.
+    pub fn degree(mut self, degree: impl Into<Content>) -> Self {
+        self.degree = Some(Box::new(degree.into()));
+        self
+    }
+}
+
+impl ApplyBuilder<Init> {
+    /// Build the [`Apply`] element.
+    pub fn build(self) -> Apply {
+        Apply {
+            op: self
+                .op
+                .expect("Operator is guaranteed to be initialized at compile time."),
+            args: self.args,
+            bvar: self.bvar,
+            lowlimit: self.lowlimit,
+            uplimit: self.uplimit,
+            degree: self.degree,
+        }
+    }
+}
+
+/// Precedence of `eq`, the weakest binding notation.
+const RELATION: u8 = 1;
+
+/// Precedence of `sum` and `int`, which extend as far to the right as possible.
+const BIG_OPERATOR: u8 = 2;
+
+/// Precedence of `plus` and `minus` with two arguments.
+const SUM: u8 = 3;
+
+/// Precedence of `minus` with one argument and of negative numbers.
+const NEGATION: u8 = 4;
+
+/// Precedence of `times`.
+const PRODUCT: u8 = 5;
+
+/// Precedence of `divide`, shown as a fraction.
+const FRACTION: u8 = 6;
+
+/// Precedence of `power`.
+const POWER: u8 = 7;
+
+/// Precedence of identifiers, positive numbers, roots and function applications, which never
+/// need parentheses.
+const ATOM: u8 = 8;
+
+fn precedence(content: &Content) -> u8 {
+    match content {
+        Content::Ident(_) => ATOM,
+        Content::Number(number) if number.starts_with('-') => NEGATION,
+        Content::Number(_) => ATOM,
+        Content::Apply(apply) => match apply.op() {
+            ContentOp::Eq => RELATION,
+            ContentOp::Int | ContentOp::Sum => BIG_OPERATOR,
+            ContentOp::Minus if apply.args().len() == 1 => NEGATION,
+            ContentOp::Plus | ContentOp::Minus => SUM,
+            ContentOp::Times => PRODUCT,
+            ContentOp::Divide => FRACTION,
+            ContentOp::Power => POWER,
+            ContentOp::Root | ContentOp::Function(_) => ATOM,
+        },
+    }
+}
+
+fn presentation(content: &Content) -> Vec<Element> {
+    match content {
+        Content::Ident(name) => vec![Ident::from(name.as_str()).into()],
+        Content::Number(number) => match number.strip_prefix('-') {
+            Some(number) => vec![Operator::minus().into(), Num::from(number).into()],
+            None => vec![Num::from(number.as_str()).into()],
+        },
+        Content::Apply(apply) => apply_presentation(apply),
+    }
+}
+
+/// The presentation of the argument at the given index, or an empty row if it is missing.
+fn argument(args: &[Content], idx: usize) -> Vec<Element> {
+    match args.get(idx) {
+        Some(arg) => presentation(arg),
+        None => vec![Row::default().into()],
+    }
+}
+
+/// The presentation of an operand, placed in parentheses if it binds weaker than `min`. Sums and
+/// integrals are not placed in parentheses if they are the last operand, e.g. `2∑x`. Operands
+/// that start with a minus sign are also placed in parentheses if negations bind weaker than
+/// `min`, e.g. `a(-b⁢c)` instead of `a-b⁢c`.
+fn operand(content: &Content, min: u8, last: bool) -> Vec<Element> {
+    let precedence = precedence(content);
+    let elements = presentation(content);
+    let negated = min > NEGATION && starts_with_minus(&elements);
+
+    if (precedence >= min || (last && precedence == BIG_OPERATOR)) && !negated {
+        elements
+    } else {
+        parens(elements)
+    }
+}
+
+/// Whether the presentation starts with a minus sign, e.g. `-2` or `-b⁢c`.
+fn starts_with_minus(elements: &[Element]) -> bool {
+    match elements.first() {
+        Some(Element::Operator(op)) => op.op() == Operator::minus().op(),
+        _ => false,
+    }
+}
+
+fn parens(elements: Vec<Element>) -> Vec<Element> {
+    let row = std::iter::once(Operator::lparens().into())
+        .chain(elements)
+        .chain(std::iter::once(Operator::rparens().into()))
+        .collect::<Elements>();
+
+    vec![Row::from(row).into()]
+}
+
+/// The presentation of the arguments joined with the operators returned by `op`. The first
+/// operand binds at least as strong as `first`, all others at least as strong as `rest`.
+fn infix(
+    args: &[Content],
+    first: u8,
+    rest: u8,
+    op: impl Fn(&[Element]) -> Operator,
+) -> Vec<Element> {
+    let mut elements = Vec::new();
+
+    for (idx, arg) in args.iter().enumerate() {
+        let last = idx + 1 == args.len();
+
+        if idx == 0 {
+            elements.extend(operand(arg, first, last));
+            continue;
+        }
+
+        let operand = operand(arg, rest, last);
+        elements.push(op(&operand).into());
+        elements.extend(operand);
+    }
+
+    elements
+}
+
+fn apply_presentation(apply: &Apply) -> Vec<Element> {
+    let args = apply.args();
+
+    match apply.op() {
+        ContentOp::Plus => infix(args, SUM, PRODUCT, |_| Operator::plus()),
+        ContentOp::Minus if args.len() == 1 => std::iter::once(Operator::minus().into())
+            .chain(operand(&args[0], PRODUCT, true))
+            .collect(),
+        ContentOp::Minus => infix(args, SUM, PRODUCT, |_| Operator::minus()),
+        ContentOp::Times => infix(args, NEGATION, PRODUCT, |operand| match starts_with_number(
+            operand,
+        ) {
+            true => Operator::dot(),
+            false => Operator::from("\u{2062}"),
+        }),
+        ContentOp::Eq => infix(args, BIG_OPERATOR, BIG_OPERATOR, |_| Operator::eq()),
+        ContentOp::Divide => vec![Frac::builder()
+            .num(argument(args, 0))
+            .denom(argument(args, 1))
+            .build()
+            .into()],
+        ContentOp::Power => {
+            let base = match args.first() {
+                Some(base) => operand(base, ATOM, false),
+                None => vec![Row::default().into()],
+            };
+
+            vec![SubSup::builder()
+                .base(base)
+                .supscript(argument(args, 1))
+                .build()
+                .into()]
+        }
+        ContentOp::Root => {
+            let index = match apply.degree() {
+                Some(degree) => presentation(degree),
+                None => vec![Num::from(2).into()],
+            };
+
+            vec![Radical::builder()
+                .index(index)
+                .content(argument(args, 0))
+                .build()
+                .into()]
+        }
+        ContentOp::Int => {
+            let int = Operator::integral();
+
+            let int: Element = match (apply.lowlimit(), apply.uplimit()) {
+                (Some(low), Some(up)) => SubSup::builder()
+                    .base([int])
+                    .subscript(presentation(low))
+                    .supscript(presentation(up))
+                    .build()
+                    .into(),
+                (Some(low), None) => SubSup::builder()
+                    .base([int])
+                    .subscript(presentation(low))
+                    .build()
+                    .into(),
+                (None, Some(up)) => SubSup::builder()
+                    .base([int])
+                    .supscript(presentation(up))
+                    .build()
+                    .into(),
+                (None, None) => int.into(),
+            };
+
+            let mut elements = vec![int];
+
+            if let Some(body) = args.first() {
+                elements.extend(operand(body, PRODUCT, apply.bvar().is_empty()));
+            }
+
+            for bvar in apply.bvar() {
+                elements.push(Ident::from("d").into());
+                elements.extend(presentation(bvar));
+            }
+
+            elements
+        }
+        ContentOp::Sum => {
+            let under = match (apply.bvar().first(), apply.lowlimit()) {
+                (Some(bvar), Some(low)) => Some(
+                    presentation(bvar)
+                        .into_iter()
+                        .chain(std::iter::once(Operator::eq().into()))
+                        .chain(presentation(low))
+                        .collect::<Vec<_>>(),
+                ),
+                (_, Some(low)) => Some(presentation(low)),
+                (_, None) => None,
+            };
+
+            let over = apply.uplimit().map(presentation);
+
+            let sum: Element = match (under, over) {
+                (Some(under), Some(over)) => UnderOver::builder()
+                    .expr([Operator::sum()])
+                    .under(under)
+                    .over(over)
+                    .build()
+                    .into(),
+                (Some(under), None) => UnderOver::builder()
+                    .expr([Operator::sum()])
+                    .under(under)
+                    .build()
+                    .into(),
+                (None, Some(over)) => UnderOver::builder()
+                    .expr([Operator::sum()])
+                    .over(over)
+                    .build()
+                    .into(),
+                (None, None) => Operator::sum().into(),
+            };
+
+            let mut elements = vec![sum];
+
+            if let Some(body) = args.first() {
+                elements.extend(operand(body, PRODUCT, true));
+            }
+
+            elements
+        }
+        ContentOp::Function(name) => {
+            let mut arguments = Vec::new();
+
+            for (idx, arg) in args.iter().enumerate() {
+                if idx > 0 {
+                    arguments.push(Operator::from(",").into());
+                }
+
+                arguments.extend(presentation(arg));
+            }
+
+            let mut elements = vec![
+                Ident::from(name.as_str()).into(),
+                Operator::from("\u{2061}").into(),
+            ];
+
+            elements.extend(parens(arguments));
+            elements
+        }
+    }
+}
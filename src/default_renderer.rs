@@ -3,23 +3,67 @@ use std::convert::Infallible;
 use crate::{
     attributes::{Attribute, Dir, ScriptLevel},
     elements::{
-        grouping::{ActionAttr, Prescripts},
-        scripted::UnderOverAttr,
-        AnnotationAttr, AnnotationContent, FracAttr, OperatorAttr, PaddedAttr, SpaceAttr,
-        TableAttr, TableCellAttr,
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, AnnotationAttr, AnnotationContent, Frac, FracAttr, Ident, Num, Operator,
+        OperatorAttr, Padded, PaddedAttr, Semantics, Space, SpaceAttr, StrLiteral, Table,
+        TableAttr, TableCellAttr, Text,
     },
-    DisplayAttr, Element, MathMlAttr, Renderer,
+    escape::{self, EscapeCtx},
+    markup, BufMathMlWriter, DisplayAttr, Element, MathMl, MathMlAttr, Renderer, Writer,
 };
 
 /// Default MathMl [`Renderer`] implementation.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Element content and attribute values are escaped, so that any text contained in the elements
+/// produces well-formed markup. Use [`RawMathMlFormatter`] if the content is already escaped.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MathMlFormatter;
 
-impl MathMlFormatter {
-    fn render_elements(
-        &mut self,
-        elements: &[Element],
-    ) -> Result<<Self as Renderer>::Output, <Self as Renderer>::Error> {
+/// MathMl [`Renderer`] implementation that renders element content and attribute values as they
+/// are, without escaping them.
+///
+/// Only use this renderer if all content has already been escaped, otherwise the produced markup
+/// might be malformed.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RawMathMlFormatter;
+
+/// The formatter behind [`MathMlFormatter`] and [`RawMathMlFormatter`], which only differ in the
+/// escaping of element content and attribute values.
+#[derive(Debug, Copy, Clone)]
+struct Formatter {
+    /// Whether content and attribute values are rendered as they are.
+    raw: bool,
+}
+
+impl Formatter {
+    fn content(&self, content: &str) -> String {
+        if self.raw {
+            String::from(content)
+        } else {
+            escape::escape(content, EscapeCtx::Content)
+        }
+    }
+
+    fn attr_value(&self, name: &str, value: &str) -> String {
+        if self.raw {
+            format!(r#"{name}="{value}""#)
+        } else {
+            let value = escape::escape(value, EscapeCtx::AttrValue);
+            format!(r#"{name}="{value}""#)
+        }
+    }
+
+    fn attr_name(&self, name: &str) -> String {
+        if self.raw {
+            String::from(name)
+        } else {
+            escape::name(name)
+        }
+    }
+
+    fn render_elements(&mut self, elements: &[Element]) -> Result<String, Infallible> {
         elements
             .iter()
             .map(|el| self.render_element(el))
@@ -27,7 +71,135 @@ impl MathMlFormatter {
     }
 }
 
-impl Renderer for MathMlFormatter {
+/// Implement [`Renderer`] for the public formatters by delegating to [`Formatter`].
+macro_rules! delegate_renderer {
+    ($($formatter:ty => $raw:literal),* $(,)?) => {
+        $(
+            impl Renderer for $formatter {
+                type Output = String;
+                type Error = Infallible;
+
+                fn render_action(&mut self, action: &Action) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_action(action)
+                }
+
+                fn render_annotation(
+                    &mut self,
+                    annotation: &Annotation,
+                ) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_annotation(annotation)
+                }
+
+                fn render_error(&mut self, error: &Error) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_error(error)
+                }
+
+                fn render_frac(&mut self, frac: &Frac) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_frac(frac)
+                }
+
+                fn render_ident(&mut self, ident: &Ident) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_ident(ident)
+                }
+
+                fn render_multiscripts(
+                    &mut self,
+                    multiscripts: &Multiscripts,
+                ) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_multiscripts(multiscripts)
+                }
+
+                fn render_prescripts(
+                    &mut self,
+                    prescripts: &Prescripts,
+                ) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_prescripts(prescripts)
+                }
+
+                fn render_num(&mut self, num: &Num) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_num(num)
+                }
+
+                fn render_operator(&mut self, operator: &Operator) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_operator(operator)
+                }
+
+                fn render_padded(&mut self, padded: &Padded) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_padded(padded)
+                }
+
+                fn render_phantom(&mut self, phantom: &Phantom) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_phantom(phantom)
+                }
+
+                fn render_radical(&mut self, radical: &Radical) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_radical(radical)
+                }
+
+                fn render_row(&mut self, row: &Row) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_row(row)
+                }
+
+                fn render_semantics(
+                    &mut self,
+                    semantics: &Semantics,
+                ) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_semantics(semantics)
+                }
+
+                fn render_space(&mut self, space: &Space) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_space(space)
+                }
+
+                fn render_str_literal(
+                    &mut self,
+                    str_literal: &StrLiteral,
+                ) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_str_literal(str_literal)
+                }
+
+                fn render_style(&mut self, style: &Style) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_style(style)
+                }
+
+                fn render_subsup(&mut self, sub_sup: &SubSup) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_subsup(sub_sup)
+                }
+
+                fn render_table(&mut self, table: &Table) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_table(table)
+                }
+
+                fn render_text(&mut self, text: &Text) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_text(text)
+                }
+
+                fn render_underover(
+                    &mut self,
+                    under_over: &UnderOver,
+                ) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_underover(under_over)
+                }
+
+                fn render_attr(&mut self, attr: &Attribute) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_attr(attr)
+                }
+
+                fn render_element(&mut self, element: &Element) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_element(element)
+                }
+
+                fn render_mathml(&mut self, mathml: &MathMl) -> Result<String, Infallible> {
+                    Formatter { raw: $raw }.render_mathml(mathml)
+                }
+            }
+        )*
+    };
+}
+
+delegate_renderer!(MathMlFormatter => false, RawMathMlFormatter => true);
+
+impl Renderer for Formatter {
     type Output = String;
     type Error = Infallible;
 
@@ -40,8 +212,8 @@ impl Renderer for MathMlFormatter {
             .iter()
             .map(|m| match m {
                 ActionAttr::Global(g_attr) => self.render_attr(g_attr),
-                ActionAttr::Selection(sel) => Ok(format!(r#"selection="{sel}""#)),
-                ActionAttr::ActionType(at) => Ok(format!(r#"actiontype="{at}""#)),
+                ActionAttr::Selection(sel) => Ok(self.attr_value("selection", sel)),
+                ActionAttr::ActionType(at) => Ok(self.attr_value("actiontype", at)),
             })
             .collect::<Result<Vec<_>, _>>()?
             .join(" ");
@@ -56,8 +228,18 @@ impl Renderer for MathMlFormatter {
         annotation: &crate::elements::Annotation,
     ) -> Result<Self::Output, Self::Error> {
         let (tag, content) = match annotation.content() {
-            AnnotationContent::Text(ref t) => ("annotation", t.clone()),
+            AnnotationContent::Text(ref t) => ("annotation", self.content(t)),
             AnnotationContent::Nested(ref m) => ("annotation-xml", self.render_elements(m)?),
+            AnnotationContent::Content(ref tree) => {
+                let mut writer = match self.raw {
+                    true => BufMathMlWriter::raw(),
+                    false => BufMathMlWriter::default(),
+                };
+
+                markup::content_tree(&mut writer, tree)
+                    .expect("Writing into a string never fails.");
+                ("annotation-xml", writer.into_inner())
+            }
         };
 
         let attrs = annotation
@@ -65,7 +247,7 @@ impl Renderer for MathMlFormatter {
             .iter()
             .map(|a| match a {
                 AnnotationAttr::Global(g_attr) => self.render_attr(g_attr),
-                AnnotationAttr::Encoding(enc) => Ok(format!(r#"encoding="{enc}""#)),
+                AnnotationAttr::Encoding(enc) => Ok(self.attr_value("encoding", enc)),
             })
             .collect::<Result<Vec<_>, _>>()?
             .join(" ");
@@ -98,7 +280,7 @@ impl Renderer for MathMlFormatter {
             .iter()
             .map(|a| match a {
                 FracAttr::Global(ga) => self.render_attr(ga),
-                FracAttr::LineThickness(lt) => Ok(format!(r#"linethickness="{lt}""#)),
+                FracAttr::LineThickness(lt) => Ok(self.attr_value("linethickness", lt)),
             })
             .collect::<Result<Vec<_>, _>>()?
             .join(" ");
@@ -110,7 +292,7 @@ impl Renderer for MathMlFormatter {
         &mut self,
         ident: &crate::elements::Ident,
     ) -> Result<Self::Output, Self::Error> {
-        let content = ident.ident();
+        let content = self.content(ident.ident());
         let attrs = ident
             .attributes()
             .iter()
@@ -147,7 +329,7 @@ impl Renderer for MathMlFormatter {
     }
 
     fn render_num(&mut self, num: &crate::elements::Num) -> Result<Self::Output, Self::Error> {
-        let num_str = num.num();
+        let num_str = self.content(num.num());
         let attr = num
             .attributes()
             .iter()
@@ -162,7 +344,7 @@ impl Renderer for MathMlFormatter {
         &mut self,
         operator: &crate::elements::Operator,
     ) -> Result<Self::Output, Self::Error> {
-        let op = operator.op();
+        let op = self.content(operator.op());
         let attr = operator
             .attributes()
             .iter()
@@ -172,10 +354,10 @@ impl Renderer for MathMlFormatter {
                     OperatorAttr::Form(form) => format!(r#"form="{form}""#),
                     OperatorAttr::Fence => String::from("fence=\"true\""),
                     OperatorAttr::Separator => String::from("separator=\"true\""),
-                    OperatorAttr::LeftSpace(sp) => format!(r#"lspace="{sp}""#),
-                    OperatorAttr::RightSpace(sp) => format!(r#"rspace="{sp}""#),
-                    OperatorAttr::MaxSize(s) => format!(r#"maxsize="{s}""#),
-                    OperatorAttr::MinSize(s) => format!(r#"minsize="{s}""#),
+                    OperatorAttr::LeftSpace(sp) => self.attr_value("lspace", sp),
+                    OperatorAttr::RightSpace(sp) => self.attr_value("rspace", sp),
+                    OperatorAttr::MaxSize(s) => self.attr_value("maxsize", s),
+                    OperatorAttr::MinSize(s) => self.attr_value("minsize", s),
                     OperatorAttr::Stretchy => String::from("stretchy=\"true\""),
                     OperatorAttr::Symmetric => String::from("symmetric=\"true\""),
                     OperatorAttr::LargeOp => String::from("largeop=\"true\""),
@@ -199,11 +381,11 @@ impl Renderer for MathMlFormatter {
             .iter()
             .map(|a| {
                 let attr = match a {
-                    PaddedAttr::Width(w) => format!(r#"width="{w}""#),
-                    PaddedAttr::Height(h) => format!(r#"height="{h}""#),
-                    PaddedAttr::Depth(d) => format!(r#"depth="{d}""#),
-                    PaddedAttr::LeftSpace(ls) => format!(r#"lspace="{ls}""#),
-                    PaddedAttr::VerticalOffset(voffs) => format!(r#"voffset="{voffs}""#),
+                    PaddedAttr::Width(w) => self.attr_value("width", w),
+                    PaddedAttr::Height(h) => self.attr_value("height", h),
+                    PaddedAttr::Depth(d) => self.attr_value("depth", d),
+                    PaddedAttr::LeftSpace(ls) => self.attr_value("lspace", ls),
+                    PaddedAttr::VerticalOffset(voffs) => self.attr_value("voffset", voffs),
                     PaddedAttr::Global(ga) => self.render_attr(ga)?,
                 };
                 Ok(attr)
@@ -289,9 +471,9 @@ impl Renderer for MathMlFormatter {
             .iter()
             .map(|a| {
                 let attr = match a {
-                    SpaceAttr::Width(w) => format!(r#"width="{w}""#),
-                    SpaceAttr::Height(h) => format!(r#"height="{h}""#),
-                    SpaceAttr::Depth(d) => format!(r#"depth="{d}""#),
+                    SpaceAttr::Width(w) => self.attr_value("width", w),
+                    SpaceAttr::Height(h) => self.attr_value("height", h),
+                    SpaceAttr::Depth(d) => self.attr_value("depth", d),
                     SpaceAttr::Global(ga) => self.render_attr(ga)?,
                 };
 
@@ -307,7 +489,7 @@ impl Renderer for MathMlFormatter {
         &mut self,
         str_literal: &crate::elements::StrLiteral,
     ) -> Result<Self::Output, Self::Error> {
-        let content = str_literal.content();
+        let content = self.content(str_literal.content());
         let attr = str_literal
             .attributes()
             .iter()
@@ -380,8 +562,8 @@ impl Renderer for MathMlFormatter {
                     .attributes()
                     .iter()
                     .map(|a| match a {
-                        TableCellAttr::ColumnSpan(cs) => Ok(format!(r#"columnspan="{cs}""#)),
-                        TableCellAttr::RowSpan(rs) => Ok(format!(r#"rowspan="{rs}""#)),
+                        TableCellAttr::ColumnSpan(cs) => Ok(self.attr_value("columnspan", cs)),
+                        TableCellAttr::RowSpan(rs) => Ok(self.attr_value("rowspan", rs)),
                         TableCellAttr::Global(ga) => self.render_attr(ga),
                     })
                     .collect::<Result<Vec<_>, _>>()?
@@ -427,7 +609,7 @@ impl Renderer for MathMlFormatter {
     }
 
     fn render_text(&mut self, text: &crate::elements::Text) -> Result<Self::Output, Self::Error> {
-        let content = text.text();
+        let content = self.content(text.text());
         let attr = text
             .attributes()
             .iter()
@@ -483,32 +665,36 @@ impl Renderer for MathMlFormatter {
         attr: &crate::attributes::Attribute,
     ) -> Result<Self::Output, Self::Error> {
         let attr = match attr {
-            Attribute::Class(c) => format!("class=\"{}\"", c),
-            Attribute::Data { name, value } => format!(r#"data-{name}="{value}""#),
+            Attribute::Class(c) => self.attr_value("class", c),
+            Attribute::Data { name, value } => {
+                self.attr_value(&format!("data-{}", self.attr_name(name)), value)
+            }
             Attribute::Dir(dir) => match dir {
                 Dir::RightToLeft => String::from(r#"dir="rtl""#),
                 Dir::LeftToRight => String::from(r#"dir="ltr""#),
             },
             Attribute::DisplayStyle(d) => {
                 if *d {
-                    String::from(r#"display="normal"#)
+                    String::from(r#"display="normal""#)
                 } else {
                     String::from(r#"display="compact""#)
                 }
             }
-            Attribute::Id(id) => format!(r#"id="{id}""#),
-            Attribute::MathBackground(c) => format!(r#"mathbackground="{c}""#),
-            Attribute::MathColor(c) => format!(r#"mathcolor="{c}""#),
-            Attribute::MathSize(s) => format!(r#"mathsize="{s}""#),
-            Attribute::Nonce(n) => format!(r#"nonce="{n}""#),
+            Attribute::Id(id) => self.attr_value("id", id),
+            Attribute::MathBackground(c) => self.attr_value("mathbackground", c),
+            Attribute::MathColor(c) => self.attr_value("mathcolor", c),
+            Attribute::MathSize(s) => self.attr_value("mathsize", s),
+            Attribute::Nonce(n) => self.attr_value("nonce", n),
             Attribute::ScriptLevel(sl) => match sl {
                 ScriptLevel::Add(num) => format!(r#"scriptlevel="+{}""#, num),
                 ScriptLevel::Sub(num) => format!(r#"scriptlevel="-{}""#, num),
                 ScriptLevel::Num(num) => format!(r#"scriptlevel="{}""#, num),
             },
-            Attribute::Style(st) => format!(r#"style="{st}""#),
+            Attribute::Style(st) => self.attr_value("style", st),
             Attribute::TabIndex(ti) => format!(r#"tabindex="{ti}""#),
-            Attribute::OnHandler { name, handler } => format!(r#"on{name}="{handler}""#),
+            Attribute::OnHandler { name, handler } => {
+                self.attr_value(&format!("on{}", self.attr_name(name)), handler)
+            }
             Attribute::MathVariant(mv) => format!(r#"mathvariant="{mv}""#),
         };
 
@@ -526,7 +712,8 @@ impl Renderer for MathMlFormatter {
                     DisplayAttr::Block => Ok(String::from(r#"display="block""#)),
                     DisplayAttr::Inline => Ok(String::from(r#"display="inline""#)),
                 },
-                MathMlAttr::AltText(alt_t) => Ok(format!(r#"alttext="{alt_t}""#)),
+                MathMlAttr::AltText(alt_t) => Ok(self.attr_value("alttext", alt_t)),
+                MathMlAttr::AriaLabel(label) => Ok(self.attr_value("aria-label", label)),
                 MathMlAttr::Global(a) => self.render_attr(a),
             })
             .collect::<Result<Vec<_>, _>>()?
@@ -243,3 +243,40 @@ macro_rules! element_from_type {
 }
 
 pub(crate) use element_from_type;
+
+macro_rules! attr_accessors {
+    ($type:ident, $field:ident: $attr:ty) => {
+        impl $type {
+            /// Get a mutable reference to all attributes of the
+            #[doc = concat!("[`", stringify!($type), "`] element.")]
+            pub fn attributes_mut(&mut self) -> &mut Vec<$attr> {
+                &mut self.$field
+            }
+
+            /// Remove all attributes equal to the given attribute from the
+            #[doc = concat!("[`", stringify!($type), "`] element.")]
+            /// Returns `true` if any attribute was removed.
+            pub fn remove_attr(&mut self, attr: impl Into<$attr>) -> bool {
+                let attr = attr.into();
+                let len = self.$field.len();
+                self.$field.retain(|a| *a != attr);
+                self.$field.len() != len
+            }
+        }
+    };
+}
+
+pub(crate) use attr_accessors;
+
+/// Wrap the elements in a [`Row`](grouping::Row) unless there is exactly one element, so that they
+/// form a single argument of elements such as `mfrac`, which expect an exact number of children.
+/// An empty list becomes an empty row.
+pub(crate) fn single_argument(elements: impl IntoElements) -> Elements {
+    let elements = elements.into_elements();
+
+    if elements.len() == 1 {
+        elements
+    } else {
+        grouping::Row::from(elements).into_elements()
+    }
+}
@@ -2,6 +2,7 @@ use std::marker::PhantomData;
 
 use crate::{
     attributes::Attribute,
+    content::Content,
     markers::{Init, Uninit},
     Element, Elements,
 };
@@ -15,6 +16,8 @@ pub enum AnnotationContent {
     Text(String),
     /// MathML content of the `annotation` element. Implies the `annotation-xml` variant.
     Nested(Elements),
+    /// Content MathML tree of the `annotation` element. Implies the `annotation-xml` variant.
+    Content(Content),
 }
 
 impl From<String> for AnnotationContent {
@@ -38,6 +41,12 @@ impl From<Element> for AnnotationContent {
     }
 }
 
+impl From<Content> for AnnotationContent {
+    fn from(value: Content) -> Self {
+        Self::Content(value)
+    }
+}
+
 /// An attribute of `annotation` element. Either one of the global [`Attribute`]s, or `encode`
 /// attribute.
 ///
@@ -91,12 +100,40 @@ impl Annotation {
         &self.content
     }
 
+    /// Get a mutable reference to the inner content of the [`Annotation`] element.
+    pub fn content_mut(&mut self) -> &mut AnnotationContent {
+        &mut self.content
+    }
+
     /// Get a reference to all attributes of the [`Annotation`] element.
     pub fn attributes(&self) -> &[AnnotationAttr] {
         &self.attributes
     }
+
+    /// Replace the inner content of the [`Annotation`] element.
+    pub fn set_content(&mut self, content: impl Into<AnnotationContent>) {
+        self.content = content.into();
+    }
+
+    /// Consume the [`Annotation`] element and return its inner content and attributes.
+    pub fn into_parts(self) -> (AnnotationContent, Vec<AnnotationAttr>) {
+        (self.content, self.attributes)
+    }
+
+    /// Create the [`Annotation`] element from its inner content and attributes.
+    pub fn from_parts(
+        content: impl Into<AnnotationContent>,
+        attributes: Vec<AnnotationAttr>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Annotation, attributes: AnnotationAttr);
+
 /// Builder of the [`Annotation`] element.
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct AnnotationBuilder<T> {
@@ -163,12 +200,37 @@ impl Semantics {
         &self.children
     }
 
+    /// Get a mutable reference to the inner content of the [`Semantics`] element.
+    pub fn children_mut(&mut self) -> &mut Elements {
+        &mut self.children
+    }
+
     /// Get a reference to all attributes of the [`Semantics`] element.
     pub fn attributes(&self) -> &[Attribute] {
         &self.attr
     }
+
+    /// Replace the children of the [`Semantics`] element.
+    pub fn set_children(&mut self, children: impl IntoElements) {
+        self.children = children.into_elements();
+    }
+
+    /// Consume the [`Semantics`] element and return its children and attributes.
+    pub fn into_parts(self) -> (Elements, Vec<Attribute>) {
+        (self.children, self.attr)
+    }
+
+    /// Create the [`Semantics`] element from its children and attributes.
+    pub fn from_parts(children: impl IntoElements, attributes: Vec<Attribute>) -> Self {
+        Self {
+            children: children.into_elements(),
+            attr: attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Semantics, attr: Attribute);
+
 crate::element_from_type!(Semantics => Semantics);
 
 /// Builder of the [`Semantics`] element.
@@ -32,8 +32,8 @@ pub enum ActionAttr {
     ///   ```
     ///
     /// * `toggle`: When there is a click on the subexpression, the rendering alternates the
-    ///    display of selected subexpressions. Therefore each click increments the selection value.
-    ///    The syntax is:
+    ///   display of selected subexpressions. Therefore each click increments the selection value.
+    ///   The syntax is:
     ///
     ///   ```html
     ///   <maction actiontype="toggle" selection="positive-integer">
@@ -78,12 +78,37 @@ impl Action {
         &self.content
     }
 
+    /// Get a mutable reference to the inner content of the [`Action`] element.
+    pub fn content_mut(&mut self) -> &mut Elements {
+        &mut self.content
+    }
+
     /// Get a reference to all attributes of the [`Action`] element.
     pub fn attributes(&self) -> &[ActionAttr] {
         &self.attributes
     }
+
+    /// Replace the inner content of the [`Action`] element.
+    pub fn set_content(&mut self, content: impl IntoElements) {
+        self.content = content.into_elements();
+    }
+
+    /// Consume the [`Action`] element and return its inner content and attributes.
+    pub fn into_parts(self) -> (Elements, Vec<ActionAttr>) {
+        (self.content, self.attributes)
+    }
+
+    /// Create the [`Action`] element from its inner content and attributes.
+    pub fn from_parts(content: impl IntoElements, attributes: Vec<ActionAttr>) -> Self {
+        Self {
+            content: content.into_elements(),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Action, attributes: ActionAttr);
+
 crate::element_from_type!(Action => Action);
 
 /// Builder of the [`Action`] element.
@@ -36,12 +36,37 @@ impl Error {
         &self.content
     }
 
+    /// Get a mutable reference to the inner content of the [`Error`] element.
+    pub fn content_mut(&mut self) -> &mut Elements {
+        &mut self.content
+    }
+
     /// Get a reference to all attributes of the [`Error`] element.
     pub fn attributes(&self) -> &[Attribute] {
         &self.attributes
     }
+
+    /// Replace the inner content of the [`Error`] element.
+    pub fn set_content(&mut self, content: impl IntoElements) {
+        self.content = content.into_elements();
+    }
+
+    /// Consume the [`Error`] element and return its inner content and attributes.
+    pub fn into_parts(self) -> (Elements, Vec<Attribute>) {
+        (self.content, self.attributes)
+    }
+
+    /// Create the [`Error`] element from its inner content and attributes.
+    pub fn from_parts(content: impl IntoElements, attributes: Vec<Attribute>) -> Self {
+        Self {
+            content: content.into_elements(),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Error, attributes: Attribute);
+
 impl From<Element> for Error {
     fn from(value: Element) -> Self {
         Self {
@@ -4,7 +4,6 @@ use crate::attributes::Attribute;
 use crate::markers::{Init, Uninit};
 use crate::{Element, Elements};
 
-use super::grouping::Row;
 use super::IntoElements;
 
 /// An attribute of `mfrac` element. Either one of the global [`Attribute`]s, or `linethickness`
@@ -19,7 +18,10 @@ pub enum FracAttr {
     /// The `linethickness` attribute indicates the fraction line thickness to use for the fraction
     /// bar.
     /// It must have a value that is a valid
-    /// [`<length-percentage>`](https://www.w3.org/TR/css-values-4/#typedef-length-percentage).
+    /// [`<length-percentage>`](https://www.w3.org/TR/css-values-4/#typedef-length-percentage),
+    /// e.g. built from [`Length::px`].
+    ///
+    /// [`Length::px`]: crate::attributes::Length::px
     LineThickness(String),
 }
 
@@ -45,17 +47,68 @@ impl Frac {
         &self.num
     }
 
+    /// Get a mutable reference to the content of the numerator.
+    pub fn num_mut(&mut self) -> &mut Elements {
+        &mut self.num
+    }
+
     /// Get a reference to the content of the denominator.
     pub fn denom(&self) -> &[Element] {
         &self.denom
     }
 
+    /// Get a mutable reference to the content of the denominator.
+    pub fn denom_mut(&mut self) -> &mut Elements {
+        &mut self.denom
+    }
+
     /// Get a reference to all attributes of the [`Frac`] element.
     pub fn attributes(&self) -> &[FracAttr] {
         &self.attributes
     }
+
+    /// Replace the content of the numerator. Multiple or no elements are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_num(&mut self, num: impl IntoElements) {
+        self.num = super::single_argument(num);
+    }
+
+    /// Replace the content of the denominator. Multiple or no elements are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_denom(&mut self, denom: impl IntoElements) {
+        self.denom = super::single_argument(denom);
+    }
+
+    /// Consume the [`Frac`] element and return its numerator, denominator and attributes.
+    pub fn into_parts(self) -> (Elements, Elements, Vec<FracAttr>) {
+        (self.num, self.denom, self.attributes)
+    }
+
+    /// Create the [`Frac`] element from its numerator, denominator and attributes. Multiple or no
+    /// elements in the numerator or denominator are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn from_parts(
+        num: impl IntoElements,
+        denom: impl IntoElements,
+        attributes: Vec<FracAttr>,
+    ) -> Self {
+        Self {
+            num: super::single_argument(num),
+            denom: super::single_argument(denom),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Frac, attributes: FracAttr);
+
+/// Multiple or no elements in the numerator or denominator are wrapped in a [`Row`], like in
+/// [`FracBuilder`].
+///
+/// [`Row`]: super::grouping::Row
 impl<N, D> From<(N, D)> for Frac
 where
     N: IntoElements,
@@ -71,8 +124,8 @@ where
         };
 
         Self {
-            num: num.into_elements(),
-            denom: denom.into_elements(),
+            num: super::single_argument(num),
+            denom: super::single_argument(denom),
             attributes: Default::default(),
         }
     }
@@ -101,33 +154,31 @@ pub struct FracBuilder<N, D> {
 }
 
 impl<N, D> FracBuilder<N, D> {
-    /// Add or overwrite the numerator to the `mfrac` element.
+    /// Add or overwrite the numerator to the `mfrac` element. Multiple or no elements are
+    /// wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
     pub fn num(self, num: impl IntoElements) -> FracBuilder<Init, D> {
-        let mut num = num.into_elements();
-
-        if num.len() > 1 {
-            num = Row::from(num).into_elements();
-        }
+        let num = super::single_argument(num);
 
         FracBuilder {
-            num: Some(num.into_elements()),
+            num: Some(num),
             denom: self.denom,
             attr: self.attr,
             _marker: PhantomData,
         }
     }
 
-    /// Add or overwrite the denominator to the `mfrac` element.
+    /// Add or overwrite the denominator to the `mfrac` element. Multiple or no elements are
+    /// wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
     pub fn denom(self, denom: impl IntoElements) -> FracBuilder<N, Init> {
-        let mut denom = denom.into_elements();
-
-        if denom.len() > 1 {
-            denom = Row::from(denom).into_elements();
-        }
+        let denom = super::single_argument(denom);
 
         FracBuilder {
             num: self.num,
-            denom: Some(denom.into_elements()),
+            denom: Some(denom),
             attr: self.attr,
             _marker: PhantomData,
         }
@@ -30,8 +30,33 @@ impl Ident {
     pub fn attributes(&self) -> &[Attribute] {
         &self.attributes
     }
+
+    /// Get a mutable reference to the identifier of the [`Ident`] element.
+    pub fn ident_mut(&mut self) -> &mut String {
+        &mut self.ident
+    }
+
+    /// Replace the identifier of the [`Ident`] element.
+    pub fn set_ident(&mut self, ident: impl Into<String>) {
+        self.ident = ident.into();
+    }
+
+    /// Consume the [`Ident`] element and return its identifier and attributes.
+    pub fn into_parts(self) -> (String, Vec<Attribute>) {
+        (self.ident, self.attributes)
+    }
+
+    /// Create the [`Ident`] element from its identifier and attributes.
+    pub fn from_parts(ident: impl Into<String>, attributes: Vec<Attribute>) -> Self {
+        Self {
+            ident: ident.into(),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Ident, attributes: Attribute);
+
 impl<T> From<T> for Ident
 where
     T: Into<String>,
@@ -22,12 +22,37 @@ impl Multiscripts {
         &self.content
     }
 
+    /// Get a mutable reference to the inner content of the [`Multiscripts`] element.
+    pub fn content_mut(&mut self) -> &mut Elements {
+        &mut self.content
+    }
+
     /// Get a reference to all attributes of the [`Multiscripts`] element.
     pub fn attributes(&self) -> &[Attribute] {
         &self.attributes
     }
+
+    /// Replace the inner content of the [`Multiscripts`] element.
+    pub fn set_content(&mut self, content: impl IntoElements) {
+        self.content = content.into_elements();
+    }
+
+    /// Consume the [`Multiscripts`] element and return its inner content and attributes.
+    pub fn into_parts(self) -> (Elements, Vec<Attribute>) {
+        (self.content, self.attributes)
+    }
+
+    /// Create the [`Multiscripts`] element from its inner content and attributes.
+    pub fn from_parts(content: impl IntoElements, attributes: Vec<Attribute>) -> Self {
+        Self {
+            content: content.into_elements(),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Multiscripts, attributes: Attribute);
+
 impl From<Elements> for Multiscripts {
     fn from(value: Elements) -> Self {
         Self {
@@ -106,6 +131,18 @@ impl Prescripts {
     pub fn attributes(&self) -> &[Attribute] {
         &self.attr
     }
+
+    /// Consume the [`Prescripts`] element and return its attributes.
+    pub fn into_parts(self) -> Vec<Attribute> {
+        self.attr
+    }
+
+    /// Create the [`Prescripts`] element from its attributes.
+    pub fn from_parts(attributes: Vec<Attribute>) -> Self {
+        Self { attr: attributes }
+    }
 }
 
+crate::attr_accessors!(Prescripts, attr: Attribute);
+
 crate::element_from_type!(Prescripts => Prescripts);
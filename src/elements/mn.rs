@@ -21,6 +21,9 @@ impl<'a> From<&'a str> for Num {
 crate::from_types!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize => Num;
             |val| Num { num: format!("{}", val), attributes: Default::default() });
 
+crate::from_types!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize => crate::Element;
+            |val| crate::Element::Num(Num::from(val)));
+
 impl Num {
     /// Add attributes to this instance of [`Num`].
     pub fn add_attr<I, A>(&mut self, attr: I)
@@ -50,6 +53,31 @@ impl Num {
     pub fn attributes(&self) -> &[Attribute] {
         &self.attributes
     }
+
+    /// Get a mutable reference to the number of the [`Num`] element.
+    pub fn num_mut(&mut self) -> &mut String {
+        &mut self.num
+    }
+
+    /// Replace the number of the [`Num`] element.
+    pub fn set_num(&mut self, num: impl Into<String>) {
+        self.num = num.into();
+    }
+
+    /// Consume the [`Num`] element and return its number and attributes.
+    pub fn into_parts(self) -> (String, Vec<Attribute>) {
+        (self.num, self.attributes)
+    }
+
+    /// Create the [`Num`] element from its number and attributes.
+    pub fn from_parts(num: impl Into<String>, attributes: Vec<Attribute>) -> Self {
+        Self {
+            num: num.into(),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Num, attributes: Attribute);
+
 crate::element_from_type!(Num => Num);
@@ -466,3 +466,114 @@ impl Operator {
         Self::from("\u{007E}")
     }
 }
+
+impl Operator {
+    /// The name of the operator when spoken, e.g. `"plus"` for `+` or `"is less than"` for `<`.
+    /// Returns `None` for operators without a known name.
+    pub fn spoken_name(&self) -> Option<&'static str> {
+        let name = match self.op() {
+            "\u{002B}" => "plus",
+            "\u{002D}" | "\u{2212}" => "minus",
+            "\u{22C5}" | "\u{00B7}" | "\u{00D7}" => "times",
+            "\u{00A8}" => "double dot",
+            "\u{2217}" | "*" => "asterisk",
+            "\u{22C6}" => "star",
+            "\u{002F}" | "\u{00F7}" => "divided by",
+            "\u{2216}" => "set minus",
+            "\u{22C9}" => "left semidirect product",
+            "\u{22CA}" => "right semidirect product",
+            "\u{22C8}" => "bowtie",
+            "\u{2218}" => "composed with",
+            "\u{2295}" => "circle plus",
+            "\u{2297}" => "circle times",
+            "\u{2299}" => "circle dot",
+            "\u{2211}" => "sum",
+            "\u{220F}" => "product",
+            "\u{2210}" => "coproduct",
+            "\u{2227}" | "\u{22C0}" => "and",
+            "\u{2228}" | "\u{22C1}" => "or",
+            "\u{2229}" | "\u{22C2}" => "intersection",
+            "\u{222A}" | "\u{22C3}" => "union",
+            "\u{222B}" => "integral",
+            "\u{222C}" => "double integral",
+            "\u{222D}" => "triple integral",
+            "\u{222E}" => "contour integral",
+            "\u{2202}" => "partial",
+            "\u{2207}" => "nabla",
+            "\u{00B1}" => "plus or minus",
+            "\u{2213}" => "minus or plus",
+            "\u{2234}" => "therefore",
+            "\u{2235}" => "because",
+            "\u{2220}" => "angle",
+            "\u{230A}" => "left floor",
+            "\u{230B}" => "right floor",
+            "\u{2308}" => "left ceiling",
+            "\u{2309}" => "right ceiling",
+            "\u{2254}" => "is defined as",
+            "\u{003D}" => "equals",
+            "\u{2260}" => "is not equal to",
+            "\u{003C}" => "is less than",
+            "\u{003E}" => "is greater than",
+            "\u{2264}" => "is less than or equal to",
+            "\u{2265}" => "is greater than or equal to",
+            "\u{227A}" => "precedes",
+            "\u{227B}" => "succeeds",
+            "\u{227C}" => "precedes or equals",
+            "\u{227D}" => "succeeds or equals",
+            "\u{2208}" => "is an element of",
+            "\u{2209}" => "is not an element of",
+            "\u{2282}" => "is a subset of",
+            "\u{2283}" => "is a superset of",
+            "\u{2286}" => "is a subset of or equal to",
+            "\u{2287}" => "is a superset of or equal to",
+            "\u{2261}" => "is equivalent to",
+            "\u{2245}" => "is congruent to",
+            "\u{2248}" => "is approximately equal to",
+            "\u{221D}" => "is proportional to",
+            "\u{00AC}" => "not",
+            "\u{21D2}" => "implies",
+            "\u{21D0}" => "is implied by",
+            "\u{21D4}" => "if and only if",
+            "\u{2200}" => "for all",
+            "\u{2203}" => "there exists",
+            "\u{22A5}" => "bottom",
+            "\u{22A4}" => "top",
+            "\u{22A2}" => "proves",
+            "\u{22A8}" => "models",
+            "\u{0028}" => "open paren",
+            "\u{0029}" => "close paren",
+            "\u{2322}" => "frown",
+            "\u{2323}" => "smile",
+            "\u{005B}" => "open bracket",
+            "\u{005D}" => "close bracket",
+            "\u{23B4}" => "top bracket",
+            "\u{23B5}" => "bottom bracket",
+            "\u{007B}" => "open brace",
+            "\u{007D}" => "close brace",
+            "\u{23DE}" => "top brace",
+            "\u{23DF}" => "bottom brace",
+            "\u{27E8}" => "open angle bracket",
+            "\u{27E9}" => "close angle bracket",
+            "\u{007C}" | "\u{2223}" => "vertical bar",
+            "\u{2225}" | "\u{2016}" => "double vertical bar",
+            "\u{005E}" | "\u{02C6}" => "hat",
+            "\u{00AF}" | "\u{203E}" => "bar",
+            "\u{2192}" => "right arrow",
+            "\u{2190}" => "left arrow",
+            "\u{2194}" => "left right arrow",
+            "\u{21A6}" => "maps to",
+            "\u{007E}" | "\u{02DC}" => "tilde",
+            "\u{02D9}" => "dot",
+            "\u{2032}" => "prime",
+            "\u{2033}" => "double prime",
+            "\u{2034}" => "triple prime",
+            "\u{0021}" => "factorial",
+            "\u{002C}" => "comma",
+            "\u{2026}" | "\u{22EF}" => "dot dot dot",
+            "\u{221E}" => "infinity",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+}
@@ -29,6 +29,11 @@ impl std::fmt::Display for OpForm {
 }
 
 /// Attribute for the `mo` (`Operator`) element.
+///
+/// The values of `lspace`, `rspace`, `maxsize` and `minsize` are strings, which can be built from
+/// a [`Length`].
+///
+/// [`Length`]: crate::attributes::Length
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum OperatorAttr {
     /// One of the global attributes.
@@ -126,8 +131,33 @@ impl Operator {
     pub fn attributes(&self) -> &[OperatorAttr] {
         &self.attributes
     }
+
+    /// Get a mutable reference to the operator of the [`Operator`] element.
+    pub fn op_mut(&mut self) -> &mut String {
+        &mut self.op
+    }
+
+    /// Replace the operator of the [`Operator`] element.
+    pub fn set_op(&mut self, op: impl Into<String>) {
+        self.op = op.into();
+    }
+
+    /// Consume the [`Operator`] element and return its operator and attributes.
+    pub fn into_parts(self) -> (String, Vec<OperatorAttr>) {
+        (self.op, self.attributes)
+    }
+
+    /// Create the [`Operator`] element from its operator and attributes.
+    pub fn from_parts(op: impl Into<String>, attributes: Vec<OperatorAttr>) -> Self {
+        Self {
+            op: op.into(),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Operator, attributes: OperatorAttr);
+
 crate::element_from_type!(Operator => Operator);
 
 /// Builder of the [`Operator`] element.
@@ -7,6 +7,11 @@ use super::IntoElements;
 ///
 /// The `width`, `height`, `depth`, `lspace` and `voffset` if present, must have a value that is a
 /// valid [`<length-percentage>`](https://www.w3.org/TR/css-values-4/#typedef-length-percentage).
+/// Unlike other length attributes, they may also be relative to the content, e.g. `+1width`. The
+/// values are strings and can be built from a [`Length`], such as [`Length::relative`].
+///
+/// [`Length`]: crate::attributes::Length
+/// [`Length::relative`]: crate::attributes::Length::relative
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum PaddedAttr {
     /// A `<length-percentage>` indicating the desired horizontal length of the `mpadded` element.
@@ -92,10 +97,35 @@ impl Padded {
         &self.children
     }
 
+    /// Get a mutable reference to the children of the [`Padded`] element.
+    pub fn children_mut(&mut self) -> &mut Elements {
+        &mut self.children
+    }
+
     /// Get a reference to all attributes of the [`Padded`] element.
     pub fn attributes(&self) -> &[PaddedAttr] {
         &self.attributes
     }
+
+    /// Replace the children of the [`Padded`] element.
+    pub fn set_children(&mut self, children: impl IntoElements) {
+        self.children = children.into_elements();
+    }
+
+    /// Consume the [`Padded`] element and return its children and attributes.
+    pub fn into_parts(self) -> (Elements, Vec<PaddedAttr>) {
+        (self.children, self.attributes)
+    }
+
+    /// Create the [`Padded`] element from its children and attributes.
+    pub fn from_parts(children: impl IntoElements, attributes: Vec<PaddedAttr>) -> Self {
+        Self {
+            children: children.into_elements(),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Padded, attributes: PaddedAttr);
+
 crate::element_from_type!(Padded => Padded);
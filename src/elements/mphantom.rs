@@ -62,10 +62,35 @@ impl Phantom {
         &self.children
     }
 
+    /// Get a mutable reference to the children of the [`Phantom`] element.
+    pub fn children_mut(&mut self) -> &mut Elements {
+        &mut self.children
+    }
+
     /// Get a reference to all attributes of the [`Phantom`] element.
     pub fn attributes(&self) -> &[Attribute] {
         &self.attributes
     }
+
+    /// Replace the children of the [`Phantom`] element.
+    pub fn set_children(&mut self, children: impl IntoElements) {
+        self.children = children.into_elements();
+    }
+
+    /// Consume the [`Phantom`] element and return its children and attributes.
+    pub fn into_parts(self) -> (Elements, Vec<Attribute>) {
+        (self.children, self.attributes)
+    }
+
+    /// Create the [`Phantom`] element from its children and attributes.
+    pub fn from_parts(children: impl IntoElements, attributes: Vec<Attribute>) -> Self {
+        Self {
+            children: children.into_elements(),
+            attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Phantom, attributes: Attribute);
+
 crate::element_from_type!(Phantom => Phantom);
@@ -6,7 +6,7 @@ use crate::{
     Element, Elements,
 };
 
-use super::{grouping::Row, IntoElements};
+use super::IntoElements;
 
 /// The radical elements construct an expression with a root symbol `√` with a line over the content.
 /// The msqrt element is used for square roots, while the mroot element is used to draw radicals
@@ -46,6 +46,11 @@ impl Radical {
         &self.index
     }
 
+    /// Get a mutable reference to the index of the radical. e.g. "2" for the square root.
+    pub fn index_mut(&mut self) -> &mut Elements {
+        &mut self.index
+    }
+
     /// Check if the radical is a square root.
     pub fn is_square(&self) -> bool {
         if self.index().len() != 1 {
@@ -63,12 +68,70 @@ impl Radical {
         &self.content
     }
 
+    /// Get a mutable reference to the inner content of the [`Radical`] element.
+    pub fn content_mut(&mut self) -> &mut Elements {
+        &mut self.content
+    }
+
     /// Get a reference to all attributes of the [`Radical`] element.
     pub fn attributes(&self) -> &[Attribute] {
         &self.attributes
     }
+
+    /// Replace the index of the [`Radical`] element. Multiple or no elements are wrapped in a
+    /// [`Row`], as is the content if the radical is no longer a square root.
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_index(&mut self, index: impl IntoElements) {
+        self.index = super::single_argument(index);
+        self.wrap_content();
+    }
+
+    /// Replace the inner content of the [`Radical`] element. Multiple or no elements are wrapped
+    /// in a [`Row`] unless the radical is a square root.
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_content(&mut self, content: impl IntoElements) {
+        self.content = content.into_elements();
+        self.wrap_content();
+    }
+
+    /// Wrap the content in a row unless the radical is a square root, since `mroot` has exactly
+    /// one child for the content.
+    fn wrap_content(&mut self) {
+        if !self.is_square() {
+            self.content = super::single_argument(std::mem::take(&mut self.content));
+        }
+    }
+
+    /// Consume the [`Radical`] element and return its index, inner content and attributes.
+    pub fn into_parts(self) -> (Elements, Elements, Vec<Attribute>) {
+        (self.index, self.content, self.attributes)
+    }
+
+    /// Create the [`Radical`] element from its index, inner content and attributes. The index and,
+    /// unless the radical is a square root, the content are wrapped in a [`Row`] like in
+    /// [`RadicalsBuilder`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn from_parts(
+        index: impl IntoElements,
+        content: impl IntoElements,
+        attributes: Vec<Attribute>,
+    ) -> Self {
+        let mut radical = Self {
+            index: super::single_argument(index),
+            content: content.into_elements(),
+            attributes,
+        };
+
+        radical.wrap_content();
+        radical
+    }
 }
 
+crate::attr_accessors!(Radical, attributes: Attribute);
+
 crate::element_from_type!(Radical => Radical);
 
 /// Builder of the [`Radical`] element.
@@ -88,14 +151,8 @@ impl<T1, T2> RadicalsBuilder<T1, T2> {
     /// than "2" they will be wrapped in a `mrow`. If you use [`alemat::row!`] to wrap children in
     /// a row yourself, they won't be wrapped again.
     pub fn index(self, index: impl IntoElements) -> RadicalsBuilder<Init, T2> {
-        let mut index = index.into_elements();
-
-        if index.len() > 1 {
-            index = Row::from(index).into_elements();
-        }
-
         RadicalsBuilder {
-            index: Some(index.into_elements()),
+            index: Some(super::single_argument(index)),
             content: self.content,
             attr: self.attr,
             _marker: PhantomData,
@@ -143,11 +200,7 @@ impl RadicalsBuilder<Init, Init> {
             attributes: self.attr,
         };
 
-        if !radical.is_square() && radical.content.len() > 1 {
-            let row = Row::from(radical.content);
-            radical.content = Elements(vec![Element::Row(row)]);
-        }
-
+        radical.wrap_content();
         radical
     }
 }
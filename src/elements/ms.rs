@@ -49,6 +49,31 @@ impl StrLiteral {
     pub fn attributes(&self) -> &[Attribute] {
         &self.attr
     }
+
+    /// Get a mutable reference to the content of the [`StrLiteral`] element.
+    pub fn content_mut(&mut self) -> &mut String {
+        &mut self.literal
+    }
+
+    /// Replace the content of the [`StrLiteral`] element.
+    pub fn set_content(&mut self, content: impl Into<String>) {
+        self.literal = content.into();
+    }
+
+    /// Consume the [`StrLiteral`] element and return its content and attributes.
+    pub fn into_parts(self) -> (String, Vec<Attribute>) {
+        (self.literal, self.attr)
+    }
+
+    /// Create the [`StrLiteral`] element from its content and attributes.
+    pub fn from_parts(content: impl Into<String>, attributes: Vec<Attribute>) -> Self {
+        Self {
+            literal: content.into(),
+            attr: attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(StrLiteral, attr: Attribute);
+
 crate::element_from_type!(StrLiteral => StrLiteral);
@@ -4,6 +4,9 @@ use crate::attributes::Attribute;
 ///
 /// The `width`, `height`, `depth`, if present, must have a value that is a valid
 /// [length-percentage](https://www.w3.org/TR/css-values-4/#typedef-length-percentage).
+/// The values are strings, a [`Length`] converts into a valid one.
+///
+/// [`Length`]: crate::attributes::Length
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SpaceAttr {
     /// If present, valid and not a percentage then it is used as a presentational hint setting the
@@ -66,6 +69,18 @@ impl Space {
     pub fn attributes(&self) -> &[SpaceAttr] {
         &self.attr
     }
+
+    /// Consume the [`Space`] element and return its attributes.
+    pub fn into_parts(self) -> Vec<SpaceAttr> {
+        self.attr
+    }
+
+    /// Create the [`Space`] element from its attributes.
+    pub fn from_parts(attributes: Vec<SpaceAttr>) -> Self {
+        Self { attr: attributes }
+    }
 }
 
+crate::attr_accessors!(Space, attr: SpaceAttr);
+
 crate::element_from_type!(Space => Space);
@@ -55,10 +55,35 @@ impl Style {
         &self.children
     }
 
+    /// Get a mutable reference to the children of the [`Style`] element.
+    pub fn children_mut(&mut self) -> &mut Elements {
+        &mut self.children
+    }
+
     /// Get a reference to all attributes of the [`Style`] element.
     pub fn attributes(&self) -> &[Attribute] {
         &self.attr
     }
+
+    /// Replace the children of the [`Style`] element.
+    pub fn set_children(&mut self, children: impl IntoElements) {
+        self.children = children.into_elements();
+    }
+
+    /// Consume the [`Style`] element and return its children and attributes.
+    pub fn into_parts(self) -> (Elements, Vec<Attribute>) {
+        (self.children, self.attr)
+    }
+
+    /// Create the [`Style`] element from its children and attributes.
+    pub fn from_parts(children: impl IntoElements, attributes: Vec<Attribute>) -> Self {
+        Self {
+            children: children.into_elements(),
+            attr: attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Style, attr: Attribute);
+
 crate::element_from_type!(Style => Style);
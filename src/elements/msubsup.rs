@@ -6,7 +6,7 @@ use crate::{
     Element, Elements,
 };
 
-use super::{grouping::Row, IntoElements};
+use super::IntoElements;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 enum SubSupInner {
@@ -40,6 +40,14 @@ impl SubSup {
         }
     }
 
+    /// Get a mutable reference to the subscript content of the [`SubSup`] element if present.
+    pub fn sub_mut(&mut self) -> Option<&mut Elements> {
+        match self.inner {
+            SubSupInner::Sub(ref mut sub) | SubSupInner::SubSup { ref mut sub, .. } => Some(sub),
+            _ => None,
+        }
+    }
+
     /// Get a reference to the superscript content of the [`SubSup`] element if present.
     pub fn sup(&self) -> Option<&[Element]> {
         match self.inner {
@@ -48,17 +56,115 @@ impl SubSup {
         }
     }
 
+    /// Get a mutable reference to the superscript content of the [`SubSup`] element if
+    /// present.
+    pub fn sup_mut(&mut self) -> Option<&mut Elements> {
+        match self.inner {
+            SubSupInner::Sup(ref mut sup) | SubSupInner::SubSup { ref mut sup, .. } => Some(sup),
+            _ => None,
+        }
+    }
+
     /// Get a reference to the base content of the [`SubSup`] element.
     pub fn base(&self) -> &[Element] {
         &self.base
     }
 
+    /// Get a mutable reference to the base content of the [`SubSup`] element.
+    pub fn base_mut(&mut self) -> &mut Elements {
+        &mut self.base
+    }
+
     /// Get a reference to all attributes of the [`SubSup`] element.
     pub fn attributes(&self) -> &[Attribute] {
         &self.attributes
     }
+
+    /// Replace the base content of the [`SubSup`] element. Multiple or no elements are wrapped in a
+    /// [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_base(&mut self, base: impl IntoElements) {
+        self.base = super::single_argument(base);
+    }
+
+    /// Set the subscript content of the [`SubSup`] element, replacing the existing subscript if
+    /// present. Multiple or no elements are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_sub(&mut self, sub: impl IntoElements) {
+        let sub = super::single_argument(sub);
+
+        let inner = std::mem::replace(&mut self.inner, SubSupInner::Sub(Elements::default()));
+
+        self.inner = match inner {
+            SubSupInner::Sup(sup) | SubSupInner::SubSup { sup, .. } => {
+                SubSupInner::SubSup { sub, sup }
+            }
+            SubSupInner::Sub(_) => SubSupInner::Sub(sub),
+        };
+    }
+
+    /// Set the superscript content of the [`SubSup`] element, replacing the existing superscript if
+    /// present. Multiple or no elements are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_sup(&mut self, sup: impl IntoElements) {
+        let sup = super::single_argument(sup);
+
+        let inner = std::mem::replace(&mut self.inner, SubSupInner::Sup(Elements::default()));
+
+        self.inner = match inner {
+            SubSupInner::Sub(sub) | SubSupInner::SubSup { sub, .. } => {
+                SubSupInner::SubSup { sub, sup }
+            }
+            SubSupInner::Sup(_) => SubSupInner::Sup(sup),
+        };
+    }
+
+    /// Consume the [`SubSup`] element and return its base, subscript, superscript and
+    /// attributes.
+    pub fn into_parts(self) -> (Elements, Option<Elements>, Option<Elements>, Vec<Attribute>) {
+        let (sub, sup) = match self.inner {
+            SubSupInner::Sub(sub) => (Some(sub), None),
+            SubSupInner::Sup(sup) => (None, Some(sup)),
+            SubSupInner::SubSup { sub, sup } => (Some(sub), Some(sup)),
+        };
+
+        (self.base, sub, sup, self.attributes)
+    }
+
+    /// Create the [`SubSup`] element from its base, subscript, superscript and attributes. Returns
+    /// `None` if neither subscript nor superscript is given. Multiple or no elements in any part
+    /// are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn from_parts(
+        base: impl IntoElements,
+        sub: Option<Elements>,
+        sup: Option<Elements>,
+        attributes: Vec<Attribute>,
+    ) -> Option<Self> {
+        let inner = match (
+            sub.map(super::single_argument),
+            sup.map(super::single_argument),
+        ) {
+            (Some(sub), Some(sup)) => SubSupInner::SubSup { sub, sup },
+            (Some(sub), None) => SubSupInner::Sub(sub),
+            (None, Some(sup)) => SubSupInner::Sup(sup),
+            (None, None) => return None,
+        };
+
+        Some(Self {
+            base: super::single_argument(base),
+            inner,
+            attributes,
+        })
+    }
 }
 
+crate::attr_accessors!(SubSup, attributes: Attribute);
+
 /// Builder of the [`SubSup`] element.
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SubSupBuilder<T1, T2> {
@@ -70,16 +176,14 @@ pub struct SubSupBuilder<T1, T2> {
 }
 
 impl<T1, T2> SubSupBuilder<T1, T2> {
-    /// Set the base of the [`SubSup`] element.
+    /// Set the base of the [`SubSup`] element. Multiple or no elements are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
     pub fn base(self, base: impl IntoElements) -> SubSupBuilder<Init, T2> {
-        let mut base = base.into_elements();
-
-        if base.len() > 1 {
-            base = Row::from(base).into_elements();
-        }
+        let base = super::single_argument(base);
 
         SubSupBuilder {
-            base: Some(base.into_elements()),
+            base: Some(base),
             sub: self.sub,
             sup: self.sup,
             attr: self.attr,
@@ -87,35 +191,33 @@ impl<T1, T2> SubSupBuilder<T1, T2> {
         }
     }
 
-    /// Set the subscript of the [`SubSup`] element.
+    /// Set the subscript of the [`SubSup`] element. Multiple or no elements are wrapped in a
+    /// [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
     pub fn subscript(self, sub: impl IntoElements) -> SubSupBuilder<T1, Init> {
-        let mut sub = sub.into_elements();
-
-        if sub.len() > 1 {
-            sub = Row::from(sub).into_elements();
-        }
+        let sub = super::single_argument(sub);
 
         SubSupBuilder {
             base: self.base,
-            sub: Some(sub.into_elements()),
+            sub: Some(sub),
             sup: self.sup,
             attr: self.attr,
             _marker: PhantomData,
         }
     }
 
-    /// Set the superscript of the [`SubSup`] element.
+    /// Set the superscript of the [`SubSup`] element. Multiple or no elements are wrapped in a
+    /// [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
     pub fn supscript(self, sup: impl IntoElements) -> SubSupBuilder<T1, Init> {
-        let mut sup = sup.into_elements();
-
-        if sup.len() > 1 {
-            sup = Row::from(sup).into_elements();
-        }
+        let sup = super::single_argument(sup);
 
         SubSupBuilder {
             base: self.base,
             sub: self.sub,
-            sup: Some(sup.into_elements()),
+            sup: Some(sup),
             attr: self.attr,
             _marker: PhantomData,
         }
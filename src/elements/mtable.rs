@@ -51,6 +51,11 @@ impl Table {
         &self.rows
     }
 
+    /// Get a mutable reference to the [`Table`]s rows.
+    pub fn rows_mut(&mut self) -> &mut Vec<TableRow> {
+        &mut self.rows
+    }
+
     /// Add a [`TableRow`] to the [`Table`].
     pub fn add_row(&mut self, row: TableRow) {
         self.rows.push(row);
@@ -104,8 +109,20 @@ impl Table {
         self.attributes.extend(attr.into_iter().map(Into::into));
         self
     }
+
+    /// Consume the [`Table`] and return its rows and attributes.
+    pub fn into_parts(self) -> (Vec<TableRow>, Vec<TableAttr>) {
+        (self.rows, self.attributes)
+    }
+
+    /// Create the [`Table`] from its rows and attributes.
+    pub fn from_parts(rows: Vec<TableRow>, attributes: Vec<TableAttr>) -> Self {
+        Self { rows, attributes }
+    }
 }
 
+crate::attr_accessors!(Table, attributes: TableAttr);
+
 crate::element_from_type!(Table => Table);
 
 impl<R> FromIterator<R> for Table
@@ -227,12 +244,32 @@ impl TableRow {
         &self.cells
     }
 
+    /// Get a mutable reference to the cells of the [`TableRow`] element.
+    pub fn cells_mut(&mut self) -> &mut Vec<TableCell> {
+        &mut self.cells
+    }
+
     /// Get a reference to all attributes of the [`TableRow`] element.
     pub fn attributes(&self) -> &[Attribute] {
         &self.attr
     }
+
+    /// Consume the [`TableRow`] and return its cells and attributes.
+    pub fn into_parts(self) -> (Vec<TableCell>, Vec<Attribute>) {
+        (self.cells, self.attr)
+    }
+
+    /// Create the [`TableRow`] from its cells and attributes.
+    pub fn from_parts(cells: Vec<TableCell>, attributes: Vec<Attribute>) -> Self {
+        Self {
+            cells,
+            attr: attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(TableRow, attr: Attribute);
+
 impl<I, C> From<I> for TableRow
 where
     I: IntoIterator<Item = C>,
@@ -350,6 +387,11 @@ impl TableCell {
         &self.children
     }
 
+    /// Get a mutable reference to the children of the [`TableCell`] element.
+    pub fn children_mut(&mut self) -> &mut Elements {
+        &mut self.children
+    }
+
     /// Get a reference to all attributes of the [`TableCell`] element.
     pub fn attributes(&self) -> &[TableCellAttr] {
         &self.attr
@@ -381,4 +423,24 @@ impl TableCell {
         self.attr.extend(attr.into_iter().map(Into::into));
         self
     }
+
+    /// Replace the children of the [`TableCell`] element.
+    pub fn set_children(&mut self, children: impl IntoElements) {
+        self.children = children.into_elements();
+    }
+
+    /// Consume the [`TableCell`] element and return its children and attributes.
+    pub fn into_parts(self) -> (Elements, Vec<TableCellAttr>) {
+        (self.children, self.attr)
+    }
+
+    /// Create the [`TableCell`] element from its children and attributes.
+    pub fn from_parts(children: impl IntoElements, attributes: Vec<TableCellAttr>) -> Self {
+        Self {
+            children: children.into_elements(),
+            attr: attributes,
+        }
+    }
 }
+
+crate::attr_accessors!(TableCell, attr: TableCellAttr);
@@ -52,6 +52,31 @@ impl Text {
     pub fn attributes(&self) -> &[Attribute] {
         &self.attr
     }
+
+    /// Get a mutable reference to the text of the [`Text`] element.
+    pub fn text_mut(&mut self) -> &mut String {
+        &mut self.text
+    }
+
+    /// Replace the text of the [`Text`] element.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+    }
+
+    /// Consume the [`Text`] element and return its text and attributes.
+    pub fn into_parts(self) -> (String, Vec<Attribute>) {
+        (self.text, self.attr)
+    }
+
+    /// Create the [`Text`] element from its text and attributes.
+    pub fn from_parts(text: impl Into<String>, attributes: Vec<Attribute>) -> Self {
+        Self {
+            text: text.into(),
+            attr: attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(Text, attr: Attribute);
+
 crate::element_from_type!(Text => Text);
@@ -2,7 +2,6 @@ use std::marker::PhantomData;
 
 use crate::{
     attributes::Attribute,
-    elements::grouping::Row,
     markers::{Init, Uninit},
     Element, Elements,
 };
@@ -57,6 +56,11 @@ impl UnderOver {
         &self.expr
     }
 
+    /// Get a mutable reference to the base content of the [`UnderOver`] element.
+    pub fn expr_mut(&mut self) -> &mut Elements {
+        &mut self.expr
+    }
+
     /// Get a reference to the under script content of the [`UnderOver`] element if present.
     pub fn under(&self) -> Option<&[Element]> {
         match self.inner {
@@ -67,6 +71,16 @@ impl UnderOver {
         }
     }
 
+    /// Get a mutable reference to the under script content of the [`UnderOver`] element if
+    /// present.
+    pub fn under_mut(&mut self) -> Option<&mut Elements> {
+        match self.inner {
+            UnderOverInner::Under(ref mut under)
+            | UnderOverInner::UnderOver { ref mut under, .. } => Some(under),
+            _ => None,
+        }
+    }
+
     /// Get a reference to the over script content of the [`UnderOver`] element if present.
     pub fn over(&self) -> Option<&[Element]> {
         match self.inner {
@@ -77,12 +91,112 @@ impl UnderOver {
         }
     }
 
+    /// Get a mutable reference to the over script content of the [`UnderOver`] element if
+    /// present.
+    pub fn over_mut(&mut self) -> Option<&mut Elements> {
+        match self.inner {
+            UnderOverInner::Over(ref mut over) | UnderOverInner::UnderOver { ref mut over, .. } => {
+                Some(over)
+            }
+            _ => None,
+        }
+    }
+
     /// Get a reference to all attributes of the [`UnderOver`] element.
     pub fn attributes(&self) -> &[UnderOverAttr] {
         &self.attributes
     }
+
+    /// Replace the base content of the [`UnderOver`] element. Multiple or no elements are wrapped
+    /// in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_expr(&mut self, expr: impl IntoElements) {
+        self.expr = super::single_argument(expr);
+    }
+
+    /// Set the under script content of the [`UnderOver`] element, replacing the existing under
+    /// script if present. Multiple or no elements are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_under(&mut self, under: impl IntoElements) {
+        let under = super::single_argument(under);
+        let inner = std::mem::replace(&mut self.inner, UnderOverInner::Under(Elements::default()));
+
+        self.inner = match inner {
+            UnderOverInner::Over(over) | UnderOverInner::UnderOver { over, .. } => {
+                UnderOverInner::UnderOver { under, over }
+            }
+            UnderOverInner::Under(_) => UnderOverInner::Under(under),
+        };
+    }
+
+    /// Set the over script content of the [`UnderOver`] element, replacing the existing over script
+    /// if present. Multiple or no elements are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn set_over(&mut self, over: impl IntoElements) {
+        let over = super::single_argument(over);
+        let inner = std::mem::replace(&mut self.inner, UnderOverInner::Over(Elements::default()));
+
+        self.inner = match inner {
+            UnderOverInner::Under(under) | UnderOverInner::UnderOver { under, .. } => {
+                UnderOverInner::UnderOver { under, over }
+            }
+            UnderOverInner::Over(_) => UnderOverInner::Over(over),
+        };
+    }
+
+    /// Consume the [`UnderOver`] element and return its base, under script, over script and
+    /// attributes.
+    pub fn into_parts(
+        self,
+    ) -> (
+        Elements,
+        Option<Elements>,
+        Option<Elements>,
+        Vec<UnderOverAttr>,
+    ) {
+        let (under, over) = match self.inner {
+            UnderOverInner::Under(under) => (Some(under), None),
+            UnderOverInner::Over(over) => (None, Some(over)),
+            UnderOverInner::UnderOver { under, over } => (Some(under), Some(over)),
+        };
+
+        (self.expr, under, over, self.attributes)
+    }
+
+    /// Create the [`UnderOver`] element from its base, under script, over script and attributes.
+    /// Returns `None` if neither under script nor over script is given. Multiple or no elements in
+    /// any part are wrapped in a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
+    pub fn from_parts(
+        expr: impl IntoElements,
+        under: Option<Elements>,
+        over: Option<Elements>,
+        attributes: Vec<UnderOverAttr>,
+    ) -> Option<Self> {
+        let inner = match (
+            under.map(super::single_argument),
+            over.map(super::single_argument),
+        ) {
+            (Some(under), Some(over)) => UnderOverInner::UnderOver { under, over },
+            (Some(under), None) => UnderOverInner::Under(under),
+            (None, Some(over)) => UnderOverInner::Over(over),
+            (None, None) => return None,
+        };
+
+        Some(Self {
+            expr: super::single_argument(expr),
+            inner,
+            attributes,
+        })
+    }
 }
 
+crate::attr_accessors!(UnderOver, attributes: UnderOverAttr);
+
 crate::element_from_type!(UnderOver => UnderOver);
 
 /// Builder of the [`UnderOver`] element.
@@ -97,16 +211,15 @@ pub struct UnderOverBuilder<T1, T2> {
 }
 
 impl<T1, T2> UnderOverBuilder<T1, T2> {
-    /// Set the base expression of the [`UnderOver`] element.
+    /// Set the base expression of the [`UnderOver`] element. Multiple or no elements are wrapped in
+    /// a [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
     pub fn expr(self, expr: impl IntoElements) -> UnderOverBuilder<Init, T2> {
-        let mut expr = expr.into_elements();
-
-        if expr.len() > 1 {
-            expr = Row::from(expr).into_elements();
-        }
+        let expr = super::single_argument(expr);
 
         UnderOverBuilder {
-            expr: Some(expr.into_elements()),
+            expr: Some(expr),
             under: self.under,
             over: self.over,
             attr: self.attr,
@@ -115,34 +228,32 @@ impl<T1, T2> UnderOverBuilder<T1, T2> {
         }
     }
 
-    /// Set the under script of the [`UnderOver`] element.
+    /// Set the under script of the [`UnderOver`] element. Multiple or no elements are wrapped in a
+    /// [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
     pub fn over(self, over: impl IntoElements) -> UnderOverBuilder<T1, Init> {
-        let mut over = over.into_elements();
-
-        if over.len() > 1 {
-            over = Row::from(over).into_elements();
-        }
+        let over = super::single_argument(over);
 
         UnderOverBuilder {
             expr: self.expr,
             under: self.under,
-            over: Some(over.into_elements()),
+            over: Some(over),
             attr: self.attr,
             _marker: PhantomData,
         }
     }
 
-    /// Set the over script of the [`UnderOver`] element.
+    /// Set the over script of the [`UnderOver`] element. Multiple or no elements are wrapped in a
+    /// [`Row`].
+    ///
+    /// [`Row`]: super::grouping::Row
     pub fn under(self, under: impl IntoElements) -> UnderOverBuilder<T1, Init> {
-        let mut under = under.into_elements();
-
-        if under.len() > 1 {
-            under = Row::from(under).into_elements();
-        }
+        let under = super::single_argument(under);
 
         UnderOverBuilder {
             expr: self.expr,
-            under: Some(under.into_elements()),
+            under: Some(under),
             over: self.over,
             attr: self.attr,
             _marker: PhantomData,
@@ -0,0 +1,85 @@
+//! XML escaping of element content and attribute values.
+
+use std::fmt::Write;
+
+/// The place in the markup where a string is written to. Attribute values are escaped more
+/// strictly than element content, since they are always written between double quotes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EscapeCtx {
+    /// Content of an element, e.g. the `x` in `<mi>x</mi>`.
+    Content,
+
+    /// Value of an attribute, e.g. the `x` in `id="x"`.
+    AttrValue,
+}
+
+/// Returns the escaped replacement for the given character, or `None` if the character can be
+/// written as is.
+fn replacement(c: char, ctx: EscapeCtx) -> Option<&'static str> {
+    match c {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        '"' if ctx == EscapeCtx::AttrValue => Some("&quot;"),
+
+        // Whitespace in attribute values is normalized by XML parsers, so it has to be written
+        // as character reference to be preserved.
+        '\t' if ctx == EscapeCtx::AttrValue => Some("&#x9;"),
+        '\n' if ctx == EscapeCtx::AttrValue => Some("&#xA;"),
+        '\r' => Some("&#xD;"),
+        '\t' | '\n' => None,
+
+        // All other control characters are not allowed in XML documents, not even as character
+        // references. They are replaced with the replacement character.
+        '\u{0}'..='\u{1F}' | '\u{FFFE}' | '\u{FFFF}' => Some("\u{FFFD}"),
+        _ => None,
+    }
+}
+
+/// Write the given string into the writer, escaping all characters that have special meaning in
+/// the given context.
+pub(crate) fn escape_into<W: Write + ?Sized>(
+    w: &mut W,
+    s: &str,
+    ctx: EscapeCtx,
+) -> std::fmt::Result {
+    let mut last = 0;
+
+    for (idx, c) in s.char_indices() {
+        if let Some(repl) = replacement(c, ctx) {
+            w.write_str(&s[last..idx])?;
+            w.write_str(repl)?;
+            last = idx + c.len_utf8();
+        }
+    }
+
+    w.write_str(&s[last..])
+}
+
+/// Escape the given string for the given context.
+pub(crate) fn escape(s: &str, ctx: EscapeCtx) -> String {
+    let mut out = String::with_capacity(s.len());
+    let _ = escape_into(&mut out, s, ctx);
+    out
+}
+
+/// Write the given attribute name (or part of it) into the writer. Attribute names cannot be
+/// escaped, so all characters that are not allowed in attribute names are skipped.
+pub(crate) fn name_into<W: Write + ?Sized>(w: &mut W, name: &str) -> std::fmt::Result {
+    for c in name.chars() {
+        if !is_invalid_name_char(c) {
+            w.write_char(c)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Sanitize the given attribute name (or part of it).
+pub(crate) fn name(name: &str) -> String {
+    name.chars().filter(|c| !is_invalid_name_char(*c)).collect()
+}
+
+fn is_invalid_name_char(c: char) -> bool {
+    c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '>' | '<' | '/' | '=' | '&')
+}
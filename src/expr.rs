@@ -0,0 +1,396 @@
+//! Typed arithmetic expressions, which are converted into elements with exactly the parentheses
+//! they need.
+//!
+//! An [`Expr`] is built from variables, numbers and function calls combined with the arithmetic
+//! operators `+`, `-`, `*`, `/` and [`Expr::pow`]. [`Expr::to_elements`] places parentheses only
+//! where the precedence or the associativity of the operations requires them, e.g. `a(b+c)²` for
+//! `a * (b + c)^2` but `a-b-c` for `(a - b) - c`. The [`Notation`] decides how divisions and
+//! products are written, and every generated [`Operator`] carries its `form` attribute.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{Ident, Num, OpForm, Operator, OperatorAttr};
+//! use alemat::expr::{Division, Expr, Multiplication, Notation};
+//! use alemat::Element;
+//!
+//! let expr = Expr::var("a") / (Expr::var("b") + Expr::num(2));
+//! let notation = Notation::new(Division::Solidus, Multiplication::Invisible);
+//!
+//! let infix = |op: Operator| {
+//!     Element::from(
+//!         Operator::builder()
+//!             .op(op.op())
+//!             .attr([OperatorAttr::Form(OpForm::Infix)])
+//!             .build(),
+//!     )
+//! };
+//!
+//! let elements = expr.to_elements_with(notation).into_inner();
+//!
+//! assert_eq!(elements[0], Element::from(Ident::from("a")));
+//! assert_eq!(elements[1], infix(Operator::solidus()));
+//! assert_eq!(elements.len(), 3);
+//! ```
+
+use crate::{
+    elements::{grouping::Row, scripted::SubSup, Frac, Ident, Num, OpForm, Operator, OperatorAttr},
+    Element, Elements,
+};
+
+/// An arithmetic expression.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Expr {
+    /// A variable, e.g. `x`.
+    Var(String),
+
+    /// A number, e.g. `2` or `-1.5`.
+    Num(Num),
+
+    /// The sum of both expressions.
+    Add(Box<Expr>, Box<Expr>),
+
+    /// The difference of both expressions.
+    Sub(Box<Expr>, Box<Expr>),
+
+    /// The product of both expressions.
+    Mul(Box<Expr>, Box<Expr>),
+
+    /// The quotient of both expressions.
+    Div(Box<Expr>, Box<Expr>),
+
+    /// The first expression raised to the power of the second.
+    Pow(Box<Expr>, Box<Expr>),
+
+    /// The negation of the expression.
+    Neg(Box<Expr>),
+
+    /// The function with the given name applied to the arguments, e.g. `f(x,y)`.
+    Call(String, Vec<Expr>),
+}
+
+// the operators delegate to the constructors of the same name
+#[allow(clippy::should_implement_trait)]
+impl Expr {
+    /// Create a variable with the given name.
+    pub fn var(name: impl Into<String>) -> Self {
+        Self::Var(name.into())
+    }
+
+    /// Create a number.
+    pub fn num(num: impl Into<Num>) -> Self {
+        Self::Num(num.into())
+    }
+
+    /// Create the sum `lhs + rhs`.
+    pub fn add(lhs: Expr, rhs: Expr) -> Self {
+        Self::Add(Box::new(lhs), Box::new(rhs))
+    }
+
+    /// Create the difference `lhs - rhs`.
+    pub fn sub(lhs: Expr, rhs: Expr) -> Self {
+        Self::Sub(Box::new(lhs), Box::new(rhs))
+    }
+
+    /// Create the product `lhs * rhs`.
+    pub fn mul(lhs: Expr, rhs: Expr) -> Self {
+        Self::Mul(Box::new(lhs), Box::new(rhs))
+    }
+
+    /// Create the quotient `lhs / rhs`.
+    pub fn div(lhs: Expr, rhs: Expr) -> Self {
+        Self::Div(Box::new(lhs), Box::new(rhs))
+    }
+
+    /// Create the negation `-expr`.
+    pub fn neg(expr: Expr) -> Self {
+        Self::Neg(Box::new(expr))
+    }
+
+    /// Create the power `base ^ exp`.
+    pub fn pow(base: Expr, exp: Expr) -> Self {
+        Self::Pow(Box::new(base), Box::new(exp))
+    }
+
+    /// Create the call of the function with the given name, e.g. `sin(x)`.
+    pub fn call(name: impl Into<String>, args: impl IntoIterator<Item = Expr>) -> Self {
+        Self::Call(name.into(), args.into_iter().collect())
+    }
+
+    /// Convert the expression into elements using the default [`Notation`].
+    pub fn to_elements(&self) -> Elements {
+        self.to_elements_with(Notation::default())
+    }
+
+    /// Convert the expression into elements using the given [`Notation`].
+    pub fn to_elements_with(&self, notation: Notation) -> Elements {
+        Elements(notation.elements(self))
+    }
+}
+
+macro_rules! binary_ops {
+    ($($trait:ident::$method:ident, $doc:literal;)*) => {
+        $(
+            impl std::ops::$trait for Expr {
+                type Output = Expr;
+
+                #[doc = $doc]
+                fn $method(self, rhs: Expr) -> Expr {
+                    Expr::$method(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+binary_ops! {
+    Add::add, "Create the sum `self + rhs`, see [`Expr::add`].";
+    Sub::sub, "Create the difference `self - rhs`, see [`Expr::sub`].";
+    Mul::mul, "Create the product `self * rhs`, see [`Expr::mul`].";
+    Div::div, "Create the quotient `self / rhs`, see [`Expr::div`].";
+}
+
+impl std::ops::Neg for Expr {
+    type Output = Expr;
+
+    /// Create the negation `-self`, see [`Expr::neg`].
+    fn neg(self) -> Expr {
+        Expr::neg(self)
+    }
+}
+
+/// How divisions are written.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Division {
+    /// As fraction with the dividend over the divisor.
+    #[default]
+    Fraction,
+
+    /// Inline with a solidus, e.g. `a/b`.
+    Solidus,
+}
+
+/// How products are written.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Multiplication {
+    /// With the invisible times `&#x2062;`, e.g. `2x`. A dot is used in front of numbers, which
+    /// would otherwise merge with the previous factor, e.g. `x⋅2`.
+    #[default]
+    Invisible,
+
+    /// With the dot operator `⋅`, e.g. `2⋅x`.
+    Dot,
+}
+
+/// The notation used when converting an [`Expr`] into elements.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Notation {
+    division: Division,
+    multiplication: Multiplication,
+}
+
+/// Precedence of an expression, higher values bind stronger.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Sum,
+    Negation,
+    Product,
+    Power,
+    Atom,
+}
+
+impl Notation {
+    /// Create a [`Notation`] with the given way of writing divisions and products.
+    pub fn new(division: Division, multiplication: Multiplication) -> Self {
+        Self {
+            division,
+            multiplication,
+        }
+    }
+
+    /// How divisions are written.
+    pub fn division(&self) -> Division {
+        self.division
+    }
+
+    /// How products are written.
+    pub fn multiplication(&self) -> Multiplication {
+        self.multiplication
+    }
+
+    fn precedence(&self, expr: &Expr) -> Precedence {
+        match expr {
+            Expr::Num(num) if num.num().starts_with('-') => Precedence::Negation,
+            Expr::Var(_) | Expr::Num(_) | Expr::Call(..) => Precedence::Atom,
+            Expr::Add(..) | Expr::Sub(..) => Precedence::Sum,
+            Expr::Neg(_) => Precedence::Negation,
+            Expr::Mul(..) => Precedence::Product,
+            Expr::Div(..) => match self.division {
+                Division::Fraction => Precedence::Power,
+                Division::Solidus => Precedence::Product,
+            },
+            Expr::Pow(..) => Precedence::Power,
+        }
+    }
+
+    /// The elements of the operand, in parentheses if it binds weaker than `min`. Operands that
+    /// start with a minus sign are also put in parentheses if negations bind weaker than `min`,
+    /// e.g. `a(-b⋅c)` instead of `a-b⋅c`.
+    fn operand(&self, expr: &Expr, min: Precedence) -> Vec<Element> {
+        let negated = min > Precedence::Negation && self.starts_with_minus(expr);
+
+        match self.precedence(expr) >= min && !negated {
+            true => self.elements(expr),
+            false => parens(self.elements(expr)),
+        }
+    }
+
+    /// Whether the elements of the expression start with a minus sign, e.g. `-b⋅c`.
+    fn starts_with_minus(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Num(num) => num.num().starts_with('-'),
+            Expr::Neg(_) => true,
+            Expr::Add(lhs, _) | Expr::Sub(lhs, _) => self.starts_with_minus(lhs),
+            Expr::Mul(lhs, _) => self.leads_with_minus(lhs),
+            Expr::Div(lhs, _) => match self.division {
+                Division::Fraction => false,
+                Division::Solidus => self.leads_with_minus(lhs),
+            },
+            Expr::Var(_) | Expr::Pow(..) | Expr::Call(..) => false,
+        }
+    }
+
+    /// Whether the left operand of a product starts with a minus sign that is not in parentheses.
+    fn leads_with_minus(&self, lhs: &Expr) -> bool {
+        self.precedence(lhs) >= Precedence::Negation && self.starts_with_minus(lhs)
+    }
+
+    fn elements(&self, expr: &Expr) -> Vec<Element> {
+        match expr {
+            Expr::Var(name) => vec![Ident::from(name.as_str()).into()],
+            Expr::Num(num) => match num.num().strip_prefix('-') {
+                Some(abs) => vec![
+                    operator(Operator::minus(), OpForm::Prefix),
+                    Num::from(abs).into(),
+                ],
+                None => vec![num.clone().into()],
+            },
+            // sums are associative, so the right operand only needs parentheses if it is negated
+            Expr::Add(lhs, rhs) => {
+                let rhs = match **rhs {
+                    Expr::Add(..) | Expr::Sub(..) if !self.starts_with_minus(rhs) => {
+                        self.elements(rhs)
+                    }
+                    _ => self.operand(rhs, Precedence::Product),
+                };
+
+                binary(self.operand(lhs, Precedence::Sum), Operator::plus(), rhs)
+            }
+            Expr::Sub(lhs, rhs) => binary(
+                self.operand(lhs, Precedence::Sum),
+                Operator::minus(),
+                self.operand(rhs, Precedence::Product),
+            ),
+            Expr::Neg(expr) => std::iter::once(operator(Operator::minus(), OpForm::Prefix))
+                .chain(self.operand(expr, Precedence::Product))
+                .collect(),
+            // products are associative, negated factors are only allowed in front
+            Expr::Mul(lhs, rhs) => {
+                let lhs = self.operand(lhs, Precedence::Negation);
+                let rhs = match **rhs {
+                    Expr::Mul(..) if !self.starts_with_minus(rhs) => self.elements(rhs),
+                    _ => self.operand(rhs, Precedence::Power),
+                };
+
+                let times = match self.multiplication {
+                    Multiplication::Dot => Operator::dot(),
+                    Multiplication::Invisible if starts_with_number(&rhs) => Operator::dot(),
+                    Multiplication::Invisible => Operator::from("\u{2062}"),
+                };
+
+                binary(lhs, times, rhs)
+            }
+            Expr::Div(lhs, rhs) => match self.division {
+                Division::Fraction => vec![Frac::builder()
+                    .num(self.elements(lhs))
+                    .denom(self.elements(rhs))
+                    .build()
+                    .into()],
+                Division::Solidus => binary(
+                    self.operand(lhs, Precedence::Negation),
+                    Operator::solidus(),
+                    self.operand(rhs, Precedence::Power),
+                ),
+            },
+            Expr::Pow(base, exp) => vec![SubSup::builder()
+                .base(self.operand(base, Precedence::Atom))
+                .supscript(self.elements(exp))
+                .build()
+                .into()],
+            Expr::Call(name, args) => {
+                let mut arguments = Vec::new();
+
+                for (idx, arg) in args.iter().enumerate() {
+                    if idx > 0 {
+                        arguments.push(
+                            Operator::builder()
+                                .op(",")
+                                .attr([OperatorAttr::Form(OpForm::Infix), OperatorAttr::Separator])
+                                .build()
+                                .into(),
+                        );
+                    }
+
+                    arguments.extend(self.elements(arg));
+                }
+
+                let mut elements = vec![
+                    Ident::from(name.as_str()).into(),
+                    operator(Operator::from("\u{2061}"), OpForm::Infix),
+                ];
+
+                elements.extend(parens(arguments));
+                elements
+            }
+        }
+    }
+}
+
+fn binary(lhs: Vec<Element>, op: Operator, rhs: Vec<Element>) -> Vec<Element> {
+    lhs.into_iter()
+        .chain(std::iter::once(operator(op, OpForm::Infix)))
+        .chain(rhs)
+        .collect()
+}
+
+/// Create the operator with the given `form` attribute.
+fn operator(op: Operator, form: OpForm) -> Element {
+    Operator::builder()
+        .op(op.op())
+        .attr([OperatorAttr::Form(form)])
+        .build()
+        .into()
+}
+
+fn parens(elements: Vec<Element>) -> Vec<Element> {
+    let row = std::iter::once(operator(Operator::lparens(), OpForm::Prefix))
+        .chain(elements)
+        .chain(std::iter::once(operator(
+            Operator::rparens(),
+            OpForm::Postfix,
+        )))
+        .collect::<Elements>();
+
+    vec![Row::from(row).into()]
+}
+
+/// Whether the elements start with a number, which would merge with a previous number if it is
+/// multiplied with invisible times, e.g. `2⋅3²`, or read as mixed number, e.g. `2⋅¾`.
+pub(crate) fn starts_with_number(elements: &[Element]) -> bool {
+    match elements.first() {
+        Some(Element::Num(_)) => true,
+        Some(Element::SubSup(sub_sup)) => starts_with_number(sub_sup.base()),
+        Some(Element::Frac(frac)) => starts_with_number(frac.num()),
+        _ => false,
+    }
+}
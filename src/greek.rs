@@ -0,0 +1,27 @@
+//! Names of greek letters, shared by the formatters that spell out symbols.
+
+/// The name of a plain or mathematical italic greek letter, e.g. `alpha` for `α` or `𝛼`.
+pub(crate) fn name(c: char) -> Option<&'static str> {
+    const GREEK: [&str; 25] = [
+        "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota", "kappa",
+        "lambda", "mu", "nu", "xi", "omicron", "pi", "rho", "sigma", "sigma", "tau", "upsilon",
+        "phi", "chi", "psi", "omega",
+    ];
+
+    const CAPITAL_GREEK: [&str; 25] = [
+        "Alpha", "Beta", "Gamma", "Delta", "Epsilon", "Zeta", "Eta", "Theta", "Iota", "Kappa",
+        "Lambda", "Mu", "Nu", "Xi", "Omicron", "Pi", "Rho", "Theta", "Sigma", "Tau", "Upsilon",
+        "Phi", "Chi", "Psi", "Omega",
+    ];
+
+    let code = c as u32;
+
+    // plain and mathematical italic greek letters
+    match code {
+        0x03B1..=0x03C9 => Some(GREEK[(code - 0x03B1) as usize]),
+        0x1D6FC..=0x1D714 => Some(GREEK[(code - 0x1D6FC) as usize]),
+        0x0391..=0x03A9 => Some(CAPITAL_GREEK[(code - 0x0391) as usize]),
+        0x1D6E2..=0x1D6FA => Some(CAPITAL_GREEK[(code - 0x1D6E2) as usize]),
+        _ => None,
+    }
+}
@@ -0,0 +1,182 @@
+use std::borrow::Borrow;
+use std::{fmt, io};
+
+use crate::{
+    attributes::Attribute,
+    elements::{
+        grouping::{Action, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver},
+        Annotation, Frac, Ident, Num, Operator, Padded, Semantics, Space, StrLiteral, Table, Text,
+    },
+    markup::{self, Markup},
+    Element, GeneratedAltText, MathMl, Writer,
+};
+
+/// MathMl [`Writer`] that streams the markup into any [`io::Write`], e.g. a file, a socket or a
+/// [`BufWriter`](std::io::BufWriter).
+///
+/// The markup is written directly into the inner writer without an intermediate buffer, and is
+/// byte-identical to the markup produced by [`BufMathMlWriter`](crate::BufMathMlWriter).
+///
+/// # Example
+///
+/// ```
+/// use alemat::{elements::Ident, IoMathMlWriter, MathMl, Writer};
+///
+/// let math = MathMl::with_content([Ident::from("x")]);
+///
+/// let mut writer = IoMathMlWriter::new(Vec::new());
+/// math.write(&mut writer).unwrap();
+///
+/// assert_eq!(writer.into_inner(), b"<math><mi>x</mi></math>");
+/// ```
+#[derive(Debug)]
+pub struct IoMathMlWriter<W> {
+    inner: Option<W>,
+    raw: bool,
+    alttext: GeneratedAltText,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> IoMathMlWriter<W> {
+    /// Create a new [`IoMathMlWriter`] writing into the given writer.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: Some(inner),
+            raw: false,
+            alttext: GeneratedAltText::Disabled,
+            error: None,
+        }
+    }
+
+    /// Create an [`IoMathMlWriter`] that writes element content and attribute values as they
+    /// are, without escaping them.
+    ///
+    /// Only use this writer if all content has already been escaped, otherwise the produced
+    /// markup might be malformed.
+    pub fn raw(inner: W) -> Self {
+        Self {
+            raw: true,
+            ..Self::new(inner)
+        }
+    }
+
+    /// Set which text alternatives are generated for `math` elements that do not have them
+    /// already, see [`MathMl::generate_alttext`].
+    pub fn with_generated_alttext(mut self, generated: GeneratedAltText) -> Self {
+        self.alttext = generated;
+        self
+    }
+
+    /// Convert the result of writing markup into an [`io::Result`], recovering the error of the
+    /// inner writer.
+    fn io_result(&mut self, res: fmt::Result) -> io::Result<()> {
+        res.map_err(|_| {
+            self.error
+                .take()
+                .unwrap_or_else(|| io::Error::other("formatting of markup failed"))
+        })
+    }
+}
+
+impl<W: io::Write> fmt::Write for IoMathMlWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let res = match self.inner.as_mut() {
+            Some(inner) => inner.write_all(s.as_bytes()),
+            None => Err(io::Error::other("writer has already been finished")),
+        };
+
+        res.map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+impl<W: io::Write> Markup for IoMathMlWriter<W> {
+    fn is_raw(&self) -> bool {
+        self.raw
+    }
+
+    fn generated_alttext(&self) -> GeneratedAltText {
+        self.alttext
+    }
+}
+
+macro_rules! write_io {
+    ($($fn:ident: $markup:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $fn(&mut self, element: &$ty) -> Result<(), Self::Error> {
+                let res = markup::$markup(self, element);
+                self.io_result(res)
+            }
+        )*
+    };
+}
+
+impl<W: io::Write> Writer for IoMathMlWriter<W> {
+    type Buffer = W;
+    type Error = io::Error;
+
+    write_io! {
+        write_action: action(Action),
+        write_annotation: annotation(Annotation),
+        write_error: error(Error),
+        write_frac: frac(Frac),
+        write_ident: ident(Ident),
+        write_multiscripts: multiscripts(Multiscripts),
+        write_prescripts: prescripts(Prescripts),
+        write_num: num(Num),
+        write_operator: operator(Operator),
+        write_padded: padded(Padded),
+        write_phantom: phantom(Phantom),
+        write_radical: radical(Radical),
+        write_row: row(Row),
+        write_semantics: semantics(Semantics),
+        write_space: space(Space),
+        write_str_literal: str_literal(StrLiteral),
+        write_style: style(Style),
+        write_subsup: subsup(SubSup),
+        write_table: table(Table),
+        write_text: text(Text),
+        write_underover: underover(UnderOver),
+        write_attr: attribute(Attribute),
+        write_element: element(Element),
+        write_mathml: mathml(MathMl),
+    }
+
+    /// Return a reference to the inner writer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner writer was already taken out with [`Writer::finish`].
+    fn buffer<T>(&self) -> &T
+    where
+        Self::Buffer: Borrow<T>,
+    {
+        self.inner
+            .as_ref()
+            .expect("writer has already been finished")
+            .borrow()
+    }
+
+    /// Take the inner writer out of this [`IoMathMlWriter`]. Writing any elements afterwards
+    /// fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner writer was already taken out.
+    fn finish(&mut self) -> Self::Buffer {
+        self.inner.take().expect("writer has already been finished")
+    }
+
+    /// Consume the [`IoMathMlWriter`] and return the inner writer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner writer was already taken out with [`Writer::finish`].
+    fn into_inner(mut self) -> Self::Buffer {
+        self.finish()
+    }
+}
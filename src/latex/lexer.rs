@@ -0,0 +1,90 @@
+//! Splits LaTeX math-mode input into tokens.
+
+use std::ops::Range;
+
+/// A single LaTeX token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Token<'a> {
+    /// A control sequence without the leading backslash, e.g. `frac` for `\frac` or `,` for `\,`.
+    Command(&'a str),
+
+    /// Any other character, e.g. a letter, digit or operator.
+    Char(char),
+
+    /// `{`
+    BeginGroup,
+
+    /// `}`
+    EndGroup,
+
+    /// `_`
+    Sub,
+
+    /// `^`
+    Sup,
+
+    /// `&`
+    Align,
+}
+
+/// A [`Token`] together with the byte range it occupies in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Spanned<'a> {
+    pub token: Token<'a>,
+    pub span: Range<usize>,
+}
+
+/// Tokenize the given input. Whitespace and comments are skipped, since they carry no meaning in
+/// math mode.
+pub(super) fn lex(input: &str) -> Vec<Spanned<'_>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            '\\' => {
+                let name_start = start + 1;
+                let mut end = name_start;
+
+                match chars.peek() {
+                    Some(&(_, n)) if n.is_ascii_alphabetic() => {
+                        while let Some(&(idx, n)) = chars.peek() {
+                            if !n.is_ascii_alphabetic() {
+                                break;
+                            }
+
+                            end = idx + n.len_utf8();
+                            chars.next();
+                        }
+                    }
+                    Some(&(idx, n)) => {
+                        end = idx + n.len_utf8();
+                        chars.next();
+                    }
+                    None => {}
+                }
+
+                Token::Command(&input[name_start..end])
+            }
+            '%' => {
+                while chars.next_if(|&(_, n)| n != '\n').is_some() {}
+                continue;
+            }
+            c if c.is_whitespace() => continue,
+            '{' => Token::BeginGroup,
+            '}' => Token::EndGroup,
+            '_' => Token::Sub,
+            '^' => Token::Sup,
+            '&' => Token::Align,
+            c => Token::Char(c),
+        };
+
+        let end = chars.peek().map_or(input.len(), |&(idx, _)| idx);
+        tokens.push(Spanned {
+            token,
+            span: start..end,
+        });
+    }
+
+    tokens
+}
@@ -0,0 +1,166 @@
+//! Conversion of LaTeX math-mode markup into MathML elements.
+//!
+//! The parser understands the commonly used subset of LaTeX and AMS math: fractions, roots,
+//! sub- and superscripts, large operators with limits, `\left...\right` fences, accents, font
+//! commands (mapped to [`MathVariant`](crate::attributes::MathVariant)), `\text{}`, spacing
+//! commands and matrix-like environments such as `pmatrix`, `cases`, `array` and `aligned`.
+//!
+//! The [`LatexFormatter`] renders elements back into LaTeX markup understood by the parser.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{Frac, Ident};
+//! use alemat::{latex, MathMl};
+//!
+//! let math = latex::parse(r"\frac{a}{b}").unwrap();
+//!
+//! let expected = MathMl::with_content([Frac::builder()
+//!     .num(Ident::from("a"))
+//!     .denom(Ident::from("b"))
+//!     .build()]);
+//!
+//! assert_eq!(math, expected);
+//! ```
+
+mod lexer;
+mod parser;
+mod renderer;
+mod symbols;
+
+pub use renderer::LatexFormatter;
+
+use std::ops::Range;
+
+use crate::{Elements, MathMl};
+
+/// Parse the given LaTeX math-mode input into a [`MathMl`] element.
+///
+/// The input must not contain the math-mode delimiters, i.e. `$...$` or `\[...\]`.
+pub fn parse(input: &str) -> Result<MathMl, LatexError> {
+    parse_elements(input).map(MathMl::with_content)
+}
+
+/// Parse the given LaTeX math-mode input into [`Elements`].
+///
+/// The input must not contain the math-mode delimiters, i.e. `$...$` or `\[...\]`.
+pub fn parse_elements(input: &str) -> Result<Elements, LatexError> {
+    parser::parse(input).map(Elements)
+}
+
+/// The kind of error that occurred while parsing LaTeX input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LatexErrorKind {
+    /// The input ended unexpectedly.
+    UnexpectedEof,
+
+    /// A token was found where it is not allowed, e.g. `&` outside of an environment.
+    UnexpectedToken(String),
+
+    /// The command is not supported.
+    UnknownCommand(String),
+
+    /// The environment is not supported.
+    UnknownEnvironment(String),
+
+    /// The environment was closed with `\end` of a different environment.
+    MismatchedEnvironment {
+        /// Name of the environment that was opened.
+        expected: String,
+
+        /// Name of the environment that was closed.
+        found: String,
+    },
+
+    /// The environment was never closed.
+    UnclosedEnvironment(String),
+
+    /// The command is missing a required argument.
+    MissingArgument(String),
+
+    /// The group opened with `{` (or `[` for optional arguments) was never closed.
+    UnclosedGroup,
+
+    /// The `\left` command has no matching `\right` command.
+    UnmatchedLeft,
+
+    /// The token cannot be used as a delimiter after `\left`, `\right` or similar commands.
+    InvalidDelimiter(String),
+
+    /// The column specification of an `array` environment contains an unsupported character.
+    InvalidColumnSpec(char),
+
+    /// An expression has more than one subscript.
+    DoubleSubscript,
+
+    /// An expression has more than one superscript.
+    DoubleSuperscript,
+
+    /// The input is nested deeper than the given maximum depth.
+    NestingTooDeep(usize),
+}
+
+impl std::fmt::Display for LatexErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LatexErrorKind::UnexpectedEof => f.write_str("unexpected end of input"),
+            LatexErrorKind::UnexpectedToken(token) => write!(f, "unexpected `{token}`"),
+            LatexErrorKind::UnknownCommand(name) => write!(f, "unknown command `\\{name}`"),
+            LatexErrorKind::UnknownEnvironment(name) => {
+                write!(f, "unknown environment `{name}`")
+            }
+            LatexErrorKind::MismatchedEnvironment { expected, found } => write!(
+                f,
+                "environment `{expected}` is closed with `\\end{{{found}}}`"
+            ),
+            LatexErrorKind::UnclosedEnvironment(name) => {
+                write!(f, "environment `{name}` is never closed")
+            }
+            LatexErrorKind::MissingArgument(name) => {
+                write!(f, "missing argument for `{name}`")
+            }
+            LatexErrorKind::UnclosedGroup => f.write_str("group is never closed"),
+            LatexErrorKind::UnmatchedLeft => f.write_str("`\\left` without matching `\\right`"),
+            LatexErrorKind::InvalidDelimiter(token) => write!(f, "invalid delimiter `{token}`"),
+            LatexErrorKind::InvalidColumnSpec(c) => {
+                write!(f, "invalid column specification `{c}`")
+            }
+            LatexErrorKind::DoubleSubscript => f.write_str("double subscript"),
+            LatexErrorKind::DoubleSuperscript => f.write_str("double superscript"),
+            LatexErrorKind::NestingTooDeep(max) => {
+                write!(f, "nesting is deeper than the maximum of {max} levels")
+            }
+        }
+    }
+}
+
+/// Error that occurred while parsing LaTeX input, pointing at the offending part of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatexError {
+    kind: LatexErrorKind,
+    span: Range<usize>,
+}
+
+impl LatexError {
+    pub(crate) fn new(kind: LatexErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// Get the kind of the error.
+    pub fn kind(&self) -> &LatexErrorKind {
+        &self.kind
+    }
+
+    /// Get the byte range of the input that caused the error.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl std::fmt::Display for LatexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for LatexError {}
@@ -0,0 +1,1102 @@
+//! Recursive descent parser turning LaTeX tokens into MathML elements.
+
+use std::ops::Range;
+
+use crate::{
+    attributes::{Attribute, Length, MathVariant},
+    elements::{
+        grouping::{Phantom, Row, Style},
+        radicals::Radical,
+        scripted::{SubSup, UnderOver, UnderOverAttr},
+        ColumnLine, Frac, FracAttr, Ident, Num, OpForm, Operator, OperatorAttr, Space, SpaceAttr,
+        Table, TableAttr, TableCell, TableRow, Text,
+    },
+    Element, Elements,
+};
+
+use super::{
+    lexer::{lex, Spanned, Token},
+    symbols, LatexError, LatexErrorKind,
+};
+
+/// Parse the given LaTeX math-mode input into a list of elements.
+pub(super) fn parse(input: &str) -> Result<Vec<Element>, LatexError> {
+    let mut parser = Parser {
+        input,
+        tokens: lex(input),
+        pos: 0,
+        variant: None,
+        optional: 0,
+        depth: 0,
+    };
+
+    let elements = parser.list()?;
+
+    match parser.bump() {
+        Some(tok) => Err(parser.unexpected(&tok)),
+        None => Ok(elements),
+    }
+}
+
+/// A parsed atom together with information on how scripts are attached to it.
+struct Atom {
+    element: Element,
+
+    /// Scripts are placed under and over the atom instead of after it, e.g. for `\sum`.
+    limits: bool,
+
+    /// The atom is a function name such as `\sin`, which is followed by a function application.
+    function: bool,
+}
+
+impl Atom {
+    fn new(element: impl Into<Element>) -> Self {
+        Self {
+            element: element.into(),
+            limits: false,
+            function: false,
+        }
+    }
+
+    fn with_limits(mut self, limits: bool) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+/// Horizontal alignment of the cells in a table column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnAlign {
+    Left,
+    Center,
+    Right,
+}
+
+/// Layout of a matrix-like environment.
+struct Environment {
+    open: Option<Operator>,
+    close: Option<Operator>,
+
+    /// Alignment of the columns, repeated for tables with more columns.
+    align: Vec<ColumnAlign>,
+
+    /// Lines between the columns.
+    lines: Vec<ColumnLine>,
+
+    /// Whether the content is laid out in display style, e.g. for `aligned`.
+    display: bool,
+}
+
+impl Environment {
+    fn new(open: Option<Operator>, close: Option<Operator>) -> Self {
+        Self {
+            open,
+            close,
+            align: vec![ColumnAlign::Center],
+            lines: Vec::new(),
+            display: false,
+        }
+    }
+
+    fn with_align(mut self, align: Vec<ColumnAlign>) -> Self {
+        self.align = align;
+        self
+    }
+
+    fn with_display(mut self) -> Self {
+        self.display = true;
+        self
+    }
+
+    /// Create the table of the environment with the given rows of cells.
+    fn table(self, rows: Vec<Vec<Vec<Element>>>) -> Atom {
+        let mut table = rows
+            .into_iter()
+            .map(|cells| {
+                cells
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, content)| {
+                        let cell = TableCell::from(Elements(content));
+
+                        match self.align[idx % self.align.len()] {
+                            ColumnAlign::Left => {
+                                cell.with_attr([Attribute::Style("text-align: left".into())])
+                            }
+                            ColumnAlign::Right => {
+                                cell.with_attr([Attribute::Style("text-align: right".into())])
+                            }
+                            ColumnAlign::Center => cell,
+                        }
+                    })
+                    .collect::<TableRow>()
+            })
+            .collect::<Table>();
+
+        if self.lines.iter().any(|line| *line != ColumnLine::None) {
+            table.add_attr([TableAttr::ColumnLines(self.lines)]);
+        }
+
+        if self.display {
+            table.add_attr([TableAttr::Global(Attribute::DisplayStyle(true))]);
+        }
+
+        if self.open.is_none() && self.close.is_none() {
+            return Atom::new(table);
+        }
+
+        Atom::new(fenced(self.open, vec![table.into()], self.close))
+    }
+}
+
+/// Maximum number of atoms and style commands nested into each other.
+const MAX_DEPTH: usize = 256;
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<Spanned<'a>>,
+    pos: usize,
+
+    /// Math variant set by the innermost font command, e.g. `\mathbf`.
+    variant: Option<MathVariant>,
+
+    /// Number of optional arguments (`[...]`) currently being parsed.
+    optional: usize,
+
+    /// Number of atoms and style commands currently being parsed, see [`MAX_DEPTH`].
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).map(|tok| tok.token)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<Token<'a>> {
+        self.tokens.get(self.pos + n).map(|tok| tok.token)
+    }
+
+    fn bump(&mut self) -> Option<Spanned<'a>> {
+        let tok = self.tokens.get(self.pos).cloned()?;
+        self.pos += 1;
+        Some(tok)
+    }
+
+    fn eof(&self) -> LatexError {
+        let len = self.input.len();
+        LatexError::new(LatexErrorKind::UnexpectedEof, len..len)
+    }
+
+    fn unexpected(&self, tok: &Spanned<'a>) -> LatexError {
+        LatexError::new(
+            LatexErrorKind::UnexpectedToken(self.input[tok.span.clone()].to_string()),
+            tok.span.clone(),
+        )
+    }
+
+    /// Run `parse` one nesting level deeper, failing if the input is nested too deeply.
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, LatexError>,
+    ) -> Result<T, LatexError> {
+        if self.depth >= MAX_DEPTH {
+            let span = self
+                .tokens
+                .get(self.pos)
+                .map_or(self.input.len()..self.input.len(), |tok| tok.span.clone());
+
+            return Err(LatexError::new(
+                LatexErrorKind::NestingTooDeep(MAX_DEPTH),
+                span,
+            ));
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Whether the next token ends the current list of elements.
+    fn at_list_end(&self) -> bool {
+        match self.peek() {
+            None | Some(Token::EndGroup | Token::Align) => true,
+            Some(Token::Command("\\" | "end" | "right")) => true,
+            Some(Token::Char(']')) => self.optional > 0,
+            Some(_) => false,
+        }
+    }
+
+    /// Parse elements until the end of the current group, environment cell or `\left` fence.
+    fn list(&mut self) -> Result<Vec<Element>, LatexError> {
+        let mut elements = Vec::new();
+
+        while !self.at_list_end() {
+            match self.peek() {
+                Some(Token::Command(name @ ("displaystyle" | "textstyle" | "color"))) => {
+                    elements.push(self.style_switch(name)?);
+                }
+                next => {
+                    let atom = match next {
+                        // scripts without a base, e.g. `^2`
+                        Some(Token::Sub | Token::Sup | Token::Char('\'')) => {
+                            Atom::new(Row::default())
+                        }
+                        _ => self.atom()?,
+                    };
+
+                    let function = atom.function;
+                    elements.push(self.scripts(atom)?);
+
+                    if function && !self.at_list_end() {
+                        elements.push(Operator::from("\u{2061}").into());
+                    }
+                }
+            }
+        }
+
+        Ok(elements)
+    }
+
+    /// Parse the rest of the current list with the style set by a switch such as `\color`.
+    fn style_switch(&mut self, name: &str) -> Result<Element, LatexError> {
+        let tok = self.bump().expect("Token was peeked.");
+
+        let attr = match name {
+            "color" => {
+                let (color, _) = self.raw_argument(name, tok.span)?;
+                Attribute::MathColor(color.trim().into())
+            }
+            _ => Attribute::DisplayStyle(name == "displaystyle"),
+        };
+
+        let rest = self.nested(Self::list)?;
+        Ok(Style::from(Elements(rest)).with_attr([attr]).into())
+    }
+
+    /// Parse the rest of a group opened at the given span, including the closing `}`.
+    fn group(&mut self, open: Range<usize>) -> Result<Vec<Element>, LatexError> {
+        // `]` inside of braces does not end an optional argument
+        let optional = std::mem::take(&mut self.optional);
+        let content = self.list();
+        self.optional = optional;
+        let content = content?;
+
+        match self.bump() {
+            Some(Spanned {
+                token: Token::EndGroup,
+                ..
+            }) => Ok(content),
+            Some(tok) => Err(self.unexpected(&tok)),
+            None => Err(LatexError::new(LatexErrorKind::UnclosedGroup, open)),
+        }
+    }
+
+    /// Parse a single atom without scripts.
+    fn atom(&mut self) -> Result<Atom, LatexError> {
+        self.nested(Self::parse_atom)
+    }
+
+    fn parse_atom(&mut self) -> Result<Atom, LatexError> {
+        let tok = self.bump().ok_or_else(|| self.eof())?;
+
+        match tok.token {
+            Token::BeginGroup => {
+                let content = self.group(tok.span)?;
+                Ok(Atom::new(group_element(content)))
+            }
+            Token::Char(c) if c.is_ascii_digit() => Ok(self.number(c)),
+            Token::Char(c) => Ok(Atom::new(self.char_element(c))),
+            Token::Command(name) => self.command(name, tok.span),
+            Token::Sub | Token::Sup | Token::Align | Token::EndGroup => Err(self.unexpected(&tok)),
+        }
+    }
+
+    /// Parse the number starting with the digit `c`, e.g. `3.14`.
+    fn number(&mut self, c: char) -> Atom {
+        let mut num = String::from(c);
+
+        loop {
+            match (self.peek(), self.peek_nth(1)) {
+                (Some(Token::Char(d)), _) if d.is_ascii_digit() => num.push(d),
+                (Some(Token::Char('.')), Some(Token::Char(d))) if d.is_ascii_digit() => {
+                    num.push('.')
+                }
+                _ => break,
+            }
+
+            self.bump();
+        }
+
+        Atom::new(self.num(num))
+    }
+
+    /// Parse the required argument of a command. The argument is either a group or a single
+    /// token.
+    fn argument(&mut self, command: &str, span: Range<usize>) -> Result<Vec<Element>, LatexError> {
+        let content = match self.peek() {
+            Some(Token::BeginGroup) => {
+                let tok = self.bump().expect("Token was peeked.");
+                self.nested(|parser| parser.group(tok.span))?
+            }
+            Some(Token::Char(c)) => {
+                self.bump();
+                vec![self.char_element(c)]
+            }
+            Some(Token::Command(_)) => vec![self.atom()?.element],
+            _ => {
+                return Err(LatexError::new(
+                    LatexErrorKind::MissingArgument(command.to_string()),
+                    span,
+                ))
+            }
+        };
+
+        if content.is_empty() {
+            Ok(vec![Row::default().into()])
+        } else {
+            Ok(content)
+        }
+    }
+
+    /// Parse the required argument of a command as raw source text, e.g. `a b` in `\text{a b}`.
+    fn raw_argument(
+        &mut self,
+        command: &str,
+        span: Range<usize>,
+    ) -> Result<(&'a str, Range<usize>), LatexError> {
+        let open = match self.bump() {
+            Some(tok) if tok.token == Token::BeginGroup => tok.span,
+            _ => {
+                return Err(LatexError::new(
+                    LatexErrorKind::MissingArgument(command.to_string()),
+                    span,
+                ))
+            }
+        };
+
+        let mut depth = 0;
+
+        loop {
+            let tok = self
+                .bump()
+                .ok_or_else(|| LatexError::new(LatexErrorKind::UnclosedGroup, open.clone()))?;
+
+            match tok.token {
+                Token::BeginGroup => depth += 1,
+                Token::EndGroup if depth == 0 => {
+                    let range = open.end..tok.span.start;
+                    return Ok((&self.input[range.clone()], range));
+                }
+                Token::EndGroup => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    /// Parse the optional argument of a command, e.g. `3` in `\sqrt[3]{x}`.
+    fn optional_argument(&mut self) -> Result<Option<Vec<Element>>, LatexError> {
+        let open = match self.peek() {
+            Some(Token::Char('[')) => self.bump().expect("Token was peeked.").span,
+            _ => return Ok(None),
+        };
+
+        self.optional += 1;
+        let content = self.list();
+        self.optional -= 1;
+        let content = content?;
+
+        match self.bump() {
+            Some(Spanned {
+                token: Token::Char(']'),
+                ..
+            }) => Ok(Some(content)),
+            Some(tok) => Err(self.unexpected(&tok)),
+            None => Err(LatexError::new(LatexErrorKind::UnclosedGroup, open)),
+        }
+    }
+
+    /// Parse the sub- and superscripts following the given atom.
+    fn scripts(&mut self, mut atom: Atom) -> Result<Element, LatexError> {
+        loop {
+            match self.peek() {
+                Some(Token::Command("limits")) => atom.limits = true,
+                Some(Token::Command("nolimits")) => atom.limits = false,
+                _ => break,
+            }
+
+            self.bump();
+        }
+
+        let mut sub: Option<Vec<Element>> = None;
+        let mut sup: Option<Vec<Element>> = None;
+
+        // primes can be followed by a superscript, e.g. `f'^2`
+        let mut primed = false;
+
+        loop {
+            match self.peek() {
+                Some(Token::Char('\'')) => {
+                    let span = self.tokens[self.pos].span.clone();
+
+                    if sup.is_some() {
+                        return Err(LatexError::new(LatexErrorKind::DoubleSuperscript, span));
+                    }
+
+                    let mut primes = String::new();
+                    while self.peek() == Some(Token::Char('\'')) {
+                        self.bump();
+                        primes.push('\u{2032}');
+                    }
+
+                    sup = Some(vec![Operator::from(primes).into()]);
+                    primed = true;
+                }
+                Some(Token::Sup) => {
+                    let tok = self.bump().expect("Token was peeked.");
+                    let arg = self.argument("^", tok.span.clone())?;
+
+                    match &mut sup {
+                        Some(primes) if primed => primes.extend(arg),
+                        Some(_) => {
+                            return Err(LatexError::new(
+                                LatexErrorKind::DoubleSuperscript,
+                                tok.span,
+                            ))
+                        }
+                        None => sup = Some(arg),
+                    }
+
+                    primed = false;
+                }
+                Some(Token::Sub) => {
+                    let tok = self.bump().expect("Token was peeked.");
+                    let arg = self.argument("_", tok.span.clone())?;
+
+                    if sub.is_some() {
+                        return Err(LatexError::new(LatexErrorKind::DoubleSubscript, tok.span));
+                    }
+
+                    sub = Some(arg);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(scripted(atom, sub, sup))
+    }
+
+    /// Create the element for a single character.
+    fn char_element(&self, c: char) -> Element {
+        match c {
+            '~' => Text::from("\u{00A0}").into(),
+            c if c.is_alphabetic() => self.ident(Ident::from(c.to_string())).into(),
+            c if c.is_numeric() => self.num(c.to_string()).into(),
+            c => symbols::char_operator(c).into(),
+        }
+    }
+
+    /// Apply the current math variant to the given identifier.
+    fn ident(&self, ident: Ident) -> Ident {
+        match &self.variant {
+            Some(variant) => Ident::builder()
+                .ident(ident.ident())
+                .attr([Attribute::MathVariant(variant.clone())])
+                .build(),
+            None => ident,
+        }
+    }
+
+    /// Create a number with the current math variant applied.
+    fn num(&self, num: String) -> Num {
+        let num = Num::from(num.as_str());
+
+        match &self.variant {
+            Some(variant) => num.with_attr([Attribute::MathVariant(variant.clone())]),
+            None => num,
+        }
+    }
+
+    /// Parse a command and its arguments.
+    fn command(&mut self, name: &'a str, span: Range<usize>) -> Result<Atom, LatexError> {
+        if let Some(atom) = self.symbol(name) {
+            return Ok(atom);
+        }
+
+        // the parser of the arguments is selected first and called once, which keeps the stack
+        // frame of this function small, as it is part of the recursion for nested arguments
+        let parse: fn(&mut Self, &str, Range<usize>) -> Result<Atom, LatexError> = match name {
+            _ if symbols::font(name).is_some() => Self::font,
+            _ if symbols::text(name).is_some() => Self::text,
+            _ if symbols::accent(name).is_some() => Self::accent,
+            _ if symbols::big_delimiter_size(name).is_some() => Self::big_delimiter,
+            "frac" | "dfrac" | "tfrac" | "cfrac" => Self::frac,
+            "binom" | "dbinom" | "tbinom" => Self::binom,
+            "sqrt" => Self::sqrt,
+            "overset" | "stackrel" | "underset" => Self::overset,
+            "operatorname" => Self::operatorname,
+            "not" => |parser, _, _| parser.not(),
+            "left" => |parser, _, span| parser.fenced(span),
+            "middle" => Self::middle,
+            "hspace" => Self::hspace,
+            "textcolor" => Self::textcolor,
+            "phantom" => Self::phantom,
+            "begin" => |parser, _, span| parser.environment(span),
+            _ => {
+                return Err(LatexError::new(
+                    LatexErrorKind::UnknownCommand(name.to_string()),
+                    span,
+                ))
+            }
+        };
+
+        parse(self, name, span)
+    }
+
+    /// Look up a command without arguments, e.g. `\alpha`, `\sin` or `\quad`.
+    fn symbol(&self, name: &str) -> Option<Atom> {
+        if let Some(ident) = symbols::ident(name) {
+            return Some(Atom::new(self.ident(ident)));
+        }
+
+        if let Some(op) = symbols::operator(name) {
+            return Some(Atom::new(op));
+        }
+
+        if let Some(op) = symbols::large_operator(name) {
+            return Some(Atom::new(op).with_limits(true));
+        }
+
+        if let Some(op) = symbols::integral(name) {
+            return Some(Atom::new(op));
+        }
+
+        if let Some((function, limits)) = symbols::function(name) {
+            let mut atom = Atom::new(Ident::from(function)).with_limits(limits);
+            atom.function = true;
+            return Some(atom);
+        }
+
+        if let Some(width) = symbols::space(name) {
+            return Some(Atom::new(space(width)));
+        }
+
+        None
+    }
+
+    fn font(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let variant = symbols::font(name).expect("Font is looked up");
+        let outer = self.variant.replace(variant);
+        let content = self.argument(name, span);
+        self.variant = outer;
+
+        Ok(Atom::new(group_element(content?)))
+    }
+
+    fn text(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let variant = symbols::text(name).expect("Text command is looked up");
+        let (raw, _) = self.raw_argument(name, span)?;
+        let text = Text::from(unescape_text(raw));
+
+        Ok(Atom::new(match variant {
+            Some(variant) => text.with_attr([Attribute::MathVariant(variant)]),
+            None => text,
+        }))
+    }
+
+    fn accent(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let accent = symbols::accent(name).expect("Accent is looked up");
+        let content = self.argument(name, span)?;
+
+        let op = if accent.stretchy {
+            stretchy(accent.op, [])
+        } else {
+            accent.op
+        };
+
+        let builder = UnderOver::builder().expr(content);
+
+        let element = if accent.under {
+            builder
+                .under([op])
+                .attr([UnderOverAttr::AccentUnder])
+                .build()
+        } else {
+            builder.over([op]).attr([UnderOverAttr::AccentOver]).build()
+        };
+
+        // braces take their label as limits, e.g. `\underbrace{x}_{n}`
+        let limits = matches!(name, "overbrace" | "underbrace");
+
+        Ok(Atom::new(element).with_limits(limits))
+    }
+
+    fn big_delimiter(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let size = symbols::big_delimiter_size(name).expect("Delimiter size is looked up");
+        let element: Element = match self.delimiter(name, span)? {
+            Some(op) => stretchy(
+                op,
+                [
+                    OperatorAttr::Symmetric,
+                    OperatorAttr::MinSize(size.into()),
+                    OperatorAttr::MaxSize(size.into()),
+                ],
+            )
+            .into(),
+            None => Row::default().into(),
+        };
+
+        Ok(Atom::new(element))
+    }
+
+    fn frac(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let num = self.argument(name, span.clone())?;
+        let denom = self.argument(name, span)?;
+        let frac = Frac::builder().num(num).denom(denom).build();
+
+        Ok(Atom::new(display_style(name, frac)))
+    }
+
+    fn binom(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let top = self.argument(name, span.clone())?;
+        let bottom = self.argument(name, span)?;
+        let frac = Frac::builder()
+            .num(top)
+            .denom(bottom)
+            .attr([FracAttr::LineThickness(Length::unitless(0.0).into())])
+            .build();
+
+        let binom = Row::from([
+            fence(Operator::lparens(), OpForm::Prefix),
+            frac.into(),
+            fence(Operator::rparens(), OpForm::Postfix),
+        ]);
+
+        Ok(Atom::new(display_style(name, binom)))
+    }
+
+    fn sqrt(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let index = self.optional_argument()?;
+        let content = self.argument(name, span)?;
+
+        let index = match index {
+            Some(index) if !index.is_empty() => index,
+            _ => vec![Num::from(2).into()],
+        };
+
+        Ok(Atom::new(
+            Radical::builder().index(index).content(content).build(),
+        ))
+    }
+
+    fn overset(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let script = self.argument(name, span.clone())?;
+        let base = self.argument(name, span)?;
+        let builder = UnderOver::builder().expr(base);
+
+        let element = if name == "underset" {
+            builder.under(script).build()
+        } else {
+            builder.over(script).build()
+        };
+
+        Ok(Atom::new(element))
+    }
+
+    fn operatorname(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let limits = self.peek() == Some(Token::Char('*'));
+        if limits {
+            self.bump();
+        }
+
+        let (raw, _) = self.raw_argument(name, span)?;
+
+        let mut atom = Atom::new(Ident::from(unescape_text(raw).trim())).with_limits(limits);
+        atom.function = true;
+        Ok(atom)
+    }
+
+    fn not(&mut self) -> Result<Atom, LatexError> {
+        let atom = self.atom()?;
+
+        let element = match atom.element {
+            Element::Operator(op) => match op.op() {
+                "=" => Operator::not_eq(),
+                "\u{2208}" => Operator::not_in_set(),
+                op => Operator::from(format!("{op}\u{0338}")),
+            }
+            .into(),
+            element => element,
+        };
+
+        Ok(Atom::new(element))
+    }
+
+    fn middle(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let element: Element = match self.delimiter(name, span)? {
+            Some(op) => stretchy(op, []).into(),
+            None => Row::default().into(),
+        };
+
+        Ok(Atom::new(element))
+    }
+
+    fn hspace(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let (width, _) = self.raw_argument(name, span)?;
+        Ok(Atom::new(space(width.trim())))
+    }
+
+    fn textcolor(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let (color, _) = self.raw_argument(name, span.clone())?;
+        let content = self.argument(name, span)?;
+        let color = Attribute::MathColor(color.trim().into());
+
+        Ok(Atom::new(Style::from(Elements(content)).with_attr([color])))
+    }
+
+    fn phantom(&mut self, name: &str, span: Range<usize>) -> Result<Atom, LatexError> {
+        let content = self.argument(name, span)?;
+        Ok(Atom::new(Phantom::from(Elements(content))))
+    }
+
+    /// Parse the delimiter following commands such as `\left`. Returns `None` for the empty
+    /// delimiter `.`.
+    fn delimiter(
+        &mut self,
+        command: &str,
+        span: Range<usize>,
+    ) -> Result<Option<Operator>, LatexError> {
+        let Some(tok) = self.bump() else {
+            return Err(LatexError::new(
+                LatexErrorKind::MissingArgument(command.to_string()),
+                span,
+            ));
+        };
+
+        let op = match tok.token {
+            Token::Char('.') => return Ok(None),
+            Token::Char(c) => symbols::delimiter_char(c),
+            Token::Command(name) => symbols::delimiter(name),
+            _ => None,
+        };
+
+        match op {
+            Some(op) => Ok(Some(op)),
+            None => Err(LatexError::new(
+                LatexErrorKind::InvalidDelimiter(self.input[tok.span.clone()].to_string()),
+                tok.span,
+            )),
+        }
+    }
+
+    /// Parse the content between `\left` and `\right`, the `\left` command is already consumed.
+    fn fenced(&mut self, left: Range<usize>) -> Result<Atom, LatexError> {
+        let open = self.delimiter("left", left.clone())?;
+        let content = self.list()?;
+
+        let right = match self.bump() {
+            Some(tok) if tok.token == Token::Command("right") => tok.span,
+            _ => return Err(LatexError::new(LatexErrorKind::UnmatchedLeft, left)),
+        };
+
+        let close = self.delimiter("right", right)?;
+
+        Ok(Atom::new(fenced(open, content, close)))
+    }
+
+    /// Parse an environment, the `\begin` command is already consumed.
+    fn environment(&mut self, begin: Range<usize>) -> Result<Atom, LatexError> {
+        let (name, name_span) = self.raw_argument("begin", begin.clone())?;
+        let name = name.trim();
+
+        let env = self.environment_kind(name, name_span, begin.clone())?;
+
+        let mut rows: Vec<Vec<Vec<Element>>> = Vec::new();
+        let mut cells = Vec::new();
+
+        let end = loop {
+            while self.peek() == Some(Token::Command("hline")) {
+                self.bump();
+            }
+
+            cells.push(self.list()?);
+
+            let Some(tok) = self.bump() else {
+                return Err(LatexError::new(
+                    LatexErrorKind::UnclosedEnvironment(name.to_string()),
+                    begin,
+                ));
+            };
+
+            match tok.token {
+                Token::Align => {}
+                Token::Command("\\") => {
+                    rows.push(std::mem::take(&mut cells));
+
+                    // skip the optional row spacing, e.g. `\\[2pt]`
+                    if self.peek() == Some(Token::Char('[')) {
+                        while self.bump().is_some_and(|tok| tok.token != Token::Char(']')) {}
+                    }
+                }
+                Token::Command("end") => break tok.span,
+                _ => return Err(self.unexpected(&tok)),
+            }
+        };
+
+        rows.push(cells);
+
+        // trailing `\\` does not start a new row
+        if rows.len() > 1
+            && rows
+                .last()
+                .is_some_and(|row| row.len() == 1 && row[0].is_empty())
+        {
+            rows.pop();
+        }
+
+        self.end_environment(name, end)?;
+
+        Ok(env.table(rows))
+    }
+
+    /// Parse the name of the environment closed by `\end`, which must match the opened one.
+    fn end_environment(&mut self, name: &str, end: Range<usize>) -> Result<(), LatexError> {
+        let (end_name, end_span) = self.raw_argument("end", end)?;
+        if end_name.trim() != name {
+            return Err(LatexError::new(
+                LatexErrorKind::MismatchedEnvironment {
+                    expected: name.to_string(),
+                    found: end_name.trim().to_string(),
+                },
+                end_span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Look up the fences and layout of the environment with the given name.
+    fn environment_kind(
+        &mut self,
+        name: &str,
+        name_span: Range<usize>,
+        begin: Range<usize>,
+    ) -> Result<Environment, LatexError> {
+        let env = match name {
+            "matrix" | "smallmatrix" => Environment::new(None, None),
+            "pmatrix" => Environment::new(Some(Operator::lparens()), Some(Operator::rparens())),
+            "bmatrix" => Environment::new(Some(Operator::lbracket()), Some(Operator::rbracket())),
+            "Bmatrix" => Environment::new(Some(Operator::lbrace()), Some(Operator::rbrace())),
+            "vmatrix" => Environment::new(Some(Operator::vert_bar()), Some(Operator::vert_bar())),
+            "Vmatrix" => Environment::new(Some(Operator::norm()), Some(Operator::norm())),
+            "cases" => {
+                Environment::new(Some(Operator::lbrace()), None).with_align(vec![ColumnAlign::Left])
+            }
+            "rcases" => {
+                Environment::new(None, Some(Operator::rbrace())).with_align(vec![ColumnAlign::Left])
+            }
+            "aligned" | "align" | "align*" | "split" => Environment::new(None, None)
+                .with_align(vec![ColumnAlign::Right, ColumnAlign::Left])
+                .with_display(),
+            "gathered" | "gather" | "gather*" | "equation" | "equation*" => {
+                Environment::new(None, None).with_display()
+            }
+            "array" => {
+                let (spec, spec_span) = self.raw_argument("array", begin.clone())?;
+                let (align, lines) = column_spec(spec, spec_span.start)?;
+
+                Environment {
+                    align,
+                    lines,
+                    ..Environment::new(None, None)
+                }
+            }
+            _ => {
+                return Err(LatexError::new(
+                    LatexErrorKind::UnknownEnvironment(name.to_string()),
+                    name_span,
+                ))
+            }
+        };
+
+        Ok(env)
+    }
+}
+
+/// Attach the scripts to the atom, either as limits or after it.
+fn scripted(atom: Atom, sub: Option<Vec<Element>>, sup: Option<Vec<Element>>) -> Element {
+    let base = [atom.element];
+
+    match (sub, sup) {
+        (None, None) => {
+            let [base] = base;
+            base
+        }
+        (Some(sub), None) if atom.limits => {
+            UnderOver::builder().expr(base).under(sub).build().into()
+        }
+        (None, Some(sup)) if atom.limits => {
+            UnderOver::builder().expr(base).over(sup).build().into()
+        }
+        (Some(sub), Some(sup)) if atom.limits => UnderOver::builder()
+            .expr(base)
+            .under(sub)
+            .over(sup)
+            .build()
+            .into(),
+        (Some(sub), None) => SubSup::builder().base(base).subscript(sub).build().into(),
+        (None, Some(sup)) => SubSup::builder().base(base).supscript(sup).build().into(),
+        (Some(sub), Some(sup)) => SubSup::builder()
+            .base(base)
+            .subscript(sub)
+            .supscript(sup)
+            .build()
+            .into(),
+    }
+}
+
+/// Turn the content of a group into a single element.
+fn group_element(mut content: Vec<Element>) -> Element {
+    if content.len() == 1 {
+        content.remove(0)
+    } else {
+        Row::from(Elements(content)).into()
+    }
+}
+
+/// Wrap the element in a `Style` element for commands that set the display style, e.g.
+/// `\dfrac`.
+fn display_style(command: &str, element: impl Into<Element>) -> Element {
+    match command.chars().next() {
+        Some('d' | 'c') => Style::from([element])
+            .with_attr([Attribute::DisplayStyle(true)])
+            .into(),
+        Some('t') => Style::from([element])
+            .with_attr([Attribute::DisplayStyle(false)])
+            .into(),
+        _ => element.into(),
+    }
+}
+
+/// Make the given operator stretchy and add the given attributes.
+fn stretchy<const N: usize>(op: Operator, attr: [OperatorAttr; N]) -> Operator {
+    Operator::builder()
+        .op(op.op())
+        .attr(std::iter::once(OperatorAttr::Stretchy).chain(attr))
+        .build()
+}
+
+/// Create a stretchy fence for the given operator.
+fn fence(op: Operator, form: OpForm) -> Element {
+    stretchy(op, [OperatorAttr::Fence, OperatorAttr::Form(form)]).into()
+}
+
+/// Surround the content with stretchy fences.
+fn fenced(open: Option<Operator>, content: Vec<Element>, close: Option<Operator>) -> Row {
+    let open = open.map(|op| fence(op, OpForm::Prefix));
+    let close = close.map(|op| fence(op, OpForm::Postfix));
+
+    Row::from(
+        open.into_iter()
+            .chain(content)
+            .chain(close)
+            .collect::<Elements>(),
+    )
+}
+
+fn space(width: &str) -> Space {
+    Space::default().with_attr([SpaceAttr::Width(width.into())])
+}
+
+/// Parse the column specification of an `array` environment, e.g. `cc|c`.
+fn column_spec(
+    spec: &str,
+    offset: usize,
+) -> Result<(Vec<ColumnAlign>, Vec<ColumnLine>), LatexError> {
+    let mut align = Vec::new();
+    let mut lines = Vec::new();
+    let mut line = ColumnLine::None;
+
+    for (idx, c) in spec.char_indices() {
+        let column = match c {
+            'l' => ColumnAlign::Left,
+            'c' => ColumnAlign::Center,
+            'r' => ColumnAlign::Right,
+            '|' => {
+                line = ColumnLine::Solid;
+                continue;
+            }
+            ':' => {
+                line = ColumnLine::Dashed;
+                continue;
+            }
+            c if c.is_whitespace() => continue,
+            c => {
+                let start = offset + idx;
+                return Err(LatexError::new(
+                    LatexErrorKind::InvalidColumnSpec(c),
+                    start..start + c.len_utf8(),
+                ));
+            }
+        };
+
+        // lines before the first column are not supported by MathML
+        let before = std::mem::replace(&mut line, ColumnLine::None);
+        if !align.is_empty() {
+            lines.push(before);
+        }
+
+        align.push(column);
+    }
+
+    if align.is_empty() {
+        align.push(ColumnAlign::Center);
+    }
+
+    Ok((align, lines))
+}
+
+/// Resolve escaped characters and the commands `\textbackslash`, `\textasciitilde` and
+/// `\textasciicircum` in the text argument of commands such as `\text`.
+fn unescape_text(raw: &str) -> String {
+    let mut text = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek().is_some_and(|n| n.is_ascii_alphabetic()) => {
+                let mut name = String::new();
+                while let Some(n) = chars.next_if(|n| n.is_ascii_alphabetic()) {
+                    name.push(n);
+                }
+
+                match name.as_str() {
+                    "textbackslash" => text.push('\\'),
+                    "textasciitilde" => text.push('~'),
+                    "textasciicircum" => text.push('^'),
+                    _ => {
+                        text.push('\\');
+                        text.push_str(&name);
+                        continue;
+                    }
+                }
+
+                // spaces after a command only end its name
+                while chars.next_if(|n| n.is_whitespace()).is_some() {}
+            }
+            '\\' => match chars.next_if(|n| "{}%&_#$ ".contains(*n)) {
+                Some(escaped) => text.push(escaped),
+                None => text.push(c),
+            },
+            '~' => text.push('\u{00A0}'),
+            '{' | '}' => {}
+            c if c.is_whitespace() => {
+                while chars.next_if(|n| n.is_whitespace()).is_some() {}
+                text.push(' ');
+            }
+            c => text.push(c),
+        }
+    }
+
+    text
+}
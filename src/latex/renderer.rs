@@ -0,0 +1,940 @@
+//! Conversion of MathML elements back into LaTeX markup.
+
+use std::convert::Infallible;
+
+use crate::{
+    attributes::{Attribute, Length},
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, AnnotationAttr, AnnotationContent, ColumnLine, Frac, FracAttr, Ident, Num,
+        OpForm, Operator, OperatorAttr, Padded, Semantics, Space, SpaceAttr, StrLiteral, Table,
+        TableAttr, TableCellAttr, Text,
+    },
+    Element, MathMl, MathMlAttr, Renderer,
+};
+
+use super::symbols;
+
+/// [`Renderer`] implementation that produces LaTeX math-mode markup.
+///
+/// The output does not contain the math-mode delimiters, i.e. `$...$` or `\[...\]`, so it can be
+/// passed to [`parse`](super::parse) again. Elements are mapped to the commands understood by
+/// the parser: fractions to `\frac`, roots to `\sqrt`, tables to matrix environments, math
+/// variants to font commands such as `\mathbb` and operators to their command names, e.g. `≤` to
+/// `\leq`. Characters without a command are written as they are.
+///
+/// # Example
+///
+/// ```
+/// use alemat::latex::{self, LatexFormatter};
+///
+/// let math = latex::parse(r"\sum_{i=1}^{n} \alpha_i \leq \sqrt[3]{x}").unwrap();
+/// let output = math.render_with(&mut LatexFormatter).unwrap();
+///
+/// assert_eq!(output, r"\sum_{i=1}^n\alpha_i\leq\sqrt[3]{x}");
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LatexFormatter;
+
+/// The alignment of a table column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl LatexFormatter {
+    fn render_elements(&mut self, elements: &[Element]) -> String {
+        let mut out = String::new();
+
+        for element in elements {
+            let Ok(rendered) = self.render_element(element);
+            push(&mut out, &rendered);
+        }
+
+        out
+    }
+
+    /// Render the elements as the argument of a command, e.g. `{a+b}`.
+    fn argument(&mut self, elements: &[Element]) -> String {
+        format!("{{{}}}", self.render_elements(elements))
+    }
+
+    /// Render the elements as a script, braces are omitted for single characters, e.g. `x^2`.
+    fn script(&mut self, elements: &[Element]) -> String {
+        let script = self.render_elements(elements);
+
+        if script.chars().count() == 1 && script != "\\" {
+            script
+        } else {
+            format!("{{{script}}}")
+        }
+    }
+
+    /// Render the elements as the base of scripts, braces are added unless the base is a single
+    /// atom.
+    fn base(&mut self, elements: &[Element]) -> String {
+        let base = self.render_elements(elements);
+
+        match elements {
+            [element] if is_atom(element) && !base.is_empty() => base,
+            _ => format!("{{{base}}}"),
+        }
+    }
+
+    /// Apply the math variant in the attributes with a font command, e.g. `\mathbf{x}`.
+    fn font(&mut self, attributes: &[Attribute], content: String) -> String {
+        let command = attributes.iter().find_map(|attr| match attr {
+            Attribute::MathVariant(variant) => symbols::font_command(variant),
+            _ => None,
+        });
+
+        match command {
+            Some(command) => format!("\\{command}{{{content}}}"),
+            None => content,
+        }
+    }
+
+    /// Apply the switches for the attributes, e.g. `{\color{red}x}`.
+    fn styled<'a>(
+        &mut self,
+        attributes: impl IntoIterator<Item = &'a Attribute>,
+        content: String,
+    ) -> String {
+        let mut out = String::new();
+
+        for attr in attributes {
+            let Ok(switch) = self.render_attr(attr);
+            push(&mut out, &switch);
+        }
+
+        if out.is_empty() {
+            return content;
+        }
+
+        push(&mut out, &content);
+        format!("{{{out}}}")
+    }
+
+    /// Render a row surrounded by stretchy fences with `\left` and `\right`, or as an environment
+    /// if it only contains a table.
+    fn fenced(
+        &mut self,
+        open: Option<&Operator>,
+        content: &[Element],
+        close: Option<&Operator>,
+    ) -> Option<String> {
+        let open_op = open.map(Operator::op);
+        let close_op = close.map(Operator::op);
+
+        if let [Element::Table(table)] = content {
+            let align = column_align(table);
+            let plain = !has_lines(table) && !is_display(table);
+            let left = align.iter().all(|align| *align == ColumnAlign::Left);
+
+            let env = if plain {
+                matrix_environment(open_op, close_op, left)
+            } else {
+                None
+            };
+
+            if let Some(env) = env {
+                let body = self.table_body(table);
+                return Some(format!("\\begin{{{env}}}{body}\\end{{{env}}}"));
+            }
+        }
+
+        if let ([Element::Frac(frac)], Some("("), Some(")")) = (content, open_op, close_op) {
+            if is_zero_thickness(frac) {
+                let top = self.argument(frac.num());
+                let bottom = self.argument(frac.denom());
+                return Some(format!("\\binom{top}{bottom}"));
+            }
+        }
+
+        let open = match open {
+            Some(op) => symbols::delimiter_command(op.op())?,
+            None => String::from("."),
+        };
+
+        let close = match close {
+            Some(op) => symbols::delimiter_command(op.op())?,
+            None => String::from("."),
+        };
+
+        let mut out = format!("\\left{open}");
+
+        for element in content {
+            let rendered = match element {
+                Element::Operator(op) if is_middle(op) => {
+                    match symbols::delimiter_command(op.op()) {
+                        Some(delimiter) => format!("\\middle{delimiter}"),
+                        None => operator_str(op.op()),
+                    }
+                }
+                element => {
+                    let Ok(rendered) = self.render_element(element);
+                    rendered
+                }
+            };
+
+            push(&mut out, &rendered);
+        }
+
+        push(&mut out, &format!("\\right{close}"));
+
+        Some(out)
+    }
+
+    fn table_body(&mut self, table: &Table) -> String {
+        table
+            .rows()
+            .iter()
+            .map(|row| {
+                row.cells()
+                    .iter()
+                    .map(|cell| self.render_elements(cell.children()))
+                    .collect::<Vec<_>>()
+                    .join(" & ")
+            })
+            .collect::<Vec<_>>()
+            .join(" \\\\ ")
+    }
+}
+
+impl Renderer for LatexFormatter {
+    type Output = String;
+    type Error = Infallible;
+
+    fn render_action(&mut self, action: &Action) -> Result<Self::Output, Self::Error> {
+        let selection = action.attributes().iter().find_map(|attr| match attr {
+            ActionAttr::Selection(selection) => selection.trim().parse::<usize>().ok(),
+            _ => None,
+        });
+
+        let content = action.content();
+        let selected = selection
+            .and_then(|idx| content.get(idx.checked_sub(1)?))
+            .or_else(|| content.first());
+
+        match selected {
+            Some(element) => self.render_element(element),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_annotation(&mut self, _annotation: &Annotation) -> Result<Self::Output, Self::Error> {
+        // annotations are alternative representations and are not rendered
+        Ok(String::new())
+    }
+
+    fn render_error(&mut self, error: &Error) -> Result<Self::Output, Self::Error> {
+        let content = self.render_elements(error.content());
+        Ok(self.styled(error.attributes(), content))
+    }
+
+    fn render_frac(&mut self, frac: &Frac) -> Result<Self::Output, Self::Error> {
+        let num = self.argument(frac.num());
+        let denom = self.argument(frac.denom());
+
+        let content = if is_zero_thickness(frac) {
+            format!("\\genfrac{{}}{{}}{{0pt}}{{}}{num}{denom}")
+        } else {
+            format!("\\frac{num}{denom}")
+        };
+
+        let global = frac.attributes().iter().filter_map(|attr| match attr {
+            FracAttr::Global(attr) => Some(attr),
+            _ => None,
+        });
+
+        Ok(self.styled(global, content))
+    }
+
+    fn render_ident(&mut self, ident: &Ident) -> Result<Self::Output, Self::Error> {
+        let name = ident.ident();
+
+        let content = if let Some(command) = symbols::ident_command(name) {
+            format!("\\{command}")
+        } else if name.chars().count() <= 1 {
+            escape(name)
+        } else if let Some((command, _)) = symbols::function_command(name) {
+            format!("\\{command}")
+        } else {
+            format!("\\operatorname{{{}}}", escape_text(name))
+        };
+
+        let content = self.font(ident.attributes(), content);
+        Ok(self.styled(ident.attributes(), content))
+    }
+
+    fn render_multiscripts(
+        &mut self,
+        multiscripts: &Multiscripts,
+    ) -> Result<Self::Output, Self::Error> {
+        let content = multiscripts.content();
+
+        let Some((base, scripts)) = content.split_first() else {
+            return Ok(String::new());
+        };
+
+        let (post, pre) = match scripts
+            .iter()
+            .position(|element| matches!(element, Element::Prescripts(_)))
+        {
+            Some(idx) => (&scripts[..idx], &scripts[idx + 1..]),
+            None => (scripts, &[][..]),
+        };
+
+        let mut out = String::new();
+
+        for pair in pre.chunks(2) {
+            out.push_str("{}");
+            out.push_str(&self.script_pair(pair));
+        }
+
+        push(&mut out, &self.base(std::slice::from_ref(base)));
+
+        for (idx, pair) in post.chunks(2).enumerate() {
+            if idx > 0 {
+                out.push_str("{}");
+            }
+
+            out.push_str(&self.script_pair(pair));
+        }
+
+        Ok(self.styled(multiscripts.attributes(), out))
+    }
+
+    fn render_prescripts(&mut self, _prescripts: &Prescripts) -> Result<Self::Output, Self::Error> {
+        // prescripts are handled by the surrounding multiscripts element
+        Ok(String::new())
+    }
+
+    fn render_num(&mut self, num: &Num) -> Result<Self::Output, Self::Error> {
+        let content = escape(num.num());
+        let content = self.font(num.attributes(), content);
+        Ok(self.styled(num.attributes(), content))
+    }
+
+    fn render_operator(&mut self, operator: &Operator) -> Result<Self::Output, Self::Error> {
+        let op = operator.op();
+
+        let size = operator.attributes().iter().find_map(|attr| match attr {
+            OperatorAttr::MaxSize(size) => symbols::big_delimiter_command(size),
+            _ => None,
+        });
+
+        let content = match (size, symbols::delimiter_command(op)) {
+            (Some(size), Some(delimiter)) => format!("\\{size}{delimiter}"),
+            _ => operator_str(op),
+        };
+
+        let global = operator.attributes().iter().filter_map(|attr| match attr {
+            OperatorAttr::Global(attr) => Some(attr),
+            _ => None,
+        });
+
+        Ok(self.styled(global, content))
+    }
+
+    fn render_padded(&mut self, padded: &Padded) -> Result<Self::Output, Self::Error> {
+        Ok(self.render_elements(padded.children()))
+    }
+
+    fn render_phantom(&mut self, phantom: &Phantom) -> Result<Self::Output, Self::Error> {
+        let content = self.argument(phantom.children());
+        Ok(format!("\\phantom{content}"))
+    }
+
+    fn render_radical(&mut self, radical: &Radical) -> Result<Self::Output, Self::Error> {
+        let content = self.argument(radical.content());
+
+        let out = if radical.is_square() || radical.index().is_empty() {
+            format!("\\sqrt{content}")
+        } else {
+            let index = self.render_elements(radical.index());
+            format!("\\sqrt[{index}]{content}")
+        };
+
+        Ok(self.styled(radical.attributes(), out))
+    }
+
+    fn render_row(&mut self, row: &Row) -> Result<Self::Output, Self::Error> {
+        let children = row.children();
+
+        let (open, rest) = match children.split_first() {
+            Some((Element::Operator(op), rest)) if is_fence(op, OpForm::Prefix) => (Some(op), rest),
+            _ => (None, children),
+        };
+
+        let (close, content) = match rest.split_last() {
+            Some((Element::Operator(op), rest)) if is_fence(op, OpForm::Postfix) => {
+                (Some(op), rest)
+            }
+            _ => (None, rest),
+        };
+
+        let fenced = if open.is_some() || close.is_some() {
+            self.fenced(open, content, close)
+        } else {
+            None
+        };
+
+        let content = match fenced {
+            Some(fenced) => fenced,
+            None => self.render_elements(children),
+        };
+
+        Ok(self.styled(row.attributes(), content))
+    }
+
+    fn render_semantics(&mut self, semantics: &Semantics) -> Result<Self::Output, Self::Error> {
+        let children = semantics.children();
+
+        let tex = children.iter().find_map(|child| match child {
+            Element::Annotation(annotation) if is_tex(annotation) => match annotation.content() {
+                AnnotationContent::Text(tex) => Some(tex.clone()),
+                AnnotationContent::Nested(_) | AnnotationContent::Content(_) => None,
+            },
+            _ => None,
+        });
+
+        match (tex, children.first()) {
+            (Some(tex), _) => Ok(tex),
+            (None, Some(presentation)) => self.render_element(presentation),
+            (None, None) => Ok(String::new()),
+        }
+    }
+
+    fn render_space(&mut self, space: &Space) -> Result<Self::Output, Self::Error> {
+        let width = space.attributes().iter().find_map(|attr| match attr {
+            SpaceAttr::Width(width) => Some(width),
+            _ => None,
+        });
+
+        let out = match width {
+            Some(width) => match symbols::space_command(width) {
+                Some(command) => format!("\\{command}"),
+                None => format!("\\hspace{{{width}}}"),
+            },
+            None => String::new(),
+        };
+
+        Ok(out)
+    }
+
+    fn render_str_literal(
+        &mut self,
+        str_literal: &StrLiteral,
+    ) -> Result<Self::Output, Self::Error> {
+        let content = escape_text(str_literal.content());
+        Ok(format!("\\text{{\"{content}\"}}"))
+    }
+
+    fn render_style(&mut self, style: &Style) -> Result<Self::Output, Self::Error> {
+        let attributes = style.attributes();
+
+        // `\dfrac` and `\tfrac` are fractions wrapped in a style setting the display style
+        if let ([Element::Frac(frac)], [Attribute::DisplayStyle(display)]) =
+            (style.children(), attributes)
+        {
+            if !is_zero_thickness(frac) && frac.attributes().is_empty() {
+                let command = if *display { "dfrac" } else { "tfrac" };
+                let num = self.argument(frac.num());
+                let denom = self.argument(frac.denom());
+
+                return Ok(format!("\\{command}{num}{denom}"));
+            }
+        }
+
+        let content = self.render_elements(style.children());
+        let content = self.font(attributes, content);
+
+        Ok(self.styled(attributes, content))
+    }
+
+    fn render_subsup(&mut self, sub_sup: &SubSup) -> Result<Self::Output, Self::Error> {
+        let mut out = self.base(sub_sup.base());
+
+        let mut sup = sub_sup.sup();
+
+        // a superscript following primes is grouped with them, e.g. `f'^2`
+        if let Some([Element::Row(row)]) = sup {
+            if matches!(row.children().first(), Some(Element::Operator(op)) if is_primes(op)) {
+                sup = Some(row.children());
+            }
+        }
+
+        // primes are written after the base, e.g. `f'`
+        if let Some([Element::Operator(op), rest @ ..]) = sup {
+            if is_primes(op) {
+                out.extend(op.op().chars().map(|_| '\''));
+                sup = (!rest.is_empty()).then_some(rest);
+            }
+        }
+
+        if let Some(sub) = sub_sup.sub() {
+            out.push('_');
+            out.push_str(&self.script(sub));
+        }
+
+        if let Some(sup) = sup {
+            out.push('^');
+            out.push_str(&self.script(sup));
+        }
+
+        Ok(self.styled(sub_sup.attributes(), out))
+    }
+
+    fn render_table(&mut self, table: &Table) -> Result<Self::Output, Self::Error> {
+        let align = column_align(table);
+        let body = self.table_body(table);
+
+        let alternating = align.iter().enumerate().all(|(idx, align)| {
+            *align
+                == if idx % 2 == 0 {
+                    ColumnAlign::Right
+                } else {
+                    ColumnAlign::Left
+                }
+        });
+
+        let env = if has_lines(table) || align.iter().any(|align| *align != ColumnAlign::Center) {
+            if is_display(table) && !has_lines(table) && alternating {
+                String::from("aligned")
+            } else {
+                let lines = table
+                    .attributes()
+                    .iter()
+                    .find_map(|attr| match attr {
+                        TableAttr::ColumnLines(lines) => Some(lines.as_slice()),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+
+                let mut spec = String::new();
+
+                for (idx, align) in align.iter().enumerate() {
+                    if idx > 0 {
+                        match lines.get(idx - 1) {
+                            Some(ColumnLine::Solid) => spec.push('|'),
+                            Some(ColumnLine::Dashed) => spec.push(':'),
+                            Some(ColumnLine::None) | None => {}
+                        }
+                    }
+
+                    spec.push(match align {
+                        ColumnAlign::Left => 'l',
+                        ColumnAlign::Center => 'c',
+                        ColumnAlign::Right => 'r',
+                    });
+                }
+
+                return Ok(format!("\\begin{{array}}{{{spec}}}{body}\\end{{array}}"));
+            }
+        } else if is_display(table) {
+            String::from("gathered")
+        } else {
+            String::from("matrix")
+        };
+
+        Ok(format!("\\begin{{{env}}}{body}\\end{{{env}}}"))
+    }
+
+    fn render_text(&mut self, text: &Text) -> Result<Self::Output, Self::Error> {
+        let variant = text.attributes().iter().find_map(|attr| match attr {
+            Attribute::MathVariant(variant) => Some(variant),
+            _ => None,
+        });
+
+        let command = symbols::text_command(variant).unwrap_or("text");
+        let content = format!("\\{command}{{{}}}", escape_text(text.text()));
+
+        Ok(self.styled(text.attributes(), content))
+    }
+
+    fn render_underover(&mut self, under_over: &UnderOver) -> Result<Self::Output, Self::Error> {
+        let attributes = under_over.attributes();
+        let expr = under_over.expr();
+
+        let accent_over = attributes.contains(&UnderOverAttr::AccentOver);
+        let accent_under = attributes.contains(&UnderOverAttr::AccentUnder);
+
+        let accent = match (under_over.under(), under_over.over()) {
+            (None, Some([Element::Operator(op)])) if accent_over => {
+                symbols::accent_command(op.op(), false, is_stretchy(op))
+            }
+            (Some([Element::Operator(op)]), None) if accent_under => {
+                symbols::accent_command(op.op(), true, is_stretchy(op))
+            }
+            _ => None,
+        };
+
+        if let Some(accent) = accent {
+            let content = self.argument(expr);
+            return Ok(format!("\\{accent}{content}"));
+        }
+
+        let limits = match expr {
+            [element] => has_limits(element),
+            _ => None,
+        };
+
+        let out = match limits {
+            Some(explicit) => {
+                let mut out = self.render_elements(expr);
+
+                if explicit {
+                    out.push_str("\\limits");
+                }
+
+                if let Some(under) = under_over.under() {
+                    out.push('_');
+                    out.push_str(&self.script(under));
+                }
+
+                if let Some(over) = under_over.over() {
+                    out.push('^');
+                    out.push_str(&self.script(over));
+                }
+
+                out
+            }
+            None => {
+                let mut out = self.argument(expr);
+
+                if let Some(over) = under_over.over() {
+                    out = format!("\\overset{}{out}", self.argument(over));
+                }
+
+                if let Some(under) = under_over.under() {
+                    out = format!("\\underset{}{out}", self.argument(under));
+                }
+
+                out
+            }
+        };
+
+        let global = attributes.iter().filter_map(|attr| match attr {
+            UnderOverAttr::Global(attr) => Some(attr),
+            _ => None,
+        });
+
+        Ok(self.styled(global, out))
+    }
+
+    fn render_attr(&mut self, attr: &Attribute) -> Result<Self::Output, Self::Error> {
+        let switch = match attr {
+            Attribute::DisplayStyle(true) => String::from("\\displaystyle"),
+            Attribute::DisplayStyle(false) => String::from("\\textstyle"),
+            Attribute::MathColor(color) => format!("\\color{{{color}}}"),
+            _ => String::new(),
+        };
+
+        Ok(switch)
+    }
+
+    fn render_mathml(&mut self, mathml: &MathMl) -> Result<Self::Output, Self::Error> {
+        let content = self.render_elements(mathml.content());
+
+        let global = mathml.attributes().iter().filter_map(|attr| match attr {
+            MathMlAttr::Global(attr) => Some(attr),
+            _ => None,
+        });
+
+        Ok(self.styled(global, content))
+    }
+}
+
+impl LatexFormatter {
+    /// Render a pair of sub- and superscript of a multiscripts element, e.g. `_{i}^{j}`.
+    fn script_pair(&mut self, pair: &[Element]) -> String {
+        let mut out = String::new();
+
+        if let Some(sub) = pair.first().filter(|el| !is_empty(el)) {
+            out.push('_');
+            out.push_str(&self.script(std::slice::from_ref(sub)));
+        }
+
+        if let Some(sup) = pair.get(1).filter(|el| !is_empty(el)) {
+            out.push('^');
+            out.push_str(&self.script(std::slice::from_ref(sup)));
+        }
+
+        out
+    }
+}
+
+/// Append the rendered element, separating a trailing control word from a following letter or
+/// digit, e.g. `\alpha x` instead of `\alphax`.
+fn push(out: &mut String, rendered: &str) {
+    let starts_alphanumeric = rendered
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphanumeric());
+
+    if starts_alphanumeric && ends_with_control_word(out) {
+        out.push(' ');
+    }
+
+    out.push_str(rendered);
+}
+
+fn ends_with_control_word(s: &str) -> bool {
+    let trimmed = s.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    trimmed.len() < s.len() && trimmed.ends_with('\\')
+}
+
+/// Convert an operator into LaTeX, e.g. `≤` into `\leq`.
+fn operator_str(op: &str) -> String {
+    match op {
+        // invisible operators such as function application
+        "\u{2061}" | "\u{2062}" | "\u{2063}" | "\u{2064}" => return String::new(),
+        "\u{2032}" => return String::from("'"),
+        _ => {}
+    }
+
+    let mut chars = op.chars();
+
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii() {
+            return escape(op);
+        }
+    }
+
+    if let Some(command) = symbols::operator_command(op) {
+        return format!("\\{command}");
+    }
+
+    if let Some(negated) = op.strip_suffix('\u{0338}') {
+        let mut out = String::from("\\not");
+        push(&mut out, &operator_str(negated));
+        return out;
+    }
+
+    if op.chars().count() == 1 {
+        return String::from(op);
+    }
+
+    let mut out = String::new();
+    for c in op.chars() {
+        push(&mut out, &operator_str(&c.to_string()));
+    }
+
+    out
+}
+
+/// Escape characters with a special meaning in math mode.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '{' | '}' | '%' | '&' | '#' | '$' | '_' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\\' => push(&mut out, "\\backslash"),
+            // math mode has no command for these characters, so they are written as text
+            '~' => push(&mut out, "\\text{\\textasciitilde{}}"),
+            '^' => push(&mut out, "\\text{\\textasciicircum{}}"),
+            c => push(&mut out, c.encode_utf8(&mut [0; 4])),
+        }
+    }
+
+    out
+}
+
+/// Escape characters with a special meaning in the argument of text commands.
+fn escape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '{' | '}' | '%' | '&' | '#' | '$' | '_' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\\' => out.push_str("\\textbackslash{}"),
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            '\u{00A0}' => out.push('~'),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// The environment for a table surrounded by the given fences, e.g. `pmatrix` for parentheses.
+fn matrix_environment(
+    open: Option<&str>,
+    close: Option<&str>,
+    left_aligned: bool,
+) -> Option<&'static str> {
+    let fences = [
+        (Operator::lparens(), Operator::rparens(), "pmatrix"),
+        (Operator::lbracket(), Operator::rbracket(), "bmatrix"),
+        (Operator::lbrace(), Operator::rbrace(), "Bmatrix"),
+        (Operator::vert_bar(), Operator::vert_bar(), "vmatrix"),
+        (Operator::norm(), Operator::norm(), "Vmatrix"),
+    ];
+
+    match (open, close) {
+        (Some(open), Some(close)) => fences
+            .iter()
+            .find(|(o, c, _)| o.op() == open && c.op() == close)
+            .map(|(_, _, env)| *env),
+        (Some(open), None) if left_aligned && open == Operator::lbrace().op() => Some("cases"),
+        (None, Some(close)) if left_aligned && close == Operator::rbrace().op() => Some("rcases"),
+        _ => None,
+    }
+}
+
+/// Whether the element can be the base of scripts without surrounding braces.
+fn is_atom(element: &Element) -> bool {
+    match element {
+        Element::Ident(_)
+        | Element::Num(_)
+        | Element::Operator(_)
+        | Element::Text(_)
+        | Element::Frac(_)
+        | Element::Radical(_)
+        | Element::Phantom(_) => true,
+        Element::Row(row) => matches!(
+            (row.children().first(), row.children().last()),
+            (Some(Element::Operator(open)), Some(Element::Operator(close)))
+                if is_fence(open, OpForm::Prefix) && is_fence(close, OpForm::Postfix)
+        ),
+        _ => false,
+    }
+}
+
+/// Whether the element is empty, e.g. an empty script of a multiscripts element.
+fn is_empty(element: &Element) -> bool {
+    matches!(element, Element::Row(row) if row.children().is_empty())
+}
+
+/// Whether the scripts of the element are written as limits with `_` and `^`. The returned
+/// `bool` is `true` if `\limits` has to be added.
+fn has_limits(element: &Element) -> Option<bool> {
+    match element {
+        Element::Operator(op) if symbols::large_operator_command(op.op()).is_some() => Some(false),
+        Element::Operator(op)
+            if op.attributes().contains(&OperatorAttr::LargeOp)
+                || symbols::integral_command(op.op()).is_some() =>
+        {
+            Some(true)
+        }
+        Element::Ident(ident) => {
+            symbols::function_command(ident.ident()).map(|(_, limits)| !limits)
+        }
+        // braces take their label as limits, e.g. `\underbrace{x}_{n}`
+        Element::UnderOver(under_over) => {
+            let accent = match (under_over.under(), under_over.over()) {
+                (None, Some([Element::Operator(op)])) => {
+                    symbols::accent_command(op.op(), false, is_stretchy(op))
+                }
+                (Some([Element::Operator(op)]), None) => {
+                    symbols::accent_command(op.op(), true, is_stretchy(op))
+                }
+                _ => None,
+            };
+
+            matches!(accent, Some("overbrace" | "underbrace")).then_some(false)
+        }
+        _ => None,
+    }
+}
+
+fn is_primes(op: &Operator) -> bool {
+    !op.op().is_empty() && op.op().chars().all(|c| c == '\u{2032}')
+}
+
+fn is_stretchy(op: &Operator) -> bool {
+    op.attributes().contains(&OperatorAttr::Stretchy)
+}
+
+/// Whether the operator is a stretchy fence of the given form, e.g. created by `\left(`.
+fn is_fence(op: &Operator, form: OpForm) -> bool {
+    is_stretchy(op) && op.attributes().contains(&OperatorAttr::Form(form))
+}
+
+/// Whether the operator is a stretchy delimiter inside of fences, e.g. created by `\middle|`.
+fn is_middle(op: &Operator) -> bool {
+    is_stretchy(op)
+        && !op.attributes().iter().any(|attr| {
+            matches!(
+                attr,
+                OperatorAttr::Form(_) | OperatorAttr::MinSize(_) | OperatorAttr::MaxSize(_)
+            )
+        })
+}
+
+fn is_zero_thickness(frac: &Frac) -> bool {
+    frac.attributes().iter().any(|attr| {
+        matches!(
+            attr,
+            FracAttr::LineThickness(lt) if lt.parse::<Length>().is_ok_and(|lt| lt.is_zero())
+        )
+    })
+}
+
+fn is_tex(annotation: &Annotation) -> bool {
+    annotation.attributes().iter().any(|attr| {
+        matches!(
+            attr,
+            AnnotationAttr::Encoding(encoding)
+                if matches!(encoding.as_str(), "application/x-tex" | "TeX" | "LaTeX")
+        )
+    })
+}
+
+fn is_display(table: &Table) -> bool {
+    table
+        .attributes()
+        .contains(&TableAttr::Global(Attribute::DisplayStyle(true)))
+}
+
+fn has_lines(table: &Table) -> bool {
+    table.attributes().iter().any(|attr| match attr {
+        TableAttr::ColumnLines(lines) => lines.iter().any(|line| *line != ColumnLine::None),
+        _ => false,
+    })
+}
+
+/// The alignment of each column of the table, taken from the `text-align` style of its cells.
+fn column_align(table: &Table) -> Vec<ColumnAlign> {
+    let columns = table
+        .rows()
+        .iter()
+        .map(|row| row.cells().len())
+        .max()
+        .unwrap_or_default();
+
+    (0..columns)
+        .map(|idx| {
+            table
+                .rows()
+                .iter()
+                .filter_map(|row| row.cells().get(idx))
+                .find_map(|cell| {
+                    cell.attributes().iter().find_map(|attr| match attr {
+                        TableCellAttr::Global(Attribute::Style(style)) => {
+                            match style.replace(' ', "").as_str() {
+                                "text-align:left" => Some(ColumnAlign::Left),
+                                "text-align:right" => Some(ColumnAlign::Right),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    })
+                })
+                .unwrap_or(ColumnAlign::Center)
+        })
+        .collect()
+}
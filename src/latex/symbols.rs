@@ -0,0 +1,727 @@
+//! Tables mapping LaTeX commands to MathML elements.
+
+use crate::{
+    attributes::MathVariant,
+    elements::{Ident, Operator},
+};
+
+/// Identifiers such as greek letters, e.g. `\alpha`.
+pub(super) fn ident(name: &str) -> Option<Ident> {
+    let ident = match name {
+        "alpha" => Ident::alpha(),
+        "beta" => Ident::beta(),
+        "gamma" => Ident::gamma(),
+        "Gamma" => Ident::big_gamma(),
+        "delta" => Ident::delta(),
+        "Delta" => Ident::big_delta(),
+        "epsilon" => Ident::epsilon(),
+        "varepsilon" => Ident::varepsilon(),
+        "zeta" => Ident::zeta(),
+        "eta" => Ident::eta(),
+        "theta" => Ident::theta(),
+        "vartheta" => Ident::vartheta(),
+        "Theta" => Ident::big_theta(),
+        "iota" => Ident::iota(),
+        "kappa" => Ident::kappa(),
+        "lambda" => Ident::lambda(),
+        "Lambda" => Ident::big_lambda(),
+        "mu" => Ident::mu(),
+        "nu" => Ident::nu(),
+        "xi" => Ident::xi(),
+        "Xi" => Ident::big_xi(),
+        "pi" => Ident::pi(),
+        "Pi" => Ident::big_pi(),
+        "rho" => Ident::rho(),
+        "sigma" => Ident::sigma(),
+        "Sigma" => Ident::big_sigma(),
+        "tau" => Ident::tau(),
+        "upsilon" => Ident::upsilon(),
+        "Upsilon" => Ident::from("\u{03A5}"),
+        "phi" => Ident::phi(),
+        "varphi" => Ident::varphi(),
+        "Phi" => Ident::big_phi(),
+        "chi" => Ident::chi(),
+        "psi" => Ident::psi(),
+        "Psi" => Ident::big_psi(),
+        "omega" => Ident::omega(),
+        "Omega" => Ident::big_omega(),
+        "infty" => Ident::infinity(),
+        "emptyset" | "varnothing" => Ident::empty_set(),
+        "aleph" => Ident::aleph(),
+        "hbar" => Ident::from("\u{210F}"),
+        "ell" => Ident::from("\u{2113}"),
+        "Re" => Ident::from("\u{211C}"),
+        "Im" => Ident::from("\u{2111}"),
+        "wp" => Ident::from("\u{2118}"),
+        "imath" => Ident::from("\u{0131}"),
+        "jmath" => Ident::from("\u{0237}"),
+        _ => return None,
+    };
+
+    Some(ident)
+}
+
+/// Operators, relations and punctuation, e.g. `\times` or `\leq`.
+pub(super) fn operator(name: &str) -> Option<Operator> {
+    let op = match name {
+        "{" | "lbrace" => Operator::lbrace(),
+        "}" | "rbrace" => Operator::rbrace(),
+        "|" | "Vert" | "parallel" => Operator::norm(),
+        "vert" | "mid" => Operator::vert_bar(),
+        "%" => Operator::from("%"),
+        "&" => Operator::from("&"),
+        "#" => Operator::from("#"),
+        "$" => Operator::from("$"),
+        "_" => Operator::from("_"),
+        "pm" => Operator::plus_minus(),
+        "mp" => Operator::from("\u{2213}"),
+        "times" => Operator::mult(),
+        "div" => Operator::div(),
+        "cdot" => Operator::dot(),
+        "ast" => Operator::asterisk(),
+        "star" => Operator::star(),
+        "circ" => Operator::ring(),
+        "bullet" => Operator::from("\u{2219}"),
+        "setminus" => Operator::set_minus(),
+        "cap" => Operator::cap(),
+        "cup" => Operator::cup(),
+        "wedge" | "land" => Operator::wedge(),
+        "vee" | "lor" => Operator::vee(),
+        "oplus" => Operator::circle_plus(),
+        "otimes" => Operator::circle_times(),
+        "odot" => Operator::circle_dot(),
+        "ltimes" => Operator::lfactor(),
+        "rtimes" => Operator::rfactor(),
+        "bowtie" => Operator::bowtie(),
+        "neg" | "lnot" => Operator::not(),
+        "forall" => Operator::forall(),
+        "exists" => Operator::exists(),
+        "nexists" => Operator::from("\u{2204}"),
+        "therefore" => Operator::therefore(),
+        "because" => Operator::because(),
+        "angle" => Operator::angle(),
+        "top" => Operator::top(),
+        "bot" | "perp" => Operator::bottom(),
+        "partial" => Operator::partial_diff(),
+        "nabla" => Operator::nabla(),
+        "coloneqq" => Operator::assign(),
+        "leq" | "le" => Operator::le(),
+        "geq" | "ge" => Operator::ge(),
+        "neq" | "ne" => Operator::not_eq(),
+        "ll" => Operator::from("\u{226A}"),
+        "gg" => Operator::from("\u{226B}"),
+        "equiv" => Operator::equivalent(),
+        "cong" => Operator::congruent(),
+        "approx" => Operator::approx(),
+        "sim" => Operator::from("\u{223C}"),
+        "simeq" => Operator::from("\u{2243}"),
+        "doteq" => Operator::from("\u{2250}"),
+        "propto" => Operator::propto(),
+        "prec" => Operator::prec(),
+        "succ" => Operator::succ(),
+        "preceq" => Operator::preceq(),
+        "succeq" => Operator::succeq(),
+        "in" => Operator::in_set(),
+        "notin" => Operator::not_in_set(),
+        "ni" => Operator::from("\u{220B}"),
+        "subset" => Operator::subset(),
+        "supset" => Operator::supset(),
+        "subseteq" => Operator::subseteq(),
+        "supseteq" => Operator::supseteq(),
+        "vdash" => Operator::vdash(),
+        "models" => Operator::models(),
+        "to" | "rightarrow" => Operator::rarrow(),
+        "gets" | "leftarrow" => Operator::larrow(),
+        "Rightarrow" | "implies" => Operator::implies(),
+        "Leftarrow" | "impliedby" => Operator::log_if(),
+        "Leftrightarrow" | "iff" => Operator::iff(),
+        "leftrightarrow" => Operator::from("\u{2194}"),
+        "longrightarrow" => Operator::from("\u{27F6}"),
+        "longleftarrow" => Operator::from("\u{27F5}"),
+        "Longrightarrow" => Operator::from("\u{27F9}"),
+        "Longleftarrow" => Operator::from("\u{27F8}"),
+        "mapsto" => Operator::from("\u{21A6}"),
+        "hookrightarrow" => Operator::from("\u{21AA}"),
+        "uparrow" => Operator::from("\u{2191}"),
+        "downarrow" => Operator::from("\u{2193}"),
+        "langle" => Operator::langle(),
+        "rangle" => Operator::rangle(),
+        "lfloor" => Operator::lfloor(),
+        "rfloor" => Operator::rfloor(),
+        "lceil" => Operator::lceiling(),
+        "rceil" => Operator::rceiling(),
+        "backslash" => Operator::from("\\"),
+        "ldots" | "dots" => Operator::from("\u{2026}"),
+        "cdots" => Operator::from("\u{22EF}"),
+        "vdots" => Operator::from("\u{22EE}"),
+        "ddots" => Operator::from("\u{22F1}"),
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+/// Large operators whose scripts are placed under and over them, e.g. `\sum`.
+pub(super) fn large_operator(name: &str) -> Option<Operator> {
+    let op = match name {
+        "sum" => Operator::sum(),
+        "prod" => Operator::prod(),
+        "coprod" => Operator::from("\u{2210}"),
+        "bigcup" => Operator::big_cup(),
+        "bigcap" => Operator::big_cap(),
+        "bigwedge" => Operator::big_wedge(),
+        "bigvee" => Operator::big_vee(),
+        "bigoplus" => Operator::from("\u{2A01}"),
+        "bigotimes" => Operator::from("\u{2A02}"),
+        "bigodot" => Operator::from("\u{2A00}"),
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+/// Integrals, whose scripts are placed after them, e.g. `\int`.
+pub(super) fn integral(name: &str) -> Option<Operator> {
+    let op = match name {
+        "int" => Operator::integral(),
+        "oint" => Operator::circle_integral(),
+        "iint" => Operator::from("\u{222C}"),
+        "iiint" => Operator::from("\u{222D}"),
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+/// Named functions such as `\sin`. The returned `bool` is `true` if the scripts of the function
+/// are placed under and over it, e.g. for `\lim`.
+pub(super) fn function(name: &str) -> Option<(&'static str, bool)> {
+    let function = match name {
+        "arccos" => ("arccos", false),
+        "arcsin" => ("arcsin", false),
+        "arctan" => ("arctan", false),
+        "arg" => ("arg", false),
+        "cos" => ("cos", false),
+        "cosh" => ("cosh", false),
+        "cot" => ("cot", false),
+        "coth" => ("coth", false),
+        "csc" => ("csc", false),
+        "deg" => ("deg", false),
+        "dim" => ("dim", false),
+        "exp" => ("exp", false),
+        "hom" => ("hom", false),
+        "ker" => ("ker", false),
+        "lg" => ("lg", false),
+        "ln" => ("ln", false),
+        "log" => ("log", false),
+        "bmod" | "mod" => ("mod", false),
+        "sec" => ("sec", false),
+        "sin" => ("sin", false),
+        "sinh" => ("sinh", false),
+        "tan" => ("tan", false),
+        "tanh" => ("tanh", false),
+        "det" => ("det", true),
+        "gcd" => ("gcd", true),
+        "inf" => ("inf", true),
+        "lim" => ("lim", true),
+        "liminf" => ("lim inf", true),
+        "limsup" => ("lim sup", true),
+        "max" => ("max", true),
+        "min" => ("min", true),
+        "Pr" => ("Pr", true),
+        "sup" => ("sup", true),
+        _ => return None,
+    };
+
+    Some(function)
+}
+
+/// Delimiters that can follow `\left`, `\right` and `\big` commands, e.g. `\langle`.
+pub(super) fn delimiter(name: &str) -> Option<Operator> {
+    let op = match name {
+        "{" | "lbrace" => Operator::lbrace(),
+        "}" | "rbrace" => Operator::rbrace(),
+        "|" | "Vert" | "lVert" | "rVert" => Operator::norm(),
+        "vert" | "lvert" | "rvert" => Operator::vert_bar(),
+        "langle" => Operator::langle(),
+        "rangle" => Operator::rangle(),
+        "lfloor" => Operator::lfloor(),
+        "rfloor" => Operator::rfloor(),
+        "lceil" => Operator::lceiling(),
+        "rceil" => Operator::rceiling(),
+        "uparrow" => Operator::from("\u{2191}"),
+        "downarrow" => Operator::from("\u{2193}"),
+        "backslash" => Operator::from("\\"),
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+/// Characters that can be used as delimiters, e.g. `(`.
+pub(super) fn delimiter_char(c: char) -> Option<Operator> {
+    let op = match c {
+        '(' => Operator::lparens(),
+        ')' => Operator::rparens(),
+        '[' => Operator::lbracket(),
+        ']' => Operator::rbracket(),
+        '|' => Operator::vert_bar(),
+        '/' => Operator::solidus(),
+        '<' => Operator::langle(),
+        '>' => Operator::rangle(),
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+/// Maps a single character to an operator.
+pub(super) fn char_operator(c: char) -> Operator {
+    match c {
+        '+' => Operator::plus(),
+        '-' => Operator::minus(),
+        '*' => Operator::asterisk(),
+        '/' => Operator::solidus(),
+        '=' => Operator::eq(),
+        '<' => Operator::lt(),
+        '>' => Operator::gt(),
+        '(' => Operator::lparens(),
+        ')' => Operator::rparens(),
+        '[' => Operator::lbracket(),
+        ']' => Operator::rbracket(),
+        '|' => Operator::vert_bar(),
+        '\'' => Operator::from("\u{2032}"),
+        c => Operator::from(c.to_string()),
+    }
+}
+
+/// Size of the delimiter for commands such as `\big` or `\Biggl`.
+pub(super) fn big_delimiter_size(name: &str) -> Option<&'static str> {
+    let size = match name {
+        "big" | "bigl" | "bigr" | "bigm" => "1.2em",
+        "Big" | "Bigl" | "Bigr" | "Bigm" => "1.623em",
+        "bigg" | "biggl" | "biggr" | "biggm" => "2.047em",
+        "Bigg" | "Biggl" | "Biggr" | "Biggm" => "2.470em",
+        _ => return None,
+    };
+
+    Some(size)
+}
+
+/// Accent placed over or under the argument of a command, e.g. `\hat{x}`.
+pub(super) struct Accent {
+    /// The operator used as accent.
+    pub op: Operator,
+
+    /// Whether the accent is placed under the argument.
+    pub under: bool,
+
+    /// Whether the accent stretches over the whole argument.
+    pub stretchy: bool,
+}
+
+/// Accents such as `\hat` or `\underbrace`.
+pub(super) fn accent(name: &str) -> Option<Accent> {
+    let (op, under, stretchy) = match name {
+        "hat" => (Operator::hat(), false, false),
+        "widehat" => (Operator::hat(), false, true),
+        "bar" => (Operator::bar(), false, false),
+        "overline" => (Operator::bar(), false, true),
+        "underline" => (Operator::bar(), true, true),
+        "tilde" => (Operator::tilde(), false, false),
+        "widetilde" => (Operator::tilde(), false, true),
+        "vec" => (Operator::rarrow(), false, false),
+        "overrightarrow" => (Operator::rarrow(), false, true),
+        "overleftarrow" => (Operator::larrow(), false, true),
+        "dot" => (Operator::from("\u{02D9}"), false, false),
+        "ddot" => (Operator::double_dot(), false, false),
+        "check" => (Operator::from("\u{02C7}"), false, false),
+        "breve" => (Operator::from("\u{02D8}"), false, false),
+        "acute" => (Operator::from("\u{00B4}"), false, false),
+        "grave" => (Operator::from("\u{0060}"), false, false),
+        "mathring" => (Operator::from("\u{02DA}"), false, false),
+        "overbrace" => (Operator::obrace(), false, true),
+        "underbrace" => (Operator::ubrace(), true, true),
+        "overparen" => (Operator::oparens(), false, true),
+        "underparen" => (Operator::uparens(), true, true),
+        "overbracket" => (Operator::obracket(), false, true),
+        "underbracket" => (Operator::ubracket(), true, true),
+        _ => return None,
+    };
+
+    Some(Accent {
+        op,
+        under,
+        stretchy,
+    })
+}
+
+/// Font commands such as `\mathbf`.
+pub(super) fn font(name: &str) -> Option<MathVariant> {
+    let variant = match name {
+        "mathrm" | "mathup" => MathVariant::Normal,
+        "mathit" => MathVariant::Italic,
+        "mathbf" => MathVariant::Bold,
+        "boldsymbol" | "bm" | "mathbfit" => MathVariant::BoldItalic,
+        "mathbb" => MathVariant::DoubleStruck,
+        "mathcal" | "mathscr" => MathVariant::Script,
+        "mathfrak" => MathVariant::Fraktur,
+        "mathsf" => MathVariant::SansSerif,
+        "mathtt" => MathVariant::Monospace,
+        _ => return None,
+    };
+
+    Some(variant)
+}
+
+/// Text commands such as `\textbf`, with the math variant they apply to the text.
+pub(super) fn text(name: &str) -> Option<Option<MathVariant>> {
+    let variant = match name {
+        "text" | "textrm" | "textnormal" | "mbox" => None,
+        "textbf" => Some(MathVariant::Bold),
+        "textit" => Some(MathVariant::Italic),
+        "textsf" => Some(MathVariant::SansSerif),
+        "texttt" => Some(MathVariant::Monospace),
+        _ => return None,
+    };
+
+    Some(variant)
+}
+
+/// Spacing commands such as `\quad`, with the width of the space.
+pub(super) fn space(name: &str) -> Option<&'static str> {
+    let width = match name {
+        "," | "thinspace" => "0.1667em",
+        ":" | ">" | "medspace" => "0.2222em",
+        ";" | "thickspace" => "0.2778em",
+        " " => "0.25em",
+        "!" | "negthinspace" => "-0.1667em",
+        "quad" => "1em",
+        "qquad" => "2em",
+        _ => return None,
+    };
+
+    Some(width)
+}
+
+/// Preferred names of the commands in [`ident`], used to convert identifiers back to LaTeX.
+const IDENTS: &[&str] = &[
+    "alpha",
+    "beta",
+    "gamma",
+    "Gamma",
+    "delta",
+    "Delta",
+    "epsilon",
+    "varepsilon",
+    "zeta",
+    "eta",
+    "theta",
+    "vartheta",
+    "Theta",
+    "iota",
+    "kappa",
+    "lambda",
+    "Lambda",
+    "mu",
+    "nu",
+    "xi",
+    "Xi",
+    "pi",
+    "Pi",
+    "rho",
+    "sigma",
+    "Sigma",
+    "tau",
+    "upsilon",
+    "Upsilon",
+    "phi",
+    "varphi",
+    "Phi",
+    "chi",
+    "psi",
+    "Psi",
+    "omega",
+    "Omega",
+    "infty",
+    "emptyset",
+    "aleph",
+    "hbar",
+    "ell",
+    "Re",
+    "Im",
+    "wp",
+    "imath",
+    "jmath",
+];
+
+/// Preferred names of the commands in [`operator`], [`large_operator`] and [`integral`].
+const OPERATORS: &[&str] = &[
+    "{",
+    "}",
+    "|",
+    "%",
+    "&",
+    "#",
+    "$",
+    "_",
+    "pm",
+    "mp",
+    "times",
+    "div",
+    "cdot",
+    "ast",
+    "star",
+    "circ",
+    "bullet",
+    "setminus",
+    "cap",
+    "cup",
+    "wedge",
+    "vee",
+    "oplus",
+    "otimes",
+    "odot",
+    "ltimes",
+    "rtimes",
+    "bowtie",
+    "neg",
+    "forall",
+    "exists",
+    "nexists",
+    "therefore",
+    "because",
+    "angle",
+    "top",
+    "bot",
+    "partial",
+    "nabla",
+    "coloneqq",
+    "leq",
+    "geq",
+    "neq",
+    "ll",
+    "gg",
+    "equiv",
+    "cong",
+    "approx",
+    "sim",
+    "simeq",
+    "doteq",
+    "propto",
+    "prec",
+    "succ",
+    "preceq",
+    "succeq",
+    "in",
+    "notin",
+    "ni",
+    "subset",
+    "supset",
+    "subseteq",
+    "supseteq",
+    "vdash",
+    "models",
+    "to",
+    "gets",
+    "Rightarrow",
+    "Leftarrow",
+    "Leftrightarrow",
+    "leftrightarrow",
+    "longrightarrow",
+    "longleftarrow",
+    "Longrightarrow",
+    "Longleftarrow",
+    "mapsto",
+    "hookrightarrow",
+    "uparrow",
+    "downarrow",
+    "langle",
+    "rangle",
+    "lfloor",
+    "rfloor",
+    "lceil",
+    "rceil",
+    "backslash",
+    "ldots",
+    "cdots",
+    "vdots",
+    "ddots",
+];
+
+/// Names of the commands in [`large_operator`].
+const LARGE_OPERATORS: &[&str] = &[
+    "sum",
+    "prod",
+    "coprod",
+    "bigcup",
+    "bigcap",
+    "bigwedge",
+    "bigvee",
+    "bigoplus",
+    "bigotimes",
+    "bigodot",
+];
+
+/// Names of the commands in [`integral`].
+const INTEGRALS: &[&str] = &["int", "oint", "iint", "iiint"];
+
+/// Preferred names of the commands in [`function`].
+const FUNCTIONS: &[&str] = &[
+    "arccos", "arcsin", "arctan", "arg", "cos", "cosh", "cot", "coth", "csc", "deg", "dim", "exp",
+    "hom", "ker", "lg", "ln", "log", "bmod", "sec", "sin", "sinh", "tan", "tanh", "det", "gcd",
+    "inf", "lim", "liminf", "limsup", "max", "min", "Pr", "sup",
+];
+
+/// Preferred names of the commands in [`delimiter`].
+const DELIMITERS: &[&str] = &[
+    "{",
+    "}",
+    "|",
+    "langle",
+    "rangle",
+    "lfloor",
+    "rfloor",
+    "lceil",
+    "rceil",
+    "uparrow",
+    "downarrow",
+    "backslash",
+];
+
+/// Names of the commands in [`accent`].
+const ACCENTS: &[&str] = &[
+    "hat",
+    "widehat",
+    "bar",
+    "overline",
+    "underline",
+    "tilde",
+    "widetilde",
+    "vec",
+    "overrightarrow",
+    "overleftarrow",
+    "dot",
+    "ddot",
+    "check",
+    "breve",
+    "acute",
+    "grave",
+    "mathring",
+    "overbrace",
+    "underbrace",
+    "overparen",
+    "underparen",
+    "overbracket",
+    "underbracket",
+];
+
+/// Preferred names of the commands in [`font`].
+const FONTS: &[&str] = &[
+    "mathrm",
+    "mathit",
+    "mathbf",
+    "boldsymbol",
+    "mathbb",
+    "mathcal",
+    "mathfrak",
+    "mathsf",
+    "mathtt",
+];
+
+/// Preferred names of the commands in [`text`].
+const TEXTS: &[&str] = &["text", "textbf", "textit", "textsf", "texttt"];
+
+/// Preferred names of the commands in [`space`].
+const SPACES: &[&str] = &[",", ":", ";", " ", "!", "quad", "qquad"];
+
+/// Preferred names of the commands in [`big_delimiter_size`].
+const BIG_DELIMITERS: &[&str] = &["big", "Big", "bigg", "Bigg"];
+
+/// The command producing the given identifier, e.g. `alpha` for `α`.
+pub(super) fn ident_command(s: &str) -> Option<&'static str> {
+    find(IDENTS, |name| ident(name).is_some_and(|i| i.ident() == s))
+}
+
+/// The command producing the given operator, e.g. `leq` for `≤`.
+pub(super) fn operator_command(s: &str) -> Option<&'static str> {
+    find(OPERATORS, |name| {
+        operator(name).is_some_and(|op| op.op() == s)
+    })
+    .or_else(|| large_operator_command(s))
+    .or_else(|| integral_command(s))
+}
+
+/// The command producing the given large operator, e.g. `sum` for `∑`.
+pub(super) fn large_operator_command(s: &str) -> Option<&'static str> {
+    find(LARGE_OPERATORS, |name| {
+        large_operator(name).is_some_and(|op| op.op() == s)
+    })
+}
+
+/// The command producing the given integral, e.g. `oint` for `∮`.
+pub(super) fn integral_command(s: &str) -> Option<&'static str> {
+    find(INTEGRALS, |name| {
+        integral(name).is_some_and(|op| op.op() == s)
+    })
+}
+
+/// The command producing the given function name, e.g. `liminf` for `lim inf`. The returned
+/// `bool` is `true` if the scripts of the function are placed under and over it.
+pub(super) fn function_command(s: &str) -> Option<(&'static str, bool)> {
+    FUNCTIONS.iter().find_map(|name| match function(name) {
+        Some((function, limits)) if function == s => Some((*name, limits)),
+        _ => None,
+    })
+}
+
+/// The delimiter following `\left` or `\right` for the given operator, e.g. `(` or `\langle`.
+pub(super) fn delimiter_command(s: &str) -> Option<String> {
+    let mut chars = s.chars();
+
+    if let (Some(c @ ('(' | ')' | '[' | ']' | '|' | '/')), None) = (chars.next(), chars.next()) {
+        return Some(c.to_string());
+    }
+
+    find(DELIMITERS, |name| {
+        delimiter(name).is_some_and(|op| op.op() == s)
+    })
+    .map(|name| format!("\\{name}"))
+}
+
+/// The size command for a delimiter of the given size, e.g. `big` for `1.2em`.
+pub(super) fn big_delimiter_command(size: &str) -> Option<&'static str> {
+    find(BIG_DELIMITERS, |name| {
+        big_delimiter_size(name) == Some(size)
+    })
+}
+
+/// The accent command for the given operator and placement, e.g. `widehat`.
+pub(super) fn accent_command(s: &str, under: bool, stretchy: bool) -> Option<&'static str> {
+    find(ACCENTS, |name| {
+        accent(name).is_some_and(|accent| {
+            accent.op.op() == s && accent.under == under && accent.stretchy == stretchy
+        })
+    })
+}
+
+/// The font command for the given math variant, e.g. `mathbb` for double-struck.
+pub(super) fn font_command(variant: &MathVariant) -> Option<&'static str> {
+    find(FONTS, |name| font(name).as_ref() == Some(variant))
+}
+
+/// The text command for the given math variant, e.g. `textbf` for bold.
+pub(super) fn text_command(variant: Option<&MathVariant>) -> Option<&'static str> {
+    find(TEXTS, |name| {
+        text(name).is_some_and(|v| v.as_ref() == variant)
+    })
+}
+
+/// The spacing command for a space of the given width, e.g. `quad` for `1em`.
+pub(super) fn space_command(width: &str) -> Option<&'static str> {
+    find(SPACES, |name| space(name) == Some(width))
+}
+
+fn find(names: &[&'static str], predicate: impl Fn(&str) -> bool) -> Option<&'static str> {
+    names.iter().copied().find(|name| predicate(name))
+}
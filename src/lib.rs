@@ -3,20 +3,49 @@
 
 mod buf_writer;
 mod default_renderer;
+mod escape;
+mod greek;
+mod io_writer;
+mod markup;
+mod parser;
+mod pretty_writer;
 mod to_mathml;
+mod xml;
 use elements::IntoElements;
 
+#[cfg(feature = "asciimath")]
+pub mod asciimath;
 pub mod attributes;
+pub mod braille;
+pub mod content;
 pub mod elements;
+pub mod expr;
+#[cfg(feature = "latex")]
+pub mod latex;
+pub mod linear;
 pub mod markers;
+pub mod omml;
+pub mod ops;
+pub mod speech;
+pub mod text_art;
+pub mod typst;
+#[cfg(feature = "unicodemath")]
+pub mod unicodemath;
+pub mod validate;
+pub mod visit;
 
+#[cfg(feature = "macros")]
+pub use alemat_macros::math;
 pub use attributes::Attribute;
 pub use buf_writer::BufMathMlWriter;
-pub use default_renderer::MathMlFormatter;
+pub use default_renderer::{MathMlFormatter, RawMathMlFormatter};
 pub use elements::{Element, Elements};
+pub use io_writer::IoMathMlWriter;
+pub use parser::{ParseError, ParseErrorKind};
+pub use pretty_writer::{Indent, Newline, PrettyMathMlWriter};
 pub use to_mathml::*;
 
-pub(crate) use elements::element_from_type;
+pub(crate) use elements::{attr_accessors, element_from_type};
 
 /// Specifies how the enclosed MathML markup should be rendered.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -30,6 +59,21 @@ pub enum DisplayAttr {
     Inline,
 }
 
+/// Specifies which text alternatives are generated for the `math` element, see
+/// [`MathMl::generate_alttext`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GeneratedAltText {
+    /// No text alternatives are generated.
+    #[default]
+    Disabled,
+
+    /// The `alttext` attribute is generated.
+    AltText,
+
+    /// Both the `alttext` and the `aria-label` attributes are generated.
+    AltTextAndAriaLabel,
+}
+
 /// Attributes of the `math` ([`MathMl`]) element.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum MathMlAttr {
@@ -48,6 +92,10 @@ pub enum MathMlAttr {
     /// implement math layout.
     AltText(String),
 
+    /// The `aria-label` attribute, which is read by assistive technologies such as screen
+    /// readers instead of the content of the element.
+    AriaLabel(String),
+
     /// One of the global [`Attribute`]s.
     Global(Attribute),
 }
@@ -69,6 +117,11 @@ impl MathMl {
         &self.content
     }
 
+    /// Get a mutable reference to the inner content of the `math` element.
+    pub fn content_mut(&mut self) -> &mut Elements {
+        &mut self.content
+    }
+
     /// Map the content contained in [`MathMl`].
     ///
     /// Useful, for example, when wrapping the content in [`elements::Row`] is desired.
@@ -92,6 +145,19 @@ impl MathMl {
         self
     }
 
+    /// Parse MathML markup into a [`MathMl`]. The markup must contain exactly one `math` element.
+    ///
+    /// # Example
+    /// ```rust
+    /// use alemat::MathMl;
+    ///
+    /// let math = MathMl::parse("<math><mi>x</mi><mo>+</mo><mn>1</mn></math>").unwrap();
+    /// assert_eq!(math.len(), 3);
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        input.parse()
+    }
+
     /// Get a reference to all attributes of the `math` element.
     pub fn attributes(&self) -> &[MathMlAttr] {
         &self.attr
@@ -134,6 +200,77 @@ impl MathMl {
         self.attr.extend(attr.into_iter().map(Into::into))
     }
 
+    /// Generate a readable text alternative of this `math` element, e.g. `the fraction a over b`.
+    ///
+    /// The text is spoken math produced by the default [`SpeechFormatter`].
+    ///
+    /// [`SpeechFormatter`]: speech::SpeechFormatter
+    pub fn generated_alttext(&self) -> String {
+        let Ok(alttext) = self.render_with(&mut speech::SpeechFormatter::default());
+        alttext
+    }
+
+    /// Set the generated text alternatives on this `math` element. Attributes that are already
+    /// present are kept, so text alternatives written by hand take precedence.
+    pub fn generate_alttext(&mut self, generated: GeneratedAltText) {
+        let (alttext, aria_label) = self.missing_alttext(generated);
+
+        if !alttext && !aria_label {
+            return;
+        }
+
+        let text = self.generated_alttext();
+
+        if alttext {
+            self.attr.push(MathMlAttr::AltText(text.clone()));
+        }
+
+        if aria_label {
+            self.attr.push(MathMlAttr::AriaLabel(text));
+        }
+    }
+
+    /// Check which of the `alttext` and `aria-label` attributes are missing but should be
+    /// generated.
+    pub(crate) fn missing_alttext(&self, generated: GeneratedAltText) -> (bool, bool) {
+        let has = |f: fn(&MathMlAttr) -> bool| self.attr.iter().any(f);
+
+        let alttext = generated != GeneratedAltText::Disabled
+            && !has(|attr| matches!(attr, MathMlAttr::AltText(_)));
+
+        let aria_label = generated == GeneratedAltText::AltTextAndAriaLabel
+            && !has(|attr| matches!(attr, MathMlAttr::AriaLabel(_)));
+
+        (alttext, aria_label)
+    }
+
+    /// Create new instance of `MathMl` with a generated `alttext` attribute, unless the
+    /// attribute is already present.
+    ///
+    /// # Example
+    /// ```rust
+    /// use alemat::{elements::Frac, elements::Ident, MathMl};
+    ///
+    /// let out = MathMl::with_content(
+    ///     Frac::builder()
+    ///         .num(Ident::from("a"))
+    ///         .denom(Ident::from("b"))
+    ///         .build(),
+    /// )
+    /// .with_generated_alttext()
+    /// .render()
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     out,
+    ///     r#"<math alttext="the fraction a over b"><mfrac><mi>a</mi><mi>b</mi></mfrac></math>"#
+    /// );
+    /// ```
+    pub fn with_generated_alttext(mut self) -> Self {
+        self.generate_alttext(GeneratedAltText::AltText);
+        self
+    }
+
     /// Return the number of elements this `math` element contains.
     pub fn len(&self) -> usize {
         self.content.len()
@@ -165,8 +302,23 @@ impl MathMl {
         self.write(&mut BufMathMlWriter::default())
             .map(|w| w.finish())
     }
+
+    /// Consume the `math` element and return its inner content and attributes.
+    pub fn into_parts(self) -> (Elements, Vec<MathMlAttr>) {
+        (self.content, self.attr)
+    }
+
+    /// Create the `math` element from its inner content and attributes.
+    pub fn from_parts(content: impl IntoElements, attributes: Vec<MathMlAttr>) -> Self {
+        Self {
+            content: content.into_elements(),
+            attr: attributes,
+        }
+    }
 }
 
+crate::attr_accessors!(MathMl, attr: MathMlAttr);
+
 macro_rules! from_types {
     ($($type:path),* $(,)? => $for_type:path; $func:expr) => {
         $(
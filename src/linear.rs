@@ -0,0 +1,921 @@
+//! Rendering of MathML elements into a compact, single-line Unicode string.
+//!
+//! The linear form is meant for places where markup cannot be displayed, e.g. alternative text,
+//! search snippets, logs or terminal output. Scripts use superscript and subscript code points
+//! where possible (`x²+1`), math variants use the mathematical alphanumeric symbols (`ℝ`) and
+//! everything else falls back to a linear notation, which is selected with [`Fallback`].
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{radicals::Radical, scripted::SubSup, Ident, Num, Operator};
+//! use alemat::linear::LinearFormatter;
+//! use alemat::{children, MathMl};
+//!
+//! let math = MathMl::with_content(children![
+//!     SubSup::builder()
+//!         .base(Ident::from("x"))
+//!         .supscript(Num::from(2))
+//!         .build(),
+//!     Operator::plus(),
+//!     Radical::builder()
+//!         .index(Num::from(2))
+//!         .content(children![Ident::from("a"), Operator::plus(), Ident::from("b")])
+//!         .build(),
+//! ]);
+//!
+//! let output = math.render_with(&mut LinearFormatter::default()).unwrap();
+//! assert_eq!(output, "x²+√(a+b)");
+//! ```
+
+use std::convert::Infallible;
+
+use crate::{
+    attributes::{Attribute, Length, MathVariant},
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, Frac, FracAttr, Ident, Num, Operator, OperatorAttr, Padded, Semantics, Space,
+        SpaceAttr, StrLiteral, Table, Text,
+    },
+    Element, MathMl, Renderer,
+};
+
+/// The notation used for constructs that have no plain Unicode representation, e.g. roots with an
+/// index other than 2, 3 or 4, or matrices.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Fallback {
+    /// UnicodeMath notation, e.g. `√(n&x)`, `x┴a` and `■(a&b@c&d)`.
+    #[default]
+    UnicodeMath,
+
+    /// AsciiMath notation, e.g. `root(n)(x)`, `overset(a)(x)` and `[(a,b),(c,d)]`.
+    AsciiMath,
+}
+
+/// [`Renderer`] implementation that produces a compact, single-line Unicode string, e.g.
+/// `x²+1`, `√(a+b)`, `(a+b)/c` or `∑_(i=0)^n`.
+///
+/// Annotations are left out, as the line only holds the presented notation.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LinearFormatter {
+    fallback: Fallback,
+}
+
+impl LinearFormatter {
+    /// Create a [`LinearFormatter`] that uses the given notation for constructs without a plain
+    /// Unicode representation.
+    pub fn with_fallback(fallback: Fallback) -> Self {
+        Self { fallback }
+    }
+
+    /// The notation used for constructs without a plain Unicode representation.
+    pub fn fallback(&self) -> Fallback {
+        self.fallback
+    }
+
+    fn render_elements(&mut self, elements: &[Element]) -> String {
+        let mut out = String::new();
+        let mut separate = false;
+
+        for element in elements {
+            let rendered = match element {
+                Element::Frac(frac) => self.frac(frac, out.chars().last()),
+                element => {
+                    let Ok(rendered) = self.render_element(element);
+                    rendered
+                }
+            };
+
+            if rendered.is_empty() {
+                continue;
+            }
+
+            // keep words apart, e.g. `sin x` or `∑_i i`
+            let word = is_word(element);
+            if (separate || word && out.ends_with(char::is_alphanumeric))
+                && rendered.starts_with(char::is_alphanumeric)
+            {
+                out.push(' ');
+            }
+
+            separate = word || rendered.contains(['_', '^']);
+            out.push_str(&rendered);
+        }
+
+        out
+    }
+
+    /// Render the elements, surrounded by parentheses unless they form a single atom.
+    fn group(&mut self, elements: &[Element]) -> String {
+        let rendered = self.render_elements(elements);
+
+        if is_atom(elements) && !rendered.is_empty() {
+            rendered
+        } else {
+            format!("({rendered})")
+        }
+    }
+
+    /// Render a fraction following the given character. After a letter or digit the fraction is
+    /// put in parentheses, e.g. `a(b/c)`, and after a digit no vulgar fraction is used, which
+    /// would read as mixed number, e.g. `2(1/2)` instead of `2½`.
+    fn frac(&mut self, frac: &Frac, previous: Option<char>) -> String {
+        let after_digit = previous.is_some_and(|c| c.is_ascii_digit());
+
+        let rendered = if is_stacked(frac) {
+            self.stacked(frac)
+        } else {
+            let num = self.group(frac.num());
+            let denom = self.group(frac.denom());
+
+            if let Some(vulgar) = vulgar_fraction(&num, &denom).filter(|_| !after_digit) {
+                return vulgar.to_string();
+            }
+
+            format!("{num}/{denom}")
+        };
+
+        match previous.is_some_and(char::is_alphanumeric) {
+            true => format!("({rendered})"),
+            false => rendered,
+        }
+    }
+
+    /// Render a script after `_` or `^`, surrounded by parentheses unless it is a single
+    /// character.
+    fn script(&mut self, elements: &[Element]) -> String {
+        let rendered = self.render_elements(elements);
+
+        if rendered.chars().count() == 1 {
+            rendered
+        } else {
+            format!("({rendered})")
+        }
+    }
+
+    /// Render sub- and superscripts, using code points if both scripts can be represented with
+    /// them.
+    fn scripts(&mut self, sub: Option<&[Element]>, sup: Option<&[Element]>) -> String {
+        let sub_rendered = sub.map(|sub| self.render_elements(sub));
+        let sup_rendered = sup.map(|sup| self.render_elements(sup));
+
+        let sub_chars = sub_rendered.as_deref().map(subscript);
+        let sup_chars = sup_rendered.as_deref().map(superscript);
+
+        if !matches!(sub_chars, Some(None)) && !matches!(sup_chars, Some(None)) {
+            return sub_chars
+                .flatten()
+                .into_iter()
+                .chain(sup_chars.flatten())
+                .collect();
+        }
+
+        self.limits(sub, sup)
+    }
+
+    /// Render scripts with `_` and `^`, e.g. `_(i=0)^n`.
+    fn limits(&mut self, sub: Option<&[Element]>, sup: Option<&[Element]>) -> String {
+        let mut out = String::new();
+
+        if let Some(sub) = sub {
+            out.push('_');
+            out.push_str(&self.script(sub));
+        }
+
+        if let Some(sup) = sup {
+            out.push('^');
+            out.push_str(&self.script(sup));
+        }
+
+        out
+    }
+
+    fn variant(&self, content: &str, attributes: &[Attribute]) -> String {
+        let variant = attributes.iter().find_map(|attr| match attr {
+            Attribute::MathVariant(variant) => Some(variant),
+            _ => None,
+        });
+
+        match variant {
+            Some(variant) => content
+                .chars()
+                .map(|c| styled_char(c, variant).unwrap_or(c))
+                .collect(),
+            None => content.to_string(),
+        }
+    }
+}
+
+impl Renderer for LinearFormatter {
+    type Output = String;
+    type Error = Infallible;
+
+    fn render_action(&mut self, action: &Action) -> Result<Self::Output, Self::Error> {
+        let selection = action.attributes().iter().find_map(|attr| match attr {
+            ActionAttr::Selection(selection) => selection.trim().parse::<usize>().ok(),
+            _ => None,
+        });
+
+        let content = action.content();
+        let selected = selection
+            .and_then(|idx| content.get(idx.checked_sub(1)?))
+            .or_else(|| content.first());
+
+        match selected {
+            Some(element) => self.render_element(element),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_annotation(&mut self, _annotation: &Annotation) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_error(&mut self, error: &Error) -> Result<Self::Output, Self::Error> {
+        Ok(self.render_elements(error.content()))
+    }
+
+    fn render_frac(&mut self, frac: &Frac) -> Result<Self::Output, Self::Error> {
+        Ok(self.frac(frac, None))
+    }
+
+    fn render_ident(&mut self, ident: &Ident) -> Result<Self::Output, Self::Error> {
+        Ok(self.variant(ident.ident(), ident.attributes()))
+    }
+
+    fn render_multiscripts(
+        &mut self,
+        multiscripts: &Multiscripts,
+    ) -> Result<Self::Output, Self::Error> {
+        let content = multiscripts.content();
+
+        let Some((base, scripts)) = content.split_first() else {
+            return Ok(String::new());
+        };
+
+        let (post, pre) = match scripts
+            .iter()
+            .position(|element| matches!(element, Element::Prescripts(_)))
+        {
+            Some(idx) => (&scripts[..idx], &scripts[idx + 1..]),
+            None => (scripts, &[][..]),
+        };
+
+        let mut out = String::new();
+
+        for pair in pre.chunks(2) {
+            let (sub, sup) = script_pair(pair);
+            let scripts = self.scripts(sub, sup);
+
+            if scripts.starts_with(['_', '^']) {
+                match self.fallback {
+                    Fallback::UnicodeMath => out.push_str(&format!("({scripts})")),
+                    Fallback::AsciiMath => out.push_str(&format!("{{::}}{scripts}")),
+                }
+            } else {
+                out.push_str(&scripts);
+            }
+        }
+
+        out.push_str(&self.group(std::slice::from_ref(base)));
+
+        for pair in post.chunks(2) {
+            let (sub, sup) = script_pair(pair);
+            out.push_str(&self.scripts(sub, sup));
+        }
+
+        Ok(out)
+    }
+
+    fn render_prescripts(&mut self, _prescripts: &Prescripts) -> Result<Self::Output, Self::Error> {
+        // prescripts are handled by the surrounding multiscripts element
+        Ok(String::new())
+    }
+
+    fn render_num(&mut self, num: &Num) -> Result<Self::Output, Self::Error> {
+        Ok(self.variant(num.num(), num.attributes()))
+    }
+
+    fn render_operator(&mut self, operator: &Operator) -> Result<Self::Output, Self::Error> {
+        let op = match operator.op() {
+            // invisible operators such as function application
+            "\u{2061}" | "\u{2062}" | "\u{2063}" | "\u{2064}" => "",
+            op => op,
+        };
+
+        Ok(op.to_string())
+    }
+
+    fn render_padded(&mut self, padded: &Padded) -> Result<Self::Output, Self::Error> {
+        Ok(self.render_elements(padded.children()))
+    }
+
+    fn render_phantom(&mut self, phantom: &Phantom) -> Result<Self::Output, Self::Error> {
+        match self.fallback {
+            Fallback::UnicodeMath => {
+                let content = self.render_elements(phantom.children());
+                Ok(format!("⟡({content})"))
+            }
+            Fallback::AsciiMath => Ok(String::new()),
+        }
+    }
+
+    fn render_radical(&mut self, radical: &Radical) -> Result<Self::Output, Self::Error> {
+        let index = self.render_elements(radical.index());
+
+        let root = match index.as_str() {
+            "" | "2" => Some('√'),
+            "3" => Some('∛'),
+            "4" => Some('∜'),
+            _ => None,
+        };
+
+        if let Some(root) = root {
+            let content = self.group(radical.content());
+            return Ok(format!("{root}{content}"));
+        }
+
+        let content = self.render_elements(radical.content());
+
+        Ok(match self.fallback {
+            Fallback::UnicodeMath => format!("√({index}&{content})"),
+            Fallback::AsciiMath => format!("root({index})({content})"),
+        })
+    }
+
+    fn render_row(&mut self, row: &Row) -> Result<Self::Output, Self::Error> {
+        // AsciiMath matrices take their brackets from the surrounding row, e.g. `[(a,b),(c,d)]`
+        if let (Fallback::AsciiMath, [Element::Operator(open), content, Element::Operator(close)]) =
+            (self.fallback, row.children())
+        {
+            let rows = match content {
+                Element::Table(table) => Some(self.table_rows(table)),
+                Element::Frac(frac) if is_stacked(frac) => {
+                    let num = self.render_elements(frac.num());
+                    let denom = self.render_elements(frac.denom());
+                    Some(format!("({num}),({denom})"))
+                }
+                _ => None,
+            };
+
+            if let Some(rows) = rows {
+                return Ok(format!("{}{rows}{}", open.op(), close.op()));
+            }
+        }
+
+        Ok(self.render_elements(row.children()))
+    }
+
+    fn render_semantics(&mut self, semantics: &Semantics) -> Result<Self::Output, Self::Error> {
+        match semantics.children().first() {
+            Some(presentation) => self.render_element(presentation),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_space(&mut self, space: &Space) -> Result<Self::Output, Self::Error> {
+        let visible = space.attributes().iter().any(|attr| match attr {
+            SpaceAttr::Width(width) => {
+                width
+                    .parse::<Length>()
+                    .is_ok_and(|width| match width.named_space() {
+                        Some(named) => named.em() > 0.0,
+                        None => width.value().is_some_and(|value| value > 0.0),
+                    })
+            }
+            _ => false,
+        });
+
+        Ok(if visible { " " } else { "" }.to_string())
+    }
+
+    fn render_str_literal(
+        &mut self,
+        str_literal: &StrLiteral,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(format!("\"{}\"", str_literal.content()))
+    }
+
+    fn render_style(&mut self, style: &Style) -> Result<Self::Output, Self::Error> {
+        let content = self.render_elements(style.children());
+        Ok(self.variant(&content, style.attributes()))
+    }
+
+    fn render_subsup(&mut self, sub_sup: &SubSup) -> Result<Self::Output, Self::Error> {
+        let base = self.group(sub_sup.base());
+        let scripts = self.scripts(sub_sup.sub(), sub_sup.sup());
+
+        Ok(format!("{base}{scripts}"))
+    }
+
+    fn render_table(&mut self, table: &Table) -> Result<Self::Output, Self::Error> {
+        Ok(match self.fallback {
+            Fallback::UnicodeMath => {
+                let rows = table
+                    .rows()
+                    .iter()
+                    .map(|row| {
+                        row.cells()
+                            .iter()
+                            .map(|cell| self.render_elements(cell.children()))
+                            .collect::<Vec<_>>()
+                            .join("&")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("@");
+
+                format!("■({rows})")
+            }
+            Fallback::AsciiMath => format!("{{:{}:}}", self.table_rows(table)),
+        })
+    }
+
+    fn render_text(&mut self, text: &Text) -> Result<Self::Output, Self::Error> {
+        Ok(format!("\"{}\"", text.text()))
+    }
+
+    fn render_underover(&mut self, under_over: &UnderOver) -> Result<Self::Output, Self::Error> {
+        let attributes = under_over.attributes();
+        let expr = under_over.expr();
+
+        let accent = match (under_over.under(), under_over.over()) {
+            (None, Some([Element::Operator(op)]))
+                if attributes.contains(&UnderOverAttr::AccentOver) =>
+            {
+                Some((op.op(), false))
+            }
+            (Some([Element::Operator(op)]), None)
+                if attributes.contains(&UnderOverAttr::AccentUnder) =>
+            {
+                Some((op.op(), true))
+            }
+            _ => None,
+        };
+
+        if let Some((op, under)) = accent {
+            return Ok(self.accent(expr, op, under));
+        }
+
+        // scripts of large operators and functions are written as limits, e.g. `∑_(i=0)^n`
+        if matches!(expr, [element] if has_limits(element)) {
+            let base = self.render_elements(expr);
+            let limits = self.limits(under_over.under(), under_over.over());
+
+            return Ok(format!("{base}{limits}"));
+        }
+
+        let mut out = self.group(expr);
+
+        match self.fallback {
+            Fallback::UnicodeMath => {
+                if let Some(under) = under_over.under() {
+                    out = format!("{out}┬{}", self.group(under));
+                }
+
+                if let Some(over) = under_over.over() {
+                    out = format!("{out}┴{}", self.group(over));
+                }
+            }
+            Fallback::AsciiMath => {
+                if let Some(under) = under_over.under() {
+                    let under = self.render_elements(under);
+                    out = format!("underset({under})({})", unwrap_parens(&out));
+                }
+
+                if let Some(over) = under_over.over() {
+                    let over = self.render_elements(over);
+                    out = format!("overset({over})({})", unwrap_parens(&out));
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn render_attr(&mut self, _attr: &Attribute) -> Result<Self::Output, Self::Error> {
+        // attributes have no representation in the linear form, except for math variants which
+        // are applied to the content of the elements
+        Ok(String::new())
+    }
+
+    fn render_mathml(&mut self, mathml: &MathMl) -> Result<Self::Output, Self::Error> {
+        Ok(self.render_elements(mathml.content()))
+    }
+}
+
+impl LinearFormatter {
+    /// Render a fraction without a bar, e.g. the content of a binomial coefficient `(n¦k)`.
+    fn stacked(&mut self, frac: &Frac) -> String {
+        match self.fallback {
+            Fallback::UnicodeMath => {
+                let num = self.group(frac.num());
+                let denom = self.group(frac.denom());
+                format!("{num}¦{denom}")
+            }
+            Fallback::AsciiMath => {
+                let num = self.render_elements(frac.num());
+                let denom = self.render_elements(frac.denom());
+                format!("{{:({num}),({denom}):}}")
+            }
+        }
+    }
+
+    /// Render the rows of a table as AsciiMath matrix rows, e.g. `(a,b),(c,d)`.
+    fn table_rows(&mut self, table: &Table) -> String {
+        table
+            .rows()
+            .iter()
+            .map(|row| {
+                let cells = row
+                    .cells()
+                    .iter()
+                    .map(|cell| self.render_elements(cell.children()))
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                format!("({cells})")
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Render an accent, using a combining character if the expression is a single character,
+    /// e.g. `x̂`.
+    fn accent(&mut self, expr: &[Element], op: &str, under: bool) -> String {
+        let content = self.render_elements(expr);
+        let combining = combining_accent(op, under);
+
+        match (self.fallback, combining) {
+            (_, Some(combining)) if content.chars().count() == 1 => format!("{content}{combining}"),
+            (Fallback::UnicodeMath, Some(combining)) => format!("({content}){combining}"),
+            (Fallback::UnicodeMath, None) => {
+                let position = if under { '┬' } else { '┴' };
+                format!("{}{position}{op}", self.group(expr))
+            }
+            (Fallback::AsciiMath, _) => match ascii_math_accent(op, under) {
+                Some(name) => format!("{name}({content})"),
+                None if under => format!("underset({op})({content})"),
+                None => format!("overset({op})({content})"),
+            },
+        }
+    }
+}
+
+/// Split a pair of multiscripts into sub- and superscript, empty rows denote missing scripts.
+fn script_pair(pair: &[Element]) -> (Option<&[Element]>, Option<&[Element]>) {
+    let script = |idx: usize| {
+        pair.get(idx)
+            .filter(|el| !matches!(el, Element::Row(row) if row.children().is_empty()))
+            .map(std::slice::from_ref)
+    };
+
+    (script(0), script(1))
+}
+
+/// Whether the fraction has no bar, e.g. in a binomial coefficient.
+fn is_stacked(frac: &Frac) -> bool {
+    frac.attributes().iter().any(|attr| {
+        matches!(
+            attr,
+            FracAttr::LineThickness(lt) if lt.parse::<Length>().is_ok_and(|lt| lt.is_zero())
+        )
+    })
+}
+
+/// Whether the element is a word that has to be separated from surrounding letters, e.g. `sin`.
+fn is_word(element: &Element) -> bool {
+    matches!(element, Element::Ident(ident) if ident.ident().chars().count() > 1)
+}
+
+/// Whether the scripts of the element are written as limits, e.g. for `∑` or `lim`.
+fn has_limits(element: &Element) -> bool {
+    match element {
+        Element::Operator(op) => {
+            op.attributes().contains(&OperatorAttr::LargeOp)
+                || matches!(
+                    op.op(),
+                    "∑" | "∏"
+                        | "∐"
+                        | "⋃"
+                        | "⋂"
+                        | "⋀"
+                        | "⋁"
+                        | "⨀"
+                        | "⨁"
+                        | "⨂"
+                        | "∫"
+                        | "∬"
+                        | "∭"
+                        | "∮"
+                )
+        }
+        element => is_word(element),
+    }
+}
+
+/// Whether the elements can be written without surrounding parentheses, e.g. as numerator.
+fn is_atom(elements: &[Element]) -> bool {
+    match elements {
+        [Element::Ident(_)
+        | Element::Num(_)
+        | Element::Text(_)
+        | Element::StrLiteral(_)
+        | Element::Operator(_)
+        | Element::SubSup(_)] => true,
+        [Element::Row(row)] => match row.children() {
+            [Element::Operator(open), .., Element::Operator(close)] => {
+                is_open(open.op()) && is_close(close.op())
+            }
+            children => is_atom(children),
+        },
+        [Element::Style(style)] => is_atom(style.children()),
+        [Element::Radical(radical)] => is_atom(radical.content()),
+        _ => false,
+    }
+}
+
+fn is_open(op: &str) -> bool {
+    matches!(op, "(" | "[" | "{" | "|" | "‖" | "∥" | "⟨" | "⌊" | "⌈")
+}
+
+fn is_close(op: &str) -> bool {
+    matches!(op, ")" | "]" | "}" | "|" | "‖" | "∥" | "⟩" | "⌋" | "⌉")
+}
+
+fn unwrap_parens(s: &str) -> &str {
+    s.strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .unwrap_or(s)
+}
+
+/// Convert the string into superscript code points, if all characters have one.
+fn superscript(s: &str) -> Option<String> {
+    s.chars()
+        .map(|c| {
+            let sup = match c {
+                '0' => '⁰',
+                '1' => '¹',
+                '2' => '²',
+                '3' => '³',
+                '4' => '⁴',
+                '5' => '⁵',
+                '6' => '⁶',
+                '7' => '⁷',
+                '8' => '⁸',
+                '9' => '⁹',
+                '+' => '⁺',
+                '-' | '−' => '⁻',
+                '=' => '⁼',
+                '(' => '⁽',
+                ')' => '⁾',
+                // primes are already raised
+                '′' | '″' | '‴' | '*' => c,
+                'a' => 'ᵃ',
+                'b' => 'ᵇ',
+                'c' => 'ᶜ',
+                'd' => 'ᵈ',
+                'e' => 'ᵉ',
+                'f' => 'ᶠ',
+                'g' => 'ᵍ',
+                'h' => 'ʰ',
+                'i' => 'ⁱ',
+                'j' => 'ʲ',
+                'k' => 'ᵏ',
+                'l' => 'ˡ',
+                'm' => 'ᵐ',
+                'n' => 'ⁿ',
+                'o' => 'ᵒ',
+                'p' => 'ᵖ',
+                'r' => 'ʳ',
+                's' => 'ˢ',
+                't' => 'ᵗ',
+                'u' => 'ᵘ',
+                'v' => 'ᵛ',
+                'w' => 'ʷ',
+                'x' => 'ˣ',
+                'y' => 'ʸ',
+                'z' => 'ᶻ',
+                'A' => 'ᴬ',
+                'B' => 'ᴮ',
+                'D' => 'ᴰ',
+                'E' => 'ᴱ',
+                'G' => 'ᴳ',
+                'H' => 'ᴴ',
+                'I' => 'ᴵ',
+                'J' => 'ᴶ',
+                'K' => 'ᴷ',
+                'L' => 'ᴸ',
+                'M' => 'ᴹ',
+                'N' => 'ᴺ',
+                'O' => 'ᴼ',
+                'P' => 'ᴾ',
+                'R' => 'ᴿ',
+                'T' => 'ᵀ',
+                'U' => 'ᵁ',
+                'V' => 'ⱽ',
+                'W' => 'ᵂ',
+                'α' => 'ᵅ',
+                'β' => 'ᵝ',
+                'γ' => 'ᵞ',
+                'δ' => 'ᵟ',
+                'θ' => 'ᶿ',
+                'φ' => 'ᵠ',
+                'χ' => 'ᵡ',
+                _ => return None,
+            };
+
+            Some(sup)
+        })
+        .collect()
+}
+
+/// Convert the string into subscript code points, if all characters have one.
+fn subscript(s: &str) -> Option<String> {
+    s.chars()
+        .map(|c| {
+            let sub = match c {
+                '0' => '₀',
+                '1' => '₁',
+                '2' => '₂',
+                '3' => '₃',
+                '4' => '₄',
+                '5' => '₅',
+                '6' => '₆',
+                '7' => '₇',
+                '8' => '₈',
+                '9' => '₉',
+                '+' => '₊',
+                '-' | '−' => '₋',
+                '=' => '₌',
+                '(' => '₍',
+                ')' => '₎',
+                'a' => 'ₐ',
+                'e' => 'ₑ',
+                'h' => 'ₕ',
+                'i' => 'ᵢ',
+                'j' => 'ⱼ',
+                'k' => 'ₖ',
+                'l' => 'ₗ',
+                'm' => 'ₘ',
+                'n' => 'ₙ',
+                'o' => 'ₒ',
+                'p' => 'ₚ',
+                'r' => 'ᵣ',
+                's' => 'ₛ',
+                't' => 'ₜ',
+                'u' => 'ᵤ',
+                'v' => 'ᵥ',
+                'x' => 'ₓ',
+                'β' => 'ᵦ',
+                'γ' => 'ᵧ',
+                'ρ' => 'ᵨ',
+                'φ' => 'ᵩ',
+                'χ' => 'ᵪ',
+                _ => return None,
+            };
+
+            Some(sub)
+        })
+        .collect()
+}
+
+/// Single code point for common fractions, e.g. `½`.
+fn vulgar_fraction(num: &str, denom: &str) -> Option<char> {
+    let vulgar = match (num, denom) {
+        ("1", "2") => '½',
+        ("1", "3") => '⅓',
+        ("2", "3") => '⅔',
+        ("1", "4") => '¼',
+        ("3", "4") => '¾',
+        ("1", "5") => '⅕',
+        ("2", "5") => '⅖',
+        ("3", "5") => '⅗',
+        ("4", "5") => '⅘',
+        ("1", "6") => '⅙',
+        ("5", "6") => '⅚',
+        ("1", "7") => '⅐',
+        ("1", "8") => '⅛',
+        ("3", "8") => '⅜',
+        ("5", "8") => '⅝',
+        ("7", "8") => '⅞',
+        ("1", "9") => '⅑',
+        ("1", "10") => '⅒',
+        _ => return None,
+    };
+
+    Some(vulgar)
+}
+
+/// The combining character for an accent, e.g. U+0302 for `^`.
+fn combining_accent(op: &str, under: bool) -> Option<char> {
+    let combining = match (op, under) {
+        ("^" | "ˆ", false) => '\u{0302}',
+        ("~" | "˜", false) => '\u{0303}',
+        ("¯" | "‾" | "_", false) => '\u{0305}',
+        ("¯" | "‾" | "_", true) => '\u{0332}',
+        ("˘", false) => '\u{0306}',
+        ("˙" | ".", false) => '\u{0307}',
+        ("¨" | "..", false) => '\u{0308}',
+        ("˚", false) => '\u{030A}',
+        ("ˇ", false) => '\u{030C}',
+        ("´", false) => '\u{0301}',
+        ("`", false) => '\u{0300}',
+        ("→", false) => '\u{20D7}',
+        ("←", false) => '\u{20D6}',
+        _ => return None,
+    };
+
+    Some(combining)
+}
+
+/// The AsciiMath command for an accent, e.g. `hat`.
+fn ascii_math_accent(op: &str, under: bool) -> Option<&'static str> {
+    let name = match (op, under) {
+        ("^" | "ˆ", false) => "hat",
+        ("~" | "˜", false) => "tilde",
+        ("¯" | "‾" | "_", false) => "bar",
+        ("¯" | "‾" | "_", true) => "ul",
+        ("˙" | ".", false) => "dot",
+        ("¨" | "..", false) => "ddot",
+        ("→", false) => "vec",
+        ("⏞", false) => "obrace",
+        ("⏟", true) => "ubrace",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Map the character to the mathematical alphanumeric symbol of the variant, e.g. `R` to `ℝ` for
+/// double-struck.
+pub(crate) fn styled_char(c: char, variant: &MathVariant) -> Option<char> {
+    // letters that were encoded before the mathematical alphanumeric symbols block
+    let exception = match (variant, c) {
+        (MathVariant::Italic, 'h') => Some('ℎ'),
+        (MathVariant::Script, 'B') => Some('ℬ'),
+        (MathVariant::Script, 'E') => Some('ℰ'),
+        (MathVariant::Script, 'F') => Some('ℱ'),
+        (MathVariant::Script, 'H') => Some('ℋ'),
+        (MathVariant::Script, 'I') => Some('ℐ'),
+        (MathVariant::Script, 'L') => Some('ℒ'),
+        (MathVariant::Script, 'M') => Some('ℳ'),
+        (MathVariant::Script, 'R') => Some('ℛ'),
+        (MathVariant::Script, 'e') => Some('ℯ'),
+        (MathVariant::Script, 'g') => Some('ℊ'),
+        (MathVariant::Script, 'o') => Some('ℴ'),
+        (MathVariant::Fraktur, 'C') => Some('ℭ'),
+        (MathVariant::Fraktur, 'H') => Some('ℌ'),
+        (MathVariant::Fraktur, 'I') => Some('ℑ'),
+        (MathVariant::Fraktur, 'R') => Some('ℜ'),
+        (MathVariant::Fraktur, 'Z') => Some('ℨ'),
+        (MathVariant::DoubleStruck, 'C') => Some('ℂ'),
+        (MathVariant::DoubleStruck, 'H') => Some('ℍ'),
+        (MathVariant::DoubleStruck, 'N') => Some('ℕ'),
+        (MathVariant::DoubleStruck, 'P') => Some('ℙ'),
+        (MathVariant::DoubleStruck, 'Q') => Some('ℚ'),
+        (MathVariant::DoubleStruck, 'R') => Some('ℝ'),
+        (MathVariant::DoubleStruck, 'Z') => Some('ℤ'),
+        _ => None,
+    };
+
+    if exception.is_some() {
+        return exception;
+    }
+
+    let letters = match variant {
+        MathVariant::Bold => Some(0x1D400),
+        MathVariant::Italic => Some(0x1D434),
+        MathVariant::BoldItalic => Some(0x1D468),
+        MathVariant::Script => Some(0x1D49C),
+        MathVariant::BoldScript => Some(0x1D4D0),
+        MathVariant::Fraktur => Some(0x1D504),
+        MathVariant::DoubleStruck => Some(0x1D538),
+        MathVariant::BoldFraktur => Some(0x1D56C),
+        MathVariant::SansSerif => Some(0x1D5A0),
+        MathVariant::BoldSansSerif => Some(0x1D5D4),
+        MathVariant::SansSerifItalic => Some(0x1D608),
+        MathVariant::SansSerifBoldItalic => Some(0x1D63C),
+        MathVariant::Monospace => Some(0x1D670),
+        _ => None,
+    };
+
+    let digits = match variant {
+        MathVariant::Bold => Some(0x1D7CE),
+        MathVariant::DoubleStruck => Some(0x1D7D8),
+        MathVariant::SansSerif => Some(0x1D7E2),
+        MathVariant::BoldSansSerif => Some(0x1D7EC),
+        MathVariant::Monospace => Some(0x1D7F6),
+        _ => None,
+    };
+
+    let code = match c {
+        'A'..='Z' => letters? + (c as u32 - 'A' as u32),
+        'a'..='z' => letters? + 26 + (c as u32 - 'a' as u32),
+        '0'..='9' => digits? + (c as u32 - '0' as u32),
+        _ => return None,
+    };
+
+    char::from_u32(code)
+}
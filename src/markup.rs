@@ -0,0 +1,824 @@
+//! Markup writing shared by the MathMl [`Writer`](crate::Writer) implementations.
+//!
+//! The functions in this module write elements into any [`Markup`] sink, so that all writers
+//! produce exactly the same markup regardless of where it is written to.
+
+use std::fmt::{self, Write};
+
+use crate::{
+    attributes::{Attribute, Dir, ScriptLevel},
+    content::{Content, ContentOp},
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, AnnotationAttr, AnnotationContent, Frac, FracAttr, Ident, Num, Operator,
+        OperatorAttr, Padded, PaddedAttr, Semantics, Space, SpaceAttr, StrLiteral, Table,
+        TableAttr, TableCellAttr, Text,
+    },
+    escape::{self, EscapeCtx},
+    DisplayAttr, Element, GeneratedAltText, MathMl, MathMlAttr,
+};
+
+/// Sink that markup is written into.
+pub(crate) trait Markup: Write {
+    /// Whether element content and attribute values are written without escaping them.
+    fn is_raw(&self) -> bool;
+
+    /// The text alternatives generated for `math` elements that do not have them already.
+    fn generated_alttext(&self) -> GeneratedAltText {
+        GeneratedAltText::Disabled
+    }
+
+    /// Called before the start tag of an element is written.
+    fn before_start(&mut self) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Called after the start tag of an element with content is written. `token` is `true` for
+    /// elements containing text instead of other elements, e.g. `mi`.
+    fn after_start(&mut self, _token: bool) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Called before the end tag of an element with content is written. `token` is `true` for
+    /// elements containing text instead of other elements, e.g. `mi`.
+    fn before_end(&mut self, _token: bool) -> fmt::Result {
+        Ok(())
+    }
+}
+
+fn content<W: Markup + ?Sized>(w: &mut W, content: &str) -> fmt::Result {
+    if w.is_raw() {
+        w.write_str(content)
+    } else {
+        escape::escape_into(w, content, EscapeCtx::Content)
+    }
+}
+
+fn attr_value<W: Markup + ?Sized>(w: &mut W, name: &str, value: &str) -> fmt::Result {
+    w.write_str(name)?;
+    quoted(w, value)
+}
+
+fn quoted<W: Markup + ?Sized>(w: &mut W, value: &str) -> fmt::Result {
+    w.write_str("=\"")?;
+
+    if w.is_raw() {
+        w.write_str(value)?;
+    } else {
+        escape::escape_into(w, value, EscapeCtx::AttrValue)?;
+    }
+
+    w.write_str("\"")
+}
+
+fn attr_name<W: Markup + ?Sized>(w: &mut W, prefix: &str, name: &str) -> fmt::Result {
+    w.write_str(prefix)?;
+
+    if w.is_raw() {
+        w.write_str(name)
+    } else {
+        escape::name_into(w, name)
+    }
+}
+
+fn elements<W: Markup + ?Sized>(w: &mut W, elements: &[Element]) -> fmt::Result {
+    for e in elements {
+        element(w, e)?;
+    }
+
+    Ok(())
+}
+
+/// Write an [`Element`] into the sink.
+pub(crate) fn element<W: Markup + ?Sized>(w: &mut W, element: &Element) -> fmt::Result {
+    match element {
+        Element::Action(a) => action(w, a),
+        Element::Annotation(a) => annotation(w, a),
+        Element::Error(e) => error(w, e),
+        Element::Frac(f) => frac(w, f),
+        Element::Ident(i) => ident(w, i),
+        Element::Multiscripts(m) => multiscripts(w, m),
+        Element::Num(n) => num(w, n),
+        Element::Operator(o) => operator(w, o),
+        Element::Padded(p) => padded(w, p),
+        Element::Phantom(p) => phantom(w, p),
+        Element::Radical(r) => radical(w, r),
+        Element::Row(r) => row(w, r),
+        Element::Semantics(s) => semantics(w, s),
+        Element::Space(s) => space(w, s),
+        Element::StrLiteral(s) => str_literal(w, s),
+        Element::Style(s) => style(w, s),
+        Element::SubSup(s) => subsup(w, s),
+        Element::Table(t) => table(w, t),
+        Element::Text(t) => text(w, t),
+        Element::UnderOver(u) => underover(w, u),
+        Element::Prescripts(p) => prescripts(w, p),
+    }
+}
+
+pub(crate) fn action<W: Markup + ?Sized>(w: &mut W, action: &Action) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<maction")?;
+
+    for attr in action.attributes().iter() {
+        w.write_str(" ")?;
+
+        match attr {
+            ActionAttr::Global(g_attr) => {
+                attribute(w, g_attr)?;
+            }
+            ActionAttr::Selection(sel) => {
+                attr_value(w, "selection", sel)?;
+            }
+            ActionAttr::ActionType(at) => {
+                attr_value(w, "actiontype", at)?;
+            }
+        }
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+
+    elements(w, action.content())?;
+
+    w.before_end(false)?;
+    w.write_str("</maction>")
+}
+
+pub(crate) fn annotation<W: Markup + ?Sized>(w: &mut W, annotation: &Annotation) -> fmt::Result {
+    let write_attr = |w: &mut W| -> fmt::Result {
+        for attr in annotation.attributes() {
+            w.write_str(" ")?;
+
+            match attr {
+                AnnotationAttr::Global(ref g_attr) => attribute(w, g_attr)?,
+                AnnotationAttr::Encoding(ref enc) => {
+                    attr_value(w, "encoding", enc)?;
+                }
+            }
+        }
+
+        Ok(())
+    };
+
+    match annotation.content() {
+        AnnotationContent::Text(text) => {
+            w.before_start()?;
+            w.write_str("<annotation")?;
+            write_attr(w)?;
+            w.write_str(">")?;
+            w.after_start(true)?;
+            content(w, text)?;
+            w.before_end(true)?;
+            w.write_str("</annotation>")
+        }
+        AnnotationContent::Nested(nested) => {
+            w.before_start()?;
+            w.write_str("<annotation-xml")?;
+            write_attr(w)?;
+            w.write_str(">")?;
+            w.after_start(false)?;
+            elements(w, nested)?;
+            w.before_end(false)?;
+            w.write_str("</annotation-xml>")
+        }
+        AnnotationContent::Content(tree) => {
+            w.before_start()?;
+            w.write_str("<annotation-xml")?;
+            write_attr(w)?;
+            w.write_str(">")?;
+            w.after_start(false)?;
+            content_tree(w, tree)?;
+            w.before_end(false)?;
+            w.write_str("</annotation-xml>")
+        }
+    }
+}
+
+/// Write a Content MathML tree into the sink.
+pub(crate) fn content_tree<W: Markup + ?Sized>(w: &mut W, tree: &Content) -> fmt::Result {
+    let token = |w: &mut W, tag: &str, text: &str| -> fmt::Result {
+        w.before_start()?;
+        write!(w, "<{tag}>")?;
+        w.after_start(true)?;
+        content(w, text)?;
+        w.before_end(true)?;
+        write!(w, "</{tag}>")
+    };
+
+    let qualifier = |w: &mut W, tag: &str, tree: &Content| -> fmt::Result {
+        w.before_start()?;
+        write!(w, "<{tag}>")?;
+        w.after_start(false)?;
+        content_tree(w, tree)?;
+        w.before_end(false)?;
+        write!(w, "</{tag}>")
+    };
+
+    let apply = match tree {
+        Content::Ident(name) => return token(w, "ci", name),
+        Content::Number(number) => return token(w, "cn", number),
+        Content::Apply(apply) => apply,
+    };
+
+    w.before_start()?;
+    w.write_str("<apply>")?;
+    w.after_start(false)?;
+
+    match apply.op() {
+        ContentOp::Function(name) => token(w, "ci", name)?,
+        op => {
+            w.before_start()?;
+            write!(w, "<{}/>", op.name().unwrap_or_default())?;
+        }
+    }
+
+    for bvar in apply.bvar() {
+        qualifier(w, "bvar", bvar)?;
+    }
+
+    if let Some(lowlimit) = apply.lowlimit() {
+        qualifier(w, "lowlimit", lowlimit)?;
+    }
+
+    if let Some(uplimit) = apply.uplimit() {
+        qualifier(w, "uplimit", uplimit)?;
+    }
+
+    if let Some(degree) = apply.degree() {
+        qualifier(w, "degree", degree)?;
+    }
+
+    for arg in apply.args() {
+        content_tree(w, arg)?;
+    }
+
+    w.before_end(false)?;
+    w.write_str("</apply>")
+}
+
+pub(crate) fn error<W: Markup + ?Sized>(w: &mut W, error: &Error) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<merror")?;
+
+    for attr in error.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, error.content())?;
+    w.before_end(false)?;
+    w.write_str("</merror>")
+}
+
+pub(crate) fn frac<W: Markup + ?Sized>(w: &mut W, frac: &Frac) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mfrac")?;
+
+    for attr in frac.attributes().iter() {
+        w.write_str(" ")?;
+
+        match attr {
+            FracAttr::Global(ga) => attribute(w, ga)?,
+            FracAttr::LineThickness(lt) => {
+                attr_value(w, "linethickness", lt)?;
+            }
+        }
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, frac.num())?;
+    elements(w, frac.denom())?;
+    w.before_end(false)?;
+    w.write_str("</mfrac>")
+}
+
+pub(crate) fn ident<W: Markup + ?Sized>(w: &mut W, ident: &Ident) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mi")?;
+
+    for attr in ident.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(true)?;
+    content(w, ident.ident())?;
+
+    w.before_end(true)?;
+    w.write_str("</mi>")?;
+
+    Ok(())
+}
+
+pub(crate) fn multiscripts<W: Markup + ?Sized>(
+    w: &mut W,
+    multiscripts: &Multiscripts,
+) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mmultiscripts")?;
+
+    for attr in multiscripts.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, multiscripts.content())?;
+    w.before_end(false)?;
+    w.write_str("</mmultiscripts>")?;
+
+    Ok(())
+}
+
+pub(crate) fn prescripts<W: Markup + ?Sized>(w: &mut W, prescripts: &Prescripts) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mprescripts")?;
+
+    for attr in prescripts.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str("/>")?;
+
+    Ok(())
+}
+
+pub(crate) fn num<W: Markup + ?Sized>(w: &mut W, num: &Num) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mn")?;
+
+    for attr in num.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(true)?;
+    content(w, num.num())?;
+    w.before_end(true)?;
+    w.write_str("</mn>")?;
+
+    Ok(())
+}
+
+pub(crate) fn operator<W: Markup + ?Sized>(w: &mut W, operator: &Operator) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mo")?;
+
+    for attr in operator.attributes().iter() {
+        w.write_str(" ")?;
+
+        match attr {
+            OperatorAttr::Global(ga) => attribute(w, ga)?,
+            OperatorAttr::Form(form) => write!(w, r#"form="{form}""#)?,
+            OperatorAttr::Fence => write!(w, "fence=\"true\"")?,
+            OperatorAttr::Separator => write!(w, "separator=\"true\"")?,
+            OperatorAttr::LeftSpace(sp) => attr_value(w, "lspace", sp)?,
+            OperatorAttr::RightSpace(sp) => attr_value(w, "rspace", sp)?,
+            OperatorAttr::MaxSize(s) => attr_value(w, "maxsize", s)?,
+            OperatorAttr::MinSize(s) => attr_value(w, "minsize", s)?,
+            OperatorAttr::Stretchy => w.write_str("stretchy=\"true\"")?,
+            OperatorAttr::Symmetric => w.write_str("symmetric=\"true\"")?,
+            OperatorAttr::LargeOp => w.write_str("largeop=\"true\"")?,
+            OperatorAttr::MovableLimits => w.write_str("movablelimits=\"true\"")?,
+        }
+    }
+
+    w.write_str(">")?;
+    w.after_start(true)?;
+    content(w, operator.op())?;
+    w.before_end(true)?;
+    w.write_str("</mo>")?;
+
+    Ok(())
+}
+
+pub(crate) fn padded<W: Markup + ?Sized>(w: &mut W, padded: &Padded) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mpadded")?;
+
+    for attr in padded.attributes().iter() {
+        w.write_str(" ")?;
+
+        match attr {
+            PaddedAttr::Width(width) => attr_value(w, "width", width)?,
+            PaddedAttr::Height(h) => attr_value(w, "height", h)?,
+            PaddedAttr::Depth(d) => attr_value(w, "depth", d)?,
+            PaddedAttr::LeftSpace(ls) => attr_value(w, "lspace", ls)?,
+            PaddedAttr::VerticalOffset(voffs) => attr_value(w, "voffset", voffs)?,
+            PaddedAttr::Global(ga) => attribute(w, ga)?,
+        }
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, padded.children())?;
+    w.before_end(false)?;
+    w.write_str("</mpadded>")
+}
+
+pub(crate) fn phantom<W: Markup + ?Sized>(w: &mut W, phantom: &Phantom) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mphantom")?;
+
+    for attr in phantom.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, phantom.children())?;
+    w.before_end(false)?;
+    w.write_str("</mphantom>")
+}
+
+pub(crate) fn radical<W: Markup + ?Sized>(w: &mut W, radical: &Radical) -> fmt::Result {
+    if radical.is_square() {
+        w.before_start()?;
+        w.write_str("<msqrt")?;
+    } else {
+        w.before_start()?;
+        w.write_str("<mroot")?;
+    }
+
+    for attr in radical.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, radical.content())?;
+
+    if radical.is_square() {
+        w.before_end(false)?;
+        w.write_str("</msqrt>")
+    } else {
+        elements(w, radical.index())?;
+        w.before_end(false)?;
+        w.write_str("</mroot>")
+    }
+}
+
+pub(crate) fn row<W: Markup + ?Sized>(w: &mut W, row: &Row) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mrow")?;
+
+    for attr in row.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, row.children())?;
+    w.before_end(false)?;
+    w.write_str("</mrow>")
+}
+
+pub(crate) fn semantics<W: Markup + ?Sized>(w: &mut W, semantics: &Semantics) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<semantics")?;
+
+    for attr in semantics.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, semantics.children())?;
+    w.before_end(false)?;
+    w.write_str("</semantics>")
+}
+
+pub(crate) fn space<W: Markup + ?Sized>(w: &mut W, space: &Space) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mspace")?;
+
+    for attr in space.attributes().iter() {
+        w.write_str(" ")?;
+
+        match attr {
+            SpaceAttr::Width(width) => attr_value(w, "width", width)?,
+            SpaceAttr::Height(h) => attr_value(w, "height", h)?,
+            SpaceAttr::Depth(d) => attr_value(w, "depth", d)?,
+            SpaceAttr::Global(ref ga) => attribute(w, ga)?,
+        }
+    }
+
+    w.write_str("/>")
+}
+
+pub(crate) fn str_literal<W: Markup + ?Sized>(w: &mut W, str_literal: &StrLiteral) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<ms")?;
+
+    for attr in str_literal.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(true)?;
+    content(w, str_literal.content())?;
+    w.before_end(true)?;
+    w.write_str("</ms>")
+}
+
+pub(crate) fn style<W: Markup + ?Sized>(w: &mut W, style: &Style) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mstyle")?;
+
+    for attr in style.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, style.children())?;
+    w.before_end(false)?;
+    w.write_str("</mstyle>")
+}
+
+pub(crate) fn subsup<W: Markup + ?Sized>(w: &mut W, sub_sup: &SubSup) -> fmt::Result {
+    let sub = sub_sup.sub();
+    let sup = sub_sup.sup();
+
+    let write_attr = |w: &mut W| -> fmt::Result {
+        for attr in sub_sup.attributes().iter() {
+            w.write_str(" ")?;
+            attribute(w, attr)?;
+        }
+
+        Ok(())
+    };
+
+    match (sub, sup) {
+        (None, None) => unreachable!("SubSup element must have at least one of sub or sup."),
+        (None, Some(sup)) => {
+            w.before_start()?;
+            w.write_str("<msup")?;
+            write_attr(w)?;
+            w.write_str(">")?;
+            w.after_start(false)?;
+            elements(w, sub_sup.base())?;
+            elements(w, sup)?;
+            w.before_end(false)?;
+            w.write_str("</msup>")
+        }
+        (Some(sub), None) => {
+            w.before_start()?;
+            w.write_str("<msub")?;
+            write_attr(w)?;
+            w.write_str(">")?;
+            w.after_start(false)?;
+            elements(w, sub_sup.base())?;
+            elements(w, sub)?;
+            w.before_end(false)?;
+            w.write_str("</msub>")
+        }
+        (Some(sub), Some(sup)) => {
+            w.before_start()?;
+            w.write_str("<msubsup")?;
+            write_attr(w)?;
+            w.write_str(">")?;
+            w.after_start(false)?;
+            elements(w, sub_sup.base())?;
+            elements(w, sub)?;
+            elements(w, sup)?;
+            w.before_end(false)?;
+            w.write_str("</msubsup>")
+        }
+    }
+}
+
+pub(crate) fn table<W: Markup + ?Sized>(w: &mut W, table: &Table) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mtable")?;
+
+    for table_attr in table.attributes().iter() {
+        w.write_str(" ")?;
+
+        match table_attr {
+            TableAttr::ColumnLines(cl) => {
+                w.write_str("columnlines=\"")?;
+                for line in cl.iter() {
+                    w.write_str(line.as_ref())?;
+                    w.write_str(" ")?;
+                }
+
+                w.write_str("\"")?;
+            }
+            TableAttr::Global(ref ga) => attribute(w, ga)?,
+        }
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+
+    for row in table.rows() {
+        w.before_start()?;
+        w.write_str("<mtr")?;
+
+        for row_attr in row.attributes().iter() {
+            w.write_str(" ")?;
+            attribute(w, row_attr)?;
+        }
+
+        w.write_str(">")?;
+        w.after_start(false)?;
+
+        for cell in row.cells() {
+            w.before_start()?;
+            w.write_str("<mtd")?;
+
+            for cell_attr in cell.attributes().iter() {
+                w.write_str(" ")?;
+
+                match cell_attr {
+                    TableCellAttr::ColumnSpan(cs) => attr_value(w, "columnspan", cs)?,
+                    TableCellAttr::RowSpan(rs) => attr_value(w, "rowspan", rs)?,
+                    TableCellAttr::Global(ref ga) => attribute(w, ga)?,
+                }
+            }
+
+            w.write_str(">")?;
+            w.after_start(false)?;
+            elements(w, cell.children())?;
+            w.before_end(false)?;
+            w.write_str("</mtd>")?;
+        }
+
+        w.before_end(false)?;
+        w.write_str("</mtr>")?;
+    }
+
+    w.before_end(false)?;
+    w.write_str("</mtable>")
+}
+
+pub(crate) fn text<W: Markup + ?Sized>(w: &mut W, text: &Text) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<mtext")?;
+
+    for attr in text.attributes().iter() {
+        w.write_str(" ")?;
+        attribute(w, attr)?;
+    }
+
+    w.write_str(">")?;
+    w.after_start(true)?;
+    content(w, text.text())?;
+    w.before_end(true)?;
+    w.write_str("</mtext>")
+}
+
+pub(crate) fn underover<W: Markup + ?Sized>(w: &mut W, under_over: &UnderOver) -> fmt::Result {
+    let under = under_over.under();
+    let over = under_over.over();
+
+    let write_attr = |w: &mut W| -> fmt::Result {
+        for attr in under_over.attributes().iter() {
+            w.write_str(" ")?;
+
+            match attr {
+                UnderOverAttr::AccentUnder => w.write_str(r#"accentunder="true""#)?,
+                UnderOverAttr::AccentOver => w.write_str(r#"accent="true""#)?,
+                UnderOverAttr::Global(ref ga) => attribute(w, ga)?,
+            }
+        }
+
+        Ok(())
+    };
+
+    match (under, over) {
+        (None, None) => unreachable!("UnderOver element must have at least one of under or over."),
+        (None, Some(over)) => {
+            w.before_start()?;
+            w.write_str("<mover")?;
+            write_attr(w)?;
+            w.write_str(">")?;
+            w.after_start(false)?;
+            elements(w, under_over.expr())?;
+            elements(w, over)?;
+            w.before_end(false)?;
+            w.write_str("</mover>")
+        }
+        (Some(under), None) => {
+            w.before_start()?;
+            w.write_str("<munder")?;
+            write_attr(w)?;
+            w.write_str(">")?;
+            w.after_start(false)?;
+            elements(w, under_over.expr())?;
+            elements(w, under)?;
+            w.before_end(false)?;
+            w.write_str("</munder>")
+        }
+        (Some(under), Some(over)) => {
+            w.before_start()?;
+            w.write_str("<munderover")?;
+            write_attr(w)?;
+            w.write_str(">")?;
+            w.after_start(false)?;
+            elements(w, under_over.expr())?;
+            elements(w, under)?;
+            elements(w, over)?;
+            w.before_end(false)?;
+            w.write_str("</munderover>")
+        }
+    }
+}
+
+pub(crate) fn attribute<W: Markup + ?Sized>(w: &mut W, attr: &Attribute) -> fmt::Result {
+    match attr {
+        Attribute::Class(c) => attr_value(w, "class", c),
+        Attribute::Data { name, value } => {
+            attr_name(w, "data-", name)?;
+            quoted(w, value)
+        }
+        Attribute::Dir(dir) => match dir {
+            Dir::RightToLeft => w.write_str(r#"dir="rtl""#),
+            Dir::LeftToRight => w.write_str(r#"dir="ltr""#),
+        },
+        Attribute::DisplayStyle(d) => {
+            if *d {
+                w.write_str(r#"display="normal""#)
+            } else {
+                w.write_str(r#"display="compact""#)
+            }
+        }
+        Attribute::Id(id) => attr_value(w, "id", id),
+        Attribute::MathBackground(c) => attr_value(w, "mathbackground", c),
+        Attribute::MathColor(c) => attr_value(w, "mathcolor", c),
+        Attribute::MathSize(s) => attr_value(w, "mathsize", s),
+        Attribute::Nonce(n) => attr_value(w, "nonce", n),
+        Attribute::ScriptLevel(sl) => match sl {
+            ScriptLevel::Add(num) => write!(w, r#"scriptlevel="+{}""#, num),
+            ScriptLevel::Sub(num) => write!(w, r#"scriptlevel="-{}""#, num),
+            ScriptLevel::Num(num) => write!(w, r#"scriptlevel="{}""#, num),
+        },
+        Attribute::Style(st) => attr_value(w, "style", st),
+        Attribute::TabIndex(ti) => write!(w, r#"tabindex="{ti}""#),
+        Attribute::OnHandler { name, handler } => {
+            attr_name(w, "on", name)?;
+            quoted(w, handler)
+        }
+        Attribute::MathVariant(mv) => write!(w, r#"mathvariant="{mv}""#),
+    }
+}
+
+pub(crate) fn mathml<W: Markup + ?Sized>(w: &mut W, mathml: &MathMl) -> fmt::Result {
+    w.before_start()?;
+    w.write_str("<math")?;
+
+    for attr in mathml.attr.iter() {
+        w.write_str(" ")?;
+
+        match attr {
+            MathMlAttr::Display(d) => {
+                write!(w, r#"display=""#)?;
+                match d {
+                    DisplayAttr::Block => write!(w, r#"block""#)?,
+                    DisplayAttr::Inline => write!(w, r#"inline""#)?,
+                }
+            }
+            MathMlAttr::AltText(alt_t) => attr_value(w, "alttext", alt_t)?,
+            MathMlAttr::AriaLabel(label) => attr_value(w, "aria-label", label)?,
+            MathMlAttr::Global(a) => attribute(w, a)?,
+        }
+    }
+
+    let (alttext, aria_label) = mathml.missing_alttext(w.generated_alttext());
+    if alttext || aria_label {
+        let text = mathml.generated_alttext();
+
+        if alttext {
+            w.write_str(" ")?;
+            attr_value(w, "alttext", &text)?;
+        }
+
+        if aria_label {
+            w.write_str(" ")?;
+            attr_value(w, "aria-label", &text)?;
+        }
+    }
+
+    w.write_str(">")?;
+    w.after_start(false)?;
+    elements(w, mathml.content())?;
+    w.before_end(false)?;
+    w.write_str("</math>")
+}
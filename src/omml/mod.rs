@@ -0,0 +1,57 @@
+//! Conversion between MathML elements and Office Math Markup Language (OMML), the equation format
+//! of Microsoft Word documents.
+//!
+//! The [`OmmlFormatter`] renders elements into an `m:oMath` element (wrapped in `m:oMathPara`
+//! for formulas displayed as block), which can be placed into a paragraph of a `docx` document.
+//! The [`parse`] and [`parse_elements`] functions read equations from such documents, so that
+//! they can be written as MathML.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{Frac, Ident, Num};
+//! use alemat::omml::{self, OmmlFormatter};
+//! use alemat::{children, MathMl};
+//!
+//! let math = MathMl::with_content(children![Frac::builder()
+//!     .num(Ident::from("x"))
+//!     .denom(Num::from(2))
+//!     .build()]);
+//!
+//! let omml = math.render_with(&mut OmmlFormatter::default()).unwrap();
+//! assert_eq!(
+//!     omml,
+//!     concat!(
+//!         r#"<m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">"#,
+//!         "<m:f><m:num><m:r><m:t>x</m:t></m:r></m:num>",
+//!         "<m:den><m:r><m:t>2</m:t></m:r></m:den></m:f>",
+//!         "</m:oMath>",
+//!     )
+//! );
+//!
+//! assert_eq!(omml::parse(&omml).unwrap(), math);
+//! ```
+
+mod parser;
+mod renderer;
+
+pub use renderer::OmmlFormatter;
+
+use crate::{Elements, MathMl, ParseError};
+
+/// Parse an OMML equation into a [`MathMl`] element.
+///
+/// The root element of the input must be either `m:oMath` or `m:oMathPara`. Equations in an
+/// `m:oMathPara` are displayed as block, multiple equations are placed into the rows of a
+/// [`Table`](crate::elements::Table).
+pub fn parse(input: &str) -> Result<MathMl, ParseError> {
+    parser::parse(input)
+}
+
+/// Parse an OMML fragment into [`Elements`].
+///
+/// The root element of the input can be any OMML element, e.g. `m:f` or `m:r`. The content of
+/// `m:oMath` and `m:oMathPara` elements is returned without the surrounding element.
+pub fn parse_elements(input: &str) -> Result<Elements, ParseError> {
+    parser::parse_elements(input)
+}
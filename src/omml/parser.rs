@@ -0,0 +1,605 @@
+//! Conversion of OMML markup into MathML elements.
+
+use crate::{
+    attributes::{Attribute, Length, MathVariant},
+    elements::{
+        grouping::{Phantom, Prescripts, Row},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Frac, FracAttr, Ident, Num, OpForm, Operator, OperatorAttr, Table, TableCell, TableRow,
+        Text,
+    },
+    xml::{self, XmlElement, XmlNode},
+    DisplayAttr, Element, Elements, MathMl, MathMlAttr, ParseError, ParseErrorKind,
+};
+
+pub(super) fn parse(input: &str) -> Result<MathMl, ParseError> {
+    let root = xml::parse(input)?;
+    let parser = OmmlParser { input };
+
+    match root.local_name() {
+        "oMathPara" => Ok(MathMl::with_content(parser.paragraph(&root)?)
+            .with_attr([MathMlAttr::Display(DisplayAttr::Block)])),
+        "oMath" => Ok(MathMl::with_content(parser.content(&root)?)),
+        _ => Err(parser.unexpected("#document", &root)),
+    }
+}
+
+pub(super) fn parse_elements(input: &str) -> Result<Elements, ParseError> {
+    let root = xml::parse(input)?;
+    let parser = OmmlParser { input };
+
+    let elements = match root.local_name() {
+        "oMathPara" => parser.paragraph(&root)?,
+        _ => parser.element(&root)?,
+    };
+
+    Ok(Elements(elements))
+}
+
+/// Converts the parsed XML tree of OMML markup into MathML elements.
+///
+/// Elements are matched by their local name, so any namespace prefix can be used. Property
+/// elements (e.g. `m:fPr`) are read by the element they belong to and skipped otherwise. Missing
+/// arguments (e.g. an `m:sup` without content) are treated as empty.
+struct OmmlParser<'a> {
+    input: &'a str,
+}
+
+impl OmmlParser<'_> {
+    fn error(&self, kind: ParseErrorKind, offset: usize) -> ParseError {
+        ParseError::new(kind, self.input, offset)
+    }
+
+    fn unexpected(&self, parent: &str, el: &XmlElement) -> ParseError {
+        self.error(
+            ParseErrorKind::UnexpectedElement {
+                parent: parent.to_string(),
+                element: el.name.clone(),
+            },
+            el.offset,
+        )
+    }
+
+    /// Parse the equations of an `m:oMathPara`. Multiple equations are placed into the rows of a
+    /// table.
+    fn paragraph(&self, el: &XmlElement) -> Result<Vec<Element>, ParseError> {
+        let mut equations = Vec::new();
+
+        for child in el.child_elements() {
+            match child.local_name() {
+                "oMath" => equations.push(self.content(child)?),
+                name if name.ends_with("Pr") => {}
+                _ => return Err(self.unexpected(&el.name, child)),
+            }
+        }
+
+        if equations.len() == 1 {
+            return Ok(equations.remove(0));
+        }
+
+        let table = equations
+            .into_iter()
+            .map(|equation| TableRow::from_iter([TableCell::from(Elements(equation))]))
+            .collect::<Table>();
+
+        Ok(vec![table.into()])
+    }
+
+    /// Parse the child elements of an element containing math, e.g. `m:oMath` or `m:e`,
+    /// rejecting any non-whitespace text.
+    fn content(&self, el: &XmlElement) -> Result<Vec<Element>, ParseError> {
+        let mut elements = Vec::new();
+
+        for node in el.children.iter() {
+            match node {
+                XmlNode::Element(child) if child.local_name().ends_with("Pr") => {}
+                XmlNode::Element(child) => elements.extend(self.element(child)?),
+                XmlNode::Text { text, .. } if text.trim().is_empty() => {}
+                XmlNode::Text { text, offset } => {
+                    return Err(self.error(
+                        ParseErrorKind::UnexpectedText(text.trim().to_string()),
+                        *offset,
+                    ))
+                }
+            }
+        }
+
+        Ok(elements)
+    }
+
+    /// Parse the content of the argument with the given name, e.g. `m:num` of `m:f`.
+    fn arg(&self, el: &XmlElement, name: &str) -> Result<Vec<Element>, ParseError> {
+        match el.child_elements().find(|child| child.local_name() == name) {
+            Some(arg) => self.content(arg),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Parse the content of an optional argument, which is `None` if it is hidden with the given
+    /// property or empty.
+    fn optional_arg(
+        &self,
+        el: &XmlElement,
+        name: &str,
+        hide: &str,
+    ) -> Result<Option<Vec<Element>>, ParseError> {
+        if flag(el, hide) {
+            return Ok(None);
+        }
+
+        let arg = self.arg(el, name)?;
+        Ok((!arg.is_empty()).then_some(arg))
+    }
+
+    /// Parse a single OMML element, which can produce multiple MathML elements, e.g. the
+    /// characters of a run or a large operator followed by its operand.
+    fn element(&self, el: &XmlElement) -> Result<Vec<Element>, ParseError> {
+        let element: Element = match el.local_name() {
+            "oMath" | "box" | "borderBox" => return self.content_or_arg(el),
+            "r" => return self.run(el),
+            "nary" => return self.nary(el),
+            "func" => {
+                let mut elements = self.arg(el, "fName")?;
+                elements.push(Operator::from("\u{2061}").into());
+                elements.extend(self.arg(el, "e")?);
+                return Ok(elements);
+            }
+            "phant" => {
+                let content = self.arg(el, "e")?;
+
+                // phantoms are shown by default and only take up no space
+                if !matches!(prop(el, "show"), Some("0" | "off" | "false")) {
+                    return Ok(content);
+                }
+
+                Phantom::from(Elements(content)).into()
+            }
+            "f" => {
+                let frac = Frac::builder()
+                    .num(self.arg(el, "num")?)
+                    .denom(self.arg(el, "den")?);
+
+                match prop(el, "type") {
+                    Some("noBar") => frac
+                        .attr([FracAttr::LineThickness(Length::unitless(0.0).into())])
+                        .build()
+                        .into(),
+                    _ => frac.build().into(),
+                }
+            }
+            "rad" => {
+                let index = match self.optional_arg(el, "deg", "degHide")? {
+                    Some(index) => index,
+                    None => vec![Num::from(2).into()],
+                };
+
+                Radical::builder()
+                    .index(index)
+                    .content(self.arg(el, "e")?)
+                    .build()
+                    .into()
+            }
+            "sSub" => SubSup::builder()
+                .base(self.arg(el, "e")?)
+                .subscript(self.arg(el, "sub")?)
+                .build()
+                .into(),
+            "sSup" => SubSup::builder()
+                .base(self.arg(el, "e")?)
+                .supscript(self.arg(el, "sup")?)
+                .build()
+                .into(),
+            "sSubSup" => SubSup::builder()
+                .base(self.arg(el, "e")?)
+                .subscript(self.arg(el, "sub")?)
+                .supscript(self.arg(el, "sup")?)
+                .build()
+                .into(),
+            "sPre" => Multiscripts::builder()
+                .content([
+                    single(self.arg(el, "e")?),
+                    Prescripts::default().into(),
+                    single(self.arg(el, "sub")?),
+                    single(self.arg(el, "sup")?),
+                ])
+                .build()
+                .into(),
+            "d" => self.delimiter(el)?.into(),
+            "m" => self.matrix(el)?.into(),
+            "eqArr" => self.equation_array(el)?.into(),
+            "acc" => {
+                let chr = prop(el, "chr").unwrap_or("\u{302}");
+                let chr = spacing_accent(chr).unwrap_or(chr);
+
+                UnderOver::builder()
+                    .expr(self.arg(el, "e")?)
+                    .over(Operator::from(chr))
+                    .attr([UnderOverAttr::AccentOver])
+                    .build()
+                    .into()
+            }
+            "bar" => {
+                let builder = UnderOver::builder().expr(self.arg(el, "e")?);
+
+                match prop(el, "pos") {
+                    Some("top") => builder
+                        .over(Operator::from("¯"))
+                        .attr([UnderOverAttr::AccentOver]),
+                    _ => builder
+                        .under(Operator::from("_"))
+                        .attr([UnderOverAttr::AccentUnder]),
+                }
+                .build()
+                .into()
+            }
+            "groupChr" => {
+                let chr = prop(el, "chr").unwrap_or("⏟");
+                let builder = UnderOver::builder().expr(self.arg(el, "e")?);
+
+                match prop(el, "pos") {
+                    Some("top") => builder.over(Operator::from(chr)),
+                    _ => builder.under(Operator::from(chr)),
+                }
+                .build()
+                .into()
+            }
+            "limLow" => UnderOver::builder()
+                .expr(self.arg(el, "e")?)
+                .under(self.arg(el, "lim")?)
+                .build()
+                .into(),
+            "limUpp" => UnderOver::builder()
+                .expr(self.arg(el, "e")?)
+                .over(self.arg(el, "lim")?)
+                .build()
+                .into(),
+            // other markup of the document inside of equations, e.g. bookmarks
+            _ if el.name.starts_with("w:") => return Ok(Vec::new()),
+            _ => return Err(self.error(ParseErrorKind::UnknownElement(el.name.clone()), el.offset)),
+        };
+
+        Ok(vec![element])
+    }
+
+    /// Parse the `m:e` argument of a box, or the content of an equation.
+    fn content_or_arg(&self, el: &XmlElement) -> Result<Vec<Element>, ParseError> {
+        match el.local_name() {
+            "oMath" => self.content(el),
+            _ => self.arg(el, "e"),
+        }
+    }
+
+    /// Split the text of a run into identifiers, numbers and operators. Text runs (`m:nor`) and
+    /// runs of the document (`w:r`) are parsed as [`Text`].
+    fn run(&self, el: &XmlElement) -> Result<Vec<Element>, ParseError> {
+        let mut text = String::new();
+
+        for t in el
+            .child_elements()
+            .filter(|child| child.local_name() == "t")
+        {
+            for node in t.children.iter() {
+                if let XmlNode::Text { text: t, .. } = node {
+                    text.push_str(t);
+                }
+            }
+        }
+
+        if text.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if flag(el, "nor") || el.name.starts_with("w:") {
+            return Ok(vec![Text::from(text).into()]);
+        }
+
+        let sty = prop(el, "sty");
+        let variant = variant(prop(el, "scr"), sty);
+
+        // the default style of identifiers with a single letter is italic, upright otherwise
+        let ident = |ident: String| {
+            let default = match ident.chars().count() {
+                1 => MathVariant::Italic,
+                _ => MathVariant::Normal,
+            };
+
+            let builder = Ident::builder().ident(ident);
+            match variant == default {
+                true => builder.build(),
+                false => builder
+                    .attr([Attribute::MathVariant(variant.clone())])
+                    .build(),
+            }
+            .into()
+        };
+
+        // numbers and operators are upright, even in the italic runs used by default
+        let styled = !matches!(variant, MathVariant::Normal | MathVariant::Italic);
+        let attr = || Attribute::MathVariant(variant.clone());
+
+        let chars: Vec<char> = text.chars().collect();
+        let mut elements = Vec::new();
+        let mut idx = 0;
+
+        while idx < chars.len() {
+            let c = chars[idx];
+            let start = idx;
+            idx += 1;
+
+            if c.is_whitespace() {
+                continue;
+            }
+
+            if c.is_ascii_digit() {
+                while idx < chars.len() {
+                    let decimal = chars[idx] == '.'
+                        && chars.get(idx + 1).is_some_and(|next| next.is_ascii_digit());
+
+                    if !chars[idx].is_ascii_digit() && !decimal {
+                        break;
+                    }
+
+                    idx += 1;
+                }
+
+                let num: String = chars[start..idx].iter().collect();
+                let num = Num::from(num.as_str());
+                elements.push(match styled {
+                    true => num.with_attr([attr()]).into(),
+                    false => num.into(),
+                });
+            } else if c.is_alphabetic() {
+                // upright letters form words, e.g. function names like `sin`
+                if sty == Some("p") {
+                    while idx < chars.len() && chars[idx].is_alphabetic() {
+                        idx += 1;
+                    }
+                }
+
+                elements.push(ident(chars[start..idx].iter().collect()));
+            } else {
+                let op = Operator::builder().op(c);
+                elements.push(match styled {
+                    true => op.attr([OperatorAttr::Global(attr())]).build().into(),
+                    false => op.build().into(),
+                });
+            }
+        }
+
+        Ok(elements)
+    }
+
+    /// Parse a large operator, followed by its operand.
+    fn nary(&self, el: &XmlElement) -> Result<Vec<Element>, ParseError> {
+        let chr = prop(el, "chr").filter(|chr| !chr.is_empty()).unwrap_or("∫");
+        let op = Operator::from(chr);
+
+        let integral = matches!(chr, "∫" | "∬" | "∭" | "∮" | "∯" | "∰");
+        let under_over = match prop(el, "limLoc") {
+            Some(loc) => loc == "undOvr",
+            None => !integral,
+        };
+
+        let sub = self.optional_arg(el, "sub", "subHide")?;
+        let sup = self.optional_arg(el, "sup", "supHide")?;
+
+        let op: Element = match (sub, sup) {
+            (None, None) => op.into(),
+            (Some(sub), None) if under_over => {
+                UnderOver::builder().expr([op]).under(sub).build().into()
+            }
+            (None, Some(sup)) if under_over => {
+                UnderOver::builder().expr([op]).over(sup).build().into()
+            }
+            (Some(sub), Some(sup)) if under_over => UnderOver::builder()
+                .expr([op])
+                .under(sub)
+                .over(sup)
+                .build()
+                .into(),
+            (Some(sub), None) => SubSup::builder().base([op]).subscript(sub).build().into(),
+            (None, Some(sup)) => SubSup::builder().base([op]).supscript(sup).build().into(),
+            (Some(sub), Some(sup)) => SubSup::builder()
+                .base([op])
+                .subscript(sub)
+                .supscript(sup)
+                .build()
+                .into(),
+        };
+
+        let mut elements = vec![op];
+        elements.extend(self.arg(el, "e")?);
+
+        Ok(elements)
+    }
+
+    /// Parse a delimiter into a row with stretchy fences. Multiple arguments are separated by the
+    /// separator character.
+    fn delimiter(&self, el: &XmlElement) -> Result<Row, ParseError> {
+        let open = prop(el, "begChr").unwrap_or("(");
+        let close = prop(el, "endChr").unwrap_or(")");
+        let separator = prop(el, "sepChr").unwrap_or("|");
+
+        let mut elements = Vec::new();
+
+        if !open.is_empty() {
+            elements.push(fence(open, OpForm::Prefix));
+        }
+
+        for (idx, arg) in el
+            .child_elements()
+            .filter(|child| child.local_name() == "e")
+            .enumerate()
+        {
+            if idx > 0 && !separator.is_empty() {
+                let separator = Operator::builder()
+                    .op(separator)
+                    .attr([OperatorAttr::Separator])
+                    .build();
+
+                elements.push(separator.into());
+            }
+
+            elements.extend(self.content(arg)?);
+        }
+
+        if !close.is_empty() {
+            elements.push(fence(close, OpForm::Postfix));
+        }
+
+        Ok(Row::from(Elements(elements)))
+    }
+
+    fn matrix(&self, el: &XmlElement) -> Result<Table, ParseError> {
+        let mut rows = Vec::new();
+
+        for row in el.child_elements() {
+            match row.local_name() {
+                "mr" => {
+                    let cells = row
+                        .child_elements()
+                        .filter(|child| child.local_name() == "e")
+                        .map(|cell| {
+                            self.content(cell)
+                                .map(|cell| TableCell::from(Elements(cell)))
+                        })
+                        .collect::<Result<TableRow, _>>()?;
+
+                    rows.push(cells);
+                }
+                name if name.ends_with("Pr") => {}
+                _ => return Err(self.unexpected(&el.name, row)),
+            }
+        }
+
+        Ok(Table::from_iter(rows))
+    }
+
+    /// Parse an equation array into a table. The `&` characters of the equations mark the
+    /// alignment points, which are turned into columns aligned alternately right and left.
+    fn equation_array(&self, el: &XmlElement) -> Result<Table, ParseError> {
+        let rows = el
+            .child_elements()
+            .filter(|child| child.local_name() == "e")
+            .map(|row| {
+                let content = self.content(row)?;
+                let cells: Vec<Vec<Element>> = content
+                    .split(|element| matches!(element, Element::Operator(op) if op.op() == "&"))
+                    .map(<[Element]>::to_vec)
+                    .collect();
+
+                Ok(cells)
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let aligned = rows.iter().any(|cells| cells.len() > 1);
+
+        let table = rows
+            .into_iter()
+            .map(|cells| {
+                cells
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, cell)| {
+                        let cell = TableCell::from(Elements(cell));
+                        let align = match idx % 2 {
+                            0 => "text-align: right",
+                            _ => "text-align: left",
+                        };
+
+                        match aligned {
+                            true => cell.with_attr([Attribute::Style(align.into())]),
+                            false => cell,
+                        }
+                    })
+                    .collect::<TableRow>()
+            })
+            .collect();
+
+        Ok(table)
+    }
+}
+
+/// The `m:val` attribute of the property with the given name, e.g. `∑` for
+/// `<m:naryPr><m:chr m:val="∑"/></m:naryPr>`. Properties without the attribute have an empty
+/// value.
+fn prop<'el>(el: &'el XmlElement, name: &str) -> Option<&'el str> {
+    el.child_elements()
+        .filter(|child| child.local_name().ends_with("Pr"))
+        .flat_map(XmlElement::child_elements)
+        .find(|prop| prop.local_name() == name)
+        .map(|prop| {
+            prop.attributes
+                .iter()
+                .find(|attr| attr.name == "val" || attr.name.ends_with(":val"))
+                .map_or("", |attr| attr.value.as_str())
+        })
+}
+
+/// Whether the boolean property with the given name is set. Properties without value are set.
+fn flag(el: &XmlElement, name: &str) -> bool {
+    prop(el, name).is_some_and(|value| !matches!(value, "0" | "off" | "false"))
+}
+
+/// The `mathvariant` of the `m:scr` and `m:sty` run properties. Runs are italic by default.
+fn variant(scr: Option<&str>, sty: Option<&str>) -> MathVariant {
+    let bold = matches!(sty, Some("b" | "bi"));
+
+    match (scr.unwrap_or("roman"), sty.unwrap_or("i")) {
+        ("double-struck", _) => MathVariant::DoubleStruck,
+        ("script", _) if bold => MathVariant::BoldScript,
+        ("script", _) => MathVariant::Script,
+        ("fraktur", _) if bold => MathVariant::BoldFraktur,
+        ("fraktur", _) => MathVariant::Fraktur,
+        ("sans-serif", "b") => MathVariant::BoldSansSerif,
+        ("sans-serif", "i") => MathVariant::SansSerifItalic,
+        ("sans-serif", "bi") => MathVariant::SansSerifBoldItalic,
+        ("sans-serif", _) => MathVariant::SansSerif,
+        ("monospace", _) => MathVariant::Monospace,
+        (_, "p") => MathVariant::Normal,
+        (_, "b") => MathVariant::Bold,
+        (_, "bi") => MathVariant::BoldItalic,
+        _ => MathVariant::Italic,
+    }
+}
+
+/// The spacing character of a combining accent used by `m:acc`.
+fn spacing_accent(chr: &str) -> Option<&'static str> {
+    let accent = match chr {
+        "\u{302}" => "^",
+        "\u{303}" => "~",
+        "\u{307}" => "˙",
+        "\u{308}" => "¨",
+        "\u{30C}" => "ˇ",
+        "\u{301}" => "´",
+        "\u{300}" => "`",
+        "\u{306}" => "˘",
+        "\u{304}" | "\u{305}" => "¯",
+        "\u{20D7}" => "→",
+        _ => return None,
+    };
+
+    Some(accent)
+}
+
+/// Create a stretchy fence for the given character.
+fn fence(op: &str, form: OpForm) -> Element {
+    Operator::builder()
+        .op(op)
+        .attr([
+            OperatorAttr::Stretchy,
+            OperatorAttr::Fence,
+            OperatorAttr::Form(form),
+        ])
+        .build()
+        .into()
+}
+
+/// Turn the elements into a single element, wrapping them into a row if needed.
+fn single(mut elements: Vec<Element>) -> Element {
+    match elements.len() {
+        1 => elements.remove(0),
+        _ => Row::from(Elements(elements)).into(),
+    }
+}
@@ -0,0 +1,732 @@
+//! Conversion of MathML elements into OMML markup.
+
+use std::convert::Infallible;
+
+use crate::{
+    attributes::{Attribute, Length, MathVariant},
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, Frac, FracAttr, Ident, Num, Operator, OperatorAttr, Padded, Semantics, Space,
+        StrLiteral, Table, Text,
+    },
+    escape::{self, EscapeCtx},
+    DisplayAttr, Element, MathMl, MathMlAttr, Renderer,
+};
+
+/// The namespace of the OMML elements.
+const NAMESPACE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/math";
+
+/// [`Renderer`] implementation that turns elements into Office Math Markup Language.
+///
+/// The elements are mapped to their OMML counterparts:
+///
+/// * fractions to `m:f`, radicals to `m:rad` and scripts to `m:sSub`, `m:sSup`, `m:sSubSup` and
+///   `m:sPre`,
+/// * large operators (e.g. `∑`, `∫`) with or without limits to `m:nary`, with the following
+///   expression as its operand,
+/// * accents and bars to `m:acc` and `m:bar`, other under- and overscripts to `m:limLow` and
+///   `m:limUpp`,
+/// * matching fences in a row to `m:d` and tables to `m:m`,
+/// * the `mathvariant` attribute to the `m:sty` and `m:scr` run properties.
+///
+/// Annotations, spaces and attributes have no counterpart in OMML and are dropped. Text content
+/// and attribute values are escaped.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OmmlFormatter {
+    /// The `mathvariant` inherited from enclosing `mstyle` elements.
+    variant: Option<MathVariant>,
+}
+
+/// A large operator with its limits, written as `m:nary`.
+struct Nary<'a> {
+    chr: &'a str,
+    sub: Option<&'a [Element]>,
+    sup: Option<&'a [Element]>,
+
+    /// Whether the limits are placed under and over the operator instead of as scripts.
+    under_over: bool,
+}
+
+impl OmmlFormatter {
+    fn render(&mut self, element: &Element) -> String {
+        let Ok(omml) = self.render_element(element);
+        omml
+    }
+
+    fn row(&mut self, elements: &[Element]) -> String {
+        let mut out = String::new();
+        let mut idx = 0;
+
+        while idx < elements.len() {
+            let (omml, len) = self.item(&elements[idx..]);
+            out.push_str(&omml);
+            idx += len;
+        }
+
+        out
+    }
+
+    /// Render the first item of the elements, returning the markup and the number of elements
+    /// the item consists of. Fenced expressions and large operators with their operand span
+    /// multiple elements.
+    fn item(&mut self, elements: &[Element]) -> (String, usize) {
+        if let Some(close) = closing_fence(elements) {
+            let (Element::Operator(open), Element::Operator(end)) =
+                (&elements[0], &elements[close])
+            else {
+                unreachable!("fences are operators");
+            };
+
+            let content = self.row(&elements[1..close]);
+            let omml = format!(
+                "<m:d><m:dPr>{}{}</m:dPr><m:e>{content}</m:e></m:d>",
+                val("m:begChr", open.op()),
+                val("m:endChr", end.op()),
+            );
+
+            return (omml, close + 1);
+        }
+
+        if let Some(nary) = Nary::new(&elements[0]) {
+            // the operand extends up to the next operator, e.g. `∑ i²` in `∑ i² + 1`
+            let mut operand = String::new();
+            let mut len = 1;
+
+            while let Some(element) = elements.get(len) {
+                if matches!(element, Element::Operator(op) if !is_invisible(op.op()) && !is_fence(op.op()))
+                {
+                    break;
+                }
+
+                let (omml, item) = self.item(&elements[len..]);
+                operand.push_str(&omml);
+                len += item;
+            }
+
+            return (self.nary(&nary, &operand), len);
+        }
+
+        (self.render(&elements[0]), 1)
+    }
+
+    fn nary(&mut self, nary: &Nary, operand: &str) -> String {
+        let mut props = val("m:chr", nary.chr);
+
+        props.push_str(&val(
+            "m:limLoc",
+            if nary.under_over { "undOvr" } else { "subSup" },
+        ));
+
+        if nary.sub.is_none() {
+            props.push_str(&val("m:subHide", "1"));
+        }
+
+        if nary.sup.is_none() {
+            props.push_str(&val("m:supHide", "1"));
+        }
+
+        let sub = nary.sub.map(|sub| self.row(sub)).unwrap_or_default();
+        let sup = nary.sup.map(|sup| self.row(sup)).unwrap_or_default();
+
+        format!(
+            "<m:nary><m:naryPr>{props}</m:naryPr>{}{}{}</m:nary>",
+            wrap("m:sub", &sub),
+            wrap("m:sup", &sup),
+            wrap("m:e", operand),
+        )
+    }
+
+    /// Write text as an `m:r` run, with run properties for the given (or inherited)
+    /// `mathvariant`. Identifiers longer than a single character are written upright, like in
+    /// MathML.
+    fn run(&self, text: &str, attributes: &[Attribute], upright: bool) -> String {
+        let variant = attributes
+            .iter()
+            .find_map(|attr| match attr {
+                Attribute::MathVariant(variant) => Some(variant),
+                _ => None,
+            })
+            .or(self.variant.as_ref());
+
+        let (scr, sty) = match variant {
+            Some(variant) => style(variant),
+            None => (None, upright.then_some("p")),
+        };
+
+        let mut props = String::new();
+        if let Some(scr) = scr {
+            props.push_str(&val("m:scr", scr));
+        }
+        if let Some(sty) = sty {
+            props.push_str(&val("m:sty", sty));
+        }
+
+        if !props.is_empty() {
+            props = format!("<m:rPr>{props}</m:rPr>");
+        }
+
+        format!("<m:r>{props}{}</m:r>", text_element(text))
+    }
+
+    /// Write text that is not part of the formula, e.g. the content of `mtext`.
+    fn normal_text(&self, text: &str) -> String {
+        format!("<m:r><m:rPr><m:nor/></m:rPr>{}</m:r>", text_element(text))
+    }
+
+    fn scripts(&mut self, base: &str, sub: Option<&[Element]>, sup: Option<&[Element]>) -> String {
+        let sub = sub.map(|sub| wrap("m:sub", &self.row(sub)));
+        let sup = sup.map(|sup| wrap("m:sup", &self.row(sup)));
+        let e = wrap("m:e", base);
+
+        match (sub, sup) {
+            (Some(sub), Some(sup)) => format!("<m:sSubSup>{e}{sub}{sup}</m:sSubSup>"),
+            (Some(sub), None) => format!("<m:sSub>{e}{sub}</m:sSub>"),
+            (None, Some(sup)) => format!("<m:sSup>{e}{sup}</m:sSup>"),
+            (None, None) => base.to_string(),
+        }
+    }
+}
+
+impl<'a> Nary<'a> {
+    /// Get the large operator written by the element, if there is one.
+    fn new(element: &'a Element) -> Option<Self> {
+        match element {
+            Element::Operator(op) if is_large(op) => Some(Self {
+                chr: op.op(),
+                sub: None,
+                sup: None,
+                under_over: true,
+            }),
+            Element::SubSup(sub_sup) => {
+                Self::with_limits(sub_sup.base(), sub_sup.sub(), sub_sup.sup(), false)
+            }
+            Element::UnderOver(under_over) => Self::with_limits(
+                under_over.expr(),
+                under_over.under(),
+                under_over.over(),
+                true,
+            ),
+            _ => None,
+        }
+    }
+
+    fn with_limits(
+        base: &'a [Element],
+        sub: Option<&'a [Element]>,
+        sup: Option<&'a [Element]>,
+        under_over: bool,
+    ) -> Option<Self> {
+        match base {
+            [Element::Operator(op)] if is_large(op) => Some(Self {
+                chr: op.op(),
+                sub,
+                sup,
+                under_over,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Renderer for OmmlFormatter {
+    type Output = String;
+    type Error = Infallible;
+
+    fn render_action(&mut self, action: &Action) -> Result<Self::Output, Self::Error> {
+        let selection = action.attributes().iter().find_map(|attr| match attr {
+            ActionAttr::Selection(selection) => selection.trim().parse::<usize>().ok(),
+            _ => None,
+        });
+
+        let content = action.content();
+        let selected = selection
+            .and_then(|idx| content.get(idx.checked_sub(1)?))
+            .or_else(|| content.first());
+
+        match selected {
+            Some(element) => self.render_element(element),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_annotation(&mut self, _annotation: &Annotation) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_error(&mut self, error: &Error) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(error.content()))
+    }
+
+    fn render_frac(&mut self, frac: &Frac) -> Result<Self::Output, Self::Error> {
+        let props = match is_stacked(frac) {
+            true => wrap("m:fPr", &val("m:type", "noBar")),
+            false => String::new(),
+        };
+
+        let num = self.row(frac.num());
+        let denom = self.row(frac.denom());
+
+        Ok(format!(
+            "<m:f>{props}{}{}</m:f>",
+            wrap("m:num", &num),
+            wrap("m:den", &denom)
+        ))
+    }
+
+    fn render_ident(&mut self, ident: &Ident) -> Result<Self::Output, Self::Error> {
+        let upright = ident.ident().chars().count() > 1;
+        Ok(self.run(ident.ident(), ident.attributes(), upright))
+    }
+
+    fn render_multiscripts(
+        &mut self,
+        multiscripts: &Multiscripts,
+    ) -> Result<Self::Output, Self::Error> {
+        let content = multiscripts.content();
+
+        let Some((base, scripts)) = content.split_first() else {
+            return Ok(String::new());
+        };
+
+        let (post, pre) = match scripts
+            .iter()
+            .position(|element| matches!(element, Element::Prescripts(_)))
+        {
+            Some(idx) => (&scripts[..idx], &scripts[idx + 1..]),
+            None => (scripts, &[][..]),
+        };
+
+        // every pair of scripts is nested around the previous ones
+        let mut omml = self.render(base);
+        for pair in post.chunks(2) {
+            let (sub, sup) = script_pair(pair);
+            omml = self.scripts(&omml, sub, sup);
+        }
+
+        for pair in pre.chunks(2).rev() {
+            let (sub, sup) = script_pair(pair);
+            let sub = sub.map(|sub| self.row(sub)).unwrap_or_default();
+            let sup = sup.map(|sup| self.row(sup)).unwrap_or_default();
+
+            omml = format!(
+                "<m:sPre>{}{}{}</m:sPre>",
+                wrap("m:sub", &sub),
+                wrap("m:sup", &sup),
+                wrap("m:e", &omml),
+            );
+        }
+
+        Ok(omml)
+    }
+
+    fn render_prescripts(&mut self, _prescripts: &Prescripts) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_num(&mut self, num: &Num) -> Result<Self::Output, Self::Error> {
+        Ok(self.run(num.num(), num.attributes(), false))
+    }
+
+    fn render_operator(&mut self, operator: &Operator) -> Result<Self::Output, Self::Error> {
+        if is_invisible(operator.op()) {
+            return Ok(String::new());
+        }
+
+        let attributes: Vec<Attribute> = operator
+            .attributes()
+            .iter()
+            .filter_map(|attr| match attr {
+                OperatorAttr::Global(attr) => Some(attr.clone()),
+                _ => None,
+            })
+            .collect();
+
+        Ok(self.run(operator.op(), &attributes, false))
+    }
+
+    fn render_padded(&mut self, padded: &Padded) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(padded.children()))
+    }
+
+    fn render_phantom(&mut self, phantom: &Phantom) -> Result<Self::Output, Self::Error> {
+        let content = self.row(phantom.children());
+
+        Ok(format!(
+            "<m:phant><m:phantPr>{}</m:phantPr>{}</m:phant>",
+            val("m:show", "0"),
+            wrap("m:e", &content)
+        ))
+    }
+
+    fn render_radical(&mut self, radical: &Radical) -> Result<Self::Output, Self::Error> {
+        let content = self.row(radical.content());
+
+        let (props, index) = match radical.is_square() || radical.index().is_empty() {
+            true => (
+                wrap("m:radPr", &val("m:degHide", "1")),
+                String::from("<m:deg/>"),
+            ),
+            false => {
+                let index = self.row(radical.index());
+                (String::new(), wrap("m:deg", &index))
+            }
+        };
+
+        Ok(format!(
+            "<m:rad>{props}{index}{}</m:rad>",
+            wrap("m:e", &content)
+        ))
+    }
+
+    fn render_row(&mut self, row: &Row) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(row.children()))
+    }
+
+    fn render_semantics(&mut self, semantics: &Semantics) -> Result<Self::Output, Self::Error> {
+        match semantics.children().first() {
+            Some(presentation) => self.render_element(presentation),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_space(&mut self, _space: &Space) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_str_literal(
+        &mut self,
+        str_literal: &StrLiteral,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(self.normal_text(&format!("\"{}\"", str_literal.content())))
+    }
+
+    fn render_style(&mut self, style: &Style) -> Result<Self::Output, Self::Error> {
+        let variant = style.attributes().iter().find_map(|attr| match attr {
+            Attribute::MathVariant(variant) => Some(variant.clone()),
+            _ => None,
+        });
+
+        let inherited = self.variant.clone();
+        if variant.is_some() {
+            self.variant = variant;
+        }
+
+        let omml = self.row(style.children());
+        self.variant = inherited;
+
+        Ok(omml)
+    }
+
+    fn render_subsup(&mut self, sub_sup: &SubSup) -> Result<Self::Output, Self::Error> {
+        // large operators without an operand, the operand is handled by the row otherwise
+        if let Some(nary) = Nary::with_limits(sub_sup.base(), sub_sup.sub(), sub_sup.sup(), false) {
+            return Ok(self.nary(&nary, ""));
+        }
+
+        let base = self.row(sub_sup.base());
+        Ok(self.scripts(&base, sub_sup.sub(), sub_sup.sup()))
+    }
+
+    fn render_table(&mut self, table: &Table) -> Result<Self::Output, Self::Error> {
+        let columns = table
+            .rows()
+            .iter()
+            .map(|row| row.cells().len())
+            .max()
+            .unwrap_or_default();
+
+        let props = format!(
+            "<m:mPr><m:mcs><m:mc><m:mcPr>{}{}</m:mcPr></m:mc></m:mcs></m:mPr>",
+            val("m:count", &columns.to_string()),
+            val("m:mcJc", "center"),
+        );
+
+        let rows: String = table
+            .rows()
+            .iter()
+            .map(|row| {
+                // every row of a matrix has the same number of cells
+                let cells: String = (0..columns)
+                    .map(|idx| match row.cells().get(idx) {
+                        Some(cell) => wrap("m:e", &self.row(cell.children())),
+                        None => String::from("<m:e/>"),
+                    })
+                    .collect();
+
+                format!("<m:mr>{cells}</m:mr>")
+            })
+            .collect();
+
+        Ok(format!("<m:m>{props}{rows}</m:m>"))
+    }
+
+    fn render_text(&mut self, text: &Text) -> Result<Self::Output, Self::Error> {
+        Ok(self.normal_text(text.text()))
+    }
+
+    fn render_underover(&mut self, under_over: &UnderOver) -> Result<Self::Output, Self::Error> {
+        let nary = Nary::with_limits(
+            under_over.expr(),
+            under_over.under(),
+            under_over.over(),
+            true,
+        );
+
+        if let Some(nary) = nary {
+            return Ok(self.nary(&nary, ""));
+        }
+
+        let attributes = under_over.attributes();
+        let accent = attributes
+            .iter()
+            .any(|attr| matches!(attr, UnderOverAttr::AccentOver));
+
+        let expr = self.row(under_over.expr());
+
+        let mut omml = match under_over.under() {
+            Some([Element::Operator(op)]) if is_bar(op.op()) => {
+                format!(
+                    "<m:bar><m:barPr>{}</m:barPr>{}</m:bar>",
+                    val("m:pos", "bot"),
+                    wrap("m:e", &expr)
+                )
+            }
+            Some(under) => {
+                let under = self.row(under);
+                format!(
+                    "<m:limLow>{}{}</m:limLow>",
+                    wrap("m:e", &expr),
+                    wrap("m:lim", &under)
+                )
+            }
+            None => expr,
+        };
+
+        omml = match under_over.over() {
+            Some([Element::Operator(op)]) if is_bar(op.op()) => {
+                format!(
+                    "<m:bar><m:barPr>{}</m:barPr>{}</m:bar>",
+                    val("m:pos", "top"),
+                    wrap("m:e", &omml)
+                )
+            }
+            Some([Element::Operator(op)]) if accent || accent_char(op.op()).is_some() => {
+                let chr = accent_char(op.op()).unwrap_or(op.op());
+                format!(
+                    "<m:acc><m:accPr>{}</m:accPr>{}</m:acc>",
+                    val("m:chr", chr),
+                    wrap("m:e", &omml)
+                )
+            }
+            Some(over) => {
+                let over = self.row(over);
+                format!(
+                    "<m:limUpp>{}{}</m:limUpp>",
+                    wrap("m:e", &omml),
+                    wrap("m:lim", &over)
+                )
+            }
+            None => omml,
+        };
+
+        Ok(omml)
+    }
+
+    fn render_attr(&mut self, _attr: &Attribute) -> Result<Self::Output, Self::Error> {
+        // attributes are written as properties of the elements they belong to
+        Ok(String::new())
+    }
+
+    fn render_mathml(&mut self, mathml: &MathMl) -> Result<Self::Output, Self::Error> {
+        self.variant = None;
+
+        let block = mathml
+            .attributes()
+            .iter()
+            .any(|attr| matches!(attr, MathMlAttr::Display(DisplayAttr::Block)));
+
+        let content = self.row(mathml.content());
+
+        Ok(match block {
+            true => format!(
+                r#"<m:oMathPara xmlns:m="{NAMESPACE}"><m:oMath>{content}</m:oMath></m:oMathPara>"#
+            ),
+            false => format!(r#"<m:oMath xmlns:m="{NAMESPACE}">{content}</m:oMath>"#),
+        })
+    }
+}
+
+/// Write an element with the given content, or an empty element if there is no content.
+fn wrap(name: &str, content: &str) -> String {
+    match content.is_empty() {
+        true => format!("<{name}/>"),
+        false => format!("<{name}>{content}</{name}>"),
+    }
+}
+
+/// Write an empty element with the `m:val` attribute, e.g. `<m:chr m:val="∑"/>`.
+fn val(name: &str, value: &str) -> String {
+    let value = escape::escape(value, EscapeCtx::AttrValue);
+    format!(r#"<{name} m:val="{value}"/>"#)
+}
+
+/// Write the `m:t` element of a run, preserving leading and trailing spaces.
+fn text_element(text: &str) -> String {
+    let content = escape::escape(text, EscapeCtx::Content);
+
+    match text.starts_with(' ') || text.ends_with(' ') {
+        true => format!(r#"<m:t xml:space="preserve">{content}</m:t>"#),
+        false => format!("<m:t>{content}</m:t>"),
+    }
+}
+
+/// The `m:scr` and `m:sty` run properties of the `mathvariant`.
+fn style(variant: &MathVariant) -> (Option<&'static str>, Option<&'static str>) {
+    match variant {
+        MathVariant::Normal => (None, Some("p")),
+        MathVariant::Bold => (None, Some("b")),
+        MathVariant::Italic => (None, Some("i")),
+        MathVariant::BoldItalic => (None, Some("bi")),
+        MathVariant::DoubleStruck => (Some("double-struck"), Some("p")),
+        MathVariant::Script => (Some("script"), Some("p")),
+        MathVariant::BoldScript => (Some("script"), Some("b")),
+        MathVariant::Fraktur => (Some("fraktur"), Some("p")),
+        MathVariant::BoldFraktur => (Some("fraktur"), Some("b")),
+        MathVariant::SansSerif => (Some("sans-serif"), Some("p")),
+        MathVariant::BoldSansSerif => (Some("sans-serif"), Some("b")),
+        MathVariant::SansSerifItalic => (Some("sans-serif"), Some("i")),
+        MathVariant::SansSerifBoldItalic => (Some("sans-serif"), Some("bi")),
+        MathVariant::Monospace => (Some("monospace"), Some("p")),
+        // arabic variants have no counterpart in OMML
+        MathVariant::Initial
+        | MathVariant::Tailed
+        | MathVariant::Looped
+        | MathVariant::Stretched => (None, None),
+    }
+}
+
+/// Whether the operator is written as `m:nary`.
+fn is_large(op: &Operator) -> bool {
+    let large = matches!(
+        op.op(),
+        "∑" | "∏"
+            | "∐"
+            | "⋃"
+            | "⋂"
+            | "⋁"
+            | "⋀"
+            | "⨁"
+            | "⨂"
+            | "⨀"
+            | "∫"
+            | "∬"
+            | "∭"
+            | "∮"
+            | "∯"
+            | "∰"
+    );
+
+    large
+        || op
+            .attributes()
+            .iter()
+            .any(|attr| matches!(attr, OperatorAttr::LargeOp))
+}
+
+fn is_invisible(op: &str) -> bool {
+    matches!(op, "\u{2061}" | "\u{2062}" | "\u{2063}" | "\u{2064}")
+}
+
+fn is_fence(op: &str) -> bool {
+    is_opening(op) || is_closing(op) || matches!(op, "|" | "‖")
+}
+
+fn is_opening(op: &str) -> bool {
+    matches!(op, "(" | "[" | "{" | "⟨" | "⌊" | "⌈")
+}
+
+fn is_closing(op: &str) -> bool {
+    matches!(op, ")" | "]" | "}" | "⟩" | "⌋" | "⌉")
+}
+
+fn is_bar(op: &str) -> bool {
+    matches!(op, "¯" | "‾" | "_" | "―")
+}
+
+/// The combining character used by `m:acc` for an accent operator.
+fn accent_char(op: &str) -> Option<&'static str> {
+    let chr = match op {
+        "^" | "ˆ" => "\u{302}",
+        "~" | "˜" => "\u{303}",
+        "˙" => "\u{307}",
+        "¨" => "\u{308}",
+        "ˇ" => "\u{30C}",
+        "´" => "\u{301}",
+        "`" => "\u{300}",
+        "˘" => "\u{306}",
+        "→" | "⃗" => "\u{20D7}",
+        _ => return None,
+    };
+
+    Some(chr)
+}
+
+/// The index of the fence closing the fence that starts the elements, if they start with one.
+/// Vertical bars close an opening vertical bar.
+fn closing_fence(elements: &[Element]) -> Option<usize> {
+    let Some(Element::Operator(open)) = elements.first() else {
+        return None;
+    };
+
+    let bar = matches!(open.op(), "|" | "‖");
+    if !is_opening(open.op()) && !bar {
+        return None;
+    }
+
+    let mut depth = 0usize;
+    for (idx, element) in elements.iter().enumerate().skip(1) {
+        let Element::Operator(op) = element else {
+            continue;
+        };
+
+        if bar && op.op() == open.op() && depth == 0 {
+            return Some(idx);
+        }
+
+        if is_opening(op.op()) {
+            depth += 1;
+        } else if is_closing(op.op()) {
+            match depth.checked_sub(1) {
+                Some(outer) => depth = outer,
+                // bars are only closed by bars, other fences by any closing fence, e.g. `[0, 1)`
+                None if bar => return None,
+                None => return Some(idx),
+            }
+        }
+    }
+
+    None
+}
+
+/// Split a pair of multiscripts into the subscript and superscript, skipping empty rows.
+fn script_pair(pair: &[Element]) -> (Option<&[Element]>, Option<&[Element]>) {
+    let script = |idx: usize| {
+        pair.get(idx)
+            .filter(|el| !matches!(el, Element::Row(row) if row.children().is_empty()))
+            .map(std::slice::from_ref)
+    };
+
+    (script(0), script(1))
+}
+
+/// Whether the fraction has no bar, e.g. in a binomial coefficient.
+fn is_stacked(frac: &Frac) -> bool {
+    frac.attributes().iter().any(|attr| {
+        matches!(
+            attr,
+            FracAttr::LineThickness(lt) if lt.parse::<Length>().is_ok_and(|lt| lt.is_zero())
+        )
+    })
+}
@@ -0,0 +1,194 @@
+//! Operator overloading for composing elements, so code reads like the math it produces.
+//!
+//! The arithmetic operators are implemented for [`Ident`], [`Num`], [`Element`] and the layout
+//! elements [`Row`], [`SubSup`], [`Frac`], [`Radical`] and [`UnderOver`]. The right operand can
+//! be anything that converts into an [`Element`], including integers:
+//!
+//! - `a + b`, `a - b` and `a * b` create a [`Row`] with the operator `+`, `-` or the invisible
+//!   times `&#x2062;` between both operands. A left operand that is a [`Row`] without attributes
+//!   is extended, so `a + b + c` results in a single [`Row`].
+//! - `-a` creates a [`Row`] with the operator `-` in front of the operand.
+//! - `a / b` creates a [`Frac`] with `a` as numerator and `b` as denominator.
+//! - `a ^ b` creates a [`SubSup`] with `b` as superscript.
+//!
+//! The [`Compose`] trait adds the methods [`sub`](Compose::sub), [`sup`](Compose::sup),
+//! [`sqrt`](Compose::sqrt) and [`paren`](Compose::paren).
+//!
+//! Parentheses in the Rust code only group the code, they are never added to the elements. Use
+//! [`paren`](Compose::paren) to write them, or the [`expr`](crate::expr) module to place them
+//! automatically. Note that `^` binds weaker than `+` in Rust, so `x ^ 2 + 1` must be written as
+//! `(x ^ 2) + 1`.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{grouping::Row, scripted::SubSup, Frac, Ident, Num, Operator};
+//! use alemat::ops::Compose;
+//! use alemat::{children, Element};
+//!
+//! let x = Ident::from("x");
+//!
+//! assert_eq!(
+//!     x.clone() + 1,
+//!     Row::from(children![Ident::from("x"), Operator::plus(), Num::from(1)])
+//! );
+//!
+//! let frac = (x.clone() ^ 2) / x.clone().paren();
+//! let expected = Frac::builder()
+//!     .num(SubSup::builder().base(x.clone()).supscript(Num::from(2)).build())
+//!     .denom(Row::from([
+//!         Element::from(Operator::lparens()),
+//!         x.clone().into(),
+//!         Operator::rparens().into(),
+//!     ]))
+//!     .build();
+//!
+//! assert_eq!(frac, expected);
+//! assert_eq!(x.clone().sub(1).sup(2), x.sup(2).sub(1));
+//! ```
+
+use crate::{
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver},
+        Frac, Ident, Num, Operator,
+    },
+    Element,
+};
+
+/// Methods for composing scripts, radicals and parentheses.
+///
+/// The trait is implemented for everything that converts into an [`Element`]. On an owned
+/// [`SubSup`] its methods take precedence over [`SubSup::sub`] and [`SubSup::sup`], which can
+/// still be called on a reference, e.g. `SubSup::sub(&sub_sup)`.
+pub trait Compose: Into<Element> {
+    /// Attach the subscript. If `self` is a [`SubSup`] without a subscript, the subscript is
+    /// added to it, so `x.sup(2).sub(1)` equals `x.sub(1).sup(2)`.
+    fn sub(self, sub: impl Into<Element>) -> SubSup {
+        match self.into() {
+            Element::SubSup(mut sub_sup) if SubSup::sub(&sub_sup).is_none() => {
+                sub_sup.set_sub([sub.into()]);
+                sub_sup
+            }
+            base => SubSup::builder()
+                .base([base])
+                .subscript([sub.into()])
+                .build(),
+        }
+    }
+
+    /// Attach the superscript. If `self` is a [`SubSup`] without a superscript, the superscript
+    /// is added to it, so `x.sub(1).sup(2)` equals `x.sup(2).sub(1)`.
+    fn sup(self, sup: impl Into<Element>) -> SubSup {
+        match self.into() {
+            Element::SubSup(mut sub_sup) if SubSup::sup(&sub_sup).is_none() => {
+                sub_sup.set_sup([sup.into()]);
+                sub_sup
+            }
+            base => SubSup::builder()
+                .base([base])
+                .supscript([sup.into()])
+                .build(),
+        }
+    }
+
+    /// Create the square root of `self`.
+    fn sqrt(self) -> Radical {
+        Radical::builder()
+            .index(Num::from(2))
+            .content([self.into()])
+            .build()
+    }
+
+    /// Wrap `self` in parentheses.
+    fn paren(self) -> Row {
+        Row::from([
+            Element::from(Operator::lparens()),
+            self.into(),
+            Operator::rparens().into(),
+        ])
+    }
+}
+
+impl<T: Into<Element>> Compose for T {}
+
+/// Create the [`Row`] `lhs op rhs`, extending `lhs` if it is a [`Row`] without attributes.
+fn infix(lhs: impl Into<Element>, op: Operator, rhs: impl Into<Element>) -> Row {
+    let mut row = match lhs.into() {
+        Element::Row(row) if row.attributes().is_empty() => row,
+        lhs => Row::from([lhs]),
+    };
+
+    row.add_element(op);
+    row.add_element(rhs);
+    row
+}
+
+macro_rules! element_ops {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl<T: Into<Element>> std::ops::Add<T> for $type {
+                type Output = Row;
+
+                /// Create the [`Row`] `self + rhs`.
+                fn add(self, rhs: T) -> Row {
+                    infix(self, Operator::plus(), rhs)
+                }
+            }
+
+            impl<T: Into<Element>> std::ops::Sub<T> for $type {
+                type Output = Row;
+
+                /// Create the [`Row`] `self - rhs`.
+                fn sub(self, rhs: T) -> Row {
+                    infix(self, Operator::minus(), rhs)
+                }
+            }
+
+            impl<T: Into<Element>> std::ops::Mul<T> for $type {
+                type Output = Row;
+
+                /// Create the [`Row`] of `self` and `rhs` joined by the invisible times.
+                fn mul(self, rhs: T) -> Row {
+                    infix(self, Operator::from("\u{2062}"), rhs)
+                }
+            }
+
+            impl<T: Into<Element>> std::ops::Div<T> for $type {
+                type Output = Frac;
+
+                /// Create the [`Frac`] with `self` as numerator and `rhs` as denominator.
+                fn div(self, rhs: T) -> Frac {
+                    Frac::builder()
+                        .num([Element::from(self)])
+                        .denom([rhs.into()])
+                        .build()
+                }
+            }
+
+            impl<T: Into<Element>> std::ops::BitXor<T> for $type {
+                type Output = SubSup;
+
+                /// Create the [`SubSup`] with `rhs` as superscript of `self`.
+                fn bitxor(self, rhs: T) -> SubSup {
+                    SubSup::builder()
+                        .base([Element::from(self)])
+                        .supscript([rhs.into()])
+                        .build()
+                }
+            }
+
+            impl std::ops::Neg for $type {
+                type Output = Row;
+
+                /// Create the [`Row`] `-self`.
+                fn neg(self) -> Row {
+                    Row::from([Element::from(Operator::minus()), self.into()])
+                }
+            }
+        )*
+    };
+}
+
+element_ops!(Element, Ident, Num, Row, SubSup, Frac, Radical, UnderOver);
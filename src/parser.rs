@@ -0,0 +1,956 @@
+//! Parsing of MathML markup into [`MathMl`] and [`Element`]s.
+
+use std::str::FromStr;
+
+use crate::{
+    attributes::{Attribute, Dir, MathVariant, ScriptLevel},
+    content::{self, Apply, Content, ContentOp},
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, AnnotationAttr, AnnotationContent, ColumnLine, Frac, FracAttr, Ident, Num,
+        OpForm, Operator, OperatorAttr, Padded, PaddedAttr, Semantics, Space, SpaceAttr,
+        StrLiteral, Table, TableAttr, TableCell, TableCellAttr, TableRow, Text,
+    },
+    xml::{self, XmlAttr, XmlElement, XmlNode},
+    DisplayAttr, Element, Elements, MathMl, MathMlAttr,
+};
+
+/// The kind of error that occurred while parsing markup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input ended unexpectedly.
+    UnexpectedEof,
+
+    /// An unexpected character was found.
+    UnexpectedChar(char),
+
+    /// The given token was expected, but not found.
+    Expected(&'static str),
+
+    /// There is more content after the root element.
+    TrailingContent,
+
+    /// The attribute with the given name is present more than once on the same element.
+    DuplicateAttribute(String),
+
+    /// The closing tag does not match the opening tag.
+    MismatchedClosingTag {
+        /// Name of the element that was opened.
+        expected: String,
+        /// Name of the element that was closed.
+        found: String,
+    },
+
+    /// The character or entity reference (e.g. `&nbsp;`) is not known.
+    UnknownReference(String),
+
+    /// The element with the given name is not known.
+    UnknownElement(String),
+
+    /// The element is known, but it is not allowed at this position, e.g. `mtd` outside of `mtr`.
+    UnexpectedElement {
+        /// Name of the parent element.
+        parent: String,
+        /// Name of the unexpected element.
+        element: String,
+    },
+
+    /// The attribute is not known for the given element.
+    UnknownAttribute {
+        /// Name of the element.
+        element: String,
+        /// Name of the attribute.
+        attribute: String,
+    },
+
+    /// The value of the attribute is not valid.
+    InvalidAttributeValue {
+        /// Name of the attribute.
+        attribute: String,
+        /// The invalid value.
+        value: String,
+    },
+
+    /// The element does not accept text content, but text was found.
+    UnexpectedText(String),
+
+    /// The element has the wrong number of child elements.
+    ChildCount {
+        /// Name of the element.
+        element: String,
+        /// The expected number of children.
+        expected: usize,
+        /// The number of children found.
+        found: usize,
+    },
+
+    /// The input is nested deeper than the given maximum depth.
+    NestingTooDeep(usize),
+}
+
+impl std::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::UnexpectedEof => f.write_str("unexpected end of input"),
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected character '{c}'"),
+            ParseErrorKind::Expected(s) => write!(f, "expected '{s}'"),
+            ParseErrorKind::TrailingContent => f.write_str("unexpected content after root element"),
+            ParseErrorKind::DuplicateAttribute(attr) => write!(f, "duplicate attribute '{attr}'"),
+            ParseErrorKind::MismatchedClosingTag { expected, found } => {
+                write!(
+                    f,
+                    "expected closing tag '</{expected}>', found '</{found}>'"
+                )
+            }
+            ParseErrorKind::UnknownReference(r) => write!(f, "unknown reference '&{r};'"),
+            ParseErrorKind::UnknownElement(el) => write!(f, "unknown element '<{el}>'"),
+            ParseErrorKind::UnexpectedElement { parent, element } => {
+                write!(
+                    f,
+                    "element '<{element}>' is not allowed inside '<{parent}>'"
+                )
+            }
+            ParseErrorKind::UnknownAttribute { element, attribute } => {
+                write!(
+                    f,
+                    "unknown attribute '{attribute}' on element '<{element}>'"
+                )
+            }
+            ParseErrorKind::InvalidAttributeValue { attribute, value } => {
+                write!(f, "invalid value '{value}' for attribute '{attribute}'")
+            }
+            ParseErrorKind::UnexpectedText(text) => write!(f, "unexpected text '{text}'"),
+            ParseErrorKind::ChildCount {
+                element,
+                expected,
+                found,
+            } => write!(
+                f,
+                "element '<{element}>' expects {expected} children, found {found}"
+            ),
+            ParseErrorKind::NestingTooDeep(max) => {
+                write!(f, "nesting is deeper than the maximum of {max} levels")
+            }
+        }
+    }
+}
+
+/// Error returned when parsing markup fails. Contains the kind of the error and the position in
+/// the input where the error occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, input: &str, offset: usize) -> Self {
+        let offset = offset.min(input.len());
+        let before = &input[..offset];
+
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        Self {
+            kind,
+            offset,
+            line,
+            column,
+        }
+    }
+
+    /// Get the kind of this error.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Byte offset in the input where the error occurred.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Line in the input where the error occurred, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column (in characters) in the input where the error occurred, starting at 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}:{}", self.kind, self.line, self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for MathMl {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let root = xml::parse(s)?;
+        let parser = MathMlParser { input: s };
+
+        if root.local_name() != "math" {
+            return Err(parser.error(
+                ParseErrorKind::UnexpectedElement {
+                    parent: String::from("#document"),
+                    element: root.name.clone(),
+                },
+                root.offset,
+            ));
+        }
+
+        parser.mathml(&root)
+    }
+}
+
+impl FromStr for Element {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let root = xml::parse(s)?;
+        MathMlParser { input: s }.element(&root, "#document")
+    }
+}
+
+/// Converts the parsed XML tree into MathML elements.
+struct MathMlParser<'a> {
+    input: &'a str,
+}
+
+impl MathMlParser<'_> {
+    fn error(&self, kind: ParseErrorKind, offset: usize) -> ParseError {
+        ParseError::new(kind, self.input, offset)
+    }
+
+    fn unknown_attr(&self, el: &XmlElement, attr: &XmlAttr) -> ParseError {
+        self.error(
+            ParseErrorKind::UnknownAttribute {
+                element: el.name.clone(),
+                attribute: attr.name.clone(),
+            },
+            attr.offset,
+        )
+    }
+
+    fn invalid_value(&self, attr: &XmlAttr) -> ParseError {
+        self.error(
+            ParseErrorKind::InvalidAttributeValue {
+                attribute: attr.name.clone(),
+                value: attr.value.clone(),
+            },
+            attr.offset,
+        )
+    }
+
+    fn mathml(&self, el: &XmlElement) -> Result<MathMl, ParseError> {
+        let content = self.children(el)?;
+        let mut attr = Vec::new();
+
+        for a in el.attributes.iter().filter(|a| !is_namespace_decl(a)) {
+            let parsed = match a.name.as_str() {
+                "display" => match a.value.trim() {
+                    "block" => MathMlAttr::Display(DisplayAttr::Block),
+                    "inline" => MathMlAttr::Display(DisplayAttr::Inline),
+                    _ => return Err(self.invalid_value(a)),
+                },
+                "alttext" => MathMlAttr::AltText(a.value.clone()),
+                "aria-label" => MathMlAttr::AriaLabel(a.value.clone()),
+                _ => MathMlAttr::Global(self.global_attr(el, a)?),
+            };
+
+            attr.push(parsed);
+        }
+
+        Ok(MathMl::with_content(content).with_attr(attr))
+    }
+
+    /// Parse the child elements of the given element, rejecting any non-whitespace text.
+    fn children(&self, el: &XmlElement) -> Result<Elements, ParseError> {
+        let mut children = Vec::with_capacity(el.children.len());
+
+        for node in el.children.iter() {
+            match node {
+                XmlNode::Element(child) => children.push(self.element(child, &el.name)?),
+                XmlNode::Text { text, .. } if text.trim().is_empty() => {}
+                XmlNode::Text { text, offset } => {
+                    return Err(self.error(
+                        ParseErrorKind::UnexpectedText(text.trim().to_string()),
+                        *offset,
+                    ))
+                }
+            }
+        }
+
+        Ok(Elements(children))
+    }
+
+    /// Parse the child elements of the given element and check that there are exactly `n` of
+    /// them.
+    fn exact_children<const N: usize>(&self, el: &XmlElement) -> Result<[Element; N], ParseError> {
+        let children = self.children(el)?.into_inner();
+        let found = children.len();
+
+        children.try_into().map_err(|_| {
+            self.error(
+                ParseErrorKind::ChildCount {
+                    element: el.name.clone(),
+                    expected: N,
+                    found,
+                },
+                el.offset,
+            )
+        })
+    }
+
+    /// Get the text content of a token element verbatim.
+    fn text(&self, el: &XmlElement) -> Result<String, ParseError> {
+        let mut text = String::new();
+
+        for node in el.children.iter() {
+            match node {
+                XmlNode::Text { text: t, .. } => text.push_str(t),
+                XmlNode::Element(child) => {
+                    return Err(self.error(
+                        ParseErrorKind::UnexpectedElement {
+                            parent: el.name.clone(),
+                            element: child.name.clone(),
+                        },
+                        child.offset,
+                    ))
+                }
+            }
+        }
+
+        Ok(text)
+    }
+
+    /// Get the text content of an `mi`, `mn` or `mo` element without leading and trailing
+    /// whitespace. Content consisting only of whitespace is kept as is, e.g. an operator that
+    /// is a space.
+    fn trimmed_text(&self, el: &XmlElement) -> Result<String, ParseError> {
+        let text = self.text(el)?;
+
+        match text.trim() {
+            "" => Ok(text),
+            trimmed => Ok(trimmed.to_string()),
+        }
+    }
+
+    /// Parse the attributes of an element that only accepts the global attributes.
+    fn global_attrs(&self, el: &XmlElement) -> Result<Vec<Attribute>, ParseError> {
+        el.attributes
+            .iter()
+            .filter(|a| !is_namespace_decl(a))
+            .map(|a| self.global_attr(el, a))
+            .collect()
+    }
+
+    fn global_attr(&self, el: &XmlElement, attr: &XmlAttr) -> Result<Attribute, ParseError> {
+        let value = || attr.value.clone();
+
+        let parsed = match attr.name.as_str() {
+            "class" => Attribute::Class(value()),
+            "dir" => match attr.value.trim() {
+                "rtl" => Attribute::Dir(Dir::RightToLeft),
+                "ltr" => Attribute::Dir(Dir::LeftToRight),
+                _ => return Err(self.invalid_value(attr)),
+            },
+            "display" => match attr.value.trim() {
+                "normal" => Attribute::DisplayStyle(true),
+                "compact" => Attribute::DisplayStyle(false),
+                _ => return Err(self.invalid_value(attr)),
+            },
+            "displaystyle" => match attr.value.trim() {
+                "true" => Attribute::DisplayStyle(true),
+                "false" => Attribute::DisplayStyle(false),
+                _ => return Err(self.invalid_value(attr)),
+            },
+            "id" => Attribute::Id(value()),
+            "mathbackground" => Attribute::MathBackground(value()),
+            "mathcolor" => Attribute::MathColor(value()),
+            "mathsize" => Attribute::MathSize(value()),
+            "mathvariant" => match math_variant(attr.value.trim()) {
+                Some(mv) => Attribute::MathVariant(mv),
+                None => return Err(self.invalid_value(attr)),
+            },
+            "nonce" => Attribute::Nonce(value()),
+            "scriptlevel" => {
+                let trimmed = attr.value.trim();
+                let level = if let Some(num) = trimmed.strip_prefix('+') {
+                    num.parse().map(ScriptLevel::Add)
+                } else if let Some(num) = trimmed.strip_prefix('-') {
+                    num.parse().map(ScriptLevel::Sub)
+                } else {
+                    trimmed.parse().map(ScriptLevel::Num)
+                };
+
+                Attribute::ScriptLevel(level.map_err(|_| self.invalid_value(attr))?)
+            }
+            "style" => Attribute::Style(value()),
+            "tabindex" => Attribute::TabIndex(
+                attr.value
+                    .trim()
+                    .parse()
+                    .map_err(|_| self.invalid_value(attr))?,
+            ),
+            name => {
+                if let Some(data) = name.strip_prefix("data-") {
+                    Attribute::Data {
+                        name: data.to_string(),
+                        value: value(),
+                    }
+                } else if let Some(event) = name.strip_prefix("on").filter(|e| !e.is_empty()) {
+                    Attribute::OnHandler {
+                        name: event.to_string(),
+                        handler: value(),
+                    }
+                } else {
+                    return Err(self.unknown_attr(el, attr));
+                }
+            }
+        };
+
+        Ok(parsed)
+    }
+
+    /// Parse a boolean attribute. Returns `Some(attr)` if the value is `true` and `None` if the
+    /// value is `false`.
+    fn bool_attr<T>(&self, attr: &XmlAttr, value: T) -> Result<Option<T>, ParseError> {
+        match attr.value.trim() {
+            "true" => Ok(Some(value)),
+            "false" => Ok(None),
+            _ => Err(self.invalid_value(attr)),
+        }
+    }
+
+    /// Parse a single element. Every element is converted in its own method to keep the stack
+    /// frame of this function small, as it is part of the recursion for nested elements.
+    fn element(&self, el: &XmlElement, parent: &str) -> Result<Element, ParseError> {
+        match el.local_name() {
+            "maction" => self.action(el).map(Element::from),
+            "annotation" => self.annotation(el).map(Element::from),
+            "annotation-xml" => self.annotation_xml(el).map(Element::from),
+            "merror" => self.error_element(el).map(Element::from),
+            "mfrac" => self.frac(el).map(Element::from),
+            "mi" => self.ident(el).map(Element::from),
+            "mmultiscripts" => self.multiscripts(el).map(Element::from),
+            "mprescripts" => self.prescripts(el).map(Element::from),
+            "none" => self.none(el).map(Element::from),
+            "mn" => self.num(el).map(Element::from),
+            "mo" => self.operator(el).map(Element::from),
+            "mpadded" => self.padded(el).map(Element::from),
+            "mphantom" => self.phantom(el).map(Element::from),
+            "msqrt" => self.sqrt(el).map(Element::from),
+            "mroot" => self.root(el).map(Element::from),
+            "mrow" => self.row(el).map(Element::from),
+            "semantics" => self.semantics(el).map(Element::from),
+            "mspace" => self.space(el).map(Element::from),
+            "ms" => self.str_literal(el).map(Element::from),
+            "mstyle" => self.style(el).map(Element::from),
+            "msub" | "msup" | "msubsup" => self.subsup(el).map(Element::from),
+            "mtable" => self.table(el).map(Element::from),
+            "mtext" => self.text_element(el).map(Element::from),
+            "munder" | "mover" | "munderover" => self.underover(el).map(Element::from),
+            "math" | "mtr" | "mtd" => Err(self.error(
+                ParseErrorKind::UnexpectedElement {
+                    parent: parent.to_string(),
+                    element: el.name.clone(),
+                },
+                el.offset,
+            )),
+            _ => Err(self.error(ParseErrorKind::UnknownElement(el.name.clone()), el.offset)),
+        }
+    }
+
+    fn error_element(&self, el: &XmlElement) -> Result<Error, ParseError> {
+        Ok(Error::builder()
+            .content(self.children(el)?)
+            .attr(self.global_attrs(el)?)
+            .build())
+    }
+
+    fn ident(&self, el: &XmlElement) -> Result<Ident, ParseError> {
+        Ok(Ident::builder()
+            .ident(self.trimmed_text(el)?)
+            .attr(self.global_attrs(el)?)
+            .build())
+    }
+
+    fn multiscripts(&self, el: &XmlElement) -> Result<Multiscripts, ParseError> {
+        Ok(Multiscripts::builder()
+            .content(self.children(el)?)
+            .attr(self.global_attrs(el)?)
+            .build())
+    }
+
+    fn prescripts(&self, el: &XmlElement) -> Result<Prescripts, ParseError> {
+        self.children(el)?;
+        Ok(Prescripts::with_attr(self.global_attrs(el)?))
+    }
+
+    fn none(&self, el: &XmlElement) -> Result<Row, ParseError> {
+        self.children(el)?;
+        Ok(Row::default())
+    }
+
+    fn num(&self, el: &XmlElement) -> Result<Num, ParseError> {
+        Ok(Num::from(self.trimmed_text(el)?.as_str()).with_attr(self.global_attrs(el)?))
+    }
+
+    fn phantom(&self, el: &XmlElement) -> Result<Phantom, ParseError> {
+        Ok(Phantom::from(self.children(el)?).with_attr(self.global_attrs(el)?))
+    }
+
+    fn sqrt(&self, el: &XmlElement) -> Result<Radical, ParseError> {
+        Ok(Radical::builder()
+            .index(Num::from(2))
+            .content(self.children(el)?)
+            .attr(self.global_attrs(el)?)
+            .build())
+    }
+
+    fn root(&self, el: &XmlElement) -> Result<Radical, ParseError> {
+        let [content, index] = self.exact_children(el)?;
+
+        Ok(Radical::builder()
+            .index([index])
+            .content([content])
+            .attr(self.global_attrs(el)?)
+            .build())
+    }
+
+    fn row(&self, el: &XmlElement) -> Result<Row, ParseError> {
+        let mut row = Row::from(self.children(el)?);
+        row.add_attr(self.global_attrs(el)?);
+        Ok(row)
+    }
+
+    fn semantics(&self, el: &XmlElement) -> Result<Semantics, ParseError> {
+        Ok(Semantics::builder()
+            .content(self.children(el)?)
+            .attr(self.global_attrs(el)?)
+            .build())
+    }
+
+    fn str_literal(&self, el: &XmlElement) -> Result<StrLiteral, ParseError> {
+        Ok(StrLiteral::from(self.text(el)?).with_attr(self.global_attrs(el)?))
+    }
+
+    fn style(&self, el: &XmlElement) -> Result<Style, ParseError> {
+        Ok(Style::from(self.children(el)?).with_attr(self.global_attrs(el)?))
+    }
+
+    fn text_element(&self, el: &XmlElement) -> Result<Text, ParseError> {
+        Ok(Text::from(self.text(el)?).with_attr(self.global_attrs(el)?))
+    }
+
+    fn action(&self, el: &XmlElement) -> Result<Action, ParseError> {
+        let attr = el
+            .attributes
+            .iter()
+            .filter(|a| !is_namespace_decl(a))
+            .map(|a| match a.name.as_str() {
+                "selection" => Ok(ActionAttr::Selection(a.value.clone())),
+                "actiontype" => Ok(ActionAttr::ActionType(a.value.clone())),
+                _ => self.global_attr(el, a).map(ActionAttr::Global),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Action::builder()
+            .content(self.children(el)?)
+            .attr(attr)
+            .build())
+    }
+
+    fn annotation_attrs(&self, el: &XmlElement) -> Result<Vec<AnnotationAttr>, ParseError> {
+        el.attributes
+            .iter()
+            .filter(|a| !is_namespace_decl(a))
+            .map(|a| match a.name.as_str() {
+                "encoding" => Ok(AnnotationAttr::Encoding(a.value.clone())),
+                _ => self.global_attr(el, a).map(AnnotationAttr::Global),
+            })
+            .collect()
+    }
+
+    fn annotation(&self, el: &XmlElement) -> Result<Annotation, ParseError> {
+        Ok(Annotation::builder()
+            .content(self.text(el)?)
+            .attr(self.annotation_attrs(el)?)
+            .build())
+    }
+
+    fn annotation_xml(&self, el: &XmlElement) -> Result<Annotation, ParseError> {
+        let attr = self.annotation_attrs(el)?;
+
+        // presentation markup is kept as nested elements, even if Content MathML is declared
+        let is_content = attr.iter().any(|a| {
+            matches!(a, AnnotationAttr::Encoding(enc) if enc.eq_ignore_ascii_case(content::ENCODING))
+        }) && matches!(
+            self.content_children(el)?.first().map(|child| child.local_name()),
+            Some("ci" | "cn" | "apply")
+        );
+
+        let content = match is_content {
+            true => AnnotationContent::Content(self.content_child(el)?),
+            false => AnnotationContent::Nested(self.children(el)?),
+        };
+
+        Ok(Annotation::builder().content(content).attr(attr).build())
+    }
+
+    /// Parse the single Content MathML child of the given element.
+    fn content_child(&self, el: &XmlElement) -> Result<Content, ParseError> {
+        let children = self.content_children(el)?;
+        let found = children.len();
+
+        let [child] = children.try_into().map_err(|_| {
+            self.error(
+                ParseErrorKind::ChildCount {
+                    element: el.name.clone(),
+                    expected: 1,
+                    found,
+                },
+                el.offset,
+            )
+        })?;
+
+        self.content(child)
+    }
+
+    fn content_children<'a>(&self, el: &'a XmlElement) -> Result<Vec<&'a XmlElement>, ParseError> {
+        el.children
+            .iter()
+            .filter_map(|node| match node {
+                XmlNode::Element(child) => Some(Ok(child)),
+                XmlNode::Text { text, .. } if text.trim().is_empty() => None,
+                XmlNode::Text { text, offset } => Some(Err(self.error(
+                    ParseErrorKind::UnexpectedText(text.trim().to_string()),
+                    *offset,
+                ))),
+            })
+            .collect()
+    }
+
+    /// Parse a Content MathML element. Attributes such as `type` of `cn` are not modelled and
+    /// therefore ignored.
+    fn content(&self, el: &XmlElement) -> Result<Content, ParseError> {
+        match el.local_name() {
+            "ci" => Ok(Content::ci(self.trimmed_text(el)?)),
+            "cn" => Ok(Content::cn(self.trimmed_text(el)?)),
+            "apply" => self.apply(el).map(Content::from),
+            _ => Err(self.error(ParseErrorKind::UnknownElement(el.name.clone()), el.offset)),
+        }
+    }
+
+    fn apply(&self, el: &XmlElement) -> Result<Apply, ParseError> {
+        let children = self.content_children(el)?;
+
+        let Some((op, rest)) = children.split_first() else {
+            return Err(self.error(
+                ParseErrorKind::ChildCount {
+                    element: el.name.clone(),
+                    expected: 1,
+                    found: 0,
+                },
+                el.offset,
+            ));
+        };
+
+        let op = match op.local_name() {
+            "ci" => ContentOp::Function(self.trimmed_text(op)?),
+            name => ContentOp::from_name(name).ok_or_else(|| {
+                self.error(ParseErrorKind::UnknownElement(op.name.clone()), op.offset)
+            })?,
+        };
+
+        let mut apply = Apply::builder().op(op);
+
+        for child in rest {
+            apply = match child.local_name() {
+                "bvar" => apply.bvar(self.content_child(child)?),
+                "lowlimit" => apply.lowlimit(self.content_child(child)?),
+                "uplimit" => apply.uplimit(self.content_child(child)?),
+                "degree" => apply.degree(self.content_child(child)?),
+                _ => apply.args([self.content(child)?]),
+            };
+        }
+
+        Ok(apply.build())
+    }
+
+    fn frac(&self, el: &XmlElement) -> Result<Frac, ParseError> {
+        let [num, denom] = self.exact_children(el)?;
+
+        let attr = el
+            .attributes
+            .iter()
+            .filter(|a| !is_namespace_decl(a))
+            .map(|a| match a.name.as_str() {
+                "linethickness" => Ok(FracAttr::LineThickness(a.value.clone())),
+                _ => self.global_attr(el, a).map(FracAttr::Global),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Frac::builder().num([num]).denom([denom]).attr(attr).build())
+    }
+
+    fn operator(&self, el: &XmlElement) -> Result<Operator, ParseError> {
+        let mut attr = Vec::new();
+
+        for a in el.attributes.iter().filter(|a| !is_namespace_decl(a)) {
+            let parsed = match a.name.as_str() {
+                "form" => match a.value.trim() {
+                    "infix" => Some(OperatorAttr::Form(OpForm::Infix)),
+                    "prefix" => Some(OperatorAttr::Form(OpForm::Prefix)),
+                    "postfix" => Some(OperatorAttr::Form(OpForm::Postfix)),
+                    _ => return Err(self.invalid_value(a)),
+                },
+                "fence" => self.bool_attr(a, OperatorAttr::Fence)?,
+                "separator" => self.bool_attr(a, OperatorAttr::Separator)?,
+                "lspace" => Some(OperatorAttr::LeftSpace(a.value.clone())),
+                "rspace" => Some(OperatorAttr::RightSpace(a.value.clone())),
+                "maxsize" => Some(OperatorAttr::MaxSize(a.value.clone())),
+                "minsize" => Some(OperatorAttr::MinSize(a.value.clone())),
+                "stretchy" => self.bool_attr(a, OperatorAttr::Stretchy)?,
+                "symmetric" => self.bool_attr(a, OperatorAttr::Symmetric)?,
+                "largeop" => self.bool_attr(a, OperatorAttr::LargeOp)?,
+                "movablelimits" => self.bool_attr(a, OperatorAttr::MovableLimits)?,
+                _ => Some(OperatorAttr::Global(self.global_attr(el, a)?)),
+            };
+
+            attr.extend(parsed);
+        }
+
+        Ok(Operator::builder()
+            .op(self.trimmed_text(el)?)
+            .attr(attr)
+            .build())
+    }
+
+    fn padded(&self, el: &XmlElement) -> Result<Padded, ParseError> {
+        let attr = el
+            .attributes
+            .iter()
+            .filter(|a| !is_namespace_decl(a))
+            .map(|a| match a.name.as_str() {
+                "width" => Ok(PaddedAttr::Width(a.value.clone())),
+                "height" => Ok(PaddedAttr::Height(a.value.clone())),
+                "depth" => Ok(PaddedAttr::Depth(a.value.clone())),
+                "lspace" => Ok(PaddedAttr::LeftSpace(a.value.clone())),
+                "voffset" => Ok(PaddedAttr::VerticalOffset(a.value.clone())),
+                _ => self.global_attr(el, a).map(PaddedAttr::Global),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Padded::from(self.children(el)?).with_attr(attr))
+    }
+
+    fn space(&self, el: &XmlElement) -> Result<Space, ParseError> {
+        self.children(el)?;
+
+        let attr = el
+            .attributes
+            .iter()
+            .filter(|a| !is_namespace_decl(a))
+            .map(|a| match a.name.as_str() {
+                "width" => Ok(SpaceAttr::Width(a.value.clone())),
+                "height" => Ok(SpaceAttr::Height(a.value.clone())),
+                "depth" => Ok(SpaceAttr::Depth(a.value.clone())),
+                _ => self.global_attr(el, a).map(SpaceAttr::Global),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Space::default().with_attr(attr))
+    }
+
+    fn subsup(&self, el: &XmlElement) -> Result<SubSup, ParseError> {
+        let attr = self.global_attrs(el)?;
+        let builder = SubSup::builder().attr(attr);
+
+        let subsup = match el.local_name() {
+            "msub" => {
+                let [base, sub] = self.exact_children(el)?;
+                builder.base([base]).subscript([sub]).build()
+            }
+            "msup" => {
+                let [base, sup] = self.exact_children(el)?;
+                builder.base([base]).supscript([sup]).build()
+            }
+            _ => {
+                let [base, sub, sup] = self.exact_children(el)?;
+                builder
+                    .base([base])
+                    .subscript([sub])
+                    .supscript([sup])
+                    .build()
+            }
+        };
+
+        Ok(subsup)
+    }
+
+    fn underover(&self, el: &XmlElement) -> Result<UnderOver, ParseError> {
+        let mut attr = Vec::new();
+
+        for a in el.attributes.iter().filter(|a| !is_namespace_decl(a)) {
+            let parsed = match a.name.as_str() {
+                "accent" => self.bool_attr(a, UnderOverAttr::AccentOver)?,
+                "accentunder" => self.bool_attr(a, UnderOverAttr::AccentUnder)?,
+                _ => Some(UnderOverAttr::Global(self.global_attr(el, a)?)),
+            };
+
+            attr.extend(parsed);
+        }
+
+        let builder = UnderOver::builder().attr(attr);
+
+        let underover = match el.local_name() {
+            "munder" => {
+                let [expr, under] = self.exact_children(el)?;
+                builder.expr([expr]).under([under]).build()
+            }
+            "mover" => {
+                let [expr, over] = self.exact_children(el)?;
+                builder.expr([expr]).over([over]).build()
+            }
+            _ => {
+                let [expr, under, over] = self.exact_children(el)?;
+                builder.expr([expr]).under([under]).over([over]).build()
+            }
+        };
+
+        Ok(underover)
+    }
+
+    fn table(&self, el: &XmlElement) -> Result<Table, ParseError> {
+        let mut attr = Vec::new();
+
+        for a in el.attributes.iter().filter(|a| !is_namespace_decl(a)) {
+            let parsed = match a.name.as_str() {
+                "columnlines" => TableAttr::ColumnLines(
+                    a.value
+                        .split_whitespace()
+                        .map(|line| match line {
+                            "none" => Ok(ColumnLine::None),
+                            "solid" => Ok(ColumnLine::Solid),
+                            "dashed" => Ok(ColumnLine::Dashed),
+                            _ => Err(self.invalid_value(a)),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                _ => TableAttr::Global(self.global_attr(el, a)?),
+            };
+
+            attr.push(parsed);
+        }
+
+        let rows = self
+            .nested(el, "mtr")?
+            .map(|row| self.table_row(row))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Table::from_iter(rows).with_attr(attr))
+    }
+
+    fn table_row(&self, el: &XmlElement) -> Result<TableRow, ParseError> {
+        let cells = self
+            .nested(el, "mtd")?
+            .map(|cell| self.table_cell(cell))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut row = TableRow::from_iter(cells);
+        row.add_attr(self.global_attrs(el)?);
+
+        Ok(row)
+    }
+
+    fn table_cell(&self, el: &XmlElement) -> Result<TableCell, ParseError> {
+        let attr = el
+            .attributes
+            .iter()
+            .filter(|a| !is_namespace_decl(a))
+            .map(|a| match a.name.as_str() {
+                "columnspan" | "colspan" => Ok(TableCellAttr::ColumnSpan(a.value.clone())),
+                "rowspan" => Ok(TableCellAttr::RowSpan(a.value.clone())),
+                _ => self.global_attr(el, a).map(TableCellAttr::Global),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TableCell::from(self.children(el)?).with_attr(attr))
+    }
+
+    /// Get the child elements of `el`, checking that all of them are `expected` elements and
+    /// that there is no text content.
+    fn nested<'el>(
+        &self,
+        el: &'el XmlElement,
+        expected: &str,
+    ) -> Result<impl Iterator<Item = &'el XmlElement>, ParseError> {
+        for node in el.children.iter() {
+            match node {
+                XmlNode::Element(child) if child.local_name() != expected => {
+                    return Err(self.error(
+                        ParseErrorKind::UnexpectedElement {
+                            parent: el.name.clone(),
+                            element: child.name.clone(),
+                        },
+                        child.offset,
+                    ));
+                }
+                XmlNode::Text { text, offset } if !text.trim().is_empty() => {
+                    return Err(self.error(
+                        ParseErrorKind::UnexpectedText(text.trim().to_string()),
+                        *offset,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(el.child_elements())
+    }
+}
+
+/// Namespace declarations such as `xmlns="http://www.w3.org/1998/Math/MathML"` are accepted on
+/// all elements, but are not part of the element tree.
+fn is_namespace_decl(attr: &XmlAttr) -> bool {
+    attr.name == "xmlns" || attr.name.starts_with("xmlns:")
+}
+
+fn math_variant(value: &str) -> Option<MathVariant> {
+    let variant = match value.to_ascii_lowercase().as_str() {
+        "normal" => MathVariant::Normal,
+        "bold" => MathVariant::Bold,
+        "italic" => MathVariant::Italic,
+        "bold-italic" => MathVariant::BoldItalic,
+        "double-struck" => MathVariant::DoubleStruck,
+        "bold-fraktur" => MathVariant::BoldFraktur,
+        "script" => MathVariant::Script,
+        "bold-script" => MathVariant::BoldScript,
+        "fraktur" => MathVariant::Fraktur,
+        "sans-serif" => MathVariant::SansSerif,
+        "bold-sans-serif" => MathVariant::BoldSansSerif,
+        "sans-serif-italic" => MathVariant::SansSerifItalic,
+        "sans-serif-bold-italic" => MathVariant::SansSerifBoldItalic,
+        "monospace" => MathVariant::Monospace,
+        "initial" => MathVariant::Initial,
+        "tailed" => MathVariant::Tailed,
+        "looped" => MathVariant::Looped,
+        "stretched" => MathVariant::Stretched,
+        _ => return None,
+    };
+
+    Some(variant)
+}
@@ -0,0 +1,316 @@
+use std::borrow::Borrow;
+use std::fmt::Write;
+
+use crate::{
+    attributes::Attribute,
+    elements::{
+        grouping::{Action, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver},
+        Annotation, Frac, Ident, Num, Operator, Padded, Semantics, Space, StrLiteral, Table, Text,
+    },
+    markup::{self, Markup},
+    Element, GeneratedAltText, MathMl, Writer,
+};
+
+/// Indentation used for each nesting level by the [`PrettyMathMlWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Indent {
+    /// Indent each level with the given number of spaces.
+    Spaces(usize),
+
+    /// Indent each level with the given number of tabs.
+    Tabs(usize),
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Self::Spaces(2)
+    }
+}
+
+/// Line ending used by the [`PrettyMathMlWriter`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Newline {
+    /// Unix style line ending `\n`.
+    #[default]
+    Lf,
+
+    /// Windows style line ending `\r\n`.
+    CrLf,
+}
+
+impl Newline {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Newline::Lf => "\n",
+            Newline::CrLf => "\r\n",
+        }
+    }
+}
+
+/// MathMl [`Writer`] that writes human readable markup, with each element on its own line and
+/// indented according to its nesting level.
+///
+/// By default, each level is indented with two spaces, lines end with `\n` and token elements
+/// (`mi`, `mn`, `mo`, `mtext`, `ms`) are written on a single line together with their content.
+/// Apart from the added whitespace, the markup is the same as the one produced by
+/// [`BufMathMlWriter`](crate::BufMathMlWriter).
+///
+/// # Example
+///
+/// ```
+/// use alemat::{elements::{grouping::Row, Ident, Operator}, MathMl, PrettyMathMlWriter, Writer};
+///
+/// let math = MathMl::with_content([Row::from([Ident::from("x"), Ident::from("y")])]);
+///
+/// let out = math.write(&mut PrettyMathMlWriter::default()).unwrap().finish();
+///
+/// assert_eq!(out, "<math>\n  <mrow>\n    <mi>x</mi>\n    <mi>y</mi>\n  </mrow>\n</math>");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PrettyMathMlWriter {
+    buf: String,
+    raw: bool,
+    alttext: GeneratedAltText,
+    indent: Indent,
+    newline: Newline,
+    multiline_tokens: bool,
+
+    /// Nesting level of the element currently being written.
+    depth: usize,
+
+    /// Whether the last written start tag is not followed by any content yet.
+    empty: bool,
+}
+
+impl PrettyMathMlWriter {
+    /// Create a [`PrettyMathMlWriter`] that writes element content and attribute values as they
+    /// are, without escaping them.
+    ///
+    /// Only use this writer if all content has already been escaped, otherwise the produced
+    /// markup might be malformed.
+    pub fn raw() -> Self {
+        Self {
+            raw: true,
+            ..Default::default()
+        }
+    }
+
+    /// Set the indentation used for each nesting level.
+    pub fn with_indent(mut self, indent: Indent) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Set the line ending written after each line.
+    pub fn with_newline(mut self, newline: Newline) -> Self {
+        self.newline = newline;
+        self
+    }
+
+    /// Set whether token elements (`mi`, `mn`, `mo`, `mtext`, `ms`) are written on a single line
+    /// together with their content. If set to `false`, the content of token elements is written
+    /// on its own line, like the children of all other elements.
+    pub fn with_inline_tokens(mut self, inline: bool) -> Self {
+        self.multiline_tokens = !inline;
+        self
+    }
+
+    /// Set which text alternatives are generated for `math` elements that do not have them
+    /// already, see [`MathMl::generate_alttext`].
+    pub fn with_generated_alttext(mut self, generated: GeneratedAltText) -> Self {
+        self.alttext = generated;
+        self
+    }
+
+    fn new_line(&mut self) -> std::fmt::Result {
+        self.buf.write_str(self.newline.as_str())?;
+
+        let (c, width) = match self.indent {
+            Indent::Spaces(width) => (' ', width),
+            Indent::Tabs(width) => ('\t', width),
+        };
+
+        self.buf.extend(std::iter::repeat_n(c, width * self.depth));
+
+        Ok(())
+    }
+
+    fn is_inline(&self, token: bool) -> bool {
+        token && !self.multiline_tokens
+    }
+}
+
+impl Write for PrettyMathMlWriter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.buf.write_str(s)
+    }
+}
+
+impl Markup for PrettyMathMlWriter {
+    fn is_raw(&self) -> bool {
+        self.raw
+    }
+
+    fn generated_alttext(&self) -> GeneratedAltText {
+        self.alttext
+    }
+
+    fn before_start(&mut self) -> std::fmt::Result {
+        self.empty = false;
+
+        if self.buf.is_empty() {
+            Ok(())
+        } else {
+            self.new_line()
+        }
+    }
+
+    fn after_start(&mut self, token: bool) -> std::fmt::Result {
+        if self.is_inline(token) {
+            return Ok(());
+        }
+
+        self.depth += 1;
+        self.empty = !token;
+
+        if token {
+            self.new_line()
+        } else {
+            Ok(())
+        }
+    }
+
+    fn before_end(&mut self, token: bool) -> std::fmt::Result {
+        if self.is_inline(token) {
+            return Ok(());
+        }
+
+        self.depth = self.depth.saturating_sub(1);
+
+        if std::mem::take(&mut self.empty) {
+            Ok(())
+        } else {
+            self.new_line()
+        }
+    }
+}
+
+impl Writer for PrettyMathMlWriter {
+    type Buffer = String;
+    type Error = std::fmt::Error;
+
+    fn write_action(&mut self, action: &Action) -> Result<(), Self::Error> {
+        markup::action(self, action)
+    }
+
+    fn write_annotation(&mut self, annotation: &Annotation) -> Result<(), Self::Error> {
+        markup::annotation(self, annotation)
+    }
+
+    fn write_error(&mut self, error: &Error) -> Result<(), Self::Error> {
+        markup::error(self, error)
+    }
+
+    fn write_frac(&mut self, frac: &Frac) -> Result<(), Self::Error> {
+        markup::frac(self, frac)
+    }
+
+    fn write_ident(&mut self, ident: &Ident) -> Result<(), Self::Error> {
+        markup::ident(self, ident)
+    }
+
+    fn write_multiscripts(&mut self, multiscripts: &Multiscripts) -> Result<(), Self::Error> {
+        markup::multiscripts(self, multiscripts)
+    }
+
+    fn write_prescripts(&mut self, prescripts: &Prescripts) -> Result<(), Self::Error> {
+        markup::prescripts(self, prescripts)
+    }
+
+    fn write_num(&mut self, num: &Num) -> Result<(), Self::Error> {
+        markup::num(self, num)
+    }
+
+    fn write_operator(&mut self, operator: &Operator) -> Result<(), Self::Error> {
+        markup::operator(self, operator)
+    }
+
+    fn write_padded(&mut self, padded: &Padded) -> Result<(), Self::Error> {
+        markup::padded(self, padded)
+    }
+
+    fn write_phantom(&mut self, phantom: &Phantom) -> Result<(), Self::Error> {
+        markup::phantom(self, phantom)
+    }
+
+    fn write_radical(&mut self, radical: &Radical) -> Result<(), Self::Error> {
+        markup::radical(self, radical)
+    }
+
+    fn write_row(&mut self, row: &Row) -> Result<(), Self::Error> {
+        markup::row(self, row)
+    }
+
+    fn write_semantics(&mut self, semantics: &Semantics) -> Result<(), Self::Error> {
+        markup::semantics(self, semantics)
+    }
+
+    fn write_space(&mut self, space: &Space) -> Result<(), Self::Error> {
+        markup::space(self, space)
+    }
+
+    fn write_str_literal(&mut self, str_literal: &StrLiteral) -> Result<(), Self::Error> {
+        markup::str_literal(self, str_literal)
+    }
+
+    fn write_style(&mut self, style: &Style) -> Result<(), Self::Error> {
+        markup::style(self, style)
+    }
+
+    fn write_subsup(&mut self, subsup: &SubSup) -> Result<(), Self::Error> {
+        markup::subsup(self, subsup)
+    }
+
+    fn write_table(&mut self, table: &Table) -> Result<(), Self::Error> {
+        markup::table(self, table)
+    }
+
+    fn write_text(&mut self, text: &Text) -> Result<(), Self::Error> {
+        markup::text(self, text)
+    }
+
+    fn write_underover(&mut self, underover: &UnderOver) -> Result<(), Self::Error> {
+        markup::underover(self, underover)
+    }
+
+    fn write_attr(&mut self, attr: &Attribute) -> Result<(), Self::Error> {
+        markup::attribute(self, attr)
+    }
+
+    fn write_element(&mut self, element: &Element) -> Result<(), Self::Error> {
+        markup::element(self, element)
+    }
+
+    fn write_mathml(&mut self, mathml: &MathMl) -> Result<(), Self::Error> {
+        markup::mathml(self, mathml)
+    }
+
+    fn buffer<T>(&self) -> &T
+    where
+        Self::Buffer: Borrow<T>,
+    {
+        self.buf.borrow()
+    }
+
+    fn into_inner(self) -> Self::Buffer {
+        self.buf
+    }
+
+    fn finish(&mut self) -> Self::Buffer {
+        self.depth = 0;
+        self.empty = false;
+        std::mem::take(&mut self.buf)
+    }
+}
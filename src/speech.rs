@@ -0,0 +1,1052 @@
+//! Rendering of MathML elements into spoken text, e.g. for screen readers that do not read
+//! MathML well.
+//!
+//! Two speech styles are supported: [`SpeechStyle::ClearSpeak`] reads formulas in natural
+//! language (`the fraction a over b`, `x squared`), while [`SpeechStyle::MathSpeak`] marks the
+//! structure explicitly (`StartFraction a Over b EndFraction`, `x Superscript 2 Baseline`). The
+//! amount of detail is selected with [`Verbosity`]. Symbols are named with
+//! [`Operator::spoken_name`].
+//!
+//! The output can be used as alternative text of a formula.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{scripted::SubSup, Frac, Ident, Num, Operator};
+//! use alemat::speech::{SpeechFormatter, SpeechStyle, Verbosity};
+//! use alemat::{children, MathMl, MathMlAttr};
+//!
+//! let math = MathMl::with_content(children![
+//!     Frac::builder()
+//!         .num(Ident::from("a"))
+//!         .denom(Ident::from("b"))
+//!         .build(),
+//!     Operator::plus(),
+//!     SubSup::builder()
+//!         .base(Ident::from("x"))
+//!         .supscript(Num::from(2))
+//!         .build(),
+//! ]);
+//!
+//! let speech = math.render_with(&mut SpeechFormatter::default()).unwrap();
+//! assert_eq!(speech, "the fraction a over b plus x squared");
+//!
+//! let mut mathspeak = SpeechFormatter::new(SpeechStyle::MathSpeak, Verbosity::Verbose);
+//! let speech = math.render_with(&mut mathspeak).unwrap();
+//! assert_eq!(
+//!     speech,
+//!     "StartFraction a Over b EndFraction plus x Superscript 2 Baseline"
+//! );
+//!
+//! let math = math.with_attr([MathMlAttr::AltText(speech)]);
+//! ```
+
+use std::convert::Infallible;
+
+use crate::{
+    attributes::{Attribute, Length, MathVariant},
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver},
+        Annotation, Frac, FracAttr, Ident, Num, Operator, Padded, Semantics, Space, StrLiteral,
+        Table, Text,
+    },
+    greek, Element, MathMl, Renderer,
+};
+
+/// The conventions used for speaking formulas.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpeechStyle {
+    /// Natural language following the ClearSpeak conventions, e.g. `the fraction a over b`.
+    #[default]
+    ClearSpeak,
+
+    /// Explicit structure following the MathSpeak conventions, e.g.
+    /// `StartFraction a Over b EndFraction`.
+    MathSpeak,
+}
+
+/// The amount of detail in the spoken text.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Verbosity {
+    /// Shortest form, e.g. `a over b` or `StartFrac a Over b EndFrac`.
+    Brief,
+
+    /// Default form, which marks the end of compound constructs.
+    #[default]
+    Medium,
+
+    /// Longest form, e.g. `the fraction with numerator a and denominator b`.
+    Verbose,
+}
+
+/// [`Renderer`] implementation that turns elements into spoken text.
+///
+/// Spaces and phantoms are silent, and annotations are not read.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct SpeechFormatter {
+    style: SpeechStyle,
+    verbosity: Verbosity,
+
+    /// The nested scripts the renderer is currently in, used by MathSpeak.
+    levels: Vec<Script>,
+
+    /// Whether the renderer is in the condition of a limit, where arrows read as `approaches`.
+    limit: bool,
+}
+
+impl SpeechFormatter {
+    /// Create a [`SpeechFormatter`] with the given speech style and verbosity.
+    pub fn new(style: SpeechStyle, verbosity: Verbosity) -> Self {
+        Self {
+            style,
+            verbosity,
+            ..Self::default()
+        }
+    }
+
+    /// The conventions used for speaking formulas.
+    pub fn style(&self) -> SpeechStyle {
+        self.style
+    }
+
+    /// The amount of detail in the spoken text.
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    fn speak(&mut self, element: &Element) -> String {
+        let Ok(speech) = self.render_element(element);
+        speech
+    }
+
+    fn speak_row(&mut self, elements: &[Element]) -> String {
+        let mut parts = Vec::with_capacity(elements.len());
+        let mut idx = 0;
+
+        while idx < elements.len() {
+            let rest = &elements[idx..];
+
+            // fenced tables are read as matrices, determinants or cases
+            if let [Element::Operator(open), Element::Table(table), after @ ..] = rest {
+                if let Some(kind) = table_kind(open.op()) {
+                    parts.push(self.table(table, kind));
+                    idx += 2;
+
+                    if matches!(after.first(), Some(Element::Operator(close)) if is_closing(close.op()))
+                    {
+                        idx += 1;
+                    }
+
+                    continue;
+                }
+            }
+
+            if let [Element::Operator(open), Element::Frac(frac), Element::Operator(close), ..] =
+                rest
+            {
+                if open.op() == "(" && close.op() == ")" && is_zero_thickness(frac) {
+                    parts.push(self.binomial(frac));
+                    idx += 3;
+                    continue;
+                }
+            }
+
+            let part = match &rest[0] {
+                // a leading minus negates the following operand
+                Element::Operator(op) if idx == 0 && is_minus(op.op()) && rest.len() > 1 => {
+                    String::from("negative")
+                }
+                Element::Operator(op) if op.op() == "\u{2061}" => {
+                    // function application is implied after function names such as `sin`
+                    let function = matches!(
+                        elements[..idx].last(),
+                        Some(Element::Ident(ident)) if function_name(ident.ident()).is_some()
+                    );
+
+                    // parentheses around a simple argument are not read, e.g. `f of x`
+                    if let [_, Element::Operator(open), argument, Element::Operator(close), ..] =
+                        rest
+                    {
+                        if open.op() == "("
+                            && close.op() == ")"
+                            && is_simple(std::slice::from_ref(argument))
+                        {
+                            let argument = self.speak(argument);
+                            parts.push(if function {
+                                argument
+                            } else {
+                                format!("of {argument}")
+                            });
+                            idx += 4;
+                            continue;
+                        }
+                    }
+
+                    String::from(if function { "" } else { "of" })
+                }
+                element => self.speak(element),
+            };
+
+            parts.push(part);
+            idx += 1;
+        }
+
+        join(parts)
+    }
+
+    fn the(&self) -> &'static str {
+        match self.verbosity {
+            Verbosity::Brief => "",
+            _ => "the",
+        }
+    }
+
+    fn identifier(&self, ident: &str, attributes: &[Attribute]) -> String {
+        let mut chars = ident.chars();
+
+        let name = match (chars.next(), chars.next()) {
+            (Some(c), None) => self.letter(c),
+            _ => match function_name(ident) {
+                Some(name) => String::from(name),
+                None => ident.to_string(),
+            },
+        };
+
+        let variant = attributes.iter().find_map(|attr| match attr {
+            Attribute::MathVariant(variant) => Some(variant),
+            _ => None,
+        });
+
+        match variant {
+            Some(MathVariant::Normal | MathVariant::Italic) | None => name,
+            Some(variant) => format!("{} {name}", variant.to_string().replace('-', " ")),
+        }
+    }
+
+    fn letter(&self, c: char) -> String {
+        let name = match c {
+            '∞' => "infinity",
+            '∅' => "the empty set",
+            'ℏ' => "h bar",
+            c => match greek::name(c) {
+                Some(name) => name,
+                None if c.is_uppercase() => {
+                    return match (self.style, self.verbosity) {
+                        (SpeechStyle::MathSpeak, _) => format!("upper {c}"),
+                        (SpeechStyle::ClearSpeak, Verbosity::Verbose) => format!("cap {c}"),
+                        (SpeechStyle::ClearSpeak, _) => c.to_string(),
+                    }
+                }
+                None => return c.to_string(),
+            },
+        };
+
+        match self.style {
+            SpeechStyle::MathSpeak if name.starts_with(char::is_uppercase) => {
+                format!("upper {}", name.to_lowercase())
+            }
+            _ => String::from(name),
+        }
+    }
+
+    fn operator(&self, operator: &Operator) -> String {
+        let op = operator.op();
+
+        let name = match op {
+            "\u{2061}" => "",
+            "\u{2062}" if self.verbosity == Verbosity::Verbose => "times",
+            "\u{2062}" => "",
+            "\u{2063}" => ",",
+            "\u{2064}" => "plus",
+            "," if self.verbosity != Verbosity::Verbose => ",",
+            "\u{2192}" if self.limit => "approaches",
+            op => match (self.style, mathspeak_fence(op)) {
+                (SpeechStyle::MathSpeak, Some(name)) => name,
+                _ => match operator.spoken_name() {
+                    Some(name) => name,
+                    None => return self.letters(op),
+                },
+            },
+        };
+
+        String::from(name)
+    }
+
+    /// Name every character of an unknown symbol, e.g. greek letters used as operators.
+    fn letters(&self, s: &str) -> String {
+        join(s.chars().map(|c| self.letter(c)))
+    }
+
+    fn binomial(&mut self, frac: &Frac) -> String {
+        let n = self.speak_row(frac.num());
+        let k = self.speak_row(frac.denom());
+
+        match (self.style, self.verbosity) {
+            (SpeechStyle::ClearSpeak, _) => format!("{n} choose {k}"),
+            (SpeechStyle::MathSpeak, Verbosity::Verbose) => {
+                format!("StartBinomial {n} Choose {k} EndBinomial")
+            }
+            (SpeechStyle::MathSpeak, _) => format!("StartBinom {n} Choose {k} EndBinom"),
+        }
+    }
+
+    fn large_op(
+        &mut self,
+        expr: &[Element],
+        under: Option<&[Element]>,
+        over: Option<&[Element]>,
+    ) -> String {
+        let limit = matches!(expr, [Element::Ident(ident)] if ident.ident() == "lim");
+        let name = self.speak_row(expr);
+
+        self.limit = limit;
+        let under = under.map(|under| self.speak_row(under));
+        self.limit = false;
+
+        let over = over.map(|over| self.speak_row(over));
+
+        let bounds = match (under, over) {
+            (Some(under), _) if limit => format!("as {under}"),
+            (Some(under), Some(over)) => format!("from {under} to {over}"),
+            (Some(under), None) => format!("over {under}"),
+            (None, Some(over)) => format!("to {over}"),
+            (None, None) => String::new(),
+        };
+
+        let of = match self.verbosity {
+            Verbosity::Verbose => "of",
+            _ => "",
+        };
+
+        join([self.the().to_string(), name, bounds, of.to_string()])
+    }
+
+    /// Read a superscript as a power, e.g. `squared` or `to the n-th power`.
+    fn power(&mut self, sup: &[Element]) -> String {
+        let exponent = self.speak_row(sup);
+        let brief = self.verbosity == Verbosity::Brief;
+
+        match sup {
+            [Element::Num(num)] if num.num() == "2" => String::from("squared"),
+            [Element::Num(num)] if num.num() == "3" => String::from("cubed"),
+            [Element::Num(num)] => match (ordinal(num.num()), brief) {
+                (Some(ordinal), true) => format!("to the {ordinal}"),
+                (Some(ordinal), false) => format!("to the {ordinal} power"),
+                (None, _) => format!("to the {exponent} power"),
+            },
+            [Element::Ident(_)] if brief => format!("to the {exponent}"),
+            [Element::Ident(_)] => format!("to the {exponent}-th power"),
+            _ => match self.verbosity {
+                Verbosity::Brief => format!("to the {exponent}"),
+                Verbosity::Medium => format!("raised to the {exponent} power"),
+                Verbosity::Verbose => format!("raised to the exponent {exponent}, end exponent"),
+            },
+        }
+    }
+
+    fn subscript(&mut self, sub: &[Element]) -> String {
+        let index = self.speak_row(sub);
+
+        match (self.verbosity, is_simple(sub)) {
+            (Verbosity::Verbose, true) => format!("subscript {index}"),
+            (Verbosity::Verbose, false) => format!("subscript {index}, end subscript"),
+            (Verbosity::Medium, false) => format!("sub {index}, end sub"),
+            _ => format!("sub {index}"),
+        }
+    }
+
+    /// The name of the current script level in MathSpeak, e.g. `Superscript Subscript`.
+    fn level(&self) -> String {
+        let verbose = self.verbosity == Verbosity::Verbose;
+
+        if self.levels.is_empty() {
+            return String::from(if verbose { "Baseline" } else { "Base" });
+        }
+
+        join(self.levels.iter().map(|script| {
+            let name = match (script, verbose) {
+                (Script::Sub, true) => "Subscript",
+                (Script::Sub, false) => "Sub",
+                (Script::Sup, true) => "Superscript",
+                (Script::Sup, false) => "Sup",
+                (Script::PreSub, true) => "Presubscript",
+                (Script::PreSub, false) => "PreSub",
+                (Script::PreSup, true) => "Presuperscript",
+                (Script::PreSup, false) => "PreSup",
+            };
+
+            String::from(name)
+        }))
+    }
+
+    /// Read scripts in MathSpeak, each script is introduced with its level and the level of the
+    /// base is restored afterwards.
+    fn mathspeak_scripts(&mut self, scripts: &[(Script, &[Element])]) -> String {
+        let mut parts = Vec::new();
+
+        for (script, elements) in scripts {
+            self.levels.push(*script);
+
+            let level = self.level();
+            let content = self.speak_row(elements);
+
+            // a nested script returning to this level is followed by the next level anyway
+            let content = match content.strip_suffix(&level) {
+                Some(content) if !content.is_empty() => content.trim_end().to_string(),
+                _ => content,
+            };
+
+            parts.push(level);
+            parts.push(content);
+
+            self.levels.pop();
+        }
+
+        parts.push(self.level());
+        join(parts)
+    }
+
+    /// Read the scripts of the base in ClearSpeak, e.g. `sub i squared`.
+    fn clearspeak_scripts(&mut self, sub: Option<&[Element]>, sup: Option<&[Element]>) -> String {
+        let sub = sub.map(|sub| self.subscript(sub));
+        let sup = sup.map(|sup| self.power(sup));
+
+        join(sub.into_iter().chain(sup))
+    }
+
+    fn accent(&mut self, expr: &[Element], accent: &Operator, over: bool) -> String {
+        let base = self.speak_row(expr);
+
+        if self.style == SpeechStyle::MathSpeak {
+            let name = self.operator(accent);
+
+            return match over {
+                true => format!("ModifyingAbove {base} With {name}"),
+                false => format!("ModifyingBelow {base} With {name}"),
+            };
+        }
+
+        match (accent.op(), over) {
+            ("^" | "\u{02C6}", true) => format!("{base} hat"),
+            ("\u{00AF}" | "\u{203E}", true) => format!("{base} bar"),
+            ("~" | "\u{02DC}", true) => format!("{base} tilde"),
+            ("\u{02D9}", true) => format!("{base} dot"),
+            ("\u{00A8}", true) => format!("{base} double dot"),
+            ("\u{2192}", true) => format!("vector {base}"),
+            ("_" | "\u{00AF}" | "\u{203E}", false) => format!("{base} underbar"),
+            (_, true) => format!("{base} with {} above", self.operator(accent)),
+            (_, false) => format!("{base} with {} below", self.operator(accent)),
+        }
+    }
+
+    fn table(&mut self, table: &Table, kind: TableKind) -> String {
+        let rows = table.rows();
+        let columns = rows
+            .iter()
+            .map(|row| row.cells().len())
+            .max()
+            .unwrap_or_default();
+
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                row.cells()
+                    .iter()
+                    .map(|cell| self.speak_row(cell.children()))
+                    .collect()
+            })
+            .collect();
+
+        match self.style {
+            SpeechStyle::ClearSpeak => self.clearspeak_table(cells, columns, kind),
+            SpeechStyle::MathSpeak => self.mathspeak_table(cells, columns, kind),
+        }
+    }
+
+    fn clearspeak_table(&self, cells: Vec<Vec<String>>, columns: usize, kind: TableKind) -> String {
+        let rows = cells.len();
+        let the = self.the();
+
+        let (header, row_name, end) = match kind {
+            TableKind::Matrix => (format!("{the} {rows} by {columns} matrix"), "row", "matrix"),
+            TableKind::Determinant => (
+                format!("{the} {rows} by {columns} determinant"),
+                "row",
+                "determinant",
+            ),
+            TableKind::Cases => (format!("{rows} cases"), "case", "cases"),
+            TableKind::Layout => (format!("{the} {rows} by {columns} table"), "row", "table"),
+        };
+
+        let mut sentences = vec![header.trim_start().to_string()];
+
+        for (idx, row) in cells.into_iter().enumerate() {
+            let row = match self.verbosity {
+                Verbosity::Verbose => row
+                    .into_iter()
+                    .enumerate()
+                    .map(|(column, cell)| format!("column {}: {cell}", column + 1))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                _ => row.join(", "),
+            };
+
+            sentences.push(format!("{row_name} {}: {row}", idx + 1));
+        }
+
+        if self.verbosity != Verbosity::Brief {
+            sentences.push(format!("end {end}"));
+        }
+
+        sentences.join(". ")
+    }
+
+    fn mathspeak_table(&self, cells: Vec<Vec<String>>, columns: usize, kind: TableKind) -> String {
+        let rows = cells.len();
+
+        let (start, end) = match kind {
+            TableKind::Matrix => (format!("Start {rows} By {columns} Matrix"), "EndMatrix"),
+            TableKind::Determinant => (
+                format!("Start {rows} By {columns} Determinant"),
+                "EndDeterminant",
+            ),
+            TableKind::Cases => (
+                String::from("Start Layout Enlarged left-brace"),
+                "EndLayout",
+            ),
+            TableKind::Layout => (String::from("Start Layout"), "EndLayout"),
+        };
+
+        let mut parts = vec![start];
+
+        for (idx, row) in cells.into_iter().enumerate() {
+            parts.push(format!("{} Row", ordinal_number(idx + 1)));
+
+            for (column, cell) in row.into_iter().enumerate() {
+                if self.verbosity != Verbosity::Brief {
+                    parts.push(format!("{} Column", ordinal_number(column + 1)));
+                }
+
+                parts.push(cell);
+            }
+        }
+
+        parts.push(String::from(end));
+        join(parts)
+    }
+}
+
+impl Renderer for SpeechFormatter {
+    type Output = String;
+    type Error = Infallible;
+
+    fn render_action(&mut self, action: &Action) -> Result<Self::Output, Self::Error> {
+        let selection = action.attributes().iter().find_map(|attr| match attr {
+            ActionAttr::Selection(selection) => selection.trim().parse::<usize>().ok(),
+            _ => None,
+        });
+
+        let content = action.content();
+        let selected = selection
+            .and_then(|idx| content.get(idx.checked_sub(1)?))
+            .or_else(|| content.first());
+
+        match selected {
+            Some(element) => self.render_element(element),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_annotation(&mut self, _annotation: &Annotation) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_error(&mut self, error: &Error) -> Result<Self::Output, Self::Error> {
+        Ok(self.speak_row(error.content()))
+    }
+
+    fn render_frac(&mut self, frac: &Frac) -> Result<Self::Output, Self::Error> {
+        let num = self.speak_row(frac.num());
+        let denom = self.speak_row(frac.denom());
+
+        if self.style == SpeechStyle::MathSpeak {
+            let nesting = nested_fracs(frac.num()).max(nested_fracs(frac.denom()));
+
+            let (start, end) = match self.verbosity {
+                Verbosity::Verbose => ("Fraction", "Fraction"),
+                _ => ("Frac", "Frac"),
+            };
+
+            let start = format!("{}Start{start}", "Start".repeat(nesting));
+            let over = "Over".repeat(nesting + 1);
+            let end = format!("{}End{end}", "End".repeat(nesting));
+
+            return Ok(format!("{start} {num} {over} {denom} {end}"));
+        }
+
+        let simple = is_simple(frac.num()) && is_simple(frac.denom());
+
+        let speech = match (self.verbosity, simple) {
+            (Verbosity::Brief, true) => format!("{num} over {denom}"),
+            (Verbosity::Brief, false) => format!("fraction {num} over {denom}, end fraction"),
+            (Verbosity::Medium, true) => format!("the fraction {num} over {denom}"),
+            (Verbosity::Medium, false) => {
+                format!("the fraction {num} over {denom}, end fraction")
+            }
+            (Verbosity::Verbose, _) => {
+                format!("the fraction with numerator {num} and denominator {denom}, end fraction")
+            }
+        };
+
+        Ok(speech)
+    }
+
+    fn render_ident(&mut self, ident: &Ident) -> Result<Self::Output, Self::Error> {
+        Ok(self.identifier(ident.ident(), ident.attributes()))
+    }
+
+    fn render_multiscripts(
+        &mut self,
+        multiscripts: &Multiscripts,
+    ) -> Result<Self::Output, Self::Error> {
+        let content = multiscripts.content();
+
+        let Some((base, scripts)) = content.split_first() else {
+            return Ok(String::new());
+        };
+
+        let (post, pre) = match scripts
+            .iter()
+            .position(|element| matches!(element, Element::Prescripts(_)))
+        {
+            Some(idx) => (&scripts[..idx], &scripts[idx + 1..]),
+            None => (scripts, &[][..]),
+        };
+
+        let base = self.speak(base);
+
+        if self.style == SpeechStyle::MathSpeak {
+            let pre = pairs(pre, Script::PreSub, Script::PreSup);
+            let post = pairs(post, Script::Sub, Script::Sup);
+
+            let pre = match pre.is_empty() {
+                true => String::new(),
+                false => self.mathspeak_scripts(&pre),
+            };
+
+            let post = match post.is_empty() {
+                true => String::new(),
+                false => self.mathspeak_scripts(&post),
+            };
+
+            return Ok(join([pre, base, post]));
+        }
+
+        let mut parts = vec![base];
+
+        let pre: Vec<String> = pairs(pre, Script::PreSub, Script::PreSup)
+            .into_iter()
+            .map(|(script, elements)| {
+                let script = match script {
+                    Script::PreSub => "presubscript",
+                    _ => "presuperscript",
+                };
+
+                format!("{script} {}", self.speak_row(elements))
+            })
+            .collect();
+
+        if !pre.is_empty() {
+            parts.push(format!("with {},", pre.join(" and ")));
+        }
+
+        for (script, elements) in pairs(post, Script::Sub, Script::Sup) {
+            let part = match script {
+                Script::Sub => self.subscript(elements),
+                _ => self.power(elements),
+            };
+
+            parts.push(part);
+        }
+
+        Ok(join(parts).trim_end_matches(',').to_string())
+    }
+
+    fn render_prescripts(&mut self, _prescripts: &Prescripts) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_num(&mut self, num: &Num) -> Result<Self::Output, Self::Error> {
+        Ok(num.num().trim().to_string())
+    }
+
+    fn render_operator(&mut self, operator: &Operator) -> Result<Self::Output, Self::Error> {
+        Ok(self.operator(operator))
+    }
+
+    fn render_padded(&mut self, padded: &Padded) -> Result<Self::Output, Self::Error> {
+        Ok(self.speak_row(padded.children()))
+    }
+
+    fn render_phantom(&mut self, _phantom: &Phantom) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_radical(&mut self, radical: &Radical) -> Result<Self::Output, Self::Error> {
+        let content = self.speak_row(radical.content());
+
+        if self.style == SpeechStyle::MathSpeak {
+            if radical.is_square() || radical.index().is_empty() {
+                return Ok(format!("StartRoot {content} EndRoot"));
+            }
+
+            let index = self.speak_row(radical.index());
+            return Ok(format!("RootIndex {index} StartRoot {content} EndRoot"));
+        }
+
+        let index = &radical.index()[..];
+        let ordinal = match index {
+            [Element::Num(num)] => ordinal(num.num()),
+            _ => None,
+        };
+
+        let name = match (index, ordinal) {
+            _ if radical.is_square() => String::from("square root"),
+            ([], _) => String::from("square root"),
+            ([Element::Num(num)], _) if num.num().trim() == "3" => String::from("cube root"),
+            (_, Some(ordinal)) => format!("{ordinal} root"),
+            ([Element::Ident(_)], _) => format!("{}-th root", self.speak_row(index)),
+            (index, None) => format!("root with index {},", self.speak_row(index)),
+        };
+
+        let end = match (self.verbosity, is_simple(radical.content())) {
+            (Verbosity::Brief, _) | (_, true) => "",
+            _ => ", end root",
+        };
+
+        Ok(format!("{} {name} of {content}{end}", self.the())
+            .trim_start()
+            .to_string())
+    }
+
+    fn render_row(&mut self, row: &Row) -> Result<Self::Output, Self::Error> {
+        Ok(self.speak_row(row.children()))
+    }
+
+    fn render_semantics(&mut self, semantics: &Semantics) -> Result<Self::Output, Self::Error> {
+        match semantics.children().first() {
+            Some(presentation) => self.render_element(presentation),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_space(&mut self, _space: &Space) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_str_literal(
+        &mut self,
+        str_literal: &StrLiteral,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(str_literal.content().trim().to_string())
+    }
+
+    fn render_style(&mut self, style: &Style) -> Result<Self::Output, Self::Error> {
+        Ok(self.speak_row(style.children()))
+    }
+
+    fn render_subsup(&mut self, sub_sup: &SubSup) -> Result<Self::Output, Self::Error> {
+        if self.style == SpeechStyle::ClearSpeak && is_large_op(sub_sup.base()) {
+            return Ok(self.large_op(sub_sup.base(), sub_sup.sub(), sub_sup.sup()));
+        }
+
+        let base = self.speak_row(sub_sup.base());
+
+        // primes are read after the base, e.g. `f prime`
+        let mut sup = sub_sup.sup();
+        let mut primes = String::new();
+
+        if let Some([Element::Operator(op), rest @ ..]) = sup {
+            if matches!(op.op(), "′" | "″" | "‴") {
+                primes = self.operator(op);
+                sup = (!rest.is_empty()).then_some(rest);
+            }
+        }
+
+        let scripts = match self.style {
+            SpeechStyle::ClearSpeak => self.clearspeak_scripts(sub_sup.sub(), sup),
+            SpeechStyle::MathSpeak => {
+                let scripts: Vec<_> = [(Script::Sub, sub_sup.sub()), (Script::Sup, sup)]
+                    .into_iter()
+                    .filter_map(|(script, elements)| Some((script, elements?)))
+                    .collect();
+
+                match scripts.is_empty() {
+                    true => String::new(),
+                    false => self.mathspeak_scripts(&scripts),
+                }
+            }
+        };
+
+        Ok(join([base, primes, scripts]))
+    }
+
+    fn render_table(&mut self, table: &Table) -> Result<Self::Output, Self::Error> {
+        Ok(self.table(table, TableKind::Layout))
+    }
+
+    fn render_text(&mut self, text: &Text) -> Result<Self::Output, Self::Error> {
+        Ok(text.text().trim().to_string())
+    }
+
+    fn render_underover(&mut self, under_over: &UnderOver) -> Result<Self::Output, Self::Error> {
+        let expr = under_over.expr();
+        let under = under_over.under();
+        let over = under_over.over();
+
+        if self.style == SpeechStyle::ClearSpeak && is_large_op(expr) {
+            return Ok(self.large_op(expr, under, over));
+        }
+
+        match (under, over) {
+            (None, Some([Element::Operator(accent)])) => return Ok(self.accent(expr, accent, true)),
+            (Some([Element::Operator(accent)]), None) => {
+                return Ok(self.accent(expr, accent, false))
+            }
+            _ => {}
+        }
+
+        let base = self.speak_row(expr);
+        let under = under.map(|under| self.speak_row(under));
+        let over = over.map(|over| self.speak_row(over));
+
+        let speech = match self.style {
+            SpeechStyle::ClearSpeak => match (under, over) {
+                (Some(under), Some(over)) => {
+                    format!("{base} with {under} below and {over} above")
+                }
+                (Some(under), None) => format!("{base} with {under} below"),
+                (None, Some(over)) => format!("{base} with {over} above"),
+                (None, None) => base,
+            },
+            SpeechStyle::MathSpeak => {
+                let (underscript, overscript) = match self.verbosity {
+                    Verbosity::Brief => ("UndScript", "OvScript"),
+                    _ => ("Underscript", "Overscript"),
+                };
+
+                let under = under.map(|under| format!("{underscript} {under}"));
+                let over = over.map(|over| format!("{overscript} {over}"));
+
+                join(
+                    [Some(base), under, over, Some(String::from("Endscripts"))]
+                        .into_iter()
+                        .flatten(),
+                )
+            }
+        };
+
+        Ok(speech)
+    }
+
+    fn render_attr(&mut self, _attr: &Attribute) -> Result<Self::Output, Self::Error> {
+        // attributes are not spoken, except for math variants which are read with the content of
+        // the token elements
+        Ok(String::new())
+    }
+
+    fn render_mathml(&mut self, mathml: &MathMl) -> Result<Self::Output, Self::Error> {
+        Ok(self.speak_row(mathml.content()))
+    }
+}
+
+/// The kind of a script in MathSpeak.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Script {
+    Sub,
+    Sup,
+    PreSub,
+    PreSup,
+}
+
+/// How a table is read, depending on the fence around it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TableKind {
+    Matrix,
+    Determinant,
+    Cases,
+    Layout,
+}
+
+fn table_kind(open: &str) -> Option<TableKind> {
+    match open {
+        "(" | "[" => Some(TableKind::Matrix),
+        "|" | "\u{2223}" => Some(TableKind::Determinant),
+        "{" => Some(TableKind::Cases),
+        _ => None,
+    }
+}
+
+fn is_closing(op: &str) -> bool {
+    matches!(op, ")" | "]" | "|" | "\u{2223}" | "}")
+}
+
+fn mathspeak_fence(op: &str) -> Option<&'static str> {
+    let name = match op {
+        "(" => "left-parenthesis",
+        ")" => "right-parenthesis",
+        "[" => "left-bracket",
+        "]" => "right-bracket",
+        "{" => "left-brace",
+        "}" => "right-brace",
+        "|" | "\u{2223}" => "vertical-bar",
+        "\u{27E8}" => "left-angle-bracket",
+        "\u{27E9}" => "right-angle-bracket",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Spoken names of common functions, e.g. `sine` for `sin`.
+fn function_name(ident: &str) -> Option<&'static str> {
+    let name = match ident {
+        "sin" => "sine",
+        "cos" => "cosine",
+        "tan" => "tangent",
+        "cot" => "cotangent",
+        "sec" => "secant",
+        "csc" => "cosecant",
+        "sinh" => "hyperbolic sine",
+        "cosh" => "hyperbolic cosine",
+        "tanh" => "hyperbolic tangent",
+        "arcsin" => "arc sine",
+        "arccos" => "arc cosine",
+        "arctan" => "arc tangent",
+        "log" => "log",
+        "ln" => "natural log",
+        "lg" => "log base 10",
+        "exp" => "exponential",
+        "det" => "determinant",
+        "dim" => "dimension",
+        "gcd" => "greatest common divisor",
+        "lim" => "limit",
+        "max" => "maximum",
+        "min" => "minimum",
+        "sup" => "supremum",
+        "inf" => "infimum",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+fn is_large_op(expr: &[Element]) -> bool {
+    match expr {
+        [Element::Operator(op)] => matches!(
+            op.op(),
+            "∑" | "∏"
+                | "∐"
+                | "⋃"
+                | "⋂"
+                | "⋀"
+                | "⋁"
+                | "⨁"
+                | "⨂"
+                | "⨀"
+                | "∫"
+                | "∬"
+                | "∭"
+                | "∮"
+        ),
+        [Element::Ident(ident)] => matches!(ident.ident(), "lim" | "max" | "min" | "sup" | "inf"),
+        _ => false,
+    }
+}
+
+fn is_minus(op: &str) -> bool {
+    matches!(op, "-" | "\u{2212}")
+}
+
+/// Whether the elements are a single identifier or number, which needs no end marker.
+fn is_simple(elements: &[Element]) -> bool {
+    match elements {
+        [Element::Ident(_) | Element::Num(_)] => true,
+        [Element::Operator(op), Element::Num(_)] => is_minus(op.op()),
+        [Element::Row(row)] => is_simple(row.children()),
+        _ => false,
+    }
+}
+
+fn is_zero_thickness(frac: &Frac) -> bool {
+    frac.attributes().iter().any(|attr| {
+        matches!(
+            attr,
+            FracAttr::LineThickness(lt) if lt.parse::<Length>().is_ok_and(|lt| lt.is_zero())
+        )
+    })
+}
+
+/// The number of fractions nested in the elements, used for MathSpeak.
+fn nested_fracs(elements: &[Element]) -> usize {
+    elements
+        .iter()
+        .map(|element| match element {
+            Element::Frac(frac) => 1 + nested_fracs(frac.num()).max(nested_fracs(frac.denom())),
+            Element::Row(row) => nested_fracs(row.children()),
+            Element::Style(style) => nested_fracs(style.children()),
+            Element::Padded(padded) => nested_fracs(padded.children()),
+            _ => 0,
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+/// Split the scripts of multiscripts into their kinds, skipping empty rows.
+fn pairs(scripts: &[Element], sub: Script, sup: Script) -> Vec<(Script, &[Element])> {
+    scripts
+        .chunks(2)
+        .flat_map(|pair| pair.iter().zip([sub, sup]))
+        .filter(|(element, _)| !matches!(element, Element::Row(row) if row.children().is_empty()))
+        .map(|(element, script)| (script, std::slice::from_ref(element)))
+        .collect()
+}
+
+fn ordinal(num: &str) -> Option<String> {
+    num.trim().parse::<usize>().ok().map(ordinal_number)
+}
+
+fn ordinal_number(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{n}{suffix}")
+}
+
+/// Join spoken parts with spaces, skipping empty parts and attaching punctuation.
+fn join(parts: impl IntoIterator<Item = String>) -> String {
+    let mut speech = String::new();
+
+    for part in parts {
+        let part = part.trim();
+
+        if part.is_empty() {
+            continue;
+        }
+
+        if !speech.is_empty() && !part.starts_with([',', '.', ';', ':']) {
+            speech.push(' ');
+        }
+
+        speech.push_str(part);
+    }
+
+    speech
+}
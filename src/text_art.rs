@@ -0,0 +1,1047 @@
+//! Rendering of MathML elements into two-dimensional text art, e.g. for terminal output.
+//!
+//! Fractions are drawn with a horizontal bar, scripts are placed above and below their base,
+//! roots get a radical sign with an overline and tables are laid out in aligned columns, with
+//! surrounding brackets stretched to the height of their content.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{Frac, Ident, Num, Operator};
+//! use alemat::text_art::TextArtFormatter;
+//! use alemat::{children, MathMl};
+//!
+//! let math = MathMl::with_content(children![
+//!     Ident::from("y"),
+//!     Operator::eq(),
+//!     Frac::builder()
+//!         .num(children![Ident::from("a"), Operator::plus(), Num::from(1)])
+//!         .denom(Ident::from("b"))
+//!         .build(),
+//! ]);
+//!
+//! let unicode = math.render_with(&mut TextArtFormatter::default()).unwrap();
+//! assert_eq!(unicode, "     a + 1\ny = ───────\n       b");
+//!
+//! let ascii = math.render_with(&mut TextArtFormatter::ascii()).unwrap();
+//! assert_eq!(ascii, "     a + 1\ny = -------\n       b");
+//! ```
+
+use std::{convert::Infallible, fmt::Display};
+
+use crate::{
+    attributes::{Attribute, Length, Unit},
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver},
+        Annotation, Frac, FracAttr, Ident, Num, Operator, OperatorAttr, Padded, Semantics, Space,
+        SpaceAttr, StrLiteral, Table, TableCellAttr, Text,
+    },
+    greek,
+    linear::styled_char,
+    Element, MathMl, Renderer,
+};
+
+/// [`Renderer`] implementation that lays out elements in two dimensions and returns them as a
+/// multi-line [`String`].
+///
+/// By default, box-drawing and bracket-piece characters are used for fraction bars, roots and
+/// stretched brackets. Use [`TextArtFormatter::ascii`] to only use ASCII characters for the
+/// drawing and to transliterate common operators and greek letters, e.g. `≤` into `<=`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextArtFormatter {
+    ascii: bool,
+}
+
+impl TextArtFormatter {
+    /// Create a [`TextArtFormatter`] that only uses ASCII characters for the drawing.
+    pub fn ascii() -> Self {
+        Self { ascii: true }
+    }
+
+    /// Whether only ASCII characters are used for the drawing.
+    pub fn is_ascii(&self) -> bool {
+        self.ascii
+    }
+
+    fn layout(&mut self, element: &Element) -> Block {
+        match element {
+            Element::Action(action) => self.action(action),
+            Element::Annotation(_) | Element::Prescripts(_) => Block::empty(),
+            Element::Error(error) => self.row(error.content()),
+            Element::Frac(frac) => self.frac(frac),
+            Element::Ident(ident) => self.token(ident.ident(), ident.attributes()),
+            Element::Multiscripts(multiscripts) => self.multiscripts(multiscripts),
+            Element::Num(num) => self.token(num.num(), num.attributes()),
+            Element::Operator(op) => Block::text(&self.operator(op.op())),
+            Element::Padded(padded) => self.row(padded.children()),
+            Element::Phantom(phantom) => self.row(phantom.children()).blank_copy(),
+            Element::Radical(radical) => self.radical(radical),
+            Element::Row(row) => self.row_with(row.children(), true),
+            Element::Semantics(semantics) => match semantics.children().first() {
+                Some(presentation) => self.layout(presentation),
+                None => Block::empty(),
+            },
+            Element::Space(space) => self.space(space),
+            Element::StrLiteral(literal) => Block::text(&format!("\"{}\"", literal.content())),
+            Element::Style(style) => self.row(style.children()),
+            Element::SubSup(sub_sup) => self.subsup(sub_sup),
+            Element::Table(table) => self.table(table),
+            Element::Text(text) => Block::text(text.text()),
+            Element::UnderOver(under_over) => self.underover(under_over),
+        }
+    }
+
+    fn action(&mut self, action: &Action) -> Block {
+        let selection = action.attributes().iter().find_map(|attr| match attr {
+            ActionAttr::Selection(selection) => selection.trim().parse::<usize>().ok(),
+            _ => None,
+        });
+
+        let content = action.content();
+        let selected = selection
+            .and_then(|idx| content.get(idx.checked_sub(1)?))
+            .or_else(|| content.first());
+
+        match selected {
+            Some(element) => self.layout(element),
+            None => Block::empty(),
+        }
+    }
+
+    fn token(&self, content: &str, attributes: &[Attribute]) -> Block {
+        let variant = attributes.iter().find_map(|attr| match attr {
+            Attribute::MathVariant(variant) => Some(variant),
+            _ => None,
+        });
+
+        let content = match variant {
+            Some(variant) if !self.ascii => content
+                .chars()
+                .map(|c| styled_char(c, variant).unwrap_or(c))
+                .collect(),
+            _ => self.transliterate(content),
+        };
+
+        Block::text(&content)
+    }
+
+    fn operator(&self, op: &str) -> String {
+        match op {
+            // invisible operators such as function application
+            "\u{2061}" | "\u{2062}" | "\u{2063}" | "\u{2064}" => String::new(),
+            op => self.transliterate(op),
+        }
+    }
+
+    /// Replace characters that are not ASCII in ASCII mode, e.g. `≤` with `<=`.
+    fn transliterate(&self, s: &str) -> String {
+        if !self.ascii || s.is_ascii() {
+            return s.to_string();
+        }
+
+        let mut out = String::new();
+
+        for c in s.chars() {
+            match ascii_char(c) {
+                Some(replacement) => {
+                    // keep words apart, e.g. `alpha beta`
+                    let word = replacement.starts_with(char::is_alphabetic);
+                    if word && out.ends_with(char::is_alphabetic) {
+                        out.push(' ');
+                    }
+
+                    out.push_str(replacement);
+                }
+                None => out.push(c),
+            }
+        }
+
+        out
+    }
+
+    fn row(&mut self, elements: &[Element]) -> Block {
+        self.row_with(elements, false)
+    }
+
+    /// Lay out elements beside each other. Brackets are stretched to the height of the other
+    /// elements if they are marked as stretchy or as fences, or if they are the first or last
+    /// child of an `mrow`, which makes them prefix or postfix operators that stretch by default.
+    fn row_with(&mut self, elements: &[Element], mrow: bool) -> Block {
+        let mut blocks: Vec<Block> = Vec::with_capacity(elements.len());
+        let mut brackets = Vec::new();
+
+        for (idx, element) in elements.iter().enumerate() {
+            let block = match element {
+                Element::Operator(op) if bracket(op.op()).is_some() => {
+                    let fence = mrow && (idx == 0 || idx + 1 == elements.len());
+                    if fence || is_stretchy(op) {
+                        brackets.push(idx);
+                    }
+
+                    Block::text(&self.operator(op.op()))
+                }
+                // function application separates a function from an argument without brackets
+                Element::Operator(op) if op.op() == "\u{2061}" => {
+                    let spaced = elements
+                        .get(idx + 1)
+                        .is_some_and(|next| !starts_with_bracket(next))
+                        && !blocks.last().is_some_and(Block::ends_with_space);
+
+                    Block::text(if spaced { " " } else { "" })
+                }
+                Element::Operator(op) => {
+                    let text = self.operator(op.op());
+
+                    let spacing = if idx == 0 || idx + 1 == elements.len() {
+                        Spacing::None
+                    } else if is_word(op.op()) {
+                        Spacing::Around
+                    } else {
+                        spacing(&text)
+                    };
+
+                    let (left, right) = match spacing {
+                        Spacing::Around => (1, 1),
+                        Spacing::After => (0, 1),
+                        Spacing::None => (0, 0),
+                    };
+
+                    // explicit spaces replace the default spacing
+                    let left = op
+                        .attributes()
+                        .iter()
+                        .find_map(|attr| match attr {
+                            OperatorAttr::LeftSpace(space) => Some(columns(space)),
+                            _ => None,
+                        })
+                        .unwrap_or(left);
+                    let right = op
+                        .attributes()
+                        .iter()
+                        .find_map(|attr| match attr {
+                            OperatorAttr::RightSpace(space) => Some(columns(space)),
+                            _ => None,
+                        })
+                        .unwrap_or(right);
+
+                    Block::text(&format!("{}{text}{}", " ".repeat(left), " ".repeat(right)))
+                }
+                // function names are set apart from a preceding operand, e.g. `a mod b`
+                Element::Ident(ident)
+                    if is_function_name(elements, idx)
+                        && idx > 0
+                        && !matches!(elements[idx - 1], Element::Operator(_)) =>
+                {
+                    let token = self.token(ident.ident(), ident.attributes());
+                    Block::beside(vec![Block::text(" "), token])
+                }
+                // keep transliterated identifiers apart, e.g. `alpha beta`
+                Element::Ident(ident)
+                    if self.ascii
+                        && matches!(
+                            elements[..idx].last(),
+                            Some(Element::Ident(prev)) if !prev.ident().is_ascii() || !ident.ident().is_ascii()
+                        ) =>
+                {
+                    let token = self.token(ident.ident(), ident.attributes());
+                    Block::beside(vec![Block::text(" "), token])
+                }
+                element => self.layout(element),
+            };
+
+            blocks.push(block);
+        }
+
+        // brackets stretch to the height of the content of the row
+        let (above, below) = blocks
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !brackets.contains(idx))
+            .fold((0, 0), |(above, below), (_, block)| {
+                (above.max(block.baseline), below.max(block.depth()))
+            });
+
+        if above + below > 0 {
+            for idx in brackets {
+                if let Element::Operator(op) = &elements[idx] {
+                    if let Some(kind) = bracket(op.op()) {
+                        blocks[idx] = self.tall_bracket(kind, above, below);
+                    }
+                }
+            }
+        }
+
+        Block::beside(blocks)
+    }
+
+    /// Draw a bracket spanning the given number of lines above and below the baseline.
+    fn tall_bracket(&self, kind: Bracket, above: usize, below: usize) -> Block {
+        let height = above + below + 1;
+        let middle = height / 2;
+
+        let (top, fill, center, bottom) = if self.ascii {
+            match kind {
+                Bracket::LeftParen => ("/", "|", "|", "\\"),
+                Bracket::RightParen => ("\\", "|", "|", "/"),
+                Bracket::LeftSquare => ("[", "[", "[", "["),
+                Bracket::RightSquare => ("]", "]", "]", "]"),
+                Bracket::LeftCurly => ("/", "|", "<", "\\"),
+                Bracket::RightCurly => ("\\", "|", ">", "/"),
+                Bracket::Vert => ("|", "|", "|", "|"),
+                Bracket::DoubleVert => ("||", "||", "||", "||"),
+                Bracket::LeftAngle => ("/", " ", "<", "\\"),
+                Bracket::RightAngle => ("\\", " ", ">", "/"),
+            }
+        } else {
+            match kind {
+                Bracket::LeftParen => ("⎛", "⎜", "⎜", "⎝"),
+                Bracket::RightParen => ("⎞", "⎟", "⎟", "⎠"),
+                Bracket::LeftSquare => ("⎡", "⎢", "⎢", "⎣"),
+                Bracket::RightSquare => ("⎤", "⎥", "⎥", "⎦"),
+                Bracket::LeftCurly => ("⎧", "⎪", "⎨", "⎩"),
+                Bracket::RightCurly => ("⎫", "⎪", "⎬", "⎭"),
+                Bracket::Vert => ("│", "│", "│", "│"),
+                Bracket::DoubleVert => ("‖", "‖", "‖", "‖"),
+                Bracket::LeftAngle => ("╱", " ", "⟨", "╲"),
+                Bracket::RightAngle => ("╲", " ", "⟩", "╱"),
+            }
+        };
+
+        let lines = (0..height)
+            .map(|line| match line {
+                0 => top,
+                line if line + 1 == height => bottom,
+                line if line == middle => center,
+                _ => fill,
+            })
+            .map(String::from)
+            .collect();
+
+        Block::new(lines, above)
+    }
+
+    fn frac(&mut self, frac: &Frac) -> Block {
+        let num = self.row(frac.num());
+        let denom = self.row(frac.denom());
+
+        let bar = !frac.attributes().iter().any(|attr| {
+            matches!(
+                attr,
+                FracAttr::LineThickness(lt) if lt.parse::<Length>().is_ok_and(|lt| lt.is_zero())
+            )
+        });
+
+        let width = num.width.max(denom.width) + 2;
+        let baseline = num.height();
+
+        if bar {
+            let line = if self.ascii { "-" } else { "─" }.repeat(width);
+            Block::stack(
+                vec![num, Block::text(&line), denom],
+                Align::Center,
+                baseline,
+            )
+        } else {
+            // stacked content without a bar, e.g. a binomial coefficient
+            let num = num.pad(width, Align::Center);
+            Block::stack(vec![num, denom], Align::Center, baseline.saturating_sub(1))
+        }
+    }
+
+    fn radical(&mut self, radical: &Radical) -> Block {
+        let content = self.row(radical.content());
+
+        let baseline = content.baseline + 1;
+
+        let overline = if self.ascii { "_" } else { "─" }.repeat(content.width);
+        let content = Block::stack(vec![Block::text(&overline), content], Align::Left, baseline);
+
+        let (fill, sign) = if self.ascii {
+            (" |", "\\/")
+        } else {
+            ("│", "√")
+        };
+
+        let sign_lines = (0..content.height())
+            .map(|line| {
+                if line + 1 == content.height() {
+                    String::from(sign)
+                } else if line == 0 {
+                    " ".repeat(fill.chars().count())
+                } else {
+                    String::from(fill)
+                }
+            })
+            .collect();
+
+        let sign = Block::new(sign_lines, content.baseline);
+        let mut blocks = vec![sign, content];
+
+        if !radical.is_square() && !radical.index().is_empty() {
+            // the index is placed left of the sign, ending at the overline
+            let index = self.row(radical.index()).above(blocks[1].baseline);
+            blocks.insert(0, index);
+        }
+
+        Block::beside(blocks)
+    }
+
+    fn scripts(&mut self, sub: Option<&[Element]>, sup: Option<&[Element]>, base: &Block) -> Block {
+        let sub = sub.map(|sub| self.row(sub));
+        let sup = sup.map(|sup| self.row(sup));
+        script_column(sub, sup, base)
+    }
+
+    fn subsup(&mut self, sub_sup: &SubSup) -> Block {
+        let base = self.row(sub_sup.base());
+        let mut sup = sub_sup.sup();
+
+        // primes are written next to the base, e.g. `f′`
+        let mut primes = None;
+        if let Some([Element::Operator(op), rest @ ..]) = sup {
+            if !op.op().is_empty() && op.op().chars().all(|c| matches!(c, '′' | '″' | '‴')) {
+                primes = Some(Block::text(&self.transliterate(op.op())));
+                sup = (!rest.is_empty()).then_some(rest);
+            }
+        }
+
+        let mut blocks = vec![base];
+        blocks.extend(primes);
+
+        if sub_sup.sub().is_some() || sup.is_some() {
+            let scripts = self.scripts(sub_sup.sub(), sup, &blocks[0]);
+            blocks.push(scripts);
+        }
+
+        Block::beside(blocks)
+    }
+
+    fn multiscripts(&mut self, multiscripts: &Multiscripts) -> Block {
+        let content = multiscripts.content();
+
+        let Some((base, scripts)) = content.split_first() else {
+            return Block::empty();
+        };
+
+        let (post, pre) = match scripts
+            .iter()
+            .position(|element| matches!(element, Element::Prescripts(_)))
+        {
+            Some(idx) => (&scripts[..idx], &scripts[idx + 1..]),
+            None => (scripts, &[][..]),
+        };
+
+        let base = self.layout(base);
+        let mut blocks = Vec::new();
+
+        for pair in pre.chunks(2) {
+            let (sub, sup) = script_pair(pair);
+            let column = self.scripts(sub, sup, &base);
+            blocks.push(column);
+        }
+
+        let pre_len = blocks.len();
+        blocks.push(base);
+
+        for pair in post.chunks(2) {
+            let (sub, sup) = script_pair(pair);
+            let column = self.scripts(sub, sup, &blocks[pre_len]);
+            blocks.push(column);
+        }
+
+        Block::beside(blocks)
+    }
+
+    fn underover(&mut self, under_over: &UnderOver) -> Block {
+        let expr = self.row(under_over.expr());
+        let width = expr.width;
+
+        let mut over = under_over.over().map(|over| self.stretched(over, width));
+        let under = under_over.under().map(|under| self.stretched(under, width));
+
+        let baseline = over.as_ref().map(Block::height).unwrap_or_default() + expr.baseline;
+
+        let blocks = over.take().into_iter().chain([expr]).chain(under).collect();
+
+        Block::stack(blocks, Align::Center, baseline)
+    }
+
+    /// Lay out a script of an under-over element, single stretchy characters such as an
+    /// overline are repeated to the given width.
+    fn stretched(&mut self, elements: &[Element], width: usize) -> Block {
+        if let [Element::Operator(op)] = elements {
+            let op = self.operator(op.op());
+
+            let fill = match op.as_str() {
+                "¯" | "‾" | "_" | "-" | "−" => Some(if self.ascii { "_" } else { "‾" }),
+                "→" | "->" => Some(if self.ascii { "-" } else { "─" }),
+                "⏞" | "⏟" => Some(if self.ascii { "~" } else { "─" }),
+                _ => None,
+            };
+
+            if let (Some(fill), true) = (fill, width > 1) {
+                let mut line = fill.repeat(width - 1);
+
+                if matches!(op.as_str(), "→" | "->") {
+                    line.push(if self.ascii { '>' } else { '→' });
+                } else {
+                    line.push_str(fill);
+                }
+
+                return Block::text(&line);
+            }
+        }
+
+        self.row(elements)
+    }
+
+    fn space(&self, space: &Space) -> Block {
+        let width = space.attributes().iter().find_map(|attr| match attr {
+            SpaceAttr::Width(width) => Some(columns(width)),
+            _ => None,
+        });
+
+        Block::text(&" ".repeat(width.unwrap_or_default()))
+    }
+
+    fn table(&mut self, table: &Table) -> Block {
+        let rows: Vec<Vec<(Block, Align)>> = table
+            .rows()
+            .iter()
+            .map(|row| {
+                row.cells()
+                    .iter()
+                    .map(|cell| {
+                        let align = cell
+                            .attributes()
+                            .iter()
+                            .find_map(|attr| match attr {
+                                TableCellAttr::Global(Attribute::Style(style)) => {
+                                    match style.replace(' ', "").as_str() {
+                                        "text-align:left" => Some(Align::Left),
+                                        "text-align:right" => Some(Align::Right),
+                                        _ => None,
+                                    }
+                                }
+                                _ => None,
+                            })
+                            .unwrap_or(Align::Center);
+
+                        (self.row(cell.children()), align)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if rows.is_empty() {
+            return Block::empty();
+        }
+
+        let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+        let widths: Vec<usize> = (0..columns)
+            .map(|idx| {
+                rows.iter()
+                    .filter_map(|row| row.get(idx))
+                    .map(|(block, _)| block.width)
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        let lines: Vec<Block> = rows
+            .into_iter()
+            .map(|row| {
+                let mut cells = Vec::new();
+
+                for (idx, (block, align)) in row.into_iter().enumerate() {
+                    if idx > 0 {
+                        cells.push(Block::text("  "));
+                    }
+
+                    cells.push(block.pad(widths[idx], align));
+                }
+
+                Block::beside(cells)
+            })
+            .collect();
+
+        let height: usize = lines.iter().map(Block::height).sum();
+        Block::stack(lines, Align::Left, height.saturating_sub(1) / 2)
+    }
+}
+
+impl Renderer for TextArtFormatter {
+    type Output = String;
+    type Error = Infallible;
+
+    fn render_action(&mut self, action: &Action) -> Result<Self::Output, Self::Error> {
+        Ok(self.action(action).to_string())
+    }
+
+    fn render_annotation(&mut self, _annotation: &Annotation) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_error(&mut self, error: &Error) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(error.content()).to_string())
+    }
+
+    fn render_frac(&mut self, frac: &Frac) -> Result<Self::Output, Self::Error> {
+        Ok(self.frac(frac).to_string())
+    }
+
+    fn render_ident(&mut self, ident: &Ident) -> Result<Self::Output, Self::Error> {
+        Ok(self.token(ident.ident(), ident.attributes()).to_string())
+    }
+
+    fn render_multiscripts(
+        &mut self,
+        multiscripts: &Multiscripts,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(self.multiscripts(multiscripts).to_string())
+    }
+
+    fn render_prescripts(&mut self, _prescripts: &Prescripts) -> Result<Self::Output, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn render_num(&mut self, num: &Num) -> Result<Self::Output, Self::Error> {
+        Ok(self.token(num.num(), num.attributes()).to_string())
+    }
+
+    fn render_operator(&mut self, operator: &Operator) -> Result<Self::Output, Self::Error> {
+        Ok(self.operator(operator.op()))
+    }
+
+    fn render_padded(&mut self, padded: &Padded) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(padded.children()).to_string())
+    }
+
+    fn render_phantom(&mut self, phantom: &Phantom) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(phantom.children()).blank_copy().to_string())
+    }
+
+    fn render_radical(&mut self, radical: &Radical) -> Result<Self::Output, Self::Error> {
+        Ok(self.radical(radical).to_string())
+    }
+
+    fn render_row(&mut self, row: &Row) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(row.children()).to_string())
+    }
+
+    fn render_semantics(&mut self, semantics: &Semantics) -> Result<Self::Output, Self::Error> {
+        match semantics.children().first() {
+            Some(presentation) => Ok(self.layout(presentation).to_string()),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_space(&mut self, space: &Space) -> Result<Self::Output, Self::Error> {
+        Ok(self.space(space).to_string())
+    }
+
+    fn render_str_literal(
+        &mut self,
+        str_literal: &StrLiteral,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(format!("\"{}\"", str_literal.content()))
+    }
+
+    fn render_style(&mut self, style: &Style) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(style.children()).to_string())
+    }
+
+    fn render_subsup(&mut self, sub_sup: &SubSup) -> Result<Self::Output, Self::Error> {
+        Ok(self.subsup(sub_sup).to_string())
+    }
+
+    fn render_table(&mut self, table: &Table) -> Result<Self::Output, Self::Error> {
+        Ok(self.table(table).to_string())
+    }
+
+    fn render_text(&mut self, text: &Text) -> Result<Self::Output, Self::Error> {
+        Ok(text.text().to_string())
+    }
+
+    fn render_underover(&mut self, under_over: &UnderOver) -> Result<Self::Output, Self::Error> {
+        Ok(self.underover(under_over).to_string())
+    }
+
+    fn render_attr(&mut self, _attr: &Attribute) -> Result<Self::Output, Self::Error> {
+        // attributes have no representation in text art, except for math variants which are
+        // applied to the content of the elements
+        Ok(String::new())
+    }
+
+    fn render_mathml(&mut self, mathml: &MathMl) -> Result<Self::Output, Self::Error> {
+        Ok(self.row(mathml.content()).to_string())
+    }
+}
+
+/// Horizontal alignment of a block inside of a wider area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// A rectangular block of text with a baseline, used to align blocks placed beside each other.
+#[derive(Debug, Clone)]
+struct Block {
+    /// The lines of the block, each padded to the width of the block.
+    lines: Vec<String>,
+
+    /// The width of the block in characters.
+    width: usize,
+
+    /// The index of the line the block is aligned on.
+    baseline: usize,
+}
+
+impl Block {
+    fn new(lines: Vec<String>, baseline: usize) -> Self {
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        let lines = lines
+            .into_iter()
+            .map(|line| pad(&line, width, Align::Left))
+            .collect();
+
+        Self {
+            lines,
+            width,
+            baseline,
+        }
+    }
+
+    fn empty() -> Self {
+        Self::text("")
+    }
+
+    fn text(text: &str) -> Self {
+        Self::new(vec![text.to_string()], 0)
+    }
+
+    fn blank(width: usize, height: usize) -> Self {
+        Self::new(vec![" ".repeat(width); height], 0)
+    }
+
+    /// A blank block with the same dimensions, e.g. for phantoms.
+    fn blank_copy(&self) -> Self {
+        Self {
+            baseline: self.baseline,
+            ..Self::blank(self.width, self.height())
+        }
+    }
+
+    /// Whether the line of the baseline ends with a space.
+    fn ends_with_space(&self) -> bool {
+        self.lines
+            .get(self.baseline)
+            .is_some_and(|line| line.ends_with(' '))
+    }
+
+    fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// The number of lines below the baseline.
+    fn depth(&self) -> usize {
+        self.height().saturating_sub(self.baseline + 1)
+    }
+
+    fn pad(self, width: usize, align: Align) -> Self {
+        if width <= self.width {
+            return self;
+        }
+
+        let lines = self
+            .lines
+            .iter()
+            .map(|line| pad(line, width, align))
+            .collect();
+
+        Self {
+            lines,
+            width,
+            baseline: self.baseline,
+        }
+    }
+
+    /// Add blank lines below the block, so that its last line is the given number of lines
+    /// above the baseline.
+    fn above(self, lines: usize) -> Self {
+        let height = self.height();
+
+        let mut blank = vec![" ".repeat(self.width); lines];
+        blank.splice(0..0, self.lines);
+
+        Self {
+            lines: blank,
+            width: self.width,
+            baseline: height - 1 + lines,
+        }
+    }
+
+    /// Place the blocks beside each other, aligned on their baselines.
+    fn beside(blocks: Vec<Block>) -> Self {
+        let above = blocks.iter().map(|b| b.baseline).max().unwrap_or_default();
+        let below = blocks.iter().map(Block::depth).max().unwrap_or_default();
+        let height = above + below + 1;
+
+        let mut lines = vec![String::new(); height];
+
+        for block in &blocks {
+            let offset = above - block.baseline;
+
+            for (idx, line) in lines.iter_mut().enumerate() {
+                match idx.checked_sub(offset).and_then(|idx| block.lines.get(idx)) {
+                    Some(content) => line.push_str(content),
+                    None => line.push_str(&" ".repeat(block.width)),
+                }
+            }
+        }
+
+        Self::new(lines, above)
+    }
+
+    /// Place the blocks above each other with the given alignment and baseline.
+    fn stack(blocks: Vec<Block>, align: Align, baseline: usize) -> Self {
+        let width = blocks.iter().map(|b| b.width).max().unwrap_or_default();
+
+        let lines = blocks
+            .into_iter()
+            .flat_map(|block| block.pad(width, align).lines)
+            .collect();
+
+        Self::new(lines, baseline)
+    }
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, line) in self.lines.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+fn pad(line: &str, width: usize, align: Align) -> String {
+    let missing = width.saturating_sub(line.chars().count());
+
+    let left = match align {
+        Align::Left => 0,
+        Align::Center => missing / 2,
+        Align::Right => missing,
+    };
+
+    format!("{}{line}{}", " ".repeat(left), " ".repeat(missing - left))
+}
+
+/// Build the column of scripts placed beside the given base, with the superscript above and the
+/// subscript below the base.
+fn script_column(sub: Option<Block>, sup: Option<Block>, base: &Block) -> Block {
+    let sup_height = sup.as_ref().map(Block::height).unwrap_or_default();
+
+    let blocks = sup
+        .into_iter()
+        .chain([Block::blank(0, base.height())])
+        .chain(sub)
+        .collect();
+
+    Block::stack(blocks, Align::Left, sup_height + base.baseline)
+}
+
+/// Split a pair of multiscripts into sub- and superscript, empty rows denote missing scripts.
+fn script_pair(pair: &[Element]) -> (Option<&[Element]>, Option<&[Element]>) {
+    let script = |idx: usize| {
+        pair.get(idx)
+            .filter(|el| !matches!(el, Element::Row(row) if row.children().is_empty()))
+            .map(std::slice::from_ref)
+    };
+
+    (script(0), script(1))
+}
+
+/// The number of columns for a length, two per `em`. Positive lengths that can't be converted
+/// into `em` take one column.
+fn columns(length: &str) -> usize {
+    let em = match length.parse::<Length>() {
+        Ok(length) => match (length.named_space(), length.value(), length.unit()) {
+            (Some(named), ..) => named.em(),
+            (_, Some(value), Some(Unit::Em)) => value,
+            (_, Some(value), Some(Unit::Ex)) => value / 2.0,
+            (_, Some(value), Some(Unit::Px)) => value / 16.0,
+            (_, Some(value), Some(Unit::Pt)) => value / 12.0,
+            (_, value, _) => value.unwrap_or_default().min(0.5),
+        },
+        Err(_) => 0.5,
+    };
+
+    if em > 0.0 {
+        ((em * 2.0).round() as usize).max(1)
+    } else {
+        0
+    }
+}
+
+fn is_stretchy(op: &Operator) -> bool {
+    op.attributes()
+        .iter()
+        .any(|attr| matches!(attr, OperatorAttr::Stretchy | OperatorAttr::Fence))
+}
+
+/// Whether the operator is a word, e.g. `mod`, which is spaced like a binary operator.
+fn is_word(op: &str) -> bool {
+    op.chars().count() > 1 && op.chars().all(char::is_alphabetic)
+}
+
+/// Whether the identifier at the index is the name of a function, i.e. a word that is followed
+/// by a function application.
+fn is_function_name(elements: &[Element], idx: usize) -> bool {
+    matches!(
+        (&elements[idx], elements.get(idx + 1)),
+        (Element::Ident(ident), Some(Element::Operator(op)))
+            if ident.ident().chars().count() > 1 && op.op() == "\u{2061}"
+    )
+}
+
+/// Whether the element starts with a bracket, e.g. the argument `(x)` of a function.
+fn starts_with_bracket(element: &Element) -> bool {
+    match element {
+        Element::Operator(op) => bracket(op.op()).is_some(),
+        Element::Row(row) => row.children().first().is_some_and(starts_with_bracket),
+        _ => false,
+    }
+}
+
+/// The spacing around an operator placed between other elements.
+enum Spacing {
+    None,
+    After,
+    Around,
+}
+
+fn spacing(op: &str) -> Spacing {
+    match op {
+        "," | ";" => Spacing::After,
+        "+" | "-" | "−" | "=" | "<" | ">" | "±" | "∓" | "×" | "÷" | "·" | "⋅" | "∗" | "≤" | "≥"
+        | "≠" | "≈" | "≡" | "≅" | "∼" | "∝" | "→" | "←" | "↔" | "⇒" | "⇐" | "⇔" | "↦" | "∈"
+        | "∉" | "∋" | "⊂" | "⊃" | "⊆" | "⊇" | "∪" | "∩" | "∧" | "∨" | "⊕" | "⊗" | "∘" | "<="
+        | ">=" | "!=" | "~=" | "->" | "<-" | "<->" | "=>" | "<=>" | "+-" | "-+" | "|->" | "*"
+        | "in" | "!in" => Spacing::Around,
+        _ => Spacing::None,
+    }
+}
+
+/// The kinds of brackets that are stretched to the height of their content.
+#[derive(Debug, Clone, Copy)]
+enum Bracket {
+    LeftParen,
+    RightParen,
+    LeftSquare,
+    RightSquare,
+    LeftCurly,
+    RightCurly,
+    Vert,
+    DoubleVert,
+    LeftAngle,
+    RightAngle,
+}
+
+fn bracket(op: &str) -> Option<Bracket> {
+    let bracket = match op {
+        "(" => Bracket::LeftParen,
+        ")" => Bracket::RightParen,
+        "[" => Bracket::LeftSquare,
+        "]" => Bracket::RightSquare,
+        "{" => Bracket::LeftCurly,
+        "}" => Bracket::RightCurly,
+        "|" | "∣" => Bracket::Vert,
+        "‖" | "∥" => Bracket::DoubleVert,
+        "⟨" | "〈" => Bracket::LeftAngle,
+        "⟩" | "〉" => Bracket::RightAngle,
+        _ => return None,
+    };
+
+    Some(bracket)
+}
+
+/// ASCII replacement for common characters that are not ASCII, e.g. `<=` for `≤`.
+fn ascii_char(c: char) -> Option<&'static str> {
+    if let Some(name) = greek::name(c) {
+        return Some(name);
+    }
+
+    let replacement = match c {
+        '−' => "-",
+        '×' => "x",
+        '·' | '⋅' | '∗' => "*",
+        '÷' => "/",
+        '±' => "+-",
+        '∓' => "-+",
+        '≤' | '⩽' => "<=",
+        '≥' | '⩾' => ">=",
+        '≠' => "!=",
+        '≈' | '≅' => "~=",
+        '≡' => "==",
+        '∼' => "~",
+        '→' => "->",
+        '←' => "<-",
+        '↔' => "<->",
+        '⇒' => "=>",
+        '⇐' => "<=",
+        '⇔' => "<=>",
+        '↦' => "|->",
+        '∞' => "oo",
+        '′' => "'",
+        '″' => "''",
+        '‴' => "'''",
+        '…' | '⋯' => "...",
+        '∈' => "in",
+        '∉' => "!in",
+        '∂' | '𝜕' => "d",
+        '∇' => "nabla",
+        '∑' => "sum",
+        '∏' => "prod",
+        '∫' => "int",
+        '∮' => "oint",
+        '√' => "sqrt",
+        '∀' => "forall",
+        '∃' => "exists",
+        '¬' => "not",
+        '∧' => "and",
+        '∨' => "or",
+        '∪' => "union",
+        '∩' => "intersect",
+        '⊂' => "subset",
+        '⊆' => "subseteq",
+        '∅' => "{}",
+        '⟨' | '〈' => "<",
+        '⟩' | '〉' => ">",
+        '‖' | '∥' => "||",
+        '∣' => "|",
+        '\u{00A0}' => " ",
+        'ϵ' | '𝜖' => "epsilon",
+        'ϑ' | '𝜗' => "theta",
+        'ϕ' | '𝜙' => "phi",
+        'ϱ' | '𝜚' => "rho",
+        'ϖ' | '𝜛' => "pi",
+        _ => return None,
+    };
+
+    Some(replacement)
+}
@@ -0,0 +1,1008 @@
+//! Conversion of MathML elements into [Typst](https://typst.app) math markup.
+//!
+//! The [`TypstFormatter`] produces the content of a Typst equation, so formulas built with this
+//! crate can be embedded into Typst documents by placing the output between `$` delimiters.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{radicals::Radical, Frac, Ident, Num};
+//! use alemat::typst::TypstFormatter;
+//! use alemat::{children, MathMl};
+//!
+//! let math = MathMl::with_content(children![Frac::builder()
+//!     .num(Ident::from("a"))
+//!     .denom(Radical::builder().index(Num::from(3)).content(Ident::from("x")).build())
+//!     .build()]);
+//!
+//! let typst = math.render_with(&mut TypstFormatter::default()).unwrap();
+//! assert_eq!(typst, "frac(a, root(3, x))");
+//! ```
+
+use std::convert::Infallible;
+
+use crate::{
+    attributes::{Attribute, Length, MathVariant, NamedSpace, Unit},
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, Frac, FracAttr, Ident, Num, Operator, OperatorAttr, Padded, Semantics, Space,
+        SpaceAttr, StrLiteral, Table, TableAttr, TableCellAttr, Text,
+    },
+    greek, Element, MathMl, Renderer,
+};
+
+/// [`Renderer`] implementation that produces Typst math markup.
+///
+/// The output does not contain the `$` delimiters of the equation. Elements are mapped to the
+/// functions of Typst's math module: fractions to `frac(a, b)`, roots to `sqrt(x)` and
+/// `root(n, x)`, tables to `mat(..)` and `cases(..)` and math variants to functions such as
+/// `bold(x)` and `bb(R)`. Operators and letters are written with their symbol names, e.g. `≤` as
+/// `<=` and `α` as `alpha`, and characters with a special meaning in Typst are escaped.
+///
+/// # Example
+///
+/// ```
+/// use alemat::elements::{scripted::UnderOver, Ident, Num, Operator};
+/// use alemat::typst::TypstFormatter;
+/// use alemat::{children, MathMl};
+///
+/// let math = MathMl::with_content(children![
+///     UnderOver::builder()
+///         .expr(Operator::sum())
+///         .under(children![Ident::from("i"), Operator::from("="), Num::from(0)])
+///         .over(Ident::from("n"))
+///         .build(),
+///     Ident::from("α"),
+///     Operator::from("≤"),
+///     Ident::from("ℝ"),
+/// ]);
+///
+/// let typst = math.render_with(&mut TypstFormatter::default()).unwrap();
+/// assert_eq!(typst, "sum_(i=0)^n alpha<=RR");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TypstFormatter {
+    /// The number of enclosing function arguments, in which commas and semicolons are escaped.
+    arguments: usize,
+}
+
+impl TypstFormatter {
+    fn render_elements(&mut self, elements: &[Element]) -> String {
+        let unmatched = unmatched_parens(elements);
+        let mut out = String::new();
+
+        for (idx, element) in elements.iter().enumerate() {
+            let rendered = match element {
+                // a lone parenthesis would be read as the start or the end of a group
+                Element::Operator(op) if unmatched.contains(&idx) => format!("\\{}", op.op()),
+                element => {
+                    let Ok(rendered) = self.render_element(element);
+                    rendered
+                }
+            };
+
+            push(&mut out, &rendered);
+        }
+
+        out
+    }
+
+    /// Render the elements as the argument of a function call, e.g. `a` in `sqrt(a)`.
+    fn argument(&mut self, elements: &[Element]) -> String {
+        self.arguments += 1;
+        let out = self.render_elements(elements);
+        self.arguments -= 1;
+
+        if out.is_empty() {
+            String::from("\"\"")
+        } else {
+            out
+        }
+    }
+
+    /// Render a function call with the elements as arguments, e.g. `frac(a, b)`.
+    fn call(&mut self, name: &str, arguments: &[&[Element]]) -> String {
+        let arguments = arguments
+            .iter()
+            .map(|elements| self.argument(elements))
+            .collect::<Vec<_>>();
+
+        format!("{name}({})", arguments.join(", "))
+    }
+
+    /// Render the elements as a script, parentheses are omitted for single tokens, e.g. `x^2`.
+    fn script(&mut self, elements: &[Element]) -> String {
+        let script = self.render_elements(elements);
+
+        if is_token(&script) {
+            script
+        } else {
+            format!("({script})")
+        }
+    }
+
+    /// Append the sub- and superscript to the base, e.g. `x_i^2`. Bases that are not a single
+    /// atom use `attach`, since parentheses around them would be displayed.
+    fn attach(
+        &mut self,
+        base: &[Element],
+        sub: Option<&[Element]>,
+        sup: Option<&[Element]>,
+    ) -> String {
+        if let [element] = base {
+            if is_atom(element) {
+                let mut out = self.render_elements(base);
+                self.scripts(&mut out, sub, sup);
+                return out;
+            }
+        }
+
+        let mut out = format!("attach({}", self.argument(base));
+
+        for (name, script) in [("b", sub), ("t", sup)] {
+            if let Some(script) = script {
+                out.push_str(&format!(", {name}: {}", self.argument(script)));
+            }
+        }
+
+        out.push(')');
+        out
+    }
+
+    /// Write the scripts with `_` and `^` after the rendered base.
+    fn scripts(&mut self, out: &mut String, sub: Option<&[Element]>, sup: Option<&[Element]>) {
+        if let Some(sub) = sub {
+            out.push('_');
+            out.push_str(&self.script(sub));
+        }
+
+        if let Some(sup) = sup {
+            out.push('^');
+            out.push_str(&self.script(sup));
+        }
+    }
+
+    /// Escape the characters with a special meaning in Typst math, e.g. `_` or `/`. Other
+    /// characters are kept as they are, so numbers such as `2.5` stay a single token.
+    fn escape(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+
+        for c in s.chars() {
+            match c {
+                '$' | '#' | '_' | '^' | '/' | '&' | '"' | '@' | '*' => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                // separators of function arguments and matrix rows
+                ',' | ';' | ':' if self.arguments > 0 => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                '\\' => push(&mut out, "backslash"),
+                '~' => push(&mut out, "tilde.op"),
+                c => out.push(c),
+            }
+        }
+
+        out
+    }
+
+    /// Convert an operator into Typst, e.g. `≤` into `<=`.
+    fn operator_str(&self, op: &str) -> String {
+        if let Some(name) = symbol_name(op) {
+            return String::from(name);
+        }
+
+        if op.chars().count() > 1 && op.chars().all(char::is_alphabetic) {
+            return function_name(op);
+        }
+
+        let mut out = String::new();
+
+        for c in op.chars() {
+            let c = c.to_string();
+            let rendered = match symbol_name(&c) {
+                Some(name) => String::from(name),
+                None => self.escape(&c),
+            };
+
+            push(&mut out, &rendered);
+        }
+
+        out
+    }
+
+    /// Render a row surrounded by fences as a matrix or a binomial coefficient, e.g.
+    /// `mat(delim: "[", a, b; c, d)`.
+    fn fenced(&mut self, children: &[Element]) -> Option<String> {
+        match children {
+            [Element::Operator(open), Element::Table(table), Element::Operator(close)] => {
+                let delim = match (open.op(), close.op()) {
+                    ("(", ")") => None,
+                    ("[", "]") => Some("\"[\""),
+                    ("{", "}") => Some("\"{\""),
+                    ("|", "|") => Some("\"|\""),
+                    ("\u{2016}" | "\u{2225}", "\u{2016}" | "\u{2225}") => Some("\"||\""),
+                    _ => return None,
+                };
+
+                Some(self.matrix(delim, table))
+            }
+            [Element::Operator(open), Element::Table(table)] if open.op() == "{" => {
+                let rows = table
+                    .rows()
+                    .iter()
+                    .map(|row| {
+                        row.cells()
+                            .iter()
+                            .map(|cell| self.argument(cell.children()))
+                            .collect::<Vec<_>>()
+                            .join(" & ")
+                    })
+                    .collect::<Vec<_>>();
+
+                Some(format!("cases({})", rows.join(", ")))
+            }
+            [Element::Operator(open), Element::Frac(frac), Element::Operator(close)]
+                if open.op() == "(" && close.op() == ")" && is_zero_thickness(frac) =>
+            {
+                Some(self.call("binom", &[frac.num(), frac.denom()]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Render the table as a matrix with the given delimiter, e.g. `mat(a, b; c, d)`.
+    fn matrix(&mut self, delim: Option<&str>, table: &Table) -> String {
+        let rows = table
+            .rows()
+            .iter()
+            .map(|row| {
+                row.cells()
+                    .iter()
+                    .map(|cell| self.argument(cell.children()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        match delim {
+            Some(delim) => format!("mat(delim: {delim}, {rows})"),
+            None => format!("mat({rows})"),
+        }
+    }
+
+    /// Render a pair of sub- and superscript of a multiscripts element as arguments of `attach`,
+    /// e.g. `tr: j, br: i`.
+    fn script_pair(&mut self, pair: &[Element], names: [&str; 2]) -> Vec<String> {
+        pair.iter()
+            .zip(names)
+            .filter(|(element, _)| !is_empty(element))
+            .map(|(element, name)| {
+                format!("{name}: {}", self.argument(std::slice::from_ref(element)))
+            })
+            .collect()
+    }
+}
+
+impl Renderer for TypstFormatter {
+    type Output = String;
+    type Error = Infallible;
+
+    fn render_action(&mut self, action: &Action) -> Result<Self::Output, Self::Error> {
+        let selection = action.attributes().iter().find_map(|attr| match attr {
+            ActionAttr::Selection(selection) => selection.trim().parse::<usize>().ok(),
+            _ => None,
+        });
+
+        let content = action.content();
+        let selected = selection
+            .and_then(|idx| content.get(idx.checked_sub(1)?))
+            .or_else(|| content.first());
+
+        match selected {
+            Some(element) => self.render_element(element),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_annotation(&mut self, _annotation: &Annotation) -> Result<Self::Output, Self::Error> {
+        // annotations are alternative representations and are not rendered
+        Ok(String::new())
+    }
+
+    fn render_error(&mut self, error: &Error) -> Result<Self::Output, Self::Error> {
+        Ok(self.render_elements(error.content()))
+    }
+
+    fn render_frac(&mut self, frac: &Frac) -> Result<Self::Output, Self::Error> {
+        let out = if is_zero_thickness(frac) {
+            let num = self.argument(frac.num());
+            let denom = self.argument(frac.denom());
+            format!("mat(delim: #none, {num}; {denom})")
+        } else {
+            self.call("frac", &[frac.num(), frac.denom()])
+        };
+
+        Ok(out)
+    }
+
+    fn render_ident(&mut self, ident: &Ident) -> Result<Self::Output, Self::Error> {
+        let name = ident.ident();
+        let single = name.chars().count() <= 1;
+
+        let content = if let Some(symbol) = symbol_name(name) {
+            String::from(symbol)
+        } else if single {
+            self.escape(name)
+        } else {
+            function_name(name)
+        };
+
+        // identifiers with several letters are upright by default
+        let attributes = ident
+            .attributes()
+            .iter()
+            .filter(|attr| single || **attr != Attribute::MathVariant(MathVariant::Normal));
+
+        Ok(variant(attributes, content))
+    }
+
+    fn render_multiscripts(
+        &mut self,
+        multiscripts: &Multiscripts,
+    ) -> Result<Self::Output, Self::Error> {
+        let content = multiscripts.content();
+
+        let Some((base, scripts)) = content.split_first() else {
+            return Ok(String::new());
+        };
+
+        let (post, pre) = match scripts
+            .iter()
+            .position(|element| matches!(element, Element::Prescripts(_)))
+        {
+            Some(idx) => (&scripts[..idx], &scripts[idx + 1..]),
+            None => (scripts, &[][..]),
+        };
+
+        let mut arguments = vec![self.argument(std::slice::from_ref(base))];
+
+        // Typst places a single script at each corner
+        if let Some(pair) = pre.chunks(2).next() {
+            arguments.extend(self.script_pair(pair, ["bl", "tl"]));
+        }
+
+        if let Some(pair) = post.chunks(2).next() {
+            arguments.extend(self.script_pair(pair, ["br", "tr"]));
+        }
+
+        Ok(format!("attach({})", arguments.join(", ")))
+    }
+
+    fn render_prescripts(&mut self, _prescripts: &Prescripts) -> Result<Self::Output, Self::Error> {
+        // prescripts are handled by the surrounding multiscripts element
+        Ok(String::new())
+    }
+
+    fn render_num(&mut self, num: &Num) -> Result<Self::Output, Self::Error> {
+        let content = self.escape(num.num());
+        Ok(variant(num.attributes(), content))
+    }
+
+    fn render_operator(&mut self, operator: &Operator) -> Result<Self::Output, Self::Error> {
+        Ok(self.operator_str(operator.op()))
+    }
+
+    fn render_padded(&mut self, padded: &Padded) -> Result<Self::Output, Self::Error> {
+        Ok(self.render_elements(padded.children()))
+    }
+
+    fn render_phantom(&mut self, phantom: &Phantom) -> Result<Self::Output, Self::Error> {
+        let content = self.render_elements(phantom.children());
+        Ok(format!("#hide(${content}$)"))
+    }
+
+    fn render_radical(&mut self, radical: &Radical) -> Result<Self::Output, Self::Error> {
+        let out = if radical.is_square() || radical.index().is_empty() {
+            self.call("sqrt", &[radical.content()])
+        } else {
+            self.call("root", &[radical.index(), radical.content()])
+        };
+
+        Ok(out)
+    }
+
+    fn render_row(&mut self, row: &Row) -> Result<Self::Output, Self::Error> {
+        let content = match self.fenced(row.children()) {
+            Some(fenced) => fenced,
+            None => self.render_elements(row.children()),
+        };
+
+        Ok(variant(row.attributes(), content))
+    }
+
+    fn render_semantics(&mut self, semantics: &Semantics) -> Result<Self::Output, Self::Error> {
+        match semantics.children().first() {
+            Some(presentation) => self.render_element(presentation),
+            None => Ok(String::new()),
+        }
+    }
+
+    fn render_space(&mut self, space: &Space) -> Result<Self::Output, Self::Error> {
+        let width = space.attributes().iter().find_map(|attr| match attr {
+            SpaceAttr::Width(width) => width.parse::<Length>().ok(),
+            _ => None,
+        });
+
+        let width = width.filter(|width| !width.is_relative());
+        let named = width.as_ref().and_then(Length::named_space);
+        let value = width
+            .as_ref()
+            .and_then(|width| width.value().zip(width.unit()));
+
+        let out = match (named, value) {
+            (Some(NamedSpace::Thin), _) => String::from("thin"),
+            (Some(NamedSpace::Medium), _) => String::from("med"),
+            (Some(NamedSpace::Thick), _) => String::from("thick"),
+            (_, Some((1.0, Unit::Em))) => String::from("quad"),
+            (_, Some((2.0, Unit::Em))) => String::from("wide"),
+            // Typst only supports absolute lengths and `em`
+            (_, Some((value, unit)))
+                if matches!(unit, Unit::Em | Unit::Pt | Unit::Mm | Unit::Cm | Unit::In) =>
+            {
+                format!("#h({value}{unit})")
+            }
+            _ => String::new(),
+        };
+
+        Ok(out)
+    }
+
+    fn render_str_literal(
+        &mut self,
+        str_literal: &StrLiteral,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(string(&format!("\"{}\"", str_literal.content())))
+    }
+
+    fn render_style(&mut self, style: &Style) -> Result<Self::Output, Self::Error> {
+        let content = self.render_elements(style.children());
+        let content = variant(style.attributes(), content);
+
+        let display = style.attributes().iter().find_map(|attr| match attr {
+            Attribute::DisplayStyle(display) => Some(*display),
+            _ => None,
+        });
+
+        let out = match display {
+            Some(true) => format!("display({content})"),
+            Some(false) => format!("inline({content})"),
+            None => content,
+        };
+
+        Ok(out)
+    }
+
+    fn render_subsup(&mut self, sub_sup: &SubSup) -> Result<Self::Output, Self::Error> {
+        let base = sub_sup.base();
+        let mut sup = sub_sup.sup();
+
+        // primes are written after the base, e.g. `f'`
+        if let Some([Element::Operator(op), rest @ ..]) = sup {
+            if is_primes(op) {
+                let mut out = self.attach(base, None, None);
+                out.extend(op.op().chars().map(|_| '\''));
+                sup = (!rest.is_empty()).then_some(rest);
+
+                self.scripts(&mut out, sub_sup.sub(), sup);
+                return Ok(out);
+            }
+        }
+
+        // large operators take limits by default, e.g. `scripts(sum)_i`
+        if let [Element::Operator(op)] = base {
+            if is_large(op.op()) {
+                let mut out = self.call("scripts", &[base]);
+                self.scripts(&mut out, sub_sup.sub(), sup);
+                return Ok(out);
+            }
+        }
+
+        Ok(self.attach(base, sub_sup.sub(), sup))
+    }
+
+    fn render_table(&mut self, table: &Table) -> Result<Self::Output, Self::Error> {
+        let display = table
+            .attributes()
+            .contains(&TableAttr::Global(Attribute::DisplayStyle(true)));
+
+        if !display {
+            return Ok(self.matrix(Some("#none"), table));
+        }
+
+        let rows = table.rows();
+        let columns = rows
+            .iter()
+            .map(|row| row.cells().len())
+            .max()
+            .unwrap_or_default();
+
+        // alternating right and left aligned columns are aligned with `&`, e.g. `x &= 1`
+        let aligned = (0..columns).all(|idx| {
+            let align = if idx % 2 == 0 { "right" } else { "left" };
+
+            rows.iter()
+                .filter_map(|row| row.cells().get(idx))
+                .all(|cell| is_aligned(cell.attributes(), align))
+        });
+
+        if columns > 1 && !aligned {
+            return Ok(self.matrix(Some("#none"), table));
+        }
+
+        // equations displayed as block are written as lines
+        let lines = rows
+            .iter()
+            .map(|row| {
+                row.cells()
+                    .iter()
+                    .map(|cell| self.render_elements(cell.children()))
+                    .collect::<Vec<_>>()
+                    .join(" &")
+            })
+            .collect::<Vec<_>>();
+
+        Ok(lines.join(" \\ "))
+    }
+
+    fn render_text(&mut self, text: &Text) -> Result<Self::Output, Self::Error> {
+        let content = string(text.text());
+        Ok(variant(text.attributes(), content))
+    }
+
+    fn render_underover(&mut self, under_over: &UnderOver) -> Result<Self::Output, Self::Error> {
+        let attributes = under_over.attributes();
+        let expr = under_over.expr();
+
+        let accent_over = attributes.contains(&UnderOverAttr::AccentOver);
+        let accent_under = attributes.contains(&UnderOverAttr::AccentUnder);
+
+        let accent = match (under_over.under(), under_over.over()) {
+            (None, Some([Element::Operator(op)])) => accent_name(op, false, accent_over),
+            (Some([Element::Operator(op)]), None) => accent_name(op, true, accent_under),
+            _ => None,
+        };
+
+        if let Some(accent) = accent {
+            return Ok(self.call(accent, &[expr]));
+        }
+
+        // braces take their label as second argument, e.g. `underbrace(x, n)`
+        if let [Element::UnderOver(inner)] = expr {
+            let brace = match (inner.under(), inner.over()) {
+                (None, Some([Element::Operator(op)])) => brace_name(op.op(), false),
+                (Some([Element::Operator(op)]), None) => brace_name(op.op(), true),
+                _ => None,
+            };
+
+            let label = match (brace, under_over.under(), under_over.over()) {
+                (Some(brace), None, Some(label)) if brace.starts_with("over") => Some(label),
+                (Some(brace), Some(label), None) if brace.starts_with("under") => Some(label),
+                _ => None,
+            };
+
+            if let (Some(brace), Some(label)) = (brace, label) {
+                return Ok(self.call(brace, &[inner.expr(), label]));
+            }
+        }
+
+        let mut out = match expr {
+            [element] if has_limits(element) => self.render_elements(expr),
+            _ => self.call("limits", &[expr]),
+        };
+
+        self.scripts(&mut out, under_over.under(), under_over.over());
+        Ok(out)
+    }
+
+    fn render_attr(&mut self, _attr: &Attribute) -> Result<Self::Output, Self::Error> {
+        // attributes are applied by the elements, e.g. math variants with `bold(x)`
+        Ok(String::new())
+    }
+
+    fn render_mathml(&mut self, mathml: &MathMl) -> Result<Self::Output, Self::Error> {
+        Ok(self.render_elements(mathml.content()))
+    }
+}
+
+/// Append the rendered element, separating words and the characters of shorthands, e.g.
+/// `x y` instead of `xy` and `< -` instead of `<-`.
+fn push(out: &mut String, rendered: &str) {
+    const SHORTHANDS: [&str; 35] = [
+        "->", "-->", "<-", "<--", "<->", "<-->", "=>", "==>", "<=", "<==", "<=>", "<==>", ">=",
+        "!=", ":=", "::=", "=:", "...", "<<", ">>", "<<<", ">>>", "|->", "|=>", "||", "[|", "|]",
+        "~>", "~~>", "<~", "<~~", ">->", "->>", "<<-", "<-<",
+    ];
+
+    if let (Some(last), Some(first)) = (out.chars().last(), rendered.chars().next()) {
+        let pair = [last, first].iter().collect::<String>();
+
+        let word = |c: char| c.is_alphanumeric();
+        let separate = (word(last) && (word(first) || first == '.'))
+            || (last == '.' && word(first))
+            || (!word(last) && !word(first) && SHORTHANDS.iter().any(|s| s.contains(&pair)));
+
+        if separate {
+            out.push(' ');
+        }
+    }
+
+    out.push_str(rendered);
+}
+
+/// Apply the math variant in the attributes with Typst's font functions, e.g. `bb(R)`.
+fn variant<'a>(attributes: impl IntoIterator<Item = &'a Attribute>, content: String) -> String {
+    let variant = attributes.into_iter().find_map(|attr| match attr {
+        Attribute::MathVariant(variant) => Some(variant),
+        _ => None,
+    });
+
+    let functions: &[&str] = match variant {
+        Some(MathVariant::Normal) => &["upright"],
+        Some(MathVariant::Bold) => &["bold"],
+        Some(MathVariant::Italic) => &["italic"],
+        Some(MathVariant::BoldItalic) => &["bold", "italic"],
+        Some(MathVariant::DoubleStruck) => &["bb"],
+        Some(MathVariant::BoldFraktur) => &["bold", "frak"],
+        Some(MathVariant::Script) => &["cal"],
+        Some(MathVariant::BoldScript) => &["bold", "cal"],
+        Some(MathVariant::Fraktur) => &["frak"],
+        Some(MathVariant::SansSerif) => &["sans"],
+        Some(MathVariant::BoldSansSerif) => &["bold", "sans"],
+        Some(MathVariant::SansSerifItalic) => &["italic", "sans"],
+        Some(MathVariant::SansSerifBoldItalic) => &["bold", "italic", "sans"],
+        Some(MathVariant::Monospace) => &["mono"],
+        _ => &[],
+    };
+
+    functions.iter().rev().fold(content, |content, function| {
+        format!("{function}({content})")
+    })
+}
+
+/// Write the text as a Typst string, e.g. `"if"`.
+fn string(text: &str) -> String {
+    let mut out = String::from("\"");
+
+    for c in text.chars() {
+        if matches!(c, '"' | '\\') {
+            out.push('\\');
+        }
+
+        out.push(c);
+    }
+
+    out.push('"');
+    out
+}
+
+/// Write an identifier with several letters, e.g. `sin` or `op("grad")`.
+fn function_name(name: &str) -> String {
+    const FUNCTIONS: [&str; 39] = [
+        "arccos", "arcsin", "arctan", "arg", "cos", "cosh", "cot", "coth", "csc", "csch", "ctg",
+        "deg", "det", "dim", "exp", "gcd", "lcm", "hom", "id", "im", "inf", "ker", "lg", "lim",
+        "liminf", "limsup", "ln", "log", "max", "min", "mod", "Pr", "sec", "sech", "sin", "sinh",
+        "sup", "tan", "tanh",
+    ];
+
+    if FUNCTIONS.contains(&name) {
+        String::from(name)
+    } else {
+        format!("op({})", string(name))
+    }
+}
+
+/// The name of the symbol in Typst's `sym` module, e.g. `infinity` for `∞`.
+fn symbol_name(s: &str) -> Option<&'static str> {
+    let name = match s {
+        "\u{2061}" | "\u{2062}" | "\u{2063}" | "\u{2064}" => "",
+        "\u{2212}" => "-",
+        "\u{2217}" => "*",
+        "\u{2032}" => "'",
+        "\u{2033}" => "''",
+        "\u{2264}" => "<=",
+        "\u{2265}" => ">=",
+        "\u{2260}" => "!=",
+        "\u{226A}" => "<<",
+        "\u{226B}" => ">>",
+        "\u{2254}" => ":=",
+        "\u{2192}" => "->",
+        "\u{2190}" => "<-",
+        "\u{2194}" => "<->",
+        "\u{21D2}" => "=>",
+        "\u{21D4}" => "<=>",
+        "\u{21D0}" => "arrow.l.double",
+        "\u{21A6}" => "|->",
+        "\u{2191}" => "arrow.t",
+        "\u{2193}" => "arrow.b",
+        "\u{2026}" => "...",
+        "\u{22EF}" => "dots.c",
+        "\u{22EE}" => "dots.v",
+        "\u{22F1}" => "dots.down",
+        "\u{00D7}" => "times",
+        "\u{00F7}" => "div",
+        "\u{00B7}" => "dot.c",
+        "\u{22C5}" => "dot.op",
+        "\u{00B1}" => "plus.minus",
+        "\u{2213}" => "minus.plus",
+        "\u{2218}" => "compose",
+        "\u{2295}" => "plus.circle",
+        "\u{2297}" => "times.circle",
+        "\u{2299}" => "dot.circle",
+        "\u{2208}" => "in",
+        "\u{2209}" => "in.not",
+        "\u{220B}" => "in.rev",
+        "\u{2282}" => "subset",
+        "\u{2283}" => "supset",
+        "\u{2286}" => "subset.eq",
+        "\u{2287}" => "supset.eq",
+        "\u{222A}" => "union",
+        "\u{2216}" => "without",
+        "\u{2227}" => "and",
+        "\u{2228}" => "or",
+        "\u{00AC}" => "not",
+        "\u{2200}" => "forall",
+        "\u{2203}" => "exists",
+        "\u{2204}" => "exists.not",
+        "\u{2205}" => "emptyset",
+        "\u{221E}" => "infinity",
+        "\u{2207}" => "nabla",
+        "\u{2248}" => "approx",
+        "\u{2261}" => "equiv",
+        "\u{223C}" => "tilde.op",
+        "\u{2245}" => "tilde.equiv",
+        "\u{221D}" => "prop",
+        "\u{22A5}" => "perp",
+        "\u{2225}" => "parallel",
+        "\u{2223}" => "divides",
+        "\u{2220}" => "angle",
+        "\u{2234}" => "therefore",
+        "\u{2235}" => "because",
+        "\u{2016}" => "||",
+        "\u{230A}" => "floor.l",
+        "\u{230B}" => "floor.r",
+        "\u{2308}" => "ceil.l",
+        "\u{2309}" => "ceil.r",
+        "\u{2211}" => "sum",
+        "\u{220F}" => "product",
+        "\u{2210}" => "product.co",
+        "\u{22C3}" => "union.big",
+        "\u{22C0}" => "and.big",
+        "\u{22C1}" => "or.big",
+        "\u{2A01}" => "plus.circle.big",
+        "\u{2A02}" => "times.circle.big",
+        "\u{2A00}" => "dot.circle.big",
+        "\u{222B}" => "integral",
+        "\u{222C}" => "integral.double",
+        "\u{222D}" => "integral.triple",
+        "\u{222E}" => "integral.cont",
+        "\u{222F}" => "integral.surf",
+        "\u{2230}" => "integral.vol",
+        "\u{2115}" => "NN",
+        "\u{2124}" => "ZZ",
+        "\u{211A}" => "QQ",
+        "\u{211D}" => "RR",
+        "\u{2102}" => "CC",
+        "\u{210F}" => "planck.reduce",
+        "\u{2113}" => "ell",
+        "\u{2135}" => "aleph",
+        _ => {
+            let mut chars = s.chars();
+
+            return match (chars.next(), chars.next()) {
+                (Some(c), None) => greek::name(c),
+                _ => None,
+            };
+        }
+    };
+
+    Some(name)
+}
+
+/// Whether the large operator takes its scripts as limits by default, e.g. `sum`.
+fn is_large(op: &str) -> bool {
+    matches!(
+        op,
+        "\u{2211}"
+            | "\u{220F}"
+            | "\u{2210}"
+            | "\u{22C2}"
+            | "\u{22C3}"
+            | "\u{22C0}"
+            | "\u{22C1}"
+            | "\u{2A00}"
+            | "\u{2A01}"
+            | "\u{2A02}"
+    )
+}
+
+/// Whether the scripts of the element are placed under and over it without `limits`.
+fn has_limits(element: &Element) -> bool {
+    match element {
+        Element::Operator(op) => is_large(op.op()),
+        Element::Ident(ident) => matches!(
+            ident.ident(),
+            "det" | "gcd" | "inf" | "lim" | "liminf" | "limsup" | "max" | "min" | "Pr" | "sup"
+        ),
+        _ => false,
+    }
+}
+
+/// The accent function for the operator placed over or under an expression, e.g. `hat`.
+/// Braces and lines are accepted without the accent attribute.
+fn accent_name(op: &Operator, under: bool, accent: bool) -> Option<&'static str> {
+    let stretchy = op.attributes().contains(&OperatorAttr::Stretchy);
+
+    let name = match (op.op(), under) {
+        ("\u{00AF}", false) if stretchy => "overline",
+        ("\u{00AF}" | "_", true) => "underline",
+        (op, under) if brace_name(op, under).is_some() => return brace_name(op, under),
+        _ if !accent || under => return None,
+        ("^", _) => "hat",
+        ("~", _) => "tilde",
+        ("\u{00AF}", _) => "macron",
+        ("\u{2192}", _) => "arrow",
+        ("\u{2190}", _) => "arrow.l",
+        ("\u{02D9}", _) => "dot",
+        ("\u{00A8}", _) => "dot.double",
+        ("\u{02C7}", _) => "caron",
+        ("\u{02D8}", _) => "breve",
+        ("\u{00B4}", _) => "acute",
+        ("`", _) => "grave",
+        ("\u{02DA}", _) => "circle",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// The function for a brace over or under an expression, e.g. `underbrace`.
+fn brace_name(op: &str, under: bool) -> Option<&'static str> {
+    let name = match (op, under) {
+        ("\u{23DE}", false) => "overbrace",
+        ("\u{23DF}", true) => "underbrace",
+        ("\u{23B4}", false) => "overbracket",
+        ("\u{23B5}", true) => "underbracket",
+        ("\u{2322}", false) => "overparen",
+        ("\u{2323}", true) => "underparen",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// The indices of parentheses without a partner in the elements, which have to be escaped.
+fn unmatched_parens(elements: &[Element]) -> Vec<usize> {
+    let mut open = Vec::new();
+    let mut unmatched = Vec::new();
+
+    for (idx, element) in elements.iter().enumerate() {
+        match element {
+            Element::Operator(op) if op.op() == "(" => open.push(idx),
+            Element::Operator(op) if op.op() == ")" && open.pop().is_none() => unmatched.push(idx),
+            _ => {}
+        }
+    }
+
+    unmatched.extend(open);
+    unmatched
+}
+
+/// Whether the rendered script can follow `_` or `^` without parentheses, e.g. `2` or `alpha`.
+fn is_token(script: &str) -> bool {
+    let mut chars = script.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => !matches!(c, '(' | ')' | '\''),
+        (Some(c), Some(_)) if c.is_alphanumeric() => {
+            script.chars().all(|c| c.is_alphanumeric() || c == '.')
+        }
+        _ => false,
+    }
+}
+
+/// Whether the element can be the base of scripts written with `_` and `^`.
+fn is_atom(element: &Element) -> bool {
+    match element {
+        Element::Ident(_)
+        | Element::Num(_)
+        | Element::Operator(_)
+        | Element::Text(_)
+        | Element::Frac(_)
+        | Element::Radical(_)
+        | Element::Phantom(_) => true,
+        Element::Row(row) => match row.children() {
+            [child] => is_atom(child),
+            children => is_group(children),
+        },
+        _ => false,
+    }
+}
+
+/// Whether the elements are enclosed by a pair of matching delimiters, e.g. `(a+b)`.
+fn is_group(elements: &[Element]) -> bool {
+    const OPEN: [&str; 6] = ["(", "[", "{", "\u{27E8}", "\u{230A}", "\u{2308}"];
+    const CLOSE: [&str; 6] = [")", "]", "}", "\u{27E9}", "\u{230B}", "\u{2309}"];
+
+    fn delimiter(element: &Element) -> Option<&str> {
+        match element {
+            Element::Operator(op) => Some(op.op()),
+            _ => None,
+        }
+    }
+
+    let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+        return false;
+    };
+
+    match (delimiter(first), delimiter(last)) {
+        (Some(open @ ("|" | "\u{2016}")), Some(close)) if open == close => {
+            elements.len() > 1
+                && !elements[1..elements.len() - 1]
+                    .iter()
+                    .any(|element| delimiter(element) == Some(open))
+        }
+        (Some(open), Some(close)) if OPEN.contains(&open) && CLOSE.contains(&close) => {
+            let mut depth = 0usize;
+
+            for (idx, element) in elements.iter().enumerate() {
+                match delimiter(element) {
+                    Some(op) if OPEN.contains(&op) => depth += 1,
+                    Some(op) if CLOSE.contains(&op) => {
+                        depth = depth.saturating_sub(1);
+
+                        if depth == 0 && idx + 1 < elements.len() {
+                            return false;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Whether the element is empty, e.g. an empty script of a multiscripts element.
+fn is_empty(element: &Element) -> bool {
+    matches!(element, Element::Row(row) if row.children().is_empty())
+}
+
+fn is_primes(op: &Operator) -> bool {
+    !op.op().is_empty() && op.op().chars().all(|c| c == '\u{2032}')
+}
+
+fn is_zero_thickness(frac: &Frac) -> bool {
+    frac.attributes().iter().any(|attr| {
+        matches!(
+            attr,
+            FracAttr::LineThickness(lt) if lt.parse::<Length>().is_ok_and(|lt| lt.is_zero())
+        )
+    })
+}
+
+/// Whether the cell has the given `text-align` style.
+fn is_aligned(attributes: &[TableCellAttr], align: &str) -> bool {
+    attributes.iter().any(|attr| match attr {
+        TableCellAttr::Global(Attribute::Style(style)) => {
+            style.replace(' ', "") == format!("text-align:{align}")
+        }
+        _ => false,
+    })
+}
@@ -0,0 +1,111 @@
+//! Conversion of [UnicodeMath](https://www.unicode.org/notes/tn28/) linear format markup into
+//! MathML elements.
+//!
+//! The parser follows the build-up rules of the linear format used by Microsoft Word: fractions
+//! (`a/b`) bind the operands around them, sub- and superscripts are attached with `_` and `^`,
+//! and parentheses used for grouping are removed from fractions, scripts and radicals, e.g.
+//! `(a+b)/c` or `√(x+1)`. Matrices are written as `■(a&b@c&d)`, equation arrays as
+//! `█(x&=1@y&=2)`. Control words such as `\alpha` or `\sqrt` are replaced by the characters they
+//! stand for.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{grouping::Row, Frac, Ident, Operator};
+//! use alemat::{children, unicodemath, MathMl};
+//!
+//! let math = unicodemath::parse("(a+b)/c").unwrap();
+//!
+//! let expected = MathMl::with_content([Frac::builder()
+//!     .num(children![Ident::from("a"), Operator::plus(), Ident::from("b")])
+//!     .denom(Ident::from("c"))
+//!     .build()]);
+//!
+//! assert_eq!(math, expected);
+//! ```
+
+mod parser;
+mod symbols;
+
+use std::ops::Range;
+
+use crate::{Elements, MathMl};
+
+/// Parse the given UnicodeMath input into a [`MathMl`] element.
+pub fn parse(input: &str) -> Result<MathMl, UnicodeMathError> {
+    parse_elements(input).map(MathMl::with_content)
+}
+
+/// Parse the given UnicodeMath input into [`Elements`].
+pub fn parse_elements(input: &str) -> Result<Elements, UnicodeMathError> {
+    parser::parse(input).map(Elements)
+}
+
+/// The kind of error that occurred while parsing UnicodeMath input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnicodeMathErrorKind {
+    /// The operator is missing its argument, e.g. `√` at the end of the input or `a/`.
+    MissingArgument(String),
+
+    /// Text started with `"` is never closed.
+    UnclosedText,
+
+    /// The parenthesis opening a matrix or radical is never closed.
+    UnclosedBracket,
+
+    /// The control word is not known, e.g. `\foo`.
+    UnknownControlWord(String),
+
+    /// The input is nested deeper than the given maximum depth.
+    NestingTooDeep(usize),
+}
+
+impl std::fmt::Display for UnicodeMathErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnicodeMathErrorKind::MissingArgument(name) => {
+                write!(f, "missing argument for `{name}`")
+            }
+            UnicodeMathErrorKind::UnclosedText => f.write_str("text is never closed"),
+            UnicodeMathErrorKind::UnclosedBracket => f.write_str("bracket is never closed"),
+            UnicodeMathErrorKind::UnknownControlWord(name) => {
+                write!(f, "unknown control word `\\{name}`")
+            }
+            UnicodeMathErrorKind::NestingTooDeep(max) => {
+                write!(f, "nesting is deeper than the maximum of {max} levels")
+            }
+        }
+    }
+}
+
+/// Error that occurred while parsing UnicodeMath input, pointing at the offending part of the
+/// input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnicodeMathError {
+    kind: UnicodeMathErrorKind,
+    span: Range<usize>,
+}
+
+impl UnicodeMathError {
+    pub(crate) fn new(kind: UnicodeMathErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// Get the kind of the error.
+    pub fn kind(&self) -> &UnicodeMathErrorKind {
+        &self.kind
+    }
+
+    /// Get the byte range of the input that caused the error.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl std::fmt::Display for UnicodeMathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for UnicodeMathError {}
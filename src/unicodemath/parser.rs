@@ -0,0 +1,912 @@
+//! Parser turning UnicodeMath input into MathML elements.
+//!
+//! The input is built up in the order of the precedence of its operators:
+//!
+//! ```text
+//! expression ::= (operand | operator)*
+//! operand    ::= entity+ ('/' entity+)*
+//! entity     ::= primary accent* prime* ('_' script)? ('^' script)?
+//! primary    ::= number | letter | function | '(' expression ')' | '√' entity | '■(' array ')'
+//! ```
+//!
+//! Whitespace ends operands and is removed, parentheses around the arguments of fractions,
+//! scripts and radicals are removed, e.g. `(a+b)/c` or `x^(n+1)`.
+
+use std::ops::Range;
+
+use crate::{
+    attributes::{Attribute, Length, NamedSpace},
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver, UnderOverAttr},
+        Frac, FracAttr, Ident, Num, Operator, OperatorAttr, Space, SpaceAttr, Table, TableCell,
+        TableRow, Text,
+    },
+    Element, Elements,
+};
+
+use super::{
+    symbols::{self, Token},
+    UnicodeMathError, UnicodeMathErrorKind,
+};
+
+/// Maximum number of brackets, radicals and scripts nested into each other.
+const MAX_DEPTH: usize = 256;
+
+/// Parse the given UnicodeMath input into a list of elements.
+pub(super) fn parse(input: &str) -> Result<Vec<Element>, UnicodeMathError> {
+    let mut parser = Parser {
+        input,
+        tokens: tokenize(input)?,
+        pos: 0,
+        depth: 0,
+        nesting: 0,
+    };
+
+    let nodes = parser.expression(false)?;
+    Ok(nodes.into_iter().map(Node::into_element).collect())
+}
+
+/// Split the input into tokens, replacing control words with the characters they stand for.
+fn tokenize(input: &str) -> Result<Vec<(Token, Range<usize>)>, UnicodeMathError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            // the em and thin spaces are kept as spaces of the formula
+            c if c.is_whitespace() && !is_space(c) => {
+                while chars
+                    .next_if(|(_, c)| c.is_whitespace() && !is_space(*c))
+                    .is_some()
+                {}
+
+                Token::Space
+            }
+            '"' => {
+                let Some(len) = input[start + 1..].find('"') else {
+                    return Err(UnicodeMathError::new(
+                        UnicodeMathErrorKind::UnclosedText,
+                        start..start + 1,
+                    ));
+                };
+
+                let text = &input[start + 1..start + 1 + len];
+                while chars.next_if(|(idx, _)| *idx <= start + len + 1).is_some() {}
+
+                Token::Text(text.to_string())
+            }
+            '\\' => {
+                let mut end = start + 1;
+
+                while let Some((idx, c)) = chars.next_if(|(_, c)| c.is_ascii_alphabetic()) {
+                    end = idx + c.len_utf8();
+                }
+
+                if end == start + 1 {
+                    // any other character is escaped and taken literally
+                    match chars.next() {
+                        Some((_, c)) => Token::Literal(c),
+                        None => Token::Literal('\\'),
+                    }
+                } else {
+                    let name = &input[start + 1..end];
+
+                    let Some(token) = symbols::control_word(name) else {
+                        return Err(UnicodeMathError::new(
+                            UnicodeMathErrorKind::UnknownControlWord(name.to_string()),
+                            start..end,
+                        ));
+                    };
+
+                    // a space ends the control word and is removed
+                    chars.next_if(|(_, c)| *c == ' ');
+                    token
+                }
+            }
+            c => Token::Char(c),
+        };
+
+        let end = chars.peek().map_or(input.len(), |(idx, _)| *idx);
+        tokens.push((token, start..end));
+    }
+
+    Ok(tokens)
+}
+
+/// A parsed expression. Brackets are kept apart from the content, since parentheses are removed
+/// when the expression is used as an argument, e.g. in `(a+b)/2`.
+enum Node {
+    Element(Element),
+    Group {
+        open: Option<Operator>,
+        content: Vec<Node>,
+        close: Option<Operator>,
+    },
+}
+
+impl Node {
+    fn into_element(self) -> Element {
+        match self {
+            Node::Element(element) => element,
+            Node::Group {
+                open,
+                content,
+                close,
+            } => {
+                let content = content.into_iter().map(Node::into_element);
+                let open = open.map(Element::from);
+                let close = close.map(Element::from);
+
+                Row::from(
+                    open.into_iter()
+                        .chain(content)
+                        .chain(close)
+                        .collect::<Elements>(),
+                )
+                .into()
+            }
+        }
+    }
+}
+
+impl<T: Into<Element>> From<T> for Node {
+    fn from(value: T) -> Self {
+        Node::Element(value.into())
+    }
+}
+
+/// Turn the nodes into an argument of a fraction, script or radical, removing the parentheses
+/// around it.
+fn into_argument(nodes: Vec<Node>) -> Vec<Element> {
+    let mut nodes = nodes;
+
+    if let [Node::Group { open, close, .. }] = nodes.as_slice() {
+        let parens = match (open, close) {
+            (Some(open), Some(close)) => open.op() == "(" && close.op() == ")",
+            (None, None) => true,
+            _ => false,
+        };
+
+        if parens {
+            let Some(Node::Group { content, .. }) = nodes.pop() else {
+                unreachable!("The node is a group.");
+            };
+
+            nodes = content;
+        }
+    }
+
+    if nodes.is_empty() {
+        vec![Row::default().into()]
+    } else {
+        nodes.into_iter().map(Node::into_element).collect()
+    }
+}
+
+/// A parsed entity together with information on how scripts are attached to it.
+struct Simple {
+    node: Node,
+
+    /// Scripts are placed under and over the entity instead of after it, e.g. for `∑`.
+    limits: bool,
+
+    /// The entity is a function name such as `sin`, which is followed by a function application.
+    function: bool,
+}
+
+impl Simple {
+    fn new(node: impl Into<Node>) -> Self {
+        Self {
+            node: node.into(),
+            limits: false,
+            function: false,
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<(Token, Range<usize>)>,
+    pos: usize,
+
+    /// Number of brackets currently open.
+    depth: usize,
+
+    /// Number of groups, arrays and arguments currently being parsed, see [`MAX_DEPTH`].
+    nesting: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        match self.peek() {
+            Some(Token::Char(c)) => Some(*c),
+            _ => None,
+        }
+    }
+
+    fn bump(&mut self) -> Option<(Token, Range<usize>)> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// The span of the next token, or the empty span at the end of the input.
+    fn span(&self) -> Range<usize> {
+        match self.tokens.get(self.pos) {
+            Some((_, span)) => span.clone(),
+            None => self.input.len()..self.input.len(),
+        }
+    }
+
+    fn skip_space(&mut self) {
+        while self.peek() == Some(&Token::Space) {
+            self.pos += 1;
+        }
+    }
+
+    fn missing_argument(&self, span: Range<usize>) -> UnicodeMathError {
+        UnicodeMathError::new(
+            UnicodeMathErrorKind::MissingArgument(self.input[span.clone()].to_string()),
+            span,
+        )
+    }
+
+    /// Run the given parse function one nesting level deeper for the operator or bracket at the
+    /// given span, failing if [`MAX_DEPTH`] is reached.
+    fn nested<T>(
+        &mut self,
+        span: Range<usize>,
+        parse: impl FnOnce(&mut Self) -> Result<T, UnicodeMathError>,
+    ) -> Result<T, UnicodeMathError> {
+        if self.nesting >= MAX_DEPTH {
+            return Err(UnicodeMathError::new(
+                UnicodeMathErrorKind::NestingTooDeep(MAX_DEPTH),
+                span,
+            ));
+        }
+
+        self.nesting += 1;
+        let result = parse(self);
+        self.nesting -= 1;
+        result
+    }
+
+    /// Whether the next token ends the current expression. Inside of arrays, `&` and `@` separate
+    /// the cells and rows.
+    fn at_expression_end(&self, array: bool) -> bool {
+        let next = self.tokens[self.pos.min(self.tokens.len())..]
+            .iter()
+            .find(|(token, _)| *token != Token::Space);
+
+        match next {
+            None => true,
+            Some((Token::Char(c), _)) if symbols::is_closing_bracket(*c) => self.depth > 0,
+            Some((Token::Char('&' | '@'), _)) => array,
+            Some(_) => false,
+        }
+    }
+
+    /// Whether the next token starts an operand, e.g. a letter, a number or a bracket.
+    fn at_operand(&self) -> bool {
+        match self.peek() {
+            Some(Token::Ident(_) | Token::Text(_)) => true,
+            Some(Token::Char(c)) => {
+                c.is_alphanumeric()
+                    || is_space(*c)
+                    || symbols::closing_bracket(*c).is_some()
+                    || symbols::nary(*c).is_some()
+                    || symbols::brace(*c).is_some()
+                    || symbols::ident(*c).is_some()
+                    || matches!(c, '├' | '√' | '∛' | '∜' | '■' | '█' | '∂' | '∇')
+            }
+            Some(Token::Literal(_) | Token::Space) | None => false,
+        }
+    }
+
+    /// Parse operands and operators until the end of input, the closing bracket of the current
+    /// group or, inside of arrays, the end of the cell.
+    fn expression(&mut self, array: bool) -> Result<Vec<Node>, UnicodeMathError> {
+        let mut nodes = Vec::new();
+
+        loop {
+            self.skip_space();
+
+            if self.at_expression_end(array) {
+                break;
+            }
+
+            // the n-ary operand glue is removed
+            if self.peek_char() == Some('▒') {
+                self.pos += 1;
+                continue;
+            }
+
+            if !self.at_operand() {
+                let op = match self.bump() {
+                    Some((Token::Char(c) | Token::Literal(c), _)) => symbols::operator(c),
+                    _ => unreachable!("Operands and spaces are handled above."),
+                };
+
+                nodes.push(op.into());
+                continue;
+            }
+
+            let (mut operand, function) = self.operand()?;
+
+            while let Some(c @ ('/' | '¦')) = self.peek_char() {
+                let span = self.span();
+                self.pos += 1;
+                self.skip_space();
+
+                if !self.at_operand() {
+                    return Err(self.missing_argument(span));
+                }
+
+                let (denom, _) = self.operand()?;
+                operand = vec![fraction(operand, denom, c == '¦').into()];
+            }
+
+            nodes.extend(operand);
+
+            if function && !self.at_expression_end(array) {
+                nodes.push(Operator::from("\u{2061}").into());
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    /// Parse the entities of an operand, e.g. `2x` or `sin(x)`. Returns whether the operand ends
+    /// with a function name.
+    fn operand(&mut self) -> Result<(Vec<Node>, bool), UnicodeMathError> {
+        let mut nodes = Vec::new();
+        let mut function = false;
+
+        while self.at_operand() {
+            if function {
+                nodes.push(Operator::from("\u{2061}").into());
+            }
+
+            let simple = self.entity(true)?;
+            function = simple.function;
+            nodes.push(simple.node);
+        }
+
+        Ok((nodes, function))
+    }
+
+    /// Parse an entity with its accents, primes and, if enabled, its scripts.
+    fn entity(&mut self, scripts: bool) -> Result<Simple, UnicodeMathError> {
+        let simple = match self.bump() {
+            Some((Token::Ident(ident), _)) => Simple::new(ident),
+            Some((Token::Text(text), _)) => Simple::new(Text::from(text)),
+            Some((Token::Char(c), span)) => self.primary(c, span)?,
+            _ => unreachable!("Entities start with an operand."),
+        };
+
+        self.postfix(simple, scripts)
+    }
+
+    /// Attach the accents, primes and, if enabled, the scripts following the entity.
+    fn postfix(&mut self, simple: Simple, scripts: bool) -> Result<Simple, UnicodeMathError> {
+        let mut node = simple.node;
+
+        while let Some((mark, under)) = self.peek_char().and_then(symbols::combining_accent) {
+            self.pos += 1;
+            node = accent(vec![node.into_element()], mark, under).into();
+        }
+
+        let mut primes = String::new();
+
+        while let Some(c @ ('\'' | '′' | '″' | '‴')) = self.peek_char() {
+            self.pos += 1;
+
+            let count = match c {
+                '″' => 2,
+                '‴' => 3,
+                _ => 1,
+            };
+
+            primes.extend(std::iter::repeat_n('\u{2032}', count));
+        }
+
+        if !scripts && primes.is_empty() {
+            return Ok(Simple { node, ..simple });
+        }
+
+        let node = self.scripts(node, simple.limits, primes, scripts)?;
+        Ok(Simple { node, ..simple })
+    }
+
+    /// Attach the primes and the scripts following the base, e.g. `x_i^2` or `lim┬(n→∞)`.
+    fn scripts(
+        &mut self,
+        base: Node,
+        limits: bool,
+        primes: String,
+        scripts: bool,
+    ) -> Result<Node, UnicodeMathError> {
+        let mut sub = None;
+        let mut sup = None;
+        let mut under = None;
+        let mut over = None;
+
+        while let Some(c) = self.peek_char().filter(|_| scripts) {
+            let script = match c {
+                '_' if sub.is_none() => &mut sub,
+                '^' if sup.is_none() => &mut sup,
+                '┬' if under.is_none() => &mut under,
+                '┴' if over.is_none() => &mut over,
+                _ => break,
+            };
+
+            let span = self.span();
+            self.pos += 1;
+            *script = Some(self.script(c, span)?);
+        }
+
+        if !primes.is_empty() {
+            let primes = Element::from(Operator::from(primes));
+            sup = Some(
+                std::iter::once(primes)
+                    .chain(sup.unwrap_or_default())
+                    .collect(),
+            );
+        }
+
+        if [&sub, &sup, &under, &over]
+            .iter()
+            .all(|script| script.is_none())
+        {
+            return Ok(base);
+        }
+
+        let mut base = base.into_element();
+
+        if under.is_some() || over.is_some() {
+            base = under_over([base], under, over);
+        }
+
+        let node = match (sub, sup) {
+            (None, None) => base,
+            (sub, sup) if limits => under_over([base], sub, sup),
+            (Some(sub), None) => SubSup::builder().base([base]).subscript(sub).build().into(),
+            (None, Some(sup)) => SubSup::builder().base([base]).supscript(sup).build().into(),
+            (Some(sub), Some(sup)) => SubSup::builder()
+                .base([base])
+                .subscript(sub)
+                .supscript(sup)
+                .build()
+                .into(),
+        };
+
+        Ok(node.into())
+    }
+
+    /// Parse the script following the script operator `_`, `^`, `┬` or `┴` at the given span. A
+    /// sign is kept with the script, e.g. `x^-1`, and a repeated operator nests the scripts, e.g.
+    /// `x^2^3` is `x^(2^3)`.
+    fn script(&mut self, op: char, span: Range<usize>) -> Result<Vec<Element>, UnicodeMathError> {
+        let mut nodes = Vec::new();
+
+        if let Some(sign @ ('+' | '-' | '−' | '±' | '∓')) = self.peek_char() {
+            self.pos += 1;
+            nodes.push(symbols::operator(sign).into());
+
+            if !self.at_operand() {
+                return Ok(into_argument(nodes));
+            }
+        }
+
+        if !self.at_operand() {
+            return Err(self.missing_argument(span));
+        }
+
+        let mut node = self.nested(span, |parser| parser.entity(false))?.node;
+
+        if self.peek_char() == Some(op) {
+            let span = self.span();
+            self.pos += 1;
+
+            let script = self.nested(span.clone(), |parser| parser.script(op, span))?;
+            let base = [node.into_element()];
+
+            node = match op {
+                '_' => SubSup::builder()
+                    .base(base)
+                    .subscript(script)
+                    .build()
+                    .into(),
+                '^' => SubSup::builder()
+                    .base(base)
+                    .supscript(script)
+                    .build()
+                    .into(),
+                '┬' => under_over(base, Some(script), None).into(),
+                _ => under_over(base, None, Some(script)).into(),
+            };
+        }
+
+        nodes.push(node);
+        Ok(into_argument(nodes))
+    }
+
+    /// Parse the argument of the prefix operator at the given span, e.g. `x` in `√x`.
+    fn argument(&mut self, span: Range<usize>) -> Result<Vec<Element>, UnicodeMathError> {
+        self.skip_space();
+
+        if !self.at_operand() {
+            return Err(self.missing_argument(span));
+        }
+
+        let entity = self.nested(span, |parser| parser.entity(false))?;
+        Ok(into_argument(vec![entity.node]))
+    }
+
+    /// Parse the entity starting with the given character at the given span. Only entities with
+    /// arguments are handled here, keeping the stack frames of deeply nested input small.
+    fn primary(&mut self, c: char, span: Range<usize>) -> Result<Simple, UnicodeMathError> {
+        match c {
+            '√' | '∛' | '∜' => self.radical(c, span).map(Simple::new),
+            '■' | '█' => self
+                .nested(span.clone(), |parser| parser.array(c, span))
+                .map(Simple::new),
+            '├' | '(' | '[' | '{' | '⟨' | '⌊' | '⌈' | '〖' => {
+                self.nested(span, |parser| parser.group(c))
+            }
+            c => match symbols::brace(c) {
+                Some((brace, under)) => self.brace(brace, under, span),
+                None => Ok(self.atom(c)),
+            },
+        }
+    }
+
+    /// Parse the entity starting with the given character that has no arguments, e.g. a number,
+    /// a letter, a function name or an operator.
+    fn atom(&mut self, c: char) -> Simple {
+        if c.is_ascii_digit() {
+            return Simple::new(self.number(c));
+        }
+
+        if let Some(function) = self.function(c) {
+            return function;
+        }
+
+        if let Some(ident) = symbols::ident(c) {
+            return Simple::new(ident);
+        }
+
+        if let Some(limits) = symbols::nary(c) {
+            return Simple {
+                limits,
+                ..Simple::new(symbols::operator(c))
+            };
+        }
+
+        match c {
+            c if c.is_alphanumeric() => Simple::new(Ident::from(c.to_string())),
+            c if is_space(c) => Simple::new(space(c)),
+            c => Simple::new(symbols::operator(c)),
+        }
+    }
+
+    /// Parse the function name starting with the given letter, e.g. `sin`. Letters that don't
+    /// form a function name are each an identifier.
+    fn function(&mut self, c: char) -> Option<Simple> {
+        if !c.is_alphabetic() {
+            return None;
+        }
+
+        let name = std::iter::once(c)
+            .chain(
+                self.tokens[self.pos..]
+                    .iter()
+                    .map_while(|(token, _)| match token {
+                        Token::Char(c) if c.is_alphabetic() => Some(*c),
+                        _ => None,
+                    }),
+            )
+            .collect::<String>();
+
+        let limits = symbols::function(&name)?;
+        self.pos += name.chars().count() - 1;
+
+        Some(Simple {
+            limits,
+            function: true,
+            ..Simple::new(Ident::from(name))
+        })
+    }
+
+    /// Parse the argument of the horizontal brace at the given span, e.g. `⏟(a+b)`.
+    fn brace(
+        &mut self,
+        brace: Operator,
+        under: bool,
+        span: Range<usize>,
+    ) -> Result<Simple, UnicodeMathError> {
+        let content = self.argument(span)?;
+        let brace = Operator::builder()
+            .op(brace.op())
+            .attr([OperatorAttr::Stretchy])
+            .build();
+
+        // braces take their label as limits, e.g. `⏟(a+b)_n`
+        Ok(Simple {
+            limits: true,
+            ..Simple::new(accent(content, brace, under))
+        })
+    }
+
+    /// Parse the number starting with the given digit, e.g. `12.5`.
+    fn number(&mut self, first: char) -> Num {
+        let mut number = String::from(first);
+
+        while let Some(c) = self.peek_char() {
+            let fraction = c == '.'
+                && matches!(self.tokens.get(self.pos + 1), Some((Token::Char(d), _)) if d.is_ascii_digit());
+
+            if !c.is_ascii_digit() && !fraction {
+                break;
+            }
+
+            number.push(c);
+            self.pos += 1;
+        }
+
+        Num::from(number.as_str())
+    }
+
+    /// Parse the bracketed expression opened with the given bracket. `├` and `┤` take the
+    /// following character as bracket, `〖` and `〗` are invisible.
+    fn group(&mut self, open: char) -> Result<Simple, UnicodeMathError> {
+        let open = match open {
+            '├' => self.fence(),
+            '〖' => None,
+            open => Some(symbols::operator(open)),
+        };
+
+        self.depth += 1;
+        let content = self.expression(false);
+        self.depth -= 1;
+        let content = content?;
+
+        let close = match self.peek_char() {
+            Some('┤') => {
+                self.pos += 1;
+                self.fence()
+            }
+            Some('〗') => {
+                self.pos += 1;
+                None
+            }
+            Some(close) if symbols::is_closing_bracket(close) => {
+                self.pos += 1;
+                Some(symbols::operator(close))
+            }
+            _ => None,
+        };
+
+        Ok(Simple::new(Node::Group {
+            open,
+            content,
+            close,
+        }))
+    }
+
+    /// Parse the bracket following `├` or `┤`, no bracket is shown if it is followed by a space.
+    fn fence(&mut self) -> Option<Operator> {
+        match self.peek() {
+            Some(Token::Char(c) | Token::Literal(c)) if !c.is_alphanumeric() => {
+                let op = symbols::operator(*c);
+                self.pos += 1;
+                Some(op)
+            }
+            _ => {
+                self.skip_space();
+                None
+            }
+        }
+    }
+
+    /// Parse a radical, e.g. `√x`, `∛x` or `√(n&x)`.
+    fn radical(&mut self, c: char, span: Range<usize>) -> Result<Element, UnicodeMathError> {
+        let (index, content) = if c == '√' && self.peek_char() == Some('(') {
+            let mut cells = self.nested(span.clone(), |parser| parser.cells(span))?;
+
+            let content = cells.pop().unwrap_or_default();
+            let index = match cells.pop() {
+                Some(index) if !index.is_empty() => into_argument(index),
+                _ => vec![Num::from(2).into()],
+            };
+
+            (index, into_argument(content))
+        } else {
+            let index = match c {
+                '∛' => 3,
+                '∜' => 4,
+                _ => 2,
+            };
+
+            (vec![Num::from(index).into()], self.argument(span)?)
+        };
+
+        Ok(Radical::builder()
+            .index(index)
+            .content(content)
+            .build()
+            .into())
+    }
+
+    /// Parse a matrix `■(a&b@c&d)` or an equation array `█(x&=1@y&=2)`.
+    fn array(&mut self, c: char, span: Range<usize>) -> Result<Element, UnicodeMathError> {
+        if self.peek_char() != Some('(') {
+            return Err(self.missing_argument(span));
+        }
+
+        let mut rows = vec![Vec::new()];
+
+        self.pos += 1;
+        self.depth += 1;
+
+        let result = loop {
+            let cell = match self.expression(true) {
+                Ok(cell) => cell,
+                Err(err) => break Err(err),
+            };
+
+            if let Some(row) = rows.last_mut() {
+                row.push(cell);
+            }
+
+            match self.bump() {
+                Some((Token::Char('&'), _)) => {}
+                Some((Token::Char('@'), _)) => rows.push(Vec::new()),
+                Some((Token::Char(')'), _)) => break Ok(()),
+                _ => {
+                    break Err(UnicodeMathError::new(
+                        UnicodeMathErrorKind::UnclosedBracket,
+                        span.end..span.end + 1,
+                    ))
+                }
+            }
+        };
+
+        self.depth -= 1;
+        result?;
+
+        // equation arrays are aligned at `&`, alternating right and left aligned columns
+        let aligned = c == '█' && rows.iter().any(|cells| cells.len() > 1);
+
+        let table = rows
+            .into_iter()
+            .map(|cells| {
+                cells
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, cell)| {
+                        let cell = cell
+                            .into_iter()
+                            .map(Node::into_element)
+                            .collect::<Elements>();
+                        let cell = TableCell::from(cell);
+                        let align = match idx % 2 {
+                            0 => "text-align: right",
+                            _ => "text-align: left",
+                        };
+
+                        match aligned {
+                            true => cell.with_attr([Attribute::Style(align.into())]),
+                            false => cell,
+                        }
+                    })
+                    .collect::<TableRow>()
+            })
+            .collect::<Table>();
+
+        Ok(table.into())
+    }
+
+    /// Parse the `&` separated cells in the parentheses following the operator at the given
+    /// span, e.g. `(n&x)`.
+    fn cells(&mut self, span: Range<usize>) -> Result<Vec<Vec<Node>>, UnicodeMathError> {
+        let mut cells = Vec::new();
+
+        self.pos += 1;
+        self.depth += 1;
+
+        let result = loop {
+            match self.expression(true) {
+                Ok(cell) => cells.push(cell),
+                Err(err) => break Err(err),
+            }
+
+            match self.bump() {
+                Some((Token::Char('&'), _)) => {}
+                Some((Token::Char(')'), _)) => break Ok(()),
+                _ => {
+                    break Err(UnicodeMathError::new(
+                        UnicodeMathErrorKind::UnclosedBracket,
+                        span.end..span.end + 1,
+                    ))
+                }
+            }
+        };
+
+        self.depth -= 1;
+        result.map(|_| cells)
+    }
+}
+
+/// Whether the character is a space that is kept in the formula.
+fn is_space(c: char) -> bool {
+    matches!(c, '\u{2003}' | '\u{2009}')
+}
+
+/// Build the fraction of the given operands, without a fraction line for `¦`.
+fn fraction(num: Vec<Node>, denom: Vec<Node>, no_line: bool) -> Frac {
+    let builder = Frac::builder()
+        .num(into_argument(num))
+        .denom(into_argument(denom));
+
+    match no_line {
+        true => builder
+            .attr([FracAttr::LineThickness(Length::unitless(0.0).into())])
+            .build(),
+        false => builder.build(),
+    }
+}
+
+/// Put the accent under or over the given content.
+fn accent(content: Vec<Element>, accent: Operator, under: bool) -> Element {
+    let builder = UnderOver::builder().expr(content);
+
+    match under {
+        true => builder
+            .under([accent])
+            .attr([UnderOverAttr::AccentUnder])
+            .build(),
+        false => builder
+            .over([accent])
+            .attr([UnderOverAttr::AccentOver])
+            .build(),
+    }
+    .into()
+}
+
+/// The space kept in the formula, see [`is_space`].
+fn space(c: char) -> Space {
+    let width = match c {
+        '\u{2009}' => Length::named(NamedSpace::Thin),
+        _ => Length::em(1.0),
+    };
+
+    Space::default().with_attr([SpaceAttr::Width(width.into())])
+}
+
+fn under_over(
+    base: [Element; 1],
+    under: Option<Vec<Element>>,
+    over: Option<Vec<Element>>,
+) -> Element {
+    match (under, over) {
+        (Some(under), Some(over)) => UnderOver::builder()
+            .expr(base)
+            .under(under)
+            .over(over)
+            .build()
+            .into(),
+        (Some(under), None) => UnderOver::builder().expr(base).under(under).build().into(),
+        (None, Some(over)) => UnderOver::builder().expr(base).over(over).build().into(),
+        (None, None) => {
+            let [base] = base;
+            base
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! Tables mapping UnicodeMath characters and control words to elements.
+
+use crate::elements::{Ident, Operator};
+
+/// A token of UnicodeMath input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Token {
+    /// A character, or the character a control word stands for, e.g. `√` for `\sqrt`.
+    Char(char),
+
+    /// A character escaped with a backslash, which is taken literally, e.g. `\/`.
+    Literal(char),
+
+    /// An identifier written as control word, e.g. `\alpha`.
+    Ident(Ident),
+
+    /// Text in quotes, e.g. `"if"`.
+    Text(String),
+
+    /// Whitespace, which ends operands and is removed when the formula is built up.
+    Space,
+}
+
+/// Operators that are recognized by their constructors.
+const OPERATORS: [fn() -> Operator; 91] = [
+    Operator::plus,
+    Operator::minus,
+    Operator::dot,
+    Operator::double_dot,
+    Operator::asterisk,
+    Operator::star,
+    Operator::solidus,
+    Operator::set_minus,
+    Operator::mult,
+    Operator::div,
+    Operator::lfactor,
+    Operator::rfactor,
+    Operator::bowtie,
+    Operator::ring,
+    Operator::circle_plus,
+    Operator::circle_times,
+    Operator::circle_dot,
+    Operator::sum,
+    Operator::prod,
+    Operator::wedge,
+    Operator::big_wedge,
+    Operator::vee,
+    Operator::big_vee,
+    Operator::cap,
+    Operator::big_cap,
+    Operator::cup,
+    Operator::big_cup,
+    Operator::integral,
+    Operator::circle_integral,
+    Operator::partial_diff,
+    Operator::nabla,
+    Operator::plus_minus,
+    Operator::therefore,
+    Operator::because,
+    Operator::angle,
+    Operator::lfloor,
+    Operator::rfloor,
+    Operator::lceiling,
+    Operator::rceiling,
+    Operator::assign,
+    Operator::eq,
+    Operator::not_eq,
+    Operator::lt,
+    Operator::gt,
+    Operator::le,
+    Operator::ge,
+    Operator::prec,
+    Operator::succ,
+    Operator::preceq,
+    Operator::succeq,
+    Operator::in_set,
+    Operator::not_in_set,
+    Operator::subset,
+    Operator::supset,
+    Operator::subseteq,
+    Operator::supseteq,
+    Operator::equivalent,
+    Operator::congruent,
+    Operator::approx,
+    Operator::propto,
+    Operator::not,
+    Operator::implies,
+    Operator::log_if,
+    Operator::iff,
+    Operator::forall,
+    Operator::exists,
+    Operator::bottom,
+    Operator::top,
+    Operator::vdash,
+    Operator::models,
+    Operator::lparens,
+    Operator::rparens,
+    Operator::oparens,
+    Operator::uparens,
+    Operator::lbracket,
+    Operator::rbracket,
+    Operator::obracket,
+    Operator::ubracket,
+    Operator::lbrace,
+    Operator::rbrace,
+    Operator::obrace,
+    Operator::ubrace,
+    Operator::langle,
+    Operator::rangle,
+    Operator::vert_bar,
+    Operator::norm,
+    Operator::hat,
+    Operator::bar,
+    Operator::rarrow,
+    Operator::larrow,
+    Operator::tilde,
+];
+
+/// Identifiers that are recognized by their constructors.
+const IDENTS: [fn() -> Ident; 45] = [
+    Ident::empty_set,
+    Ident::infinity,
+    Ident::aleph,
+    Ident::set_natural,
+    Ident::set_integer,
+    Ident::set_rational,
+    Ident::set_irrational,
+    Ident::set_complex,
+    Ident::set_body,
+    Ident::alpha,
+    Ident::beta,
+    Ident::gamma,
+    Ident::big_gamma,
+    Ident::delta,
+    Ident::big_delta,
+    Ident::epsilon,
+    Ident::varepsilon,
+    Ident::zeta,
+    Ident::eta,
+    Ident::theta,
+    Ident::big_theta,
+    Ident::vartheta,
+    Ident::iota,
+    Ident::kappa,
+    Ident::lambda,
+    Ident::big_lambda,
+    Ident::mu,
+    Ident::nu,
+    Ident::xi,
+    Ident::big_xi,
+    Ident::pi,
+    Ident::big_pi,
+    Ident::rho,
+    Ident::sigma,
+    Ident::big_sigma,
+    Ident::tau,
+    Ident::upsilon,
+    Ident::phi,
+    Ident::big_phi,
+    Ident::varphi,
+    Ident::chi,
+    Ident::psi,
+    Ident::big_psi,
+    Ident::omega,
+    Ident::big_omega,
+];
+
+/// The operator for the character, created with its constructor if there is one.
+pub(super) fn operator(c: char) -> Operator {
+    let s = c.to_string();
+
+    OPERATORS
+        .iter()
+        .map(|constructor| constructor())
+        .find(|op| op.op() == s)
+        .unwrap_or_else(|| Operator::from(s))
+}
+
+/// The identifier for the character, if it has a constructor, e.g. `∞`.
+pub(super) fn ident(c: char) -> Option<Ident> {
+    let s = c.to_string();
+
+    IDENTS
+        .iter()
+        .map(|constructor| constructor())
+        .find(|ident| ident.ident() == s)
+}
+
+/// Look up the function name. The returned `bool` is `true` if the scripts of the function are
+/// placed under and over it, e.g. for `lim`.
+pub(super) fn function(name: &str) -> Option<bool> {
+    let limits = match name {
+        "sin" | "cos" | "tan" | "cot" | "sec" | "csc" | "sinh" | "cosh" | "tanh" | "coth"
+        | "sech" | "csch" | "arcsin" | "arccos" | "arctan" | "arg" | "deg" | "det" | "dim"
+        | "exp" | "gcd" | "hom" | "ker" | "lg" | "ln" | "log" | "mod" | "Pr" => false,
+        "lim" | "max" | "min" | "sup" | "inf" => true,
+        _ => return None,
+    };
+
+    Some(limits)
+}
+
+/// Whether the character is an n-ary operator. The returned `bool` is `true` if its scripts are
+/// placed under and over it, e.g. for `∑`, and `false` for integrals.
+pub(super) fn nary(c: char) -> Option<bool> {
+    match c {
+        '∑' | '∏' | '∐' | '⋀' | '⋁' | '⋂' | '⋃' | '⨀' | '⨁' | '⨂' => Some(true),
+        '∫' | '∬' | '∭' | '∮' | '∯' | '∰' => Some(false),
+        _ => None,
+    }
+}
+
+/// The accent for a combining character following an operand, e.g. `^` for U+0302. The returned
+/// `bool` is `true` if the accent is placed under the operand.
+pub(super) fn combining_accent(c: char) -> Option<(Operator, bool)> {
+    let accent = match c {
+        '\u{0302}' => (Operator::hat(), false),
+        '\u{0303}' => (Operator::tilde(), false),
+        '\u{0304}' | '\u{0305}' => (Operator::bar(), false),
+        '\u{0307}' => (Operator::from("\u{02D9}"), false),
+        '\u{0308}' => (Operator::double_dot(), false),
+        '\u{030C}' => (Operator::from("\u{02C7}"), false),
+        '\u{0301}' => (Operator::from("\u{00B4}"), false),
+        '\u{0300}' => (Operator::from("\u{0060}"), false),
+        '\u{0306}' => (Operator::from("\u{02D8}"), false),
+        '\u{20D7}' => (Operator::rarrow(), false),
+        '\u{20D6}' => (Operator::larrow(), false),
+        '\u{0332}' => (Operator::bar(), true),
+        _ => return None,
+    };
+
+    Some(accent)
+}
+
+/// The brace placed over or under the following operand, e.g. `⏞`. The returned `bool` is
+/// `true` if the brace is placed under the operand.
+pub(super) fn brace(c: char) -> Option<(Operator, bool)> {
+    [
+        (Operator::obrace(), false),
+        (Operator::ubrace(), true),
+        (Operator::obracket(), false),
+        (Operator::ubracket(), true),
+    ]
+    .into_iter()
+    .find(|(op, _)| op.op().starts_with(c))
+}
+
+/// The closing bracket matching the opening bracket.
+pub(super) fn closing_bracket(c: char) -> Option<char> {
+    let close = match c {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '⟨' => '⟩',
+        '⌊' => '⌋',
+        '⌈' => '⌉',
+        '〖' => '〗',
+        _ => return None,
+    };
+
+    Some(close)
+}
+
+/// Whether the character closes a bracket opened with any of the opening brackets.
+pub(super) fn is_closing_bracket(c: char) -> bool {
+    matches!(c, ')' | ']' | '}' | '⟩' | '⌋' | '⌉' | '〗' | '┤')
+}
+
+/// Look up the control word, e.g. `\sqrt`.
+pub(super) fn control_word(name: &str) -> Option<Token> {
+    let c = match name {
+        // structures
+        "sqrt" => '√',
+        "cbrt" => '∛',
+        "qdrt" => '∜',
+        "matrix" => '■',
+        "eqarray" => '█',
+        "naryand" => '▒',
+        "funcapply" => '\u{2061}',
+        "below" => '┬',
+        "above" => '┴',
+        "atop" => '¦',
+        "over" => '/',
+        "begin" => '〖',
+        "end" => '〗',
+        "left" => '├',
+        "right" => '┤',
+        "overbrace" => '⏞',
+        "underbrace" => '⏟',
+        "overbracket" => '⎴',
+        "underbracket" => '⎵',
+
+        // accents
+        "hat" => '\u{0302}',
+        "tilde" => '\u{0303}',
+        "bar" => '\u{0305}',
+        "vec" => '\u{20D7}',
+        "dot" => '\u{0307}',
+        "ddot" => '\u{0308}',
+        "check" => '\u{030C}',
+        "acute" => '\u{0301}',
+        "grave" => '\u{0300}',
+        "breve" => '\u{0306}',
+
+        // n-ary operators
+        "sum" => '∑',
+        "prod" => '∏',
+        "coprod" => '∐',
+        "int" => '∫',
+        "iint" => '∬',
+        "iiint" => '∭',
+        "oint" => '∮',
+        "bigcap" => '⋂',
+        "bigcup" => '⋃',
+        "bigwedge" => '⋀',
+        "bigvee" => '⋁',
+
+        // operators and relations
+        "pm" => '±',
+        "mp" => '∓',
+        "times" => '×',
+        "div" => '÷',
+        "cdot" => '⋅',
+        "circ" => '∘',
+        "oplus" => '⊕',
+        "otimes" => '⊗',
+        "le" | "leq" => '≤',
+        "ge" | "geq" => '≥',
+        "ne" | "neq" => '≠',
+        "ll" => '≪',
+        "gg" => '≫',
+        "approx" => '≈',
+        "equiv" => '≡',
+        "cong" => '≅',
+        "sim" => '∼',
+        "propto" => '∝',
+        "in" => '∈',
+        "notin" => '∉',
+        "ni" => '∋',
+        "subset" => '⊂',
+        "supset" => '⊃',
+        "subseteq" => '⊆',
+        "supseteq" => '⊇',
+        "cap" => '∩',
+        "cup" => '∪',
+        "setminus" => '∖',
+        "wedge" => '∧',
+        "vee" => '∨',
+        "neg" => '¬',
+        "forall" => '∀',
+        "exists" => '∃',
+        "partial" => '∂',
+        "nabla" => '∇',
+        "perp" => '⊥',
+        "parallel" => '∥',
+        "angle" => '∠',
+        "therefore" => '∴',
+        "because" => '∵',
+        "to" | "rightarrow" => '→',
+        "leftarrow" => '←',
+        "leftrightarrow" => '↔',
+        "Rightarrow" => '⇒',
+        "Leftarrow" => '⇐',
+        "Leftrightarrow" => '⇔',
+        "mapsto" => '↦',
+        "ldots" => '…',
+        "cdots" => '⋯',
+        "vdots" => '⋮',
+        "ddots" => '⋱',
+        "prime" => '′',
+        "degree" => '°',
+
+        // brackets
+        "langle" => '⟨',
+        "rangle" => '⟩',
+        "lfloor" => '⌊',
+        "rfloor" => '⌋',
+        "lceil" => '⌈',
+        "rceil" => '⌉',
+        "norm" => '‖',
+        "vert" => '|',
+
+        // spaces
+        "quad" => '\u{2003}',
+        "thinsp" => '\u{2009}',
+
+        // identifiers
+        name => {
+            let ident = match name {
+                "alpha" => Ident::alpha(),
+                "beta" => Ident::beta(),
+                "gamma" => Ident::gamma(),
+                "Gamma" => Ident::big_gamma(),
+                "delta" => Ident::delta(),
+                "Delta" => Ident::big_delta(),
+                "epsilon" => Ident::epsilon(),
+                "varepsilon" => Ident::varepsilon(),
+                "zeta" => Ident::zeta(),
+                "eta" => Ident::eta(),
+                "theta" => Ident::theta(),
+                "vartheta" => Ident::vartheta(),
+                "Theta" => Ident::big_theta(),
+                "iota" => Ident::iota(),
+                "kappa" => Ident::kappa(),
+                "lambda" => Ident::lambda(),
+                "Lambda" => Ident::big_lambda(),
+                "mu" => Ident::mu(),
+                "nu" => Ident::nu(),
+                "xi" => Ident::xi(),
+                "Xi" => Ident::big_xi(),
+                "pi" => Ident::pi(),
+                "Pi" => Ident::big_pi(),
+                "rho" => Ident::rho(),
+                "sigma" => Ident::sigma(),
+                "Sigma" => Ident::big_sigma(),
+                "tau" => Ident::tau(),
+                "upsilon" => Ident::upsilon(),
+                "phi" => Ident::phi(),
+                "varphi" => Ident::varphi(),
+                "Phi" => Ident::big_phi(),
+                "chi" => Ident::chi(),
+                "psi" => Ident::psi(),
+                "Psi" => Ident::big_psi(),
+                "omega" => Ident::omega(),
+                "Omega" => Ident::big_omega(),
+                "infty" => Ident::infinity(),
+                "emptyset" => Ident::empty_set(),
+                "aleph" => Ident::aleph(),
+                _ => return None,
+            };
+
+            return Some(Token::Ident(ident));
+        }
+    };
+
+    Some(Token::Char(c))
+}
@@ -0,0 +1,712 @@
+//! Validation of MathML element trees.
+//!
+//! Elements can be edited into content that is not valid MathML Core, e.g. an `msup` whose
+//! children were removed, and some rules depend on the whole tree. [`MathMl::validate`] and
+//! [`Element::validate`] check these rules and return a [`Diagnostic`] for each violation, with
+//! the [`Path`] to the offending element:
+//!
+//! - `mfrac`, `mroot`, `msub`, `msup`, `munder` and `mover` must have exactly two children,
+//!   `msubsup` and `munderover` exactly three,
+//! - `mmultiscripts` must have a base, followed by an even number of scripts before and after
+//!   at most one `mprescripts`,
+//! - `mprescripts` is only allowed in `mmultiscripts`,
+//! - `semantics` must start with a presentation child, followed by annotations,
+//! - `annotation` and `annotation-xml` are only allowed in `semantics`,
+//! - attributes must not be repeated on the same element,
+//! - lengths must be valid, see [`Length`], and only be relative on `mpadded`,
+//! - colors must be valid, see [`Color`],
+//! - `id`s must be unique in the whole tree.
+//!
+//! Table cells can only be created inside of table rows ([`TableRow`]), so `mtd` elements are
+//! always children of `mtr` elements and don't need to be checked.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{grouping::Prescripts, scripted::Multiscripts, Ident};
+//! use alemat::validate::{DiagnosticKind, Severity};
+//! use alemat::{children, MathMl};
+//!
+//! let math = MathMl::with_content([Multiscripts::builder()
+//!     .content(children![Ident::from("X"), Ident::from("a"), Prescripts::default()])
+//!     .build()]);
+//!
+//! let diagnostics = math.validate();
+//!
+//! assert_eq!(diagnostics.len(), 1);
+//! assert_eq!(diagnostics[0].severity(), Severity::Error);
+//! assert_eq!(diagnostics[0].kind(), &DiagnosticKind::OddScripts);
+//! assert_eq!(diagnostics[0].path().to_string(), "/math[0]/mmultiscripts[0]");
+//! ```
+//!
+//! [`TableRow`]: crate::elements::TableRow
+//! [`Length`]: crate::attributes::Length
+//! [`Color`]: crate::attributes::Color
+
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+use crate::{
+    attributes::{Attribute, Color, Length},
+    elements::{
+        grouping::ActionAttr, scripted::UnderOverAttr, AnnotationAttr, AnnotationContent, FracAttr,
+        OperatorAttr, PaddedAttr, SpaceAttr, TableAttr, TableCellAttr,
+    },
+    Element, MathMl, MathMlAttr,
+};
+
+/// Severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The markup is valid, but most likely not what was intended.
+    Warning,
+
+    /// The markup is invalid.
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// The rule violated by the element a [`Diagnostic`] points to.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticKind {
+    /// The `mmultiscripts` element is empty or starts with `mprescripts`.
+    MissingBase,
+
+    /// The `mmultiscripts` element has an odd number of scripts before or after `mprescripts`.
+    OddScripts,
+
+    /// The `mmultiscripts` element contains more than one `mprescripts`.
+    MultiplePrescripts,
+
+    /// The `mprescripts` element is not a child of `mmultiscripts`.
+    MisplacedPrescripts,
+
+    /// The `semantics` element is empty or starts with an annotation.
+    MissingPresentation,
+
+    /// A child of `semantics` other than the first one is not an annotation. Such children are
+    /// not rendered.
+    UnexpectedSemanticsChild,
+
+    /// The `annotation` or `annotation-xml` element is not a child of `semantics`.
+    MisplacedAnnotation,
+
+    /// The attribute with the given name is repeated on the same element.
+    DuplicateAttribute(String),
+
+    /// The given `id` is used by more than one element.
+    DuplicateId(String),
+
+    /// The value of the attribute is not a valid length, e.g. `2 em`, or a relative length
+    /// outside of `mpadded`, e.g. `+1em`.
+    InvalidLength {
+        /// The name of the attribute.
+        name: String,
+        /// The value of the attribute as it is written.
+        value: String,
+    },
+
+    /// The value of the `mathcolor` or `mathbackground` attribute is not a valid color, e.g.
+    /// `#12`.
+    InvalidColor {
+        /// The name of the attribute.
+        name: String,
+        /// The value of the attribute as it is written.
+        value: String,
+    },
+
+    /// The element does not have the number of children required by its name, e.g. `mfrac`
+    /// requires exactly two.
+    ChildCount {
+        /// The required number of children.
+        expected: usize,
+        /// The number of children found.
+        found: usize,
+    },
+}
+
+impl DiagnosticKind {
+    /// Get the severity of a violation of this rule.
+    pub fn severity(&self) -> Severity {
+        match self {
+            DiagnosticKind::UnexpectedSemanticsChild => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::MissingBase => f.write_str("mmultiscripts has no base"),
+            DiagnosticKind::OddScripts => f.write_str("mmultiscripts has an odd number of scripts"),
+            DiagnosticKind::MultiplePrescripts => {
+                f.write_str("mmultiscripts has more than one mprescripts")
+            }
+            DiagnosticKind::MisplacedPrescripts => {
+                f.write_str("mprescripts outside of mmultiscripts")
+            }
+            DiagnosticKind::MissingPresentation => {
+                f.write_str("semantics does not start with a presentation child")
+            }
+            DiagnosticKind::UnexpectedSemanticsChild => {
+                f.write_str("child of semantics is not an annotation")
+            }
+            DiagnosticKind::MisplacedAnnotation => f.write_str("annotation outside of semantics"),
+            DiagnosticKind::DuplicateAttribute(name) => write!(f, "duplicate attribute `{name}`"),
+            DiagnosticKind::DuplicateId(id) => write!(f, "duplicate id `{id}`"),
+            DiagnosticKind::InvalidLength { name, value } => {
+                write!(f, "invalid length `{value}` of attribute `{name}`")
+            }
+            DiagnosticKind::InvalidColor { name, value } => {
+                write!(f, "invalid color `{value}` of attribute `{name}`")
+            }
+            DiagnosticKind::ChildCount { expected, found } => {
+                write!(f, "expected {expected} children, found {found}")
+            }
+        }
+    }
+}
+
+/// Step of a [`Path`], the element name and its index among its siblings.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Segment {
+    /// Name of the element, e.g. `mfrac`.
+    pub name: &'static str,
+
+    /// Index of the element among its siblings, as written in the markup.
+    pub index: usize,
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]", self.name, self.index)
+    }
+}
+
+/// Path from the root of the validated tree to an element, e.g. `/math[0]/mfrac[0]/mi[1]`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Path(Vec<Segment>);
+
+impl Path {
+    /// Get a reference to the segments of the [`Path`], starting at the root.
+    pub fn segments(&self) -> &[Segment] {
+        &self.0
+    }
+}
+
+impl Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.0 {
+            write!(f, "/{segment}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A violation of a rule found by [`MathMl::validate`] or [`Element::validate`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Diagnostic {
+    severity: Severity,
+    kind: DiagnosticKind,
+    path: Path,
+}
+
+impl Diagnostic {
+    /// Get the severity of the [`Diagnostic`].
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Get a reference to the violated rule.
+    pub fn kind(&self) -> &DiagnosticKind {
+        &self.kind
+    }
+
+    /// Get a reference to the path of the offending element.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Check whether the [`Diagnostic`] is an error.
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} at {}", self.severity, self.kind, self.path)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+impl MathMl {
+    /// Validate the whole tree and return all violations found. The returned diagnostics are
+    /// empty if the tree is valid.
+    ///
+    /// See the [`validate`](crate::validate) module for the checked rules.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validator = Validator::default();
+        validator.mathml(self);
+        validator.diagnostics
+    }
+}
+
+impl Element {
+    /// Validate the element and all its children and return all violations found. The returned
+    /// diagnostics are empty if the tree is valid.
+    ///
+    /// The element is validated as if it were the child of an `mrow`, so e.g. a standalone
+    /// `annotation` is reported as misplaced.
+    ///
+    /// See the [`validate`](crate::validate) module for the checked rules.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validator = Validator::default();
+        validator.element(self, 0, Parent::Other);
+        validator.diagnostics
+    }
+}
+
+/// Attribute of any element, either one of the global [`Attribute`]s or the name of an element
+/// specific attribute, with its value if it is a length.
+enum AnyAttr<'a> {
+    Global(&'a Attribute),
+    Specific(&'static str, Option<&'a str>),
+}
+
+trait AsAnyAttr {
+    fn as_any(&self) -> AnyAttr<'_>;
+}
+
+impl AsAnyAttr for Attribute {
+    fn as_any(&self) -> AnyAttr<'_> {
+        AnyAttr::Global(self)
+    }
+}
+
+macro_rules! as_any_attr {
+    ($($ty:ty { $($variant:pat => $name:literal $(($length:ident))?),* $(,)? })*) => {
+        $(
+            impl AsAnyAttr for $ty {
+                fn as_any(&self) -> AnyAttr<'_> {
+                    match self {
+                        Self::Global(attr) => AnyAttr::Global(attr),
+                        $($variant => AnyAttr::Specific($name, None$(.or(Some($length.as_str())))?),)*
+                    }
+                }
+            }
+        )*
+    };
+}
+
+as_any_attr! {
+    ActionAttr {
+        ActionAttr::Selection(_) => "selection",
+        ActionAttr::ActionType(_) => "actiontype",
+    }
+    AnnotationAttr {
+        AnnotationAttr::Encoding(_) => "encoding",
+    }
+    FracAttr {
+        FracAttr::LineThickness(length) => "linethickness" (length),
+    }
+    MathMlAttr {
+        MathMlAttr::Display(_) => "display",
+        MathMlAttr::AltText(_) => "alttext",
+        MathMlAttr::AriaLabel(_) => "aria-label",
+    }
+    OperatorAttr {
+        OperatorAttr::Form(_) => "form",
+        OperatorAttr::Fence => "fence",
+        OperatorAttr::Separator => "separator",
+        OperatorAttr::LeftSpace(length) => "lspace" (length),
+        OperatorAttr::RightSpace(length) => "rspace" (length),
+        OperatorAttr::MaxSize(length) => "maxsize" (length),
+        OperatorAttr::MinSize(length) => "minsize" (length),
+        OperatorAttr::Stretchy => "stretchy",
+        OperatorAttr::Symmetric => "symmetric",
+        OperatorAttr::LargeOp => "largeop",
+        OperatorAttr::MovableLimits => "movablelimits",
+    }
+    PaddedAttr {
+        PaddedAttr::Width(length) => "width" (length),
+        PaddedAttr::Height(length) => "height" (length),
+        PaddedAttr::Depth(length) => "depth" (length),
+        PaddedAttr::LeftSpace(length) => "lspace" (length),
+        PaddedAttr::VerticalOffset(length) => "voffset" (length),
+    }
+    SpaceAttr {
+        SpaceAttr::Width(length) => "width" (length),
+        SpaceAttr::Height(length) => "height" (length),
+        SpaceAttr::Depth(length) => "depth" (length),
+    }
+    TableAttr {
+        TableAttr::ColumnLines(_) => "columnlines",
+    }
+    TableCellAttr {
+        TableCellAttr::ColumnSpan(_) => "columnspan",
+        TableCellAttr::RowSpan(_) => "rowspan",
+    }
+    UnderOverAttr {
+        UnderOverAttr::AccentUnder => "accentunder",
+        UnderOverAttr::AccentOver => "accent",
+    }
+}
+
+fn global_attr_name(attr: &Attribute) -> Cow<'_, str> {
+    match attr {
+        Attribute::Class(_) => "class".into(),
+        Attribute::Data { name, .. } => format!("data-{name}").into(),
+        Attribute::Dir(_) => "dir".into(),
+        Attribute::DisplayStyle(_) => "displaystyle".into(),
+        Attribute::Id(_) => "id".into(),
+        Attribute::MathBackground(_) => "mathbackground".into(),
+        Attribute::MathColor(_) => "mathcolor".into(),
+        Attribute::MathSize(_) => "mathsize".into(),
+        Attribute::MathVariant(_) => "mathvariant".into(),
+        Attribute::Nonce(_) => "nonce".into(),
+        Attribute::ScriptLevel(_) => "scriptlevel".into(),
+        Attribute::Style(_) => "style".into(),
+        Attribute::TabIndex(_) => "tabindex".into(),
+        Attribute::OnHandler { name, .. } => format!("on{name}").into(),
+    }
+}
+
+fn element_name(element: &Element) -> &'static str {
+    match element {
+        Element::Action(_) => "maction",
+        Element::Annotation(a) => match a.content() {
+            AnnotationContent::Text(_) => "annotation",
+            AnnotationContent::Nested(_) | AnnotationContent::Content(_) => "annotation-xml",
+        },
+        Element::Error(_) => "merror",
+        Element::Frac(_) => "mfrac",
+        Element::Ident(_) => "mi",
+        Element::Multiscripts(_) => "mmultiscripts",
+        Element::Prescripts(_) => "mprescripts",
+        Element::Num(_) => "mn",
+        Element::Operator(_) => "mo",
+        Element::Padded(_) => "mpadded",
+        Element::Phantom(_) => "mphantom",
+        Element::Radical(r) if r.is_square() => "msqrt",
+        Element::Radical(_) => "mroot",
+        Element::Row(_) => "mrow",
+        Element::Semantics(_) => "semantics",
+        Element::Space(_) => "mspace",
+        Element::StrLiteral(_) => "ms",
+        Element::Style(_) => "mstyle",
+        Element::SubSup(s) => match (s.sub(), s.sup()) {
+            (Some(_), Some(_)) => "msubsup",
+            (Some(_), None) => "msub",
+            _ => "msup",
+        },
+        Element::Table(_) => "mtable",
+        Element::Text(_) => "mtext",
+        Element::UnderOver(u) => match (u.under(), u.over()) {
+            (Some(_), Some(_)) => "munderover",
+            (Some(_), None) => "munder",
+            _ => "mover",
+        },
+    }
+}
+
+/// Get the required and the actual number of children of elements with a fixed number of
+/// children, as they are written in the markup.
+fn child_count(element: &Element) -> Option<(usize, usize)> {
+    let len = |children: Option<&[Element]>| children.map_or(0, <[Element]>::len);
+
+    match element {
+        Element::Frac(frac) => Some((2, frac.num().len() + frac.denom().len())),
+        Element::Radical(radical) if !radical.is_square() => {
+            Some((2, radical.content().len() + radical.index().len()))
+        }
+        Element::SubSup(sub_sup) => {
+            let (sub, sup) = (sub_sup.sub(), sub_sup.sup());
+            let expected = if sub.is_some() && sup.is_some() { 3 } else { 2 };
+
+            Some((expected, sub_sup.base().len() + len(sub) + len(sup)))
+        }
+        Element::UnderOver(under_over) => {
+            let (under, over) = (under_over.under(), under_over.over());
+            let expected = if under.is_some() && over.is_some() {
+                3
+            } else {
+                2
+            };
+
+            Some((expected, under_over.expr().len() + len(under) + len(over)))
+        }
+        _ => None,
+    }
+}
+
+/// The parent of the element being validated, as far as it matters for the rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Parent {
+    Multiscripts,
+    Semantics,
+    Other,
+}
+
+#[derive(Debug, Default)]
+struct Validator<'a> {
+    path: Vec<Segment>,
+    ids: HashSet<&'a str>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, kind: DiagnosticKind) {
+        self.diagnostics.push(Diagnostic {
+            severity: kind.severity(),
+            kind,
+            path: Path(self.path.clone()),
+        });
+    }
+
+    fn attributes<A: AsAnyAttr>(&mut self, attributes: &'a [A]) {
+        let mut names = HashSet::new();
+
+        // relative lengths and pseudo-units such as `+1width` are only valid on `mpadded`
+        let padded = self
+            .path
+            .last()
+            .is_some_and(|segment| segment.name == "mpadded");
+
+        for attr in attributes {
+            let (name, length, relative) = match attr.as_any() {
+                AnyAttr::Global(attr) => {
+                    if let Attribute::Id(id) = attr {
+                        if !self.ids.insert(id) {
+                            self.report(DiagnosticKind::DuplicateId(id.clone()));
+                        }
+                    }
+
+                    if let Attribute::MathColor(color) | Attribute::MathBackground(color) = attr {
+                        if color.parse::<Color>().is_err() {
+                            self.report(DiagnosticKind::InvalidColor {
+                                name: global_attr_name(attr).into_owned(),
+                                value: color.clone(),
+                            });
+                        }
+                    }
+
+                    let length = match attr {
+                        Attribute::MathSize(size) => Some(size.as_str()),
+                        _ => None,
+                    };
+
+                    (global_attr_name(attr), length, false)
+                }
+                AnyAttr::Specific(name, length) => (Cow::Borrowed(name), length, padded),
+            };
+
+            let invalid = |length: &&str| match length.parse::<Length>() {
+                Ok(length) => {
+                    let pseudo = length.unit().is_some_and(|unit| unit.is_pseudo());
+                    !relative && (length.is_relative() || pseudo)
+                }
+                Err(_) => true,
+            };
+
+            if let Some(length) = length.filter(invalid) {
+                self.report(DiagnosticKind::InvalidLength {
+                    name: name.to_string(),
+                    value: length.to_string(),
+                });
+            }
+
+            if names.contains(&name) {
+                self.report(DiagnosticKind::DuplicateAttribute(name.into_owned()));
+            } else {
+                names.insert(name);
+            }
+        }
+    }
+
+    fn mathml(&mut self, mathml: &'a MathMl) {
+        self.path.push(Segment {
+            name: "math",
+            index: 0,
+        });
+
+        self.attributes(mathml.attributes());
+        self.children(mathml.content().iter(), Parent::Other);
+
+        self.path.pop();
+    }
+
+    fn children(&mut self, children: impl Iterator<Item = &'a Element>, parent: Parent) {
+        for (index, child) in children.enumerate() {
+            self.element(child, index, parent);
+        }
+    }
+
+    fn element(&mut self, element: &'a Element, index: usize, parent: Parent) {
+        self.path.push(Segment {
+            name: element_name(element),
+            index,
+        });
+
+        let is_annotation = matches!(element, Element::Annotation(_));
+        if parent == Parent::Semantics && index > 0 && !is_annotation {
+            self.report(DiagnosticKind::UnexpectedSemanticsChild);
+        }
+
+        if let Some((expected, found)) = child_count(element) {
+            if expected != found {
+                self.report(DiagnosticKind::ChildCount { expected, found });
+            }
+        }
+
+        match element {
+            Element::Action(action) => {
+                self.attributes(action.attributes());
+                self.children(action.content().iter(), Parent::Other);
+            }
+            Element::Annotation(annotation) => {
+                // an annotation as the first child is reported as missing presentation child
+                if parent != Parent::Semantics {
+                    self.report(DiagnosticKind::MisplacedAnnotation);
+                }
+
+                self.attributes(annotation.attributes());
+
+                if let AnnotationContent::Nested(children) = annotation.content() {
+                    self.children(children.iter(), Parent::Other);
+                }
+            }
+            Element::Error(error) => {
+                self.attributes(error.attributes());
+                self.children(error.content().iter(), Parent::Other);
+            }
+            Element::Frac(frac) => {
+                self.attributes(frac.attributes());
+                self.children(frac.num().iter().chain(frac.denom()), Parent::Other);
+            }
+            Element::Ident(ident) => self.attributes(ident.attributes()),
+            Element::Multiscripts(multiscripts) => {
+                self.attributes(multiscripts.attributes());
+                self.multiscripts(multiscripts.content());
+                self.children(multiscripts.content().iter(), Parent::Multiscripts);
+            }
+            Element::Prescripts(prescripts) => {
+                if parent != Parent::Multiscripts {
+                    self.report(DiagnosticKind::MisplacedPrescripts);
+                }
+
+                self.attributes(prescripts.attributes());
+            }
+            Element::Num(num) => self.attributes(num.attributes()),
+            Element::Operator(operator) => self.attributes(operator.attributes()),
+            Element::Padded(padded) => {
+                self.attributes(padded.attributes());
+                self.children(padded.children().iter(), Parent::Other);
+            }
+            Element::Phantom(phantom) => {
+                self.attributes(phantom.attributes());
+                self.children(phantom.children().iter(), Parent::Other);
+            }
+            Element::Radical(radical) => {
+                self.attributes(radical.attributes());
+
+                let index = radical.index().iter();
+                self.children(radical.content().iter().chain(index), Parent::Other);
+            }
+            Element::Row(row) => {
+                self.attributes(row.attributes());
+                self.children(row.children().iter(), Parent::Other);
+            }
+            Element::Semantics(semantics) => {
+                self.attributes(semantics.attributes());
+
+                if matches!(
+                    semantics.children().first(),
+                    None | Some(Element::Annotation(_))
+                ) {
+                    self.report(DiagnosticKind::MissingPresentation);
+                }
+
+                self.children(semantics.children().iter(), Parent::Semantics);
+            }
+            Element::Space(space) => self.attributes(space.attributes()),
+            Element::StrLiteral(str_literal) => self.attributes(str_literal.attributes()),
+            Element::Style(style) => {
+                self.attributes(style.attributes());
+                self.children(style.children().iter(), Parent::Other);
+            }
+            Element::SubSup(sub_sup) => {
+                self.attributes(sub_sup.attributes());
+
+                let scripts = sub_sup.sub().into_iter().chain(sub_sup.sup()).flatten();
+                self.children(sub_sup.base().iter().chain(scripts), Parent::Other);
+            }
+            Element::Table(table) => {
+                self.attributes(table.attributes());
+
+                for (index, row) in table.rows().iter().enumerate() {
+                    self.path.push(Segment { name: "mtr", index });
+                    self.attributes(row.attributes());
+
+                    for (index, cell) in row.cells().iter().enumerate() {
+                        self.path.push(Segment { name: "mtd", index });
+                        self.attributes(cell.attributes());
+                        self.children(cell.children().iter(), Parent::Other);
+                        self.path.pop();
+                    }
+
+                    self.path.pop();
+                }
+            }
+            Element::Text(text) => self.attributes(text.attributes()),
+            Element::UnderOver(under_over) => {
+                self.attributes(under_over.attributes());
+
+                let scripts = under_over
+                    .under()
+                    .into_iter()
+                    .chain(under_over.over())
+                    .flatten();
+                self.children(under_over.expr().iter().chain(scripts), Parent::Other);
+            }
+        }
+
+        self.path.pop();
+    }
+
+    /// Check the structure of the content of `mmultiscripts`.
+    fn multiscripts(&mut self, content: &[Element]) {
+        let is_prescripts = |e: &&Element| matches!(e, Element::Prescripts(_));
+
+        if content.iter().filter(is_prescripts).count() > 1 {
+            self.report(DiagnosticKind::MultiplePrescripts);
+        }
+
+        if matches!(content.first(), None | Some(Element::Prescripts(_))) {
+            self.report(DiagnosticKind::MissingBase);
+            return;
+        }
+
+        let scripts = content.iter().skip(1);
+        let post = scripts.clone().take_while(|e| !is_prescripts(e)).count();
+        let pre = scripts.skip(post + 1).filter(|e| !is_prescripts(e)).count();
+
+        if !post.is_multiple_of(2) || !pre.is_multiple_of(2) {
+            self.report(DiagnosticKind::OddScripts);
+        }
+    }
+}
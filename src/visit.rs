@@ -0,0 +1,594 @@
+//! Traversal of MathML element trees.
+//!
+//! The [`Visit`] trait walks a tree of elements by reference, and the [`VisitMut`] trait walks
+//! it by mutable reference, which makes it possible to rewrite elements in place. Each trait has
+//! a method for every element, and by default each method recurses into the children of the
+//! element by calling the free function of the same name in this module, e.g.
+//! [`Visit::visit_frac`] calls [`visit_frac`]. Override only the methods for elements that are
+//! of interest, and call the free function from the overridden method to keep recursing into
+//! the children.
+//!
+//! # Example
+//!
+//! ```
+//! use alemat::elements::{Frac, Ident, Num};
+//! use alemat::visit::{self, Visit, VisitMut};
+//! use alemat::MathMl;
+//!
+//! #[derive(Default)]
+//! struct Idents(Vec<String>);
+//!
+//! impl Visit for Idents {
+//!     fn visit_ident(&mut self, ident: &Ident) {
+//!         self.0.push(ident.ident().to_string());
+//!     }
+//! }
+//!
+//! struct Rename;
+//!
+//! impl VisitMut for Rename {
+//!     fn visit_element_mut(&mut self, element: &mut alemat::Element) {
+//!         if matches!(element, alemat::Element::Ident(ident) if ident.ident() == "x") {
+//!             *element = Ident::from("y").into();
+//!         }
+//!
+//!         visit::visit_element_mut(self, element);
+//!     }
+//! }
+//!
+//! let mut math = MathMl::with_content([Frac::builder()
+//!     .num(Ident::from("x"))
+//!     .denom(Num::from(2))
+//!     .build()]);
+//!
+//! Rename.visit_mathml_mut(&mut math);
+//!
+//! let mut idents = Idents::default();
+//! idents.visit_mathml(&math);
+//! assert_eq!(idents.0, ["y"]);
+//! ```
+
+use crate::{
+    elements::{
+        grouping::{Action, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver},
+        Annotation, AnnotationContent, Frac, Ident, Num, Operator, Padded, Semantics, Space,
+        StrLiteral, Table, TableCell, TableRow, Text,
+    },
+    Element, Elements, MathMl,
+};
+
+/// Trait for traversing a tree of MathML elements by reference. See the [module](self)
+/// documentation for more information.
+pub trait Visit {
+    /// Visit a [`MathMl`] element.
+    fn visit_mathml(&mut self, mathml: &MathMl) {
+        visit_mathml(self, mathml)
+    }
+
+    /// Visit a list of [`Element`]s.
+    fn visit_elements(&mut self, elements: &[Element]) {
+        visit_elements(self, elements)
+    }
+
+    /// Visit an [`Element`].
+    fn visit_element(&mut self, element: &Element) {
+        visit_element(self, element)
+    }
+
+    /// Visit an [`Action`] element.
+    fn visit_action(&mut self, action: &Action) {
+        visit_action(self, action)
+    }
+
+    /// Visit an [`Annotation`] element.
+    fn visit_annotation(&mut self, annotation: &Annotation) {
+        visit_annotation(self, annotation)
+    }
+
+    /// Visit an [`Error`] element.
+    fn visit_error(&mut self, error: &Error) {
+        visit_error(self, error)
+    }
+
+    /// Visit a [`Frac`] element.
+    fn visit_frac(&mut self, frac: &Frac) {
+        visit_frac(self, frac)
+    }
+
+    /// Visit an [`Ident`] element.
+    fn visit_ident(&mut self, _ident: &Ident) {}
+
+    /// Visit a [`Multiscripts`] element.
+    fn visit_multiscripts(&mut self, multiscripts: &Multiscripts) {
+        visit_multiscripts(self, multiscripts)
+    }
+
+    /// Visit a [`Prescripts`] element.
+    fn visit_prescripts(&mut self, _prescripts: &Prescripts) {}
+
+    /// Visit a [`Num`] element.
+    fn visit_num(&mut self, _num: &Num) {}
+
+    /// Visit an [`Operator`] element.
+    fn visit_operator(&mut self, _operator: &Operator) {}
+
+    /// Visit a [`Padded`] element.
+    fn visit_padded(&mut self, padded: &Padded) {
+        visit_padded(self, padded)
+    }
+
+    /// Visit a [`Phantom`] element.
+    fn visit_phantom(&mut self, phantom: &Phantom) {
+        visit_phantom(self, phantom)
+    }
+
+    /// Visit a [`Radical`] element.
+    fn visit_radical(&mut self, radical: &Radical) {
+        visit_radical(self, radical)
+    }
+
+    /// Visit a [`Row`] element.
+    fn visit_row(&mut self, row: &Row) {
+        visit_row(self, row)
+    }
+
+    /// Visit a [`Semantics`] element.
+    fn visit_semantics(&mut self, semantics: &Semantics) {
+        visit_semantics(self, semantics)
+    }
+
+    /// Visit a [`Space`] element.
+    fn visit_space(&mut self, _space: &Space) {}
+
+    /// Visit a [`StrLiteral`] element.
+    fn visit_str_literal(&mut self, _str_literal: &StrLiteral) {}
+
+    /// Visit a [`Style`] element.
+    fn visit_style(&mut self, style: &Style) {
+        visit_style(self, style)
+    }
+
+    /// Visit a [`SubSup`] element.
+    fn visit_subsup(&mut self, sub_sup: &SubSup) {
+        visit_subsup(self, sub_sup)
+    }
+
+    /// Visit a [`Table`] element.
+    fn visit_table(&mut self, table: &Table) {
+        visit_table(self, table)
+    }
+
+    /// Visit a [`TableRow`] of a [`Table`].
+    fn visit_table_row(&mut self, row: &TableRow) {
+        visit_table_row(self, row)
+    }
+
+    /// Visit a [`TableCell`] of a [`TableRow`].
+    fn visit_table_cell(&mut self, cell: &TableCell) {
+        visit_table_cell(self, cell)
+    }
+
+    /// Visit a [`Text`] element.
+    fn visit_text(&mut self, _text: &Text) {}
+
+    /// Visit an [`UnderOver`] element.
+    fn visit_underover(&mut self, under_over: &UnderOver) {
+        visit_underover(self, under_over)
+    }
+}
+
+/// Visit the content of the [`MathMl`] element.
+pub fn visit_mathml<V: Visit + ?Sized>(v: &mut V, mathml: &MathMl) {
+    v.visit_elements(mathml.content());
+}
+
+/// Visit each [`Element`] in the list.
+pub fn visit_elements<V: Visit + ?Sized>(v: &mut V, elements: &[Element]) {
+    for element in elements {
+        v.visit_element(element);
+    }
+}
+
+/// Dispatch to the method of the [`Visit`] corresponding to the kind of the [`Element`].
+pub fn visit_element<V: Visit + ?Sized>(v: &mut V, element: &Element) {
+    match element {
+        Element::Action(action) => v.visit_action(action),
+        Element::Annotation(annotation) => v.visit_annotation(annotation),
+        Element::Error(error) => v.visit_error(error),
+        Element::Frac(frac) => v.visit_frac(frac),
+        Element::Ident(ident) => v.visit_ident(ident),
+        Element::Multiscripts(multiscripts) => v.visit_multiscripts(multiscripts),
+        Element::Prescripts(prescripts) => v.visit_prescripts(prescripts),
+        Element::Num(num) => v.visit_num(num),
+        Element::Operator(operator) => v.visit_operator(operator),
+        Element::Padded(padded) => v.visit_padded(padded),
+        Element::Phantom(phantom) => v.visit_phantom(phantom),
+        Element::Radical(radical) => v.visit_radical(radical),
+        Element::Row(row) => v.visit_row(row),
+        Element::Semantics(semantics) => v.visit_semantics(semantics),
+        Element::Space(space) => v.visit_space(space),
+        Element::StrLiteral(str_literal) => v.visit_str_literal(str_literal),
+        Element::Style(style) => v.visit_style(style),
+        Element::SubSup(sub_sup) => v.visit_subsup(sub_sup),
+        Element::Table(table) => v.visit_table(table),
+        Element::Text(text) => v.visit_text(text),
+        Element::UnderOver(under_over) => v.visit_underover(under_over),
+    }
+}
+
+/// Visit the content of the [`Action`] element.
+pub fn visit_action<V: Visit + ?Sized>(v: &mut V, action: &Action) {
+    v.visit_elements(action.content());
+}
+
+/// Visit the nested content of the [`Annotation`] element, if any.
+pub fn visit_annotation<V: Visit + ?Sized>(v: &mut V, annotation: &Annotation) {
+    if let AnnotationContent::Nested(elements) = annotation.content() {
+        v.visit_elements(elements);
+    }
+}
+
+/// Visit the content of the [`Error`] element.
+pub fn visit_error<V: Visit + ?Sized>(v: &mut V, error: &Error) {
+    v.visit_elements(error.content());
+}
+
+/// Visit the numerator and then the denominator of the [`Frac`] element.
+pub fn visit_frac<V: Visit + ?Sized>(v: &mut V, frac: &Frac) {
+    v.visit_elements(frac.num());
+    v.visit_elements(frac.denom());
+}
+
+/// Visit the content of the [`Multiscripts`] element.
+pub fn visit_multiscripts<V: Visit + ?Sized>(v: &mut V, multiscripts: &Multiscripts) {
+    v.visit_elements(multiscripts.content());
+}
+
+/// Visit the children of the [`Padded`] element.
+pub fn visit_padded<V: Visit + ?Sized>(v: &mut V, padded: &Padded) {
+    v.visit_elements(padded.children());
+}
+
+/// Visit the children of the [`Phantom`] element.
+pub fn visit_phantom<V: Visit + ?Sized>(v: &mut V, phantom: &Phantom) {
+    v.visit_elements(phantom.children());
+}
+
+/// Visit the content and then the index of the [`Radical`] element. The index of a square root
+/// is not visited.
+pub fn visit_radical<V: Visit + ?Sized>(v: &mut V, radical: &Radical) {
+    v.visit_elements(radical.content());
+
+    if !radical.is_square() {
+        v.visit_elements(radical.index());
+    }
+}
+
+/// Visit the children of the [`Row`] element.
+pub fn visit_row<V: Visit + ?Sized>(v: &mut V, row: &Row) {
+    v.visit_elements(row.children());
+}
+
+/// Visit the children of the [`Semantics`] element.
+pub fn visit_semantics<V: Visit + ?Sized>(v: &mut V, semantics: &Semantics) {
+    v.visit_elements(semantics.children());
+}
+
+/// Visit the children of the [`Style`] element.
+pub fn visit_style<V: Visit + ?Sized>(v: &mut V, style: &Style) {
+    v.visit_elements(style.children());
+}
+
+/// Visit the base, then the subscript and then the superscript of the [`SubSup`] element.
+pub fn visit_subsup<V: Visit + ?Sized>(v: &mut V, sub_sup: &SubSup) {
+    v.visit_elements(sub_sup.base());
+
+    if let Some(sub) = sub_sup.sub() {
+        v.visit_elements(sub);
+    }
+
+    if let Some(sup) = sub_sup.sup() {
+        v.visit_elements(sup);
+    }
+}
+
+/// Visit each row of the [`Table`] element.
+pub fn visit_table<V: Visit + ?Sized>(v: &mut V, table: &Table) {
+    for row in table.rows() {
+        v.visit_table_row(row);
+    }
+}
+
+/// Visit each cell of the [`TableRow`].
+pub fn visit_table_row<V: Visit + ?Sized>(v: &mut V, row: &TableRow) {
+    for cell in row.cells() {
+        v.visit_table_cell(cell);
+    }
+}
+
+/// Visit the children of the [`TableCell`].
+pub fn visit_table_cell<V: Visit + ?Sized>(v: &mut V, cell: &TableCell) {
+    v.visit_elements(cell.children());
+}
+
+/// Visit the base, then the underscript and then the overscript of the [`UnderOver`] element.
+pub fn visit_underover<V: Visit + ?Sized>(v: &mut V, under_over: &UnderOver) {
+    v.visit_elements(under_over.expr());
+
+    if let Some(under) = under_over.under() {
+        v.visit_elements(under);
+    }
+
+    if let Some(over) = under_over.over() {
+        v.visit_elements(over);
+    }
+}
+
+/// Trait for traversing a tree of MathML elements by mutable reference, which makes it possible
+/// to rewrite elements in place. See the [module](self) documentation for more information.
+pub trait VisitMut {
+    /// Visit a [`MathMl`] element.
+    fn visit_mathml_mut(&mut self, mathml: &mut MathMl) {
+        visit_mathml_mut(self, mathml)
+    }
+
+    /// Visit a list of [`Element`]s. Elements can be added to or removed from the list.
+    fn visit_elements_mut(&mut self, elements: &mut Elements) {
+        visit_elements_mut(self, elements)
+    }
+
+    /// Visit an [`Element`]. The element can be replaced with a different one.
+    fn visit_element_mut(&mut self, element: &mut Element) {
+        visit_element_mut(self, element)
+    }
+
+    /// Visit an [`Action`] element.
+    fn visit_action_mut(&mut self, action: &mut Action) {
+        visit_action_mut(self, action)
+    }
+
+    /// Visit an [`Annotation`] element.
+    fn visit_annotation_mut(&mut self, annotation: &mut Annotation) {
+        visit_annotation_mut(self, annotation)
+    }
+
+    /// Visit an [`Error`] element.
+    fn visit_error_mut(&mut self, error: &mut Error) {
+        visit_error_mut(self, error)
+    }
+
+    /// Visit a [`Frac`] element.
+    fn visit_frac_mut(&mut self, frac: &mut Frac) {
+        visit_frac_mut(self, frac)
+    }
+
+    /// Visit an [`Ident`] element.
+    fn visit_ident_mut(&mut self, _ident: &mut Ident) {}
+
+    /// Visit a [`Multiscripts`] element.
+    fn visit_multiscripts_mut(&mut self, multiscripts: &mut Multiscripts) {
+        visit_multiscripts_mut(self, multiscripts)
+    }
+
+    /// Visit a [`Prescripts`] element.
+    fn visit_prescripts_mut(&mut self, _prescripts: &mut Prescripts) {}
+
+    /// Visit a [`Num`] element.
+    fn visit_num_mut(&mut self, _num: &mut Num) {}
+
+    /// Visit an [`Operator`] element.
+    fn visit_operator_mut(&mut self, _operator: &mut Operator) {}
+
+    /// Visit a [`Padded`] element.
+    fn visit_padded_mut(&mut self, padded: &mut Padded) {
+        visit_padded_mut(self, padded)
+    }
+
+    /// Visit a [`Phantom`] element.
+    fn visit_phantom_mut(&mut self, phantom: &mut Phantom) {
+        visit_phantom_mut(self, phantom)
+    }
+
+    /// Visit a [`Radical`] element.
+    fn visit_radical_mut(&mut self, radical: &mut Radical) {
+        visit_radical_mut(self, radical)
+    }
+
+    /// Visit a [`Row`] element.
+    fn visit_row_mut(&mut self, row: &mut Row) {
+        visit_row_mut(self, row)
+    }
+
+    /// Visit a [`Semantics`] element.
+    fn visit_semantics_mut(&mut self, semantics: &mut Semantics) {
+        visit_semantics_mut(self, semantics)
+    }
+
+    /// Visit a [`Space`] element.
+    fn visit_space_mut(&mut self, _space: &mut Space) {}
+
+    /// Visit a [`StrLiteral`] element.
+    fn visit_str_literal_mut(&mut self, _str_literal: &mut StrLiteral) {}
+
+    /// Visit a [`Style`] element.
+    fn visit_style_mut(&mut self, style: &mut Style) {
+        visit_style_mut(self, style)
+    }
+
+    /// Visit a [`SubSup`] element.
+    fn visit_subsup_mut(&mut self, sub_sup: &mut SubSup) {
+        visit_subsup_mut(self, sub_sup)
+    }
+
+    /// Visit a [`Table`] element.
+    fn visit_table_mut(&mut self, table: &mut Table) {
+        visit_table_mut(self, table)
+    }
+
+    /// Visit a [`TableRow`] of a [`Table`].
+    fn visit_table_row_mut(&mut self, row: &mut TableRow) {
+        visit_table_row_mut(self, row)
+    }
+
+    /// Visit a [`TableCell`] of a [`TableRow`].
+    fn visit_table_cell_mut(&mut self, cell: &mut TableCell) {
+        visit_table_cell_mut(self, cell)
+    }
+
+    /// Visit a [`Text`] element.
+    fn visit_text_mut(&mut self, _text: &mut Text) {}
+
+    /// Visit an [`UnderOver`] element.
+    fn visit_underover_mut(&mut self, under_over: &mut UnderOver) {
+        visit_underover_mut(self, under_over)
+    }
+}
+
+/// Visit the content of the [`MathMl`] element.
+pub fn visit_mathml_mut<V: VisitMut + ?Sized>(v: &mut V, mathml: &mut MathMl) {
+    v.visit_elements_mut(mathml.content_mut());
+}
+
+/// Visit each [`Element`] in the list.
+pub fn visit_elements_mut<V: VisitMut + ?Sized>(v: &mut V, elements: &mut Elements) {
+    for element in elements.iter_mut() {
+        v.visit_element_mut(element);
+    }
+}
+
+/// Dispatch to the method of the [`VisitMut`] corresponding to the kind of the [`Element`].
+pub fn visit_element_mut<V: VisitMut + ?Sized>(v: &mut V, element: &mut Element) {
+    match element {
+        Element::Action(action) => v.visit_action_mut(action),
+        Element::Annotation(annotation) => v.visit_annotation_mut(annotation),
+        Element::Error(error) => v.visit_error_mut(error),
+        Element::Frac(frac) => v.visit_frac_mut(frac),
+        Element::Ident(ident) => v.visit_ident_mut(ident),
+        Element::Multiscripts(multiscripts) => v.visit_multiscripts_mut(multiscripts),
+        Element::Prescripts(prescripts) => v.visit_prescripts_mut(prescripts),
+        Element::Num(num) => v.visit_num_mut(num),
+        Element::Operator(operator) => v.visit_operator_mut(operator),
+        Element::Padded(padded) => v.visit_padded_mut(padded),
+        Element::Phantom(phantom) => v.visit_phantom_mut(phantom),
+        Element::Radical(radical) => v.visit_radical_mut(radical),
+        Element::Row(row) => v.visit_row_mut(row),
+        Element::Semantics(semantics) => v.visit_semantics_mut(semantics),
+        Element::Space(space) => v.visit_space_mut(space),
+        Element::StrLiteral(str_literal) => v.visit_str_literal_mut(str_literal),
+        Element::Style(style) => v.visit_style_mut(style),
+        Element::SubSup(sub_sup) => v.visit_subsup_mut(sub_sup),
+        Element::Table(table) => v.visit_table_mut(table),
+        Element::Text(text) => v.visit_text_mut(text),
+        Element::UnderOver(under_over) => v.visit_underover_mut(under_over),
+    }
+}
+
+/// Visit the content of the [`Action`] element.
+pub fn visit_action_mut<V: VisitMut + ?Sized>(v: &mut V, action: &mut Action) {
+    v.visit_elements_mut(action.content_mut());
+}
+
+/// Visit the nested content of the [`Annotation`] element, if any.
+pub fn visit_annotation_mut<V: VisitMut + ?Sized>(v: &mut V, annotation: &mut Annotation) {
+    if let AnnotationContent::Nested(elements) = annotation.content_mut() {
+        v.visit_elements_mut(elements);
+    }
+}
+
+/// Visit the content of the [`Error`] element.
+pub fn visit_error_mut<V: VisitMut + ?Sized>(v: &mut V, error: &mut Error) {
+    v.visit_elements_mut(error.content_mut());
+}
+
+/// Visit the numerator and then the denominator of the [`Frac`] element.
+pub fn visit_frac_mut<V: VisitMut + ?Sized>(v: &mut V, frac: &mut Frac) {
+    v.visit_elements_mut(frac.num_mut());
+    v.visit_elements_mut(frac.denom_mut());
+}
+
+/// Visit the content of the [`Multiscripts`] element.
+pub fn visit_multiscripts_mut<V: VisitMut + ?Sized>(v: &mut V, multiscripts: &mut Multiscripts) {
+    v.visit_elements_mut(multiscripts.content_mut());
+}
+
+/// Visit the children of the [`Padded`] element.
+pub fn visit_padded_mut<V: VisitMut + ?Sized>(v: &mut V, padded: &mut Padded) {
+    v.visit_elements_mut(padded.children_mut());
+}
+
+/// Visit the children of the [`Phantom`] element.
+pub fn visit_phantom_mut<V: VisitMut + ?Sized>(v: &mut V, phantom: &mut Phantom) {
+    v.visit_elements_mut(phantom.children_mut());
+}
+
+/// Visit the content and then the index of the [`Radical`] element. The index of a square root
+/// is not visited.
+pub fn visit_radical_mut<V: VisitMut + ?Sized>(v: &mut V, radical: &mut Radical) {
+    v.visit_elements_mut(radical.content_mut());
+
+    if !radical.is_square() {
+        v.visit_elements_mut(radical.index_mut());
+    }
+}
+
+/// Visit the children of the [`Row`] element.
+pub fn visit_row_mut<V: VisitMut + ?Sized>(v: &mut V, row: &mut Row) {
+    v.visit_elements_mut(row.children_mut());
+}
+
+/// Visit the children of the [`Semantics`] element.
+pub fn visit_semantics_mut<V: VisitMut + ?Sized>(v: &mut V, semantics: &mut Semantics) {
+    v.visit_elements_mut(semantics.children_mut());
+}
+
+/// Visit the children of the [`Style`] element.
+pub fn visit_style_mut<V: VisitMut + ?Sized>(v: &mut V, style: &mut Style) {
+    v.visit_elements_mut(style.children_mut());
+}
+
+/// Visit the base, then the subscript and then the superscript of the [`SubSup`] element.
+pub fn visit_subsup_mut<V: VisitMut + ?Sized>(v: &mut V, sub_sup: &mut SubSup) {
+    v.visit_elements_mut(sub_sup.base_mut());
+
+    if let Some(sub) = sub_sup.sub_mut() {
+        v.visit_elements_mut(sub);
+    }
+
+    if let Some(sup) = sub_sup.sup_mut() {
+        v.visit_elements_mut(sup);
+    }
+}
+
+/// Visit each row of the [`Table`] element.
+pub fn visit_table_mut<V: VisitMut + ?Sized>(v: &mut V, table: &mut Table) {
+    for row in table.rows_mut() {
+        v.visit_table_row_mut(row);
+    }
+}
+
+/// Visit each cell of the [`TableRow`].
+pub fn visit_table_row_mut<V: VisitMut + ?Sized>(v: &mut V, row: &mut TableRow) {
+    for cell in row.cells_mut() {
+        v.visit_table_cell_mut(cell);
+    }
+}
+
+/// Visit the children of the [`TableCell`].
+pub fn visit_table_cell_mut<V: VisitMut + ?Sized>(v: &mut V, cell: &mut TableCell) {
+    v.visit_elements_mut(cell.children_mut());
+}
+
+/// Visit the base, then the underscript and then the overscript of the [`UnderOver`] element.
+pub fn visit_underover_mut<V: VisitMut + ?Sized>(v: &mut V, under_over: &mut UnderOver) {
+    v.visit_elements_mut(under_over.expr_mut());
+
+    if let Some(under) = under_over.under_mut() {
+        v.visit_elements_mut(under);
+    }
+
+    if let Some(over) = under_over.over_mut() {
+        v.visit_elements_mut(over);
+    }
+}
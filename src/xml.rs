@@ -0,0 +1,411 @@
+//! Minimal XML reader used to parse MathML (and other XML based) markup into a tree of nodes.
+//!
+//! Only the subset of XML needed for math markup is supported: elements, attributes, text,
+//! character and predefined entity references, CDATA sections, comments and processing
+//! instructions. Namespace prefixes are kept as part of the element and attribute names.
+
+use crate::{ParseError, ParseErrorKind};
+
+/// An XML element with its attributes and children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct XmlElement {
+    /// Name of the element, including the namespace prefix if present.
+    pub name: String,
+
+    /// Byte offset of the `<` that opens this element.
+    pub offset: usize,
+
+    /// Attributes in the order they appear in the markup.
+    pub attributes: Vec<XmlAttr>,
+
+    /// Child nodes of the element.
+    pub children: Vec<XmlNode>,
+}
+
+impl XmlElement {
+    /// Name of the element without the namespace prefix.
+    pub fn local_name(&self) -> &str {
+        local_name(&self.name)
+    }
+
+    /// Iterate over all child elements, skipping text nodes.
+    pub fn child_elements(&self) -> impl Iterator<Item = &XmlElement> {
+        self.children.iter().filter_map(|c| match c {
+            XmlNode::Element(el) => Some(el),
+            XmlNode::Text { .. } => None,
+        })
+    }
+}
+
+/// An attribute of an [`XmlElement`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct XmlAttr {
+    /// Name of the attribute, including the namespace prefix if present.
+    pub name: String,
+
+    /// The value of the attribute with all references resolved.
+    pub value: String,
+
+    /// Byte offset of the first character of the attribute name.
+    pub offset: usize,
+}
+
+/// A node in the XML tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum XmlNode {
+    /// An element node.
+    Element(XmlElement),
+
+    /// A text node with all references resolved.
+    Text {
+        /// The text content.
+        text: String,
+
+        /// Byte offset of the first character of the text.
+        offset: usize,
+    },
+}
+
+/// Return the given digits of a character reference if it is not empty and contains only digits
+/// matching the predicate.
+fn digits(digits: &str, is_digit: impl Fn(&char) -> bool) -> Option<&str> {
+    (!digits.is_empty() && digits.chars().all(|c| is_digit(&c))).then_some(digits)
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit_once(':').map_or(name, |(_, local)| local)
+}
+
+/// Maximum nesting depth of elements, deeper documents are rejected with
+/// [`ParseErrorKind::NestingTooDeep`].
+pub(crate) const MAX_DEPTH: usize = 256;
+
+/// Parse the given input as an XML document containing exactly one root element.
+pub(crate) fn parse(input: &str) -> Result<XmlElement, ParseError> {
+    let mut reader = Reader {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+
+    reader.skip_misc()?;
+    let root = reader.element()?;
+    reader.skip_misc()?;
+
+    if reader.pos < input.len() {
+        return Err(reader.error(ParseErrorKind::TrailingContent));
+    }
+
+    Ok(root)
+}
+
+struct Reader<'a> {
+    input: &'a str,
+    pos: usize,
+    /// Number of currently open elements.
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.input, self.pos)
+    }
+
+    fn error_at(&self, kind: ParseErrorKind, offset: usize) -> ParseError {
+        ParseError::new(kind, self.input, offset)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.rest().starts_with(s)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn expect(&mut self, s: &'static str) -> Result<(), ParseError> {
+        if self.starts_with(s) {
+            self.pos += s.len();
+            Ok(())
+        } else if self.pos >= self.input.len() {
+            Err(self.error(ParseErrorKind::UnexpectedEof))
+        } else {
+            Err(self.error(ParseErrorKind::Expected(s)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skip everything until (and including) the given delimiter.
+    fn skip_until(&mut self, delim: &'static str) -> Result<(), ParseError> {
+        match self.rest().find(delim) {
+            Some(idx) => {
+                self.pos += idx + delim.len();
+                Ok(())
+            }
+            None => {
+                self.pos = self.input.len();
+                Err(self.error(ParseErrorKind::UnexpectedEof))
+            }
+        }
+    }
+
+    /// Skip whitespace, comments, processing instructions and document type declarations.
+    fn skip_misc(&mut self) -> Result<(), ParseError> {
+        loop {
+            self.skip_whitespace();
+
+            if self.starts_with("<?") {
+                self.skip_until("?>")?;
+            } else if self.starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else if self.starts_with("<!DOCTYPE") {
+                self.skip_until(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+
+        while self.peek().is_some_and(is_name_char) {
+            self.bump();
+        }
+
+        if start == self.pos {
+            return match self.peek() {
+                Some(c) => Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+                None => Err(self.error(ParseErrorKind::UnexpectedEof)),
+            };
+        }
+
+        Ok(self.input[start..self.pos].to_string())
+    }
+
+    fn element(&mut self) -> Result<XmlElement, ParseError> {
+        let offset = self.pos;
+
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error(ParseErrorKind::NestingTooDeep(MAX_DEPTH)));
+        }
+
+        self.expect("<")?;
+        let name = self.name()?;
+
+        let mut attributes: Vec<XmlAttr> = Vec::new();
+
+        loop {
+            let had_whitespace = self.peek().is_some_and(is_whitespace);
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some('/') => {
+                    self.expect("/>")?;
+                    return Ok(XmlElement {
+                        name,
+                        offset,
+                        attributes,
+                        children: Vec::new(),
+                    });
+                }
+                Some('>') => {
+                    self.bump();
+                    break;
+                }
+                Some(c) if is_name_char(c) && had_whitespace => {
+                    let attr = self.attribute()?;
+
+                    if attributes.iter().any(|a| a.name == attr.name) {
+                        return Err(self
+                            .error_at(ParseErrorKind::DuplicateAttribute(attr.name), attr.offset));
+                    }
+
+                    attributes.push(attr);
+                }
+                Some(c) => return Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+                None => return Err(self.error(ParseErrorKind::UnexpectedEof)),
+            }
+        }
+
+        self.depth += 1;
+        let children = self.content()?;
+        self.depth -= 1;
+
+        let close_offset = self.pos;
+        self.expect("</")?;
+        let close_name = self.name()?;
+
+        if close_name != name {
+            return Err(self.error_at(
+                ParseErrorKind::MismatchedClosingTag {
+                    expected: name,
+                    found: close_name,
+                },
+                close_offset,
+            ));
+        }
+
+        self.skip_whitespace();
+        self.expect(">")?;
+
+        Ok(XmlElement {
+            name,
+            offset,
+            attributes,
+            children,
+        })
+    }
+
+    fn attribute(&mut self) -> Result<XmlAttr, ParseError> {
+        let offset = self.pos;
+        let name = self.name()?;
+
+        self.skip_whitespace();
+        self.expect("=")?;
+        self.skip_whitespace();
+
+        let quote = match self.peek() {
+            Some(q @ ('"' | '\'')) => q,
+            Some(_) => return Err(self.error(ParseErrorKind::Expected("\""))),
+            None => return Err(self.error(ParseErrorKind::UnexpectedEof)),
+        };
+        self.bump();
+
+        let mut value = String::new();
+
+        loop {
+            match self.peek() {
+                Some(c) if c == quote => {
+                    self.bump();
+                    break;
+                }
+                Some('&') => value.push_str(&self.reference()?),
+                Some('<') => return Err(self.error(ParseErrorKind::UnexpectedChar('<'))),
+                Some(c) => {
+                    self.bump();
+                    value.push(c);
+                }
+                None => return Err(self.error(ParseErrorKind::UnexpectedEof)),
+            }
+        }
+
+        Ok(XmlAttr {
+            name,
+            value,
+            offset,
+        })
+    }
+
+    /// Parse the content of an element up to (but excluding) its closing tag.
+    fn content(&mut self) -> Result<Vec<XmlNode>, ParseError> {
+        let mut children = Vec::new();
+        let mut text = String::new();
+        let mut text_offset = self.pos;
+
+        let flush = |children: &mut Vec<XmlNode>, text: &mut String, offset: usize| {
+            if !text.is_empty() {
+                children.push(XmlNode::Text {
+                    text: std::mem::take(text),
+                    offset,
+                });
+            }
+        };
+
+        loop {
+            if text.is_empty() {
+                text_offset = self.pos;
+            }
+
+            if self.starts_with("</") {
+                flush(&mut children, &mut text, text_offset);
+                return Ok(children);
+            } else if self.starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else if self.starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                let start = self.pos;
+                self.skip_until("]]>")?;
+                text.push_str(&self.input[start..self.pos - "]]>".len()]);
+            } else if self.starts_with("<?") {
+                self.skip_until("?>")?;
+            } else if self.starts_with("<") {
+                flush(&mut children, &mut text, text_offset);
+                children.push(XmlNode::Element(self.element()?));
+            } else if self.starts_with("&") {
+                text.push_str(&self.reference()?);
+            } else {
+                match self.bump() {
+                    Some(c) => text.push(c),
+                    None => return Err(self.error(ParseErrorKind::UnexpectedEof)),
+                }
+            }
+        }
+    }
+
+    /// Parse a character or entity reference, e.g. `&amp;` or `&#x3C;`.
+    fn reference(&mut self) -> Result<String, ParseError> {
+        let offset = self.pos;
+        self.expect("&")?;
+
+        let end = match self.rest().find(';') {
+            Some(end) => end,
+            None => return Err(self.error_at(ParseErrorKind::Expected(";"), offset)),
+        };
+
+        let reference = &self.rest()[..end];
+        self.pos += end + 1;
+
+        let unknown = || ParseErrorKind::UnknownReference(reference.to_string());
+
+        let resolved = match reference {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                // `from_str_radix` also accepts a leading sign, which XML does not allow
+                let code = if let Some(hex) = reference
+                    .strip_prefix("#x")
+                    .or_else(|| reference.strip_prefix("#X"))
+                {
+                    digits(hex, char::is_ascii_hexdigit)
+                        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                } else if let Some(dec) = reference.strip_prefix('#') {
+                    digits(dec, char::is_ascii_digit).and_then(|dec| dec.parse::<u32>().ok())
+                } else {
+                    None
+                };
+
+                code.and_then(char::from_u32)
+                    .ok_or_else(|| self.error_at(unknown(), offset))?
+            }
+        };
+
+        Ok(resolved.to_string())
+    }
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_' | ':' | '.')
+}
@@ -0,0 +1,318 @@
+use alemat::{
+    asciimath::{self, AsciiMathErrorKind},
+    attributes::{Attribute, MathVariant},
+    children,
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver, UnderOverAttr},
+        Element, Frac, Ident, Num, Operator, Text,
+    },
+    MathMl,
+};
+
+fn parse(input: &str) -> Vec<Element> {
+    asciimath::parse_elements(input)
+        .expect("Failed to parse AsciiMath.")
+        .into_inner()
+}
+
+#[test]
+fn symbols() {
+    assert_eq!(
+        parse("x+-12.5 != alpha oo"),
+        children![
+            Ident::from("x"),
+            Operator::plus_minus(),
+            Num::from("12.5"),
+            Operator::not_eq(),
+            Ident::alpha(),
+            Ident::infinity(),
+        ]
+        .to_vec()
+    );
+
+    assert_eq!(
+        parse("a -> b <=> c"),
+        children![
+            Ident::from("a"),
+            Operator::rarrow(),
+            Ident::from("b"),
+            Operator::iff(),
+            Ident::from("c"),
+        ]
+        .to_vec()
+    );
+}
+
+#[test]
+fn fractions() {
+    assert_eq!(
+        parse("(a+b)/2"),
+        vec![Element::from(
+            Frac::builder()
+                .num(children![
+                    Ident::from("a"),
+                    Operator::plus(),
+                    Ident::from("b")
+                ])
+                .denom(Num::from(2))
+                .build()
+        )]
+    );
+
+    assert_eq!(
+        parse("x^2/3"),
+        vec![Element::from(
+            Frac::builder()
+                .num(
+                    SubSup::builder()
+                        .base(Ident::from("x"))
+                        .supscript(Num::from(2))
+                        .build()
+                )
+                .denom(Num::from(3))
+                .build()
+        )]
+    );
+
+    assert_eq!(
+        parse("frac a b"),
+        vec![Element::from(
+            Frac::builder()
+                .num(Ident::from("a"))
+                .denom(Ident::from("b"))
+                .build()
+        )]
+    );
+}
+
+#[test]
+fn scripts() {
+    assert_eq!(
+        parse("sum_(i=1)^n i^2"),
+        children![
+            UnderOver::builder()
+                .expr(Operator::sum())
+                .under(children![Ident::from("i"), Operator::eq(), Num::from(1)])
+                .over(Ident::from("n"))
+                .build(),
+            SubSup::builder()
+                .base(Ident::from("i"))
+                .supscript(Num::from(2))
+                .build(),
+        ]
+        .to_vec()
+    );
+
+    assert_eq!(
+        parse("int_0^1 x_i"),
+        children![
+            SubSup::builder()
+                .base(Operator::integral())
+                .subscript(Num::from(0))
+                .supscript(Num::from(1))
+                .build(),
+            SubSup::builder()
+                .base(Ident::from("x"))
+                .subscript(Ident::from("i"))
+                .build(),
+        ]
+        .to_vec()
+    );
+
+    assert_eq!(
+        parse("lim_(x->0) sin x"),
+        children![
+            UnderOver::builder()
+                .expr(Ident::from("lim"))
+                .under(children![
+                    Ident::from("x"),
+                    Operator::rarrow(),
+                    Num::from(0)
+                ])
+                .build(),
+            Operator::from("\u{2061}"),
+            Ident::from("sin"),
+            Operator::from("\u{2061}"),
+            Ident::from("x"),
+        ]
+        .to_vec()
+    );
+}
+
+#[test]
+fn commands() {
+    assert_eq!(
+        parse("sqrt(x) root(3)(y)"),
+        children![
+            Radical::builder()
+                .index(Num::from(2))
+                .content(Ident::from("x"))
+                .build(),
+            Radical::builder()
+                .index(Num::from(3))
+                .content(Ident::from("y"))
+                .build(),
+        ]
+        .to_vec()
+    );
+
+    assert_eq!(
+        parse("hat x abs(y)"),
+        children![
+            UnderOver::builder()
+                .expr(Ident::from("x"))
+                .over(Operator::hat())
+                .attr([UnderOverAttr::AccentOver])
+                .build(),
+            Row::from([
+                Element::from(Operator::vert_bar()),
+                Ident::from("y").into(),
+                Operator::vert_bar().into(),
+            ]),
+        ]
+        .to_vec()
+    );
+
+    assert_eq!(
+        parse(r#"bbb R text(if) "and""#),
+        children![
+            Ident::builder()
+                .ident("R")
+                .attr([Attribute::MathVariant(MathVariant::DoubleStruck)])
+                .build(),
+            Text::from("if"),
+            Text::from("and"),
+        ]
+        .to_vec()
+    );
+}
+
+#[test]
+fn brackets() {
+    assert_eq!(
+        parse("(x, y]"),
+        vec![Element::from(Row::from([
+            Element::from(Operator::lparens()),
+            Ident::from("x").into(),
+            Operator::from(",").into(),
+            Ident::from("y").into(),
+            Operator::rbracket().into(),
+        ]))]
+    );
+
+    assert_eq!(
+        parse("{:x:}"),
+        vec![Element::from(Row::from([Ident::from("x")]))]
+    );
+
+    // unmatched brackets are kept as is
+    assert_eq!(
+        parse("x)"),
+        children![Ident::from("x"), Operator::rparens()].to_vec()
+    );
+}
+
+#[test]
+fn matrices() {
+    assert_eq!(
+        parse("[[a,b],[c,d]]"),
+        vec![Element::from(Row::from([
+            Element::from(Operator::lbracket()),
+            alemat::table![
+                [Ident::from("a"), Ident::from("b")],
+                [Ident::from("c"), Ident::from("d")]
+            ]
+            .into(),
+            Operator::rbracket().into(),
+        ]))]
+    );
+
+    // rows with different number of columns are not a matrix
+    assert_eq!(
+        parse("((a,b),(c))"),
+        vec![Element::from(Row::from([
+            Element::from(Operator::lparens()),
+            Row::from([
+                Element::from(Operator::lparens()),
+                Ident::from("a").into(),
+                Operator::from(",").into(),
+                Ident::from("b").into(),
+                Operator::rparens().into(),
+            ])
+            .into(),
+            Operator::from(",").into(),
+            Row::from([
+                Element::from(Operator::lparens()),
+                Ident::from("c").into(),
+                Operator::rparens().into(),
+            ])
+            .into(),
+            Operator::rparens().into(),
+        ]))]
+    );
+}
+
+#[test]
+fn math() {
+    assert_eq!(
+        asciimath::parse("x^2"),
+        Ok(MathMl::with_content([SubSup::builder()
+            .base(Ident::from("x"))
+            .supscript(Num::from(2))
+            .build()]))
+    );
+}
+
+fn error(input: &str) -> (AsciiMathErrorKind, std::ops::Range<usize>) {
+    let err = asciimath::parse(input).expect_err("Parsing should fail.");
+    (err.kind().clone(), err.span())
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        error("1 + sqrt"),
+        (AsciiMathErrorKind::MissingArgument("sqrt".into()), 4..8)
+    );
+
+    assert_eq!(
+        error("x_"),
+        (AsciiMathErrorKind::MissingArgument("_".into()), 1..2)
+    );
+
+    assert_eq!(
+        error("(a/)"),
+        (AsciiMathErrorKind::MissingArgument("/".into()), 2..3)
+    );
+
+    assert_eq!(error(r#"x "abc"#), (AsciiMathErrorKind::UnclosedText, 2..3));
+
+    assert_eq!(
+        error("text(abc"),
+        (AsciiMathErrorKind::UnclosedBracket, 4..5)
+    );
+}
+
+#[test]
+fn nesting_depth() {
+    let brackets = |depth: usize| format!("{}x{}", "(".repeat(depth), ")".repeat(depth));
+
+    assert!(asciimath::parse(&brackets(255)).is_ok());
+
+    assert_eq!(
+        error(&brackets(256)),
+        (AsciiMathErrorKind::NestingTooDeep(256), 256..257)
+    );
+
+    for input in [
+        "(".repeat(200_000),
+        "sqrt ".repeat(200_000),
+        "frac x ".repeat(200_000),
+        "x^(".repeat(200_000),
+    ] {
+        let (kind, _) = error(&input);
+        assert_eq!(kind, AsciiMathErrorKind::NestingTooDeep(256));
+    }
+}
@@ -0,0 +1,316 @@
+use alemat::{
+    attributes::{Attribute, MathVariant},
+    braille::{BrailleCode, BrailleError, BrailleFormatter},
+    children,
+    elements::{
+        radicals::Radical,
+        scripted::{SubSup, UnderOver},
+        Frac, Ident, Num, Operator, Text,
+    },
+    table, Element, MathMl,
+};
+
+fn nemeth(math: &MathMl) -> String {
+    math.render_with(&mut BrailleFormatter::default()).unwrap()
+}
+
+fn ueb(math: &MathMl) -> String {
+    math.render_with(&mut BrailleFormatter::new(BrailleCode::Ueb))
+        .unwrap()
+}
+
+fn frac(num: Num, denom: impl Into<Element>) -> Frac {
+    Frac::builder().num(num).denom([denom.into()]).build()
+}
+
+#[test]
+fn numbers_and_operators() {
+    let equation = MathMl::with_content(children![Ident::from("x"), Operator::eq(), Num::from(2),]);
+
+    assert_eq!(nemeth(&equation), "⠭⠀⠨⠅⠀⠼⠆");
+    assert_eq!(ueb(&equation), "⠭⠀⠐⠶⠀⠼⠃");
+
+    let inequality = MathMl::with_content(children![
+        Operator::minus(),
+        Num::from("3.5"),
+        Operator::le(),
+        Num::from(2),
+        Ident::from("a"),
+        Operator::plus_minus(),
+        Ident::from("π"),
+    ]);
+
+    assert_eq!(nemeth(&inequality), "⠤⠼⠒⠨⠢⠀⠐⠅⠱⠀⠼⠆⠁⠬⠤⠨⠏");
+    assert_eq!(ueb(&inequality), "⠐⠤⠼⠉⠲⠑⠀⠸⠈⠣⠀⠼⠃⠰⠁⠸⠖⠨⠏");
+
+    let parens = MathMl::with_content(children![
+        Operator::lparens(),
+        Ident::from("a"),
+        Operator::plus(),
+        Ident::from("b"),
+        Operator::rparens(),
+    ]);
+
+    assert_eq!(nemeth(&parens), "⠷⠁⠬⠃⠾");
+    assert_eq!(ueb(&parens), "⠐⠣⠁⠐⠖⠃⠐⠜");
+}
+
+#[test]
+fn fractions() {
+    let simple = MathMl::with_content(children![frac(Num::from(1), Num::from(2))]);
+
+    assert_eq!(nemeth(&simple), "⠹⠂⠌⠆⠼");
+    assert_eq!(ueb(&simple), "⠼⠁⠌⠃");
+
+    let letters = MathMl::with_content(children![Frac::builder()
+        .num(Ident::from("a"))
+        .denom(Ident::from("b"))
+        .build()]);
+
+    assert_eq!(nemeth(&letters), "⠹⠁⠌⠃⠼");
+    assert_eq!(ueb(&letters), "⠷⠁⠨⠌⠃⠾");
+
+    let complex = MathMl::with_content(children![Frac::builder()
+        .num(frac(Num::from(1), Ident::from("x")))
+        .denom(Num::from(2))
+        .build()]);
+
+    assert_eq!(nemeth(&complex), "⠠⠹⠹⠂⠌⠭⠼⠠⠌⠆⠠⠼");
+    assert_eq!(ueb(&complex), "⠠⠷⠷⠼⠁⠨⠌⠭⠾⠠⠨⠌⠼⠃⠠⠾");
+}
+
+#[test]
+fn radicals() {
+    let square = MathMl::with_content(children![Radical::builder()
+        .index(Num::from(2))
+        .content(Ident::from("x"))
+        .build()]);
+
+    assert_eq!(nemeth(&square), "⠜⠭⠻");
+    assert_eq!(ueb(&square), "⠩⠭⠬");
+
+    let cube = MathMl::with_content(children![Radical::builder()
+        .index(Num::from(3))
+        .content(Ident::from("x"))
+        .build()]);
+
+    assert_eq!(nemeth(&cube), "⠣⠒⠜⠭⠻");
+    assert_eq!(ueb(&cube), "⠩⠔⠼⠉⠭⠬");
+
+    let nested = MathMl::with_content(children![Radical::builder()
+        .index(Num::from(2))
+        .content(children![
+            Ident::from("x"),
+            Operator::plus(),
+            Radical::builder()
+                .index(Num::from(2))
+                .content(Ident::from("y"))
+                .build(),
+        ])
+        .build()]);
+
+    assert_eq!(nemeth(&nested), "⠜⠭⠬⠨⠜⠽⠨⠻⠻");
+    assert_eq!(ueb(&nested), "⠩⠭⠐⠖⠩⠽⠬⠬");
+}
+
+#[test]
+fn scripts() {
+    let power = MathMl::with_content(children![
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .supscript(Num::from(2))
+            .build(),
+        Operator::plus(),
+        Num::from(1),
+    ]);
+
+    assert_eq!(nemeth(&power), "⠭⠘⠆⠐⠬⠂");
+    assert_eq!(ueb(&power), "⠭⠔⠼⠃⠐⠖⠼⠁");
+
+    let index = MathMl::with_content(children![SubSup::builder()
+        .base(Ident::from("x"))
+        .subscript(Num::from(1))
+        .build()]);
+
+    assert_eq!(nemeth(&index), "⠭⠂");
+    assert_eq!(ueb(&index), "⠭⠢⠼⠁");
+
+    let both = MathMl::with_content(children![SubSup::builder()
+        .base(Ident::from("a"))
+        .subscript(Ident::from("i"))
+        .supscript(Num::from(2))
+        .build()]);
+
+    assert_eq!(nemeth(&both), "⠁⠰⠊⠘⠆");
+    assert_eq!(ueb(&both), "⠁⠢⠊⠔⠼⠃");
+
+    let nested = MathMl::with_content(children![SubSup::builder()
+        .base(Ident::from("e"))
+        .supscript(children![
+            SubSup::builder()
+                .base(Ident::from("x"))
+                .supscript(Num::from(2))
+                .build(),
+            Operator::plus(),
+            Num::from(1),
+        ])
+        .build()]);
+
+    assert_eq!(nemeth(&nested), "⠑⠘⠭⠘⠘⠆⠘⠬⠂");
+    assert_eq!(ueb(&nested), "⠑⠔⠣⠭⠔⠼⠃⠐⠖⠼⠁⠜");
+
+    let sum = MathMl::with_content(children![
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(children![Ident::from("i"), Operator::eq(), Num::from(1)])
+            .over(Ident::from("n"))
+            .build(),
+        Ident::from("i"),
+    ]);
+
+    assert_eq!(nemeth(&sum), "⠐⠨⠠⠎⠩⠊⠀⠨⠅⠀⠼⠂⠣⠝⠻⠊");
+    assert_eq!(ueb(&sum), "⠠⠨⠎⠨⠢⠣⠊⠀⠐⠶⠀⠼⠁⠜⠨⠔⠝⠊");
+}
+
+#[test]
+fn matrices() {
+    let matrix = MathMl::with_content(children![
+        Operator::lparens(),
+        table![
+            [Num::from(1), Num::from(0)],
+            [Ident::from("a"), Num::from(12)]
+        ],
+        Operator::rparens(),
+    ]);
+
+    assert_eq!(nemeth(&matrix), "⠠⠷⠂⠀⠀⠼⠴⠀⠠⠾\n⠠⠷⠁⠀⠀⠼⠂⠆⠠⠾");
+    assert_eq!(ueb(&matrix), "⠐⠣⠼⠁⠀⠀⠼⠚⠀⠐⠜\n⠐⠣⠁⠀⠀⠀⠼⠁⠃⠐⠜");
+}
+
+#[test]
+fn text() {
+    let text = MathMl::with_content(children![Text::from("50% & #1")]);
+
+    assert_eq!(nemeth(&text), "⠼⠢⠴⠈⠴⠀⠸⠯⠀⠨⠼⠂");
+    assert_eq!(ueb(&text), "⠼⠑⠚⠨⠴⠀⠈⠯⠀⠸⠹⠼⠁");
+
+    let sentence = MathMl::with_content(children![Text::from("a = b, c.")]);
+
+    assert_eq!(nemeth(&sentence), "⠁⠀⠨⠅⠀⠃⠠⠀⠉⠸⠲");
+    assert_eq!(ueb(&sentence), "⠁⠀⠐⠶⠀⠃⠂⠀⠉⠲");
+
+    let unsupported = MathMl::with_content(children![Text::from("→ ☺")]);
+
+    assert_eq!(
+        unsupported.render_with(&mut BrailleFormatter::default()),
+        Err(BrailleError::UnsupportedChar('☺'))
+    );
+}
+
+#[test]
+fn variants() {
+    let variant = |ident: &str, variant: MathVariant| {
+        Ident::builder()
+            .ident(ident)
+            .attr([Attribute::MathVariant(variant)])
+            .build()
+    };
+
+    let letters = MathMl::with_content(children![
+        variant("x", MathVariant::Bold),
+        variant("g", MathVariant::Fraktur),
+        variant("α", MathVariant::Bold),
+        Num::from("12").with_attr([Attribute::MathVariant(MathVariant::Bold)]),
+    ]);
+
+    assert_eq!(nemeth(&letters), "⠸⠰⠭⠸⠛⠸⠨⠁⠸⠼⠂⠆");
+    assert_eq!(
+        letters.render_with(&mut BrailleFormatter::new(BrailleCode::Ueb)),
+        Err(BrailleError::UnsupportedVariant(MathVariant::Fraktur))
+    );
+
+    let bold = MathMl::with_content(children![
+        variant("R", MathVariant::DoubleStruck),
+        variant("v", MathVariant::Bold),
+    ]);
+
+    assert_eq!(nemeth(&bold), "⠸⠰⠠⠗⠸⠰⠧");
+    assert_eq!(ueb(&bold), "⠘⠆⠠⠗⠘⠆⠧");
+
+    let monospace = MathMl::with_content(children![variant("x", MathVariant::Monospace)]);
+
+    assert_eq!(
+        monospace.render_with(&mut BrailleFormatter::default()),
+        Err(BrailleError::UnsupportedVariant(MathVariant::Monospace))
+    );
+}
+
+#[test]
+fn accents() {
+    let accent = |op: &str| {
+        MathMl::with_content(children![UnderOver::builder()
+            .expr(Ident::from("x"))
+            .over(Operator::from(op))
+            .build()])
+    };
+
+    assert_eq!(nemeth(&accent("^")), "⠐⠭⠣⠸⠣⠻");
+    assert_eq!(ueb(&accent("^")), "⠭⠨⠔⠈⠢");
+
+    assert_eq!(nemeth(&accent("˙")), "⠐⠭⠣⠡⠻");
+    assert_eq!(nemeth(&accent("¨")), "⠐⠭⠣⠡⠡⠻");
+    assert_eq!(nemeth(&accent("¯")), "⠐⠭⠣⠱⠻");
+
+    assert_eq!(
+        accent("ˇ").render_with(&mut BrailleFormatter::default()),
+        Err(BrailleError::UnsupportedChar('ˇ'))
+    );
+    assert_eq!(
+        accent("˙").render_with(&mut BrailleFormatter::new(BrailleCode::Ueb)),
+        Err(BrailleError::UnsupportedChar('˙'))
+    );
+}
+
+#[test]
+fn braces() {
+    let brace = |brace: &str| {
+        UnderOver::builder()
+            .expr(children![
+                Ident::from("a"),
+                Operator::plus(),
+                Ident::from("b")
+            ])
+            .under(Operator::from(brace))
+            .build()
+    };
+
+    let under = MathMl::with_content(children![UnderOver::builder()
+        .expr(brace("⏟"))
+        .under(Ident::from("n"))
+        .build()]);
+
+    assert_eq!(nemeth(&under), "⠐⠁⠬⠃⠩⠝⠻");
+    assert_eq!(ueb(&under), "⠣⠁⠐⠖⠃⠜⠨⠢⠝");
+
+    let over = MathMl::with_content(children![UnderOver::builder()
+        .expr(
+            UnderOver::builder()
+                .expr(children![
+                    Ident::from("a"),
+                    Operator::plus(),
+                    Ident::from("b")
+                ])
+                .over(Operator::from("⏞"))
+                .build()
+        )
+        .over(Ident::from("n"))
+        .build()]);
+
+    assert_eq!(nemeth(&over), "⠐⠁⠬⠃⠣⠝⠻");
+    assert_eq!(ueb(&over), "⠣⠁⠐⠖⠃⠜⠨⠔⠝");
+
+    let unlabelled = MathMl::with_content(children![brace("⏟")]);
+
+    assert_eq!(nemeth(&unlabelled), "⠁⠬⠃");
+    assert_eq!(ueb(&unlabelled), "⠁⠐⠖⠃");
+}
@@ -0,0 +1,191 @@
+use alemat::{
+    attributes::{Attribute, Color, NamedColor},
+    elements::Ident,
+    BufMathMlWriter, MathMl, Writer,
+};
+
+#[test]
+fn parse_named() {
+    assert_eq!("red".parse(), Ok(Color::named("red").unwrap()));
+    assert_eq!(
+        "RebeccaPurple".parse(),
+        Ok(Color::named("rebeccapurple").unwrap())
+    );
+    assert_eq!("currentColor".parse(), Ok(Color::CurrentColor));
+    assert_eq!("transparent".parse(), Ok(Color::Transparent));
+
+    let named = NamedColor::from_name("Gold").unwrap();
+    assert_eq!(named.name(), "gold");
+    assert_eq!(named.rgb(), [0xff, 0xd7, 0x00]);
+    assert_eq!(NamedColor::from_name("notacolor"), None);
+}
+
+#[test]
+fn parse_hex() {
+    assert_eq!("#ff8800".parse(), Ok(Color::hex(0xff8800)));
+    assert_eq!("#F80".parse(), Ok(Color::hex(0xff8800)));
+    assert_eq!(
+        "#f808".parse(),
+        Ok(Color::Hex {
+            red: 0xff,
+            green: 0x88,
+            blue: 0x00,
+            alpha: Some(0x88),
+        })
+    );
+    assert_eq!(
+        "#ff880080".parse(),
+        Ok(Color::Hex {
+            red: 0xff,
+            green: 0x88,
+            blue: 0x00,
+            alpha: Some(0x80),
+        })
+    );
+}
+
+#[test]
+fn parse_functions() {
+    assert_eq!("rgb(255, 136, 0)".parse(), Ok(Color::rgb(255, 136, 0)));
+    assert_eq!("rgb(255 136 0)".parse(), Ok(Color::rgb(255, 136, 0)));
+    assert_eq!("rgb(100%, 0%, 0%)".parse(), Ok(Color::rgb(255, 0, 0)));
+    assert_eq!("rgba(0, 0, 0, 0.5)".parse(), Ok(Color::rgba(0, 0, 0, 0.5)));
+    assert_eq!("rgb(0 0 0 / 50%)".parse(), Ok(Color::rgba(0, 0, 0, 0.5)));
+    assert_eq!(
+        "hsl(120, 100%, 50%)".parse(),
+        Ok(Color::hsl(120.0, 100.0, 50.0))
+    );
+    assert_eq!(
+        "hsl(120deg 100% 50%)".parse(),
+        Ok(Color::hsl(120.0, 100.0, 50.0))
+    );
+    assert_eq!(
+        "hsla(120, 100%, 50%, 0.25)".parse(),
+        Ok(Color::Hsl {
+            hue: 120.0,
+            saturation: 100.0,
+            lightness: 50.0,
+            alpha: Some(0.25),
+        })
+    );
+}
+
+#[test]
+fn parse_invalid() {
+    for invalid in [
+        "",
+        "#",
+        "#ff000",
+        "#gggggg",
+        "ff0000",
+        "notacolor",
+        "rgb(1, 2)",
+        "rgb(256, 0, 0)",
+        "rgb(0, 0, 0, 2)",
+        "rgb(0, 0, 0",
+        "hsl(120, 100, 50)",
+        "cmyk(0, 0, 0, 0)",
+        " red",
+    ] {
+        assert!(invalid.parse::<Color>().is_err(), "{invalid:?} parsed");
+    }
+
+    let err = "#ff000".parse::<Color>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid color `#ff000`");
+}
+
+#[test]
+fn display() {
+    assert_eq!(Color::named("Red").unwrap().to_string(), "red");
+    assert_eq!("#F80".parse::<Color>().unwrap().to_string(), "#ff8800");
+    assert_eq!(Color::rgb(1, 2, 3).to_string(), "rgb(1, 2, 3)");
+    assert_eq!(Color::rgba(1, 2, 3, 0.5).to_string(), "rgba(1, 2, 3, 0.5)");
+    assert_eq!(
+        Color::hsl(120.0, 100.0, 50.0).to_string(),
+        "hsl(120, 100%, 50%)"
+    );
+    assert_eq!(Color::CurrentColor.to_string(), "currentColor");
+}
+
+#[test]
+fn to_rgba() {
+    let green = Color::hsl(120.0, 100.0, 50.0).to_rgba().unwrap();
+    assert_eq!((green.red, green.green, green.blue), (0.0, 1.0, 0.0));
+
+    let red = Color::named("red").unwrap().to_rgba().unwrap();
+    assert_eq!(
+        (red.red, red.green, red.blue, red.alpha),
+        (1.0, 0.0, 0.0, 1.0)
+    );
+
+    assert_eq!(Color::CurrentColor.to_rgba(), None);
+    assert_eq!(Color::from("var(--color)").to_rgba(), None);
+}
+
+#[test]
+fn contrast_ratio() {
+    let black = Color::named("black").unwrap();
+    let white = Color::hex(0xffffff);
+
+    assert_eq!(black.contrast_ratio(&white), Some(21.0));
+    assert_eq!(white.contrast_ratio(&black), Some(21.0));
+    assert_eq!(white.contrast_ratio(&white), Some(1.0));
+
+    let ratio = Color::hex(0x777777).contrast_ratio(&white).unwrap();
+    assert!((ratio - 4.48).abs() < 0.01, "{ratio}");
+
+    // fully transparent text is invisible
+    let ratio = Color::rgba(0, 0, 0, 0.0).contrast_ratio(&white);
+    assert_eq!(ratio, Some(1.0));
+
+    assert_eq!(black.contrast_ratio(&Color::CurrentColor), None);
+}
+
+#[test]
+fn lenient_conversion() {
+    assert_eq!(Color::from("red"), Color::named("red").unwrap());
+    assert_eq!(
+        Color::from(String::from("var(--x)")),
+        Color::Other("var(--x)".to_string())
+    );
+}
+
+#[test]
+fn write_colors() {
+    let ident = Ident::builder()
+        .ident("x")
+        .attr([
+            Attribute::MathColor(Color::rgb(255, 0, 0).into()),
+            Attribute::MathBackground("<bg>".into()),
+        ])
+        .build();
+
+    let mut writer = BufMathMlWriter::default();
+    writer.write_ident(&ident).unwrap();
+
+    assert_eq!(
+        writer.finish(),
+        r#"<mi mathcolor="rgb(255, 0, 0)" mathbackground="&lt;bg&gt;">x</mi>"#
+    );
+}
+
+#[test]
+fn validate_colors() {
+    let math = MathMl::parse(concat!(
+        r##"<math mathcolor="notacolor">"##,
+        r##"<mi mathbackground="#12">x</mi>"##,
+        r##"<mi mathcolor="RebeccaPurple" mathbackground="rgb(0 0 0 / 50%)">y</mi>"##,
+        "</math>"
+    ))
+    .unwrap();
+
+    let diagnostics: Vec<_> = math.validate().iter().map(ToString::to_string).collect();
+
+    assert_eq!(
+        diagnostics,
+        [
+            "error: invalid color `notacolor` of attribute `mathcolor` at /math[0]",
+            "error: invalid color `#12` of attribute `mathbackground` at /math[0]/mi[0]",
+        ]
+    );
+}
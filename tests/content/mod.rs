@@ -0,0 +1,339 @@
+use alemat::{
+    children,
+    content::{Apply, Content, ContentOp},
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver},
+        Frac, Ident, Num, Operator,
+    },
+    Element, Elements, MathMl, MathMlFormatter,
+};
+
+fn apply<const N: usize>(op: ContentOp, args: [Content; N]) -> Content {
+    Apply::builder().op(op).args(args).build().into()
+}
+
+fn parens(elements: impl IntoIterator<Item = Element>) -> Element {
+    let row = std::iter::once(Operator::lparens().into())
+        .chain(elements)
+        .chain(std::iter::once(Operator::rparens().into()))
+        .collect::<Elements>();
+
+    Row::from(row).into()
+}
+
+#[test]
+fn precedence() {
+    let a = Content::ci("a");
+    let b = Content::ci("b");
+    let c = Content::ci("c");
+
+    // a - (b + c)
+    let content = apply(
+        ContentOp::Minus,
+        [a.clone(), apply(ContentOp::Plus, [b.clone(), c.clone()])],
+    );
+
+    assert_eq!(
+        content.to_presentation().into_inner(),
+        children![
+            Ident::from("a"),
+            Operator::minus(),
+            parens([
+                Ident::from("b").into(),
+                Operator::plus().into(),
+                Ident::from("c").into()
+            ]),
+        ]
+    );
+
+    // (a + b) c
+    let content = apply(
+        ContentOp::Times,
+        [apply(ContentOp::Plus, [a.clone(), b.clone()]), c.clone()],
+    );
+
+    assert_eq!(
+        content.to_presentation().into_inner(),
+        children![
+            parens([
+                Ident::from("a").into(),
+                Operator::plus().into(),
+                Ident::from("b").into()
+            ]),
+            Operator::from("\u{2062}"),
+            Ident::from("c"),
+        ]
+    );
+
+    // -a + b * 2, where products with numbers use a visible dot
+    let content = apply(
+        ContentOp::Plus,
+        [
+            apply(ContentOp::Minus, [a.clone()]),
+            apply(ContentOp::Times, [b.clone(), Content::cn("2")]),
+        ],
+    );
+
+    assert_eq!(
+        content.to_presentation().into_inner(),
+        children![
+            Operator::minus(),
+            Ident::from("a"),
+            Operator::plus(),
+            Ident::from("b"),
+            Operator::dot(),
+            Num::from(2),
+        ]
+    );
+
+    // a + (-1)
+    let content = apply(ContentOp::Plus, [a.clone(), Content::cn("-1")]);
+
+    assert_eq!(
+        content.to_presentation().into_inner(),
+        children![
+            Ident::from("a"),
+            Operator::plus(),
+            parens([Operator::minus().into(), Num::from(1).into()]),
+        ]
+    );
+
+    // (a / b)^2, but the fraction itself needs no parentheses
+    let content = apply(
+        ContentOp::Power,
+        [
+            apply(ContentOp::Divide, [apply(ContentOp::Plus, [a, b]), c]),
+            Content::cn("2"),
+        ],
+    );
+
+    let frac = Frac::builder()
+        .num(children![
+            Ident::from("a"),
+            Operator::plus(),
+            Ident::from("b")
+        ])
+        .denom(Ident::from("c"))
+        .build();
+
+    assert_eq!(
+        content.to_presentation().into_inner(),
+        children![SubSup::builder()
+            .base([parens([frac.into()])])
+            .supscript(Num::from(2))
+            .build()]
+    );
+}
+
+#[test]
+fn products() {
+    let a = Content::ci("a");
+    let c = Content::ci("c");
+    let times = Operator::from("\u{2062}");
+
+    // a (-b c)
+    let content = apply(
+        ContentOp::Times,
+        [
+            a.clone(),
+            apply(
+                ContentOp::Times,
+                [apply(ContentOp::Minus, [Content::ci("b")]), c.clone()],
+            ),
+        ],
+    );
+
+    assert_eq!(
+        content.to_presentation().into_inner(),
+        children![
+            Ident::from("a"),
+            times.clone(),
+            parens([
+                Operator::minus().into(),
+                Ident::from("b").into(),
+                times.clone().into(),
+                Ident::from("c").into(),
+            ]),
+        ]
+    );
+
+    // a (-2 c)
+    let content = apply(
+        ContentOp::Times,
+        [a, apply(ContentOp::Times, [Content::cn("-2"), c])],
+    );
+
+    assert_eq!(
+        content.to_presentation().into_inner(),
+        children![
+            Ident::from("a"),
+            times.clone(),
+            parens([
+                Operator::minus().into(),
+                Num::from(2).into(),
+                times.into(),
+                Ident::from("c").into(),
+            ]),
+        ]
+    );
+
+    // 2 * 1/2, which is not a mixed number
+    let content = apply(
+        ContentOp::Times,
+        [
+            Content::cn("2"),
+            apply(ContentOp::Divide, [Content::cn("1"), Content::cn("2")]),
+        ],
+    );
+
+    assert_eq!(
+        content.to_presentation().into_inner(),
+        children![
+            Num::from(2),
+            Operator::dot(),
+            Frac::builder()
+                .num(Num::from(1))
+                .denom(Num::from(2))
+                .build(),
+        ]
+    );
+}
+
+#[test]
+fn qualifiers() {
+    let root = Content::from(
+        Apply::builder()
+            .op(ContentOp::Root)
+            .degree(Content::cn("3"))
+            .args([Content::ci("x")])
+            .build(),
+    );
+
+    assert_eq!(
+        root.to_presentation().into_inner(),
+        children![Radical::builder()
+            .index(Num::from(3))
+            .content(Ident::from("x"))
+            .build()]
+    );
+
+    // ∫_0^1 (x + 1) dx
+    let int = Content::from(
+        Apply::builder()
+            .op(ContentOp::Int)
+            .bvar(Content::ci("x"))
+            .lowlimit(Content::cn("0"))
+            .uplimit(Content::cn("1"))
+            .args([apply(ContentOp::Plus, [Content::ci("x"), Content::cn("1")])])
+            .build(),
+    );
+
+    assert_eq!(
+        int.to_presentation().into_inner(),
+        children![
+            SubSup::builder()
+                .base(Operator::integral())
+                .subscript(Num::from(0))
+                .supscript(Num::from(1))
+                .build(),
+            parens([
+                Ident::from("x").into(),
+                Operator::plus().into(),
+                Num::from(1).into()
+            ]),
+            Ident::from("d"),
+            Ident::from("x"),
+        ]
+    );
+
+    // ∑_(i=1)^n f(i)
+    let sum = Content::from(
+        Apply::builder()
+            .op(ContentOp::Sum)
+            .bvar(Content::ci("i"))
+            .lowlimit(Content::cn("1"))
+            .uplimit(Content::ci("n"))
+            .args([apply(
+                ContentOp::Function(String::from("f")),
+                [Content::ci("i")],
+            )])
+            .build(),
+    );
+
+    assert_eq!(
+        sum.to_presentation().into_inner(),
+        children![
+            UnderOver::builder()
+                .expr(Operator::sum())
+                .under(children![Ident::from("i"), Operator::eq(), Num::from(1)])
+                .over(Ident::from("n"))
+                .build(),
+            Ident::from("f"),
+            Operator::from("\u{2061}"),
+            parens([Ident::from("i").into()]),
+        ]
+    );
+}
+
+#[test]
+fn semantics() {
+    let content = Content::from(
+        Apply::builder()
+            .op(ContentOp::Root)
+            .args([apply(ContentOp::Plus, [Content::ci("x"), Content::cn("1")])])
+            .build(),
+    );
+
+    let math = MathMl::with_content([content.to_semantics()]);
+
+    let expected = concat!(
+        "<math><semantics>",
+        "<msqrt><mi>x</mi><mo>+</mo><mn>1</mn></msqrt>",
+        r#"<annotation-xml encoding="MathML-Content">"#,
+        "<apply><root/><apply><plus/><ci>x</ci><cn>1</cn></apply></apply>",
+        "</annotation-xml></semantics></math>",
+    );
+
+    let rendered = math.render().unwrap();
+    assert_eq!(rendered, expected);
+
+    let formatted = math.render_with(&mut MathMlFormatter).unwrap();
+    assert!(formatted.contains(concat!(
+        r#"<annotation-xml encoding="MathML-Content">"#,
+        "<apply><root/><apply><plus/><ci>x</ci><cn>1</cn></apply></apply>",
+        "</annotation-xml>",
+    )));
+
+    assert_eq!(MathMl::parse(&rendered), Ok(math));
+}
+
+#[test]
+fn parse_qualifiers() {
+    let input = concat!(
+        "<math><semantics><mi>s</mi>",
+        r#"<annotation-xml encoding="MathML-Content">"#,
+        "<apply><sum/><bvar><ci>i</ci></bvar><lowlimit><cn>1</cn></lowlimit>",
+        "<uplimit><ci>n</ci></uplimit><ci>i</ci></apply>",
+        "</annotation-xml></semantics></math>",
+    );
+
+    let sum = Content::from(
+        Apply::builder()
+            .op(ContentOp::Sum)
+            .bvar(Content::ci("i"))
+            .lowlimit(Content::cn("1"))
+            .uplimit(Content::ci("n"))
+            .args([Content::ci("i")])
+            .build(),
+    );
+
+    let math = MathMl::parse(input).unwrap();
+    let Element::Semantics(semantics) = &math.content()[0] else {
+        panic!("expected semantics element");
+    };
+
+    assert_eq!(semantics.children()[1], sum.to_semantics().children()[1]);
+    assert_eq!(math.render().unwrap(), input);
+}
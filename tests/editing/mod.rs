@@ -0,0 +1,242 @@
+use alemat::{
+    attributes::Attribute,
+    children,
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver, UnderOverAttr},
+        Element, Elements, Frac, FracAttr, Ident, Num, Operator, OperatorAttr, Table, TableCell,
+    },
+    table, MathMl, MathMlAttr,
+};
+
+#[test]
+fn frac() {
+    let mut frac = Frac::builder()
+        .num(Ident::from("x"))
+        .denom(Num::from(2))
+        .attr([FracAttr::LineThickness("0".into())])
+        .build();
+
+    frac.set_denom(children![Num::from(3), Ident::from("z")]);
+    frac.num_mut()[0] = Ident::from("y").into();
+    assert!(frac.remove_attr(FracAttr::LineThickness("0".into())));
+    assert!(!frac.remove_attr(FracAttr::LineThickness("0".into())));
+
+    assert_eq!(
+        frac,
+        Frac::builder()
+            .num(Ident::from("y"))
+            .denom(children![Num::from(3), Ident::from("z")])
+            .build()
+    );
+
+    let (num, denom, attr) = frac.clone().into_parts();
+    assert_eq!(Frac::from_parts(num, denom, attr), frac);
+
+    // every argument that is not a single element is wrapped in a row
+    let sum = || children![Num::from(3), Operator::plus(), Ident::from("z")];
+    let empty = Vec::<Element>::new;
+
+    let frac = Frac::from((sum(), empty()));
+    assert_eq!(frac, Frac::builder().num(sum()).denom(empty()).build());
+    assert_eq!(frac.num(), [Row::from(sum()).into()]);
+    assert_eq!(frac.denom(), [Row::default().into()]);
+}
+
+#[test]
+fn tokens() {
+    let mut ident = Ident::builder()
+        .ident("x")
+        .attr([Attribute::Id("a".into()), Attribute::Class("b".into())])
+        .build();
+
+    ident.set_ident("y");
+    ident.ident_mut().push('z');
+    ident.remove_attr(Attribute::Id("a".into()));
+
+    assert_eq!(
+        ident,
+        Ident::builder()
+            .ident("yz")
+            .attr([Attribute::Class("b".into())])
+            .build()
+    );
+
+    let mut op = Operator::builder()
+        .op("(")
+        .attr([OperatorAttr::Stretchy, Attribute::Id("a".into()).into()])
+        .build();
+
+    op.attributes_mut()
+        .retain(|attr| !matches!(attr, OperatorAttr::Global(_)));
+
+    assert!(op.remove_attr(OperatorAttr::Stretchy));
+    assert_eq!(op, Operator::lparens());
+
+    let (num, attr) = Num::from(42).into_parts();
+    assert_eq!(Num::from_parts(num, attr), Num::from(42));
+}
+
+#[test]
+fn subsup() {
+    let mut sub_sup = SubSup::builder()
+        .base(Ident::from("x"))
+        .supscript(Num::from(2))
+        .build();
+
+    assert!(sub_sup.sub_mut().is_none());
+    sub_sup.set_sub(Ident::from("j"));
+    sub_sup.sub_mut().expect("Subscript should be present.")[0] = Ident::from("i").into();
+    sub_sup.set_sup(children![Num::from(2), Operator::from("\u{2032}")]);
+
+    assert_eq!(
+        sub_sup,
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .subscript(Ident::from("i"))
+            .supscript(children![Num::from(2), Operator::from("\u{2032}")])
+            .build()
+    );
+
+    let (base, sub, _sup, attr) = sub_sup.into_parts();
+    assert_eq!(
+        SubSup::from_parts(base.clone(), sub, None, attr.clone()),
+        Some(
+            SubSup::builder()
+                .base(Ident::from("x"))
+                .subscript(Ident::from("i"))
+                .build()
+        )
+    );
+
+    assert_eq!(SubSup::from_parts(base, None, None, attr), None);
+}
+
+#[test]
+fn underover() {
+    let mut under_over = UnderOver::builder()
+        .expr(Operator::sum())
+        .under(Ident::from("i"))
+        .build();
+
+    under_over.set_over(Ident::from("n"));
+    under_over.set_under(Ident::from("k"));
+    under_over.attributes_mut().push(UnderOverAttr::AccentOver);
+
+    assert_eq!(
+        under_over,
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(Ident::from("k"))
+            .over(Ident::from("n"))
+            .attr([UnderOverAttr::AccentOver])
+            .build()
+    );
+
+    let (expr, under, over, attr) = under_over.clone().into_parts();
+    assert_eq!(
+        UnderOver::from_parts(expr, under, over, attr),
+        Some(under_over)
+    );
+}
+
+#[test]
+fn radical() {
+    let mut radical = Radical::builder()
+        .index(Num::from(2))
+        .content(Ident::from("x"))
+        .build();
+
+    radical.set_index(Num::from(3));
+    *radical.content_mut() = Elements::from_iter([Element::from(Ident::from("y"))]);
+
+    assert_eq!(
+        radical,
+        Radical::builder()
+            .index(Num::from(3))
+            .content(Ident::from("y"))
+            .build()
+    );
+
+    // the content of roots other than square roots is wrapped in a row
+    let mut radical = Radical::builder()
+        .index(Num::from(2))
+        .content(children![Ident::from("x"), Operator::plus(), Num::from(1)])
+        .build();
+    assert_eq!(radical.content().len(), 3);
+
+    radical.set_index(children![Ident::from("n"), Operator::minus(), Num::from(1)]);
+    assert_eq!(
+        radical,
+        Radical::builder()
+            .index(children![Ident::from("n"), Operator::minus(), Num::from(1)])
+            .content(children![Ident::from("x"), Operator::plus(), Num::from(1)])
+            .build()
+    );
+    assert!(matches!(radical.index().as_slice(), [Element::Row(_)]));
+    assert!(matches!(radical.content(), [Element::Row(_)]));
+
+    radical.set_content(children![Ident::from("y"), Operator::plus(), Num::from(2)]);
+    assert!(matches!(radical.content(), [Element::Row(_)]));
+
+    let (index, content, attr) = radical.clone().into_parts();
+    assert_eq!(Radical::from_parts(index, content, attr), radical);
+
+    let radical = Radical::from_parts(
+        Num::from(3),
+        children![Ident::from("x"), Operator::plus(), Num::from(1)],
+        Vec::new(),
+    );
+    assert_eq!(
+        radical,
+        Radical::builder()
+            .index(Num::from(3))
+            .content(children![Ident::from("x"), Operator::plus(), Num::from(1)])
+            .build()
+    );
+}
+
+#[test]
+fn containers() {
+    let mut row = Row::from([Ident::from("x"), Ident::from("y")]);
+    row.children_mut().remove(0);
+    row.add_attr([Attribute::Id("r".into())]);
+
+    let (children, attr) = row.into_parts();
+    assert_eq!(attr, [Attribute::Id("r".into())]);
+
+    let mut row = Row::from_parts(children, Vec::new());
+    row.set_children(children![Ident::from("z")]);
+    assert_eq!(row, Row::from([Ident::from("z")]));
+}
+
+#[test]
+fn table() {
+    let mut table: Table = table![[Num::from(1), Num::from(2)], [Num::from(3), Num::from(4)]];
+
+    table.rows_mut().remove(0);
+    table.rows_mut()[0].cells_mut()[1] = TableCell::from(Num::from(5));
+    table.rows_mut()[0].cells_mut()[0].set_children(Num::from(6));
+
+    assert_eq!(table, table![[Num::from(6), Num::from(5)]]);
+
+    let (rows, attr) = table.clone().into_parts();
+    assert_eq!(Table::from_parts(rows, attr), table);
+}
+
+#[test]
+fn math() {
+    let mut math =
+        MathMl::with_content([Ident::from("x")]).with_attr([MathMlAttr::AltText("x".into())]);
+
+    math.content_mut().push(Num::from(1).into());
+    assert!(math.remove_attr(MathMlAttr::AltText("x".into())));
+
+    let (content, attr) = math.into_parts();
+    assert!(attr.is_empty());
+    assert_eq!(
+        MathMl::from_parts(content, Vec::new()),
+        MathMl::with_content(children![Ident::from("x"), Num::from(1)])
+    );
+}
@@ -0,0 +1,372 @@
+use alemat::{
+    attributes::Attribute,
+    children,
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, AnnotationAttr, Element, Frac, FracAttr, Ident, Num, Operator, OperatorAttr,
+        Padded, PaddedAttr, Semantics, Space, SpaceAttr, StrLiteral, Table, TableAttr, TableCell,
+        TableCellAttr, Text,
+    },
+    BufMathMlWriter, MathMl, MathMlAttr, MathMlFormatter, RawMathMlFormatter, Writer,
+};
+
+const CONTENT: &str = "a<b>c&d\"e'f\u{1}g\rh";
+const CONTENT_ESCAPED: &str = "a&lt;b&gt;c&amp;d\"e'f\u{FFFD}g&#xD;h";
+
+const ATTR: &str = "a<b>c&d\"e'f\u{1}g\nh";
+const ATTR_ESCAPED: &str = "a&lt;b&gt;c&amp;d&quot;e'f\u{FFFD}g&#xA;h";
+
+/// Render the given element with both [`BufMathMlWriter`] and [`MathMlFormatter`], check that the
+/// output is well-formed XML and that the output of [`BufMathMlWriter`] matches the expected
+/// output.
+fn check(element: impl Into<Element>, expected: &str) {
+    use std::str::FromStr;
+
+    let math = MathMl::with_content([element.into()]);
+
+    let out = math.render().expect("Failed to render MathMl.");
+    assert_eq!(out, format!("<math>{expected}</math>"));
+    assert!(
+        xmlem::Document::from_str(&out).is_ok(),
+        "{out} is not valid XML."
+    );
+
+    let formatted = math
+        .render_with(&mut MathMlFormatter)
+        .expect("Failed to render MathMl.");
+    assert!(
+        xmlem::Document::from_str(&formatted).is_ok(),
+        "{formatted} is not valid XML."
+    );
+    assert!(!formatted.contains(CONTENT) && !formatted.contains(ATTR));
+}
+
+fn id() -> Attribute {
+    Attribute::Id(ATTR.into())
+}
+
+#[test]
+fn action() {
+    check(
+        Action::builder()
+            .content(Ident::from(CONTENT))
+            .attr([
+                ActionAttr::Selection(ATTR.into()),
+                ActionAttr::ActionType(ATTR.into()),
+            ])
+            .build(),
+        &format!(
+            r#"<maction selection="{ATTR_ESCAPED}" actiontype="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi></maction>"#
+        ),
+    );
+}
+
+#[test]
+fn annotation() {
+    check(
+        Annotation::builder()
+            .content(String::from(CONTENT))
+            .attr([AnnotationAttr::Encoding(ATTR.into())])
+            .build(),
+        &format!(r#"<annotation encoding="{ATTR_ESCAPED}">{CONTENT_ESCAPED}</annotation>"#),
+    );
+
+    check(
+        Annotation::builder()
+            .content(children![Text::from(CONTENT)])
+            .attr([AnnotationAttr::Global(id())])
+            .build(),
+        &format!(
+            r#"<annotation-xml id="{ATTR_ESCAPED}"><mtext>{CONTENT_ESCAPED}</mtext></annotation-xml>"#
+        ),
+    );
+}
+
+#[test]
+fn error() {
+    check(
+        Error::builder()
+            .content(Ident::from(CONTENT))
+            .attr([id()])
+            .build(),
+        &format!(r#"<merror id="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi></merror>"#),
+    );
+}
+
+#[test]
+fn frac() {
+    check(
+        Frac::builder()
+            .num(Ident::from(CONTENT))
+            .denom(Num::from(CONTENT))
+            .attr([FracAttr::LineThickness(ATTR.into())])
+            .build(),
+        &format!(
+            r#"<mfrac linethickness="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi><mn>{CONTENT_ESCAPED}</mn></mfrac>"#
+        ),
+    );
+}
+
+#[test]
+fn ident() {
+    check(
+        Ident::builder().ident(CONTENT).attr([id()]).build(),
+        &format!(r#"<mi id="{ATTR_ESCAPED}">{CONTENT_ESCAPED}</mi>"#),
+    );
+}
+
+#[test]
+fn multiscripts() {
+    check(
+        Multiscripts::builder()
+            .content(children![
+                Ident::from(CONTENT),
+                Prescripts::with_attr([id()]),
+                Num::from(1),
+                Num::from(2),
+            ])
+            .attr([id()])
+            .build(),
+        &format!(
+            r#"<mmultiscripts id="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi><mprescripts id="{ATTR_ESCAPED}"/><mn>1</mn><mn>2</mn></mmultiscripts>"#
+        ),
+    );
+}
+
+#[test]
+fn num() {
+    check(
+        Num::from(CONTENT).with_attr([id()]),
+        &format!(r#"<mn id="{ATTR_ESCAPED}">{CONTENT_ESCAPED}</mn>"#),
+    );
+}
+
+#[test]
+fn operator() {
+    check(
+        Operator::builder()
+            .op(CONTENT)
+            .attr([
+                OperatorAttr::LeftSpace(ATTR.into()),
+                OperatorAttr::RightSpace(ATTR.into()),
+                OperatorAttr::MaxSize(ATTR.into()),
+                OperatorAttr::MinSize(ATTR.into()),
+            ])
+            .build(),
+        &format!(
+            r#"<mo lspace="{ATTR_ESCAPED}" rspace="{ATTR_ESCAPED}" maxsize="{ATTR_ESCAPED}" minsize="{ATTR_ESCAPED}">{CONTENT_ESCAPED}</mo>"#
+        ),
+    );
+
+    check(Operator::lt(), "<mo>&lt;</mo>");
+    check(Operator::gt(), "<mo>&gt;</mo>");
+}
+
+#[test]
+fn padded() {
+    check(
+        Padded::from([Ident::from(CONTENT)]).with_attr([
+            PaddedAttr::Width(ATTR.into()),
+            PaddedAttr::Height(ATTR.into()),
+            PaddedAttr::Depth(ATTR.into()),
+            PaddedAttr::LeftSpace(ATTR.into()),
+            PaddedAttr::VerticalOffset(ATTR.into()),
+        ]),
+        &format!(
+            r#"<mpadded width="{a}" height="{a}" depth="{a}" lspace="{a}" voffset="{a}"><mi>{CONTENT_ESCAPED}</mi></mpadded>"#,
+            a = ATTR_ESCAPED
+        ),
+    );
+}
+
+#[test]
+fn phantom() {
+    check(
+        Phantom::from([Ident::from(CONTENT)]).with_attr([id()]),
+        &format!(r#"<mphantom id="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi></mphantom>"#),
+    );
+}
+
+#[test]
+fn radical() {
+    check(
+        Radical::builder()
+            .index(Num::from(3))
+            .content(Ident::from(CONTENT))
+            .attr([id()])
+            .build(),
+        &format!(r#"<mroot id="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi><mn>3</mn></mroot>"#),
+    );
+}
+
+#[test]
+fn row() {
+    let mut row = Row::from([Ident::from(CONTENT)]);
+    row.add_attr([id()]);
+
+    check(
+        row,
+        &format!(r#"<mrow id="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi></mrow>"#),
+    );
+}
+
+#[test]
+fn semantics() {
+    check(
+        Semantics::builder()
+            .content(children![
+                Ident::from(CONTENT),
+                Annotation::builder().content(String::from(CONTENT)).build()
+            ])
+            .attr([id()])
+            .build(),
+        &format!(
+            r#"<semantics id="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi><annotation>{CONTENT_ESCAPED}</annotation></semantics>"#
+        ),
+    );
+}
+
+#[test]
+fn space() {
+    check(
+        Space::default().with_attr([
+            SpaceAttr::Width(ATTR.into()),
+            SpaceAttr::Height(ATTR.into()),
+            SpaceAttr::Depth(ATTR.into()),
+        ]),
+        &format!(
+            r#"<mspace width="{a}" height="{a}" depth="{a}"/>"#,
+            a = ATTR_ESCAPED
+        ),
+    );
+}
+
+#[test]
+fn str_literal() {
+    check(
+        StrLiteral::from(CONTENT).with_attr([id()]),
+        &format!(r#"<ms id="{ATTR_ESCAPED}">{CONTENT_ESCAPED}</ms>"#),
+    );
+}
+
+#[test]
+fn style() {
+    check(
+        Style::from([Ident::from(CONTENT)]).with_attr([Attribute::Style(ATTR.into())]),
+        &format!(r#"<mstyle style="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi></mstyle>"#),
+    );
+}
+
+#[test]
+fn subsup() {
+    check(
+        SubSup::builder()
+            .base(Ident::from(CONTENT))
+            .subscript(Ident::from(CONTENT))
+            .supscript(Ident::from(CONTENT))
+            .attr([id()])
+            .build(),
+        &format!(
+            r#"<msubsup id="{ATTR_ESCAPED}"><mi>{c}</mi><mi>{c}</mi><mi>{c}</mi></msubsup>"#,
+            c = CONTENT_ESCAPED
+        ),
+    );
+}
+
+#[test]
+fn table() {
+    check(
+        Table::from([[TableCell::from(Ident::from(CONTENT)).with_attr([
+            TableCellAttr::ColumnSpan(ATTR.into()),
+            TableCellAttr::RowSpan(ATTR.into()),
+        ])]])
+        .with_attr([TableAttr::Global(id())]),
+        &format!(
+            r#"<mtable id="{a}"><mtr><mtd columnspan="{a}" rowspan="{a}"><mi>{CONTENT_ESCAPED}</mi></mtd></mtr></mtable>"#,
+            a = ATTR_ESCAPED
+        ),
+    );
+}
+
+#[test]
+fn text() {
+    check(
+        Text::from(CONTENT).with_attr([id()]),
+        &format!(r#"<mtext id="{ATTR_ESCAPED}">{CONTENT_ESCAPED}</mtext>"#),
+    );
+}
+
+#[test]
+fn underover() {
+    check(
+        UnderOver::builder()
+            .expr(Ident::from(CONTENT))
+            .under(Ident::from(CONTENT))
+            .over(Ident::from(CONTENT))
+            .attr([UnderOverAttr::Global(id())])
+            .build(),
+        &format!(
+            r#"<munderover id="{ATTR_ESCAPED}"><mi>{c}</mi><mi>{c}</mi><mi>{c}</mi></munderover>"#,
+            c = CONTENT_ESCAPED
+        ),
+    );
+}
+
+#[test]
+fn global_attributes() {
+    let attributes = [
+        Attribute::Class(ATTR.into()),
+        Attribute::Data {
+            name: String::from("na\"me x"),
+            value: ATTR.into(),
+        },
+        Attribute::MathBackground(ATTR.into()),
+        Attribute::MathColor(ATTR.into()),
+        Attribute::MathSize(ATTR.into()),
+        Attribute::Nonce(ATTR.into()),
+        Attribute::OnHandler {
+            name: String::from("click=\"x"),
+            handler: ATTR.into(),
+        },
+    ];
+
+    check(
+        Ident::builder().ident("x").attr(attributes).build(),
+        &format!(
+            r#"<mi class="{a}" data-namex="{a}" mathbackground="{a}" mathcolor="{a}" mathsize="{a}" nonce="{a}" onclickx="{a}">x</mi>"#,
+            a = ATTR_ESCAPED
+        ),
+    );
+}
+
+#[test]
+fn mathml() {
+    let out = MathMl::with_content(Ident::from(CONTENT))
+        .with_attr([MathMlAttr::AltText(ATTR.into())])
+        .render()
+        .expect("Failed to render MathMl.");
+
+    assert_eq!(
+        out,
+        format!(r#"<math alttext="{ATTR_ESCAPED}"><mi>{CONTENT_ESCAPED}</mi></math>"#)
+    );
+}
+
+#[test]
+fn raw() {
+    let math = MathMl::with_content(Ident::from("&alpha;"))
+        .with_attr([MathMlAttr::AltText(String::from("&amp;"))]);
+
+    let out = math
+        .write(&mut BufMathMlWriter::raw())
+        .expect("Failed to render MathMl.")
+        .finish();
+    assert_eq!(out, r#"<math alttext="&amp;"><mi>&alpha;</mi></math>"#);
+
+    let out = math
+        .render_with(&mut RawMathMlFormatter)
+        .expect("Failed to render MathMl.");
+    assert_eq!(out, r#"<math alttext="&amp;"><mi >&alpha;</mi></math>"#);
+}
@@ -0,0 +1,352 @@
+use alemat::{
+    children,
+    elements::{grouping::Row, scripted::SubSup, Frac, Ident, Num, OpForm, Operator, OperatorAttr},
+    expr::{Division, Expr, Multiplication, Notation},
+    Element, Elements,
+};
+
+fn op(op: Operator, form: OpForm) -> Element {
+    Operator::builder()
+        .op(op.op())
+        .attr([OperatorAttr::Form(form)])
+        .build()
+        .into()
+}
+
+fn infix(operator: Operator) -> Element {
+    op(operator, OpForm::Infix)
+}
+
+fn parens(elements: impl IntoIterator<Item = Element>) -> Element {
+    let row = std::iter::once(op(Operator::lparens(), OpForm::Prefix))
+        .chain(elements)
+        .chain(std::iter::once(op(Operator::rparens(), OpForm::Postfix)))
+        .collect::<Elements>();
+
+    Row::from(row).into()
+}
+
+fn a() -> Expr {
+    Expr::var("a")
+}
+
+fn b() -> Expr {
+    Expr::var("b")
+}
+
+fn c() -> Expr {
+    Expr::var("c")
+}
+
+#[test]
+fn constructors() {
+    assert_eq!(Expr::add(a(), b()), a() + b());
+    assert_eq!(Expr::sub(a(), b()), a() - b());
+    assert_eq!(Expr::mul(a(), b()), a() * b());
+    assert_eq!(Expr::div(a(), b()), a() / b());
+    assert_eq!(Expr::neg(a()), -a());
+
+    assert_eq!(
+        Expr::add(a(), Expr::neg(b())),
+        Expr::Add(Box::new(a()), Box::new(Expr::Neg(Box::new(b()))))
+    );
+}
+
+#[test]
+fn precedence() {
+    // a * (b + c)^2
+    let expr = a() * Expr::pow(b() + c(), Expr::num(2));
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            Ident::from("a").into(),
+            infix(Operator::from("\u{2062}")),
+            SubSup::builder()
+                .base([parens([
+                    Ident::from("b").into(),
+                    infix(Operator::plus()),
+                    Ident::from("c").into(),
+                ])])
+                .supscript(Num::from(2))
+                .build()
+                .into(),
+        ]
+    );
+
+    // -(a + b) * c
+    let expr = -(a() + b()) * c();
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            op(Operator::minus(), OpForm::Prefix),
+            parens([
+                Ident::from("a").into(),
+                infix(Operator::plus()),
+                Ident::from("b").into(),
+            ]),
+            infix(Operator::from("\u{2062}")),
+            Ident::from("c").into(),
+        ]
+    );
+
+    // (-a)^2
+    let expr = Expr::pow(-a(), Expr::num(2));
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [SubSup::builder()
+            .base([parens([
+                op(Operator::minus(), OpForm::Prefix),
+                Ident::from("a").into(),
+            ])])
+            .supscript(Num::from(2))
+            .build()
+            .into()]
+    );
+}
+
+#[test]
+fn associativity() {
+    // (a - b) - c needs no parentheses, a - (b - c) does
+    let left = a() - b() - c();
+    let right = a() - (b() - c());
+
+    assert_eq!(
+        left.to_elements().into_inner(),
+        [
+            Ident::from("a").into(),
+            infix(Operator::minus()),
+            Ident::from("b").into(),
+            infix(Operator::minus()),
+            Ident::from("c").into(),
+        ]
+    );
+
+    assert_eq!(
+        right.to_elements().into_inner(),
+        [
+            Ident::from("a").into(),
+            infix(Operator::minus()),
+            parens([
+                Ident::from("b").into(),
+                infix(Operator::minus()),
+                Ident::from("c").into(),
+            ]),
+        ]
+    );
+
+    // sums are associative, but negated summands are parenthesized
+    let expr = a() + (b() + -c());
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            Ident::from("a").into(),
+            infix(Operator::plus()),
+            Ident::from("b").into(),
+            infix(Operator::plus()),
+            parens([
+                op(Operator::minus(), OpForm::Prefix),
+                Ident::from("c").into(),
+            ]),
+        ]
+    );
+
+    // (a^b)^c needs parentheses, a^(b^c) is nested in the script
+    let expr = Expr::pow(Expr::pow(a(), b()), c());
+
+    let inner = SubSup::builder()
+        .base(Ident::from("a"))
+        .supscript(Ident::from("b"))
+        .build();
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [SubSup::builder()
+            .base([parens([inner.into()])])
+            .supscript(Ident::from("c"))
+            .build()
+            .into()]
+    );
+}
+
+#[test]
+fn negated_operands() {
+    let minus = || op(Operator::minus(), OpForm::Prefix);
+    let times = || infix(Operator::from("\u{2062}"));
+
+    // a * (-b * c)
+    let expr = a() * (-b() * c());
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            Ident::from("a").into(),
+            times(),
+            parens([
+                minus(),
+                Ident::from("b").into(),
+                times(),
+                Ident::from("c").into()
+            ]),
+        ]
+    );
+
+    // a * (-1 * c)
+    let expr = a() * (Expr::num(-1) * c());
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            Ident::from("a").into(),
+            times(),
+            parens([
+                minus(),
+                Num::from(1).into(),
+                times(),
+                Ident::from("c").into()
+            ]),
+        ]
+    );
+
+    // a + (-b + c)
+    let expr = a() + (-b() + c());
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            Ident::from("a").into(),
+            infix(Operator::plus()),
+            parens([
+                minus(),
+                Ident::from("b").into(),
+                infix(Operator::plus()),
+                Ident::from("c").into(),
+            ]),
+        ]
+    );
+
+    // a - (-b) * c
+    let expr = a() - -b() * c();
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            Ident::from("a").into(),
+            infix(Operator::minus()),
+            parens([
+                minus(),
+                Ident::from("b").into(),
+                times(),
+                Ident::from("c").into()
+            ]),
+        ]
+    );
+
+    // the leading factor of a product may be negated
+    let expr = -a() * b() * c();
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            minus(),
+            Ident::from("a").into(),
+            times(),
+            Ident::from("b").into(),
+            times(),
+            Ident::from("c").into(),
+        ]
+    );
+}
+
+#[test]
+fn notation() {
+    // (a + b) / (2c)
+    let expr = (a() + b()) / (Expr::num(2) * c());
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [Frac::builder()
+            .num(children![
+                Ident::from("a"),
+                infix(Operator::plus()),
+                Ident::from("b"),
+            ])
+            .denom(children![
+                Num::from(2),
+                infix(Operator::from("\u{2062}")),
+                Ident::from("c"),
+            ])
+            .build()
+            .into()]
+    );
+
+    let solidus = Notation::new(Division::Solidus, Multiplication::Dot);
+
+    assert_eq!(
+        expr.to_elements_with(solidus).into_inner(),
+        [
+            parens([
+                Ident::from("a").into(),
+                infix(Operator::plus()),
+                Ident::from("b").into(),
+            ]),
+            infix(Operator::solidus()),
+            parens([
+                Num::from(2).into(),
+                infix(Operator::dot()),
+                Ident::from("c").into(),
+            ]),
+        ]
+    );
+
+    // invisible times turn into a dot in front of numbers
+    let expr = a() * Expr::num(2);
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            Ident::from("a").into(),
+            infix(Operator::dot()),
+            Num::from(2).into(),
+        ]
+    );
+
+    // a fraction with a numeric numerator would read as mixed number, e.g. 2¾
+    let expr = Expr::num(2) * (Expr::num(3) / Expr::num(4));
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            Num::from(2).into(),
+            infix(Operator::dot()),
+            Frac::builder()
+                .num(Num::from(3))
+                .denom(Num::from(4))
+                .build()
+                .into(),
+        ]
+    );
+
+    let expr = Expr::call("f", [a(), Expr::num(-1)]);
+
+    assert_eq!(
+        expr.to_elements().into_inner(),
+        [
+            Ident::from("f").into(),
+            infix(Operator::from("\u{2061}")),
+            parens([
+                Ident::from("a").into(),
+                Operator::builder()
+                    .op(",")
+                    .attr([OperatorAttr::Form(OpForm::Infix), OperatorAttr::Separator])
+                    .build()
+                    .into(),
+                op(Operator::minus(), OpForm::Prefix),
+                Num::from(1).into(),
+            ]),
+        ]
+    );
+}
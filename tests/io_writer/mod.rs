@@ -0,0 +1,119 @@
+use std::io;
+
+use alemat::{
+    attributes::Attribute,
+    children,
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver},
+        Frac, Ident, Num, Operator, OperatorAttr, Table, Text,
+    },
+    BufMathMlWriter, IoMathMlWriter, MathMl, MathMlAttr, Writer,
+};
+
+fn math() -> MathMl {
+    MathMl::with_content(children![
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(children![Ident::from("i"), Operator::eq(), Num::from(0)])
+            .over(Ident::from("n"))
+            .build(),
+        Frac::builder()
+            .num(
+                SubSup::builder()
+                    .base(Ident::from("x"))
+                    .supscript(Num::from(2))
+                    .build()
+            )
+            .denom(
+                Radical::builder()
+                    .index(Num::from(3))
+                    .content(Ident::from("y"))
+                    .build()
+            )
+            .build(),
+        Operator::builder()
+            .op("<")
+            .attr([OperatorAttr::Stretchy])
+            .build(),
+        Row::from([Text::from("a & \"b\"")]).with_attr([Attribute::Id("r<1>".into())].into_iter()),
+        alemat::table![[Num::from(1), Num::from(2)], [Num::from(3), Num::from(4)]],
+        Table::from_iter([[Ident::from("z")]]),
+    ])
+    .with_attr([MathMlAttr::AltText("x < y".into())])
+}
+
+#[test]
+fn matches_buf_writer() {
+    let math = math();
+
+    let mut writer = IoMathMlWriter::new(Vec::new());
+    math.write(&mut writer).expect("Failed to write MathMl.");
+    let out = String::from_utf8(writer.into_inner()).expect("Output is not valid UTF-8.");
+
+    assert_eq!(out, math.render().expect("Failed to render MathMl."));
+}
+
+#[test]
+fn matches_raw_buf_writer() {
+    let math = math();
+
+    let mut writer = IoMathMlWriter::raw(Vec::new());
+    math.write(&mut writer).expect("Failed to write MathMl.");
+    let out = String::from_utf8(writer.into_inner()).expect("Output is not valid UTF-8.");
+
+    let expected = math
+        .write(&mut BufMathMlWriter::raw())
+        .map(|w| w.finish())
+        .expect("Failed to render MathMl.");
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn writes_multiple_formulas() {
+    let mut writer = IoMathMlWriter::new(Vec::new());
+
+    for i in 0..3 {
+        MathMl::with_content([Num::from(i)])
+            .write(&mut writer)
+            .expect("Failed to write MathMl.");
+    }
+
+    assert_eq!(
+        writer.buffer::<Vec<u8>>(),
+        b"<math><mn>0</mn></math><math><mn>1</mn></math><math><mn>2</mn></math>"
+    );
+}
+
+#[derive(Debug)]
+struct FailingWriter;
+
+impl io::Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::BrokenPipe, "broken pipe"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn propagates_io_errors() {
+    let err = math()
+        .write(&mut IoMathMlWriter::new(FailingWriter))
+        .expect_err("Writing should fail.");
+
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
+
+#[test]
+fn write_after_finish_fails() {
+    let mut writer = IoMathMlWriter::new(Vec::new());
+    let buf = writer.finish();
+    assert!(buf.is_empty());
+
+    assert!(math().write(&mut writer).is_err());
+}
@@ -0,0 +1,603 @@
+use alemat::{
+    attributes::{Attribute, MathVariant},
+    children,
+    elements::{
+        grouping::{Row, Style},
+        radicals::Radical,
+        scripted::{SubSup, UnderOver, UnderOverAttr},
+        ColumnLine, Element, Frac, FracAttr, Ident, Num, OpForm, Operator, OperatorAttr, Space,
+        SpaceAttr, Table, TableAttr, TableCell, Text,
+    },
+    latex::{self, LatexErrorKind, LatexFormatter},
+    MathMl,
+};
+
+fn parse(input: &str) -> Vec<Element> {
+    latex::parse_elements(input)
+        .expect("Failed to parse LaTeX.")
+        .into_inner()
+}
+
+fn fence(op: Operator, form: OpForm) -> Element {
+    Operator::builder()
+        .op(op.op())
+        .attr([
+            OperatorAttr::Stretchy,
+            OperatorAttr::Fence,
+            OperatorAttr::Form(form),
+        ])
+        .build()
+        .into()
+}
+
+#[test]
+fn tokens() {
+    assert_eq!(
+        parse(r"x + 12.5 - \alpha \le \infty"),
+        children![
+            Ident::from("x"),
+            Operator::plus(),
+            Num::from("12.5"),
+            Operator::minus(),
+            Ident::alpha(),
+            Operator::le(),
+            Ident::infinity(),
+        ]
+        .to_vec()
+    );
+}
+
+#[test]
+fn fractions_and_roots() {
+    assert_eq!(
+        parse(r"\frac{a}{b} \frac12 \sqrt{x} \sqrt[3]{x+1}"),
+        children![
+            Frac::builder()
+                .num(Ident::from("a"))
+                .denom(Ident::from("b"))
+                .build(),
+            Frac::builder()
+                .num(Num::from(1))
+                .denom(Num::from(2))
+                .build(),
+            Radical::builder()
+                .index(Num::from(2))
+                .content(Ident::from("x"))
+                .build(),
+            Radical::builder()
+                .index(Num::from(3))
+                .content(children![Ident::from("x"), Operator::plus(), Num::from(1)])
+                .build(),
+        ]
+        .to_vec()
+    );
+
+    assert_eq!(
+        parse(r"\dfrac{1}{n}"),
+        vec![Element::from(
+            Style::from([Frac::builder()
+                .num(Num::from(1))
+                .denom(Ident::from("n"))
+                .build()])
+            .with_attr([Attribute::DisplayStyle(true)])
+        )]
+    );
+
+    assert_eq!(
+        parse(r"\binom{n}{k}"),
+        vec![Element::from(Row::from([
+            fence(Operator::lparens(), OpForm::Prefix),
+            Frac::builder()
+                .num(Ident::from("n"))
+                .denom(Ident::from("k"))
+                .attr([FracAttr::LineThickness("0".into())])
+                .build()
+                .into(),
+            fence(Operator::rparens(), OpForm::Postfix),
+        ]))]
+    );
+}
+
+#[test]
+fn scripts() {
+    assert_eq!(
+        parse(r"x_i^2 \sum_{i=0}^n \int_0^1 f'(x)"),
+        children![
+            SubSup::builder()
+                .base(Ident::from("x"))
+                .subscript(Ident::from("i"))
+                .supscript(Num::from(2))
+                .build(),
+            UnderOver::builder()
+                .expr(Operator::sum())
+                .under(children![Ident::from("i"), Operator::eq(), Num::from(0)])
+                .over(Ident::from("n"))
+                .build(),
+            SubSup::builder()
+                .base(Operator::integral())
+                .subscript(Num::from(0))
+                .supscript(Num::from(1))
+                .build(),
+            SubSup::builder()
+                .base(Ident::from("f"))
+                .supscript(Operator::from("\u{2032}"))
+                .build(),
+            Operator::lparens(),
+            Ident::from("x"),
+            Operator::rparens(),
+        ]
+        .to_vec()
+    );
+
+    assert_eq!(
+        parse(r"\lim_{x \to 0} \sin x"),
+        children![
+            UnderOver::builder()
+                .expr(Ident::from("lim"))
+                .under(children![
+                    Ident::from("x"),
+                    Operator::rarrow(),
+                    Num::from(0)
+                ])
+                .build(),
+            Operator::from("\u{2061}"),
+            Ident::from("sin"),
+            Operator::from("\u{2061}"),
+            Ident::from("x"),
+        ]
+        .to_vec()
+    );
+
+    assert_eq!(
+        parse(r"\sum\nolimits_i"),
+        vec![Element::from(
+            SubSup::builder()
+                .base(Operator::sum())
+                .subscript(Ident::from("i"))
+                .build()
+        )]
+    );
+}
+
+#[test]
+fn fences() {
+    assert_eq!(
+        parse(r"\left( \frac{1}{2} \right."),
+        vec![Element::from(Row::from([
+            fence(Operator::lparens(), OpForm::Prefix),
+            Frac::builder()
+                .num(Num::from(1))
+                .denom(Num::from(2))
+                .build()
+                .into(),
+        ]))]
+    );
+
+    assert_eq!(
+        parse(r"\left\langle x \right\rangle"),
+        vec![Element::from(Row::from([
+            fence(Operator::langle(), OpForm::Prefix),
+            Ident::from("x").into(),
+            fence(Operator::rangle(), OpForm::Postfix),
+        ]))]
+    );
+}
+
+#[test]
+fn accents() {
+    assert_eq!(
+        parse(r"\hat{x} \overline{AB} \underbrace{a+b}_{n}"),
+        children![
+            UnderOver::builder()
+                .expr(Ident::from("x"))
+                .over(Operator::hat())
+                .attr([UnderOverAttr::AccentOver])
+                .build(),
+            UnderOver::builder()
+                .expr(children![Ident::from("A"), Ident::from("B")])
+                .over(
+                    Operator::builder()
+                        .op(Operator::bar().op())
+                        .attr([OperatorAttr::Stretchy])
+                        .build()
+                )
+                .attr([UnderOverAttr::AccentOver])
+                .build(),
+            UnderOver::builder()
+                .expr(
+                    UnderOver::builder()
+                        .expr(children![
+                            Ident::from("a"),
+                            Operator::plus(),
+                            Ident::from("b")
+                        ])
+                        .under(
+                            Operator::builder()
+                                .op(Operator::ubrace().op())
+                                .attr([OperatorAttr::Stretchy])
+                                .build()
+                        )
+                        .attr([UnderOverAttr::AccentUnder])
+                        .build()
+                )
+                .under(Ident::from("n"))
+                .build(),
+        ]
+        .to_vec()
+    );
+}
+
+#[test]
+fn fonts_and_text() {
+    let variant = |ident: &str, variant: MathVariant| {
+        Ident::builder()
+            .ident(ident)
+            .attr([Attribute::MathVariant(variant)])
+            .build()
+    };
+
+    assert_eq!(
+        parse(r"\mathbb{R} \mathbf{v_1} \text{if } x\,y"),
+        children![
+            variant("R", MathVariant::DoubleStruck),
+            SubSup::builder()
+                .base(variant("v", MathVariant::Bold))
+                .subscript(Num::from(1).with_attr([Attribute::MathVariant(MathVariant::Bold)]))
+                .build(),
+            Text::from("if "),
+            Ident::from("x"),
+            Space::default().with_attr([SpaceAttr::Width("0.1667em".into())]),
+            Ident::from("y"),
+        ]
+        .to_vec()
+    );
+
+    assert_eq!(
+        parse(r"\textbf{50\% off}"),
+        vec![Element::from(
+            Text::from("50% off").with_attr([Attribute::MathVariant(MathVariant::Bold)])
+        )]
+    );
+
+    assert_eq!(
+        parse(r"\text{a\textbackslash{}b\textasciitilde c\textasciicircum{} d} \backslash"),
+        children![Text::from(r"a\b~c^ d"), Operator::from("\\")].to_vec()
+    );
+}
+
+#[test]
+fn environments() {
+    assert_eq!(
+        parse(r"\begin{pmatrix} 1 & 2 \\ 3 & 4 \\ \end{pmatrix}"),
+        vec![Element::from(Row::from([
+            fence(Operator::lparens(), OpForm::Prefix),
+            alemat::table![[Num::from(1), Num::from(2)], [Num::from(3), Num::from(4)]].into(),
+            fence(Operator::rparens(), OpForm::Postfix),
+        ]))]
+    );
+
+    fn left(content: impl Into<TableCell>) -> TableCell {
+        content
+            .into()
+            .with_attr([Attribute::Style("text-align: left".into())])
+    }
+
+    let cases: Table = [
+        [
+            left(children![Num::from(0)]),
+            left(children![Ident::from("x"), Operator::lt(), Num::from(0)]),
+        ],
+        [
+            left(children![Num::from(1)]),
+            left(children![Text::from("otherwise")]),
+        ],
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(
+        parse(r"\begin{cases} 0 & x < 0 \\ 1 & \text{otherwise} \end{cases}"),
+        vec![Element::from(Row::from([
+            fence(Operator::lbrace(), OpForm::Prefix),
+            cases.into(),
+        ]))]
+    );
+
+    let center = |n: i32| TableCell::from(Num::from(n));
+    let right = |n: i32| {
+        TableCell::from(Num::from(n)).with_attr([Attribute::Style("text-align: right".into())])
+    };
+
+    let array: Table = [[center(1), center(2), right(3)]]
+        .into_iter()
+        .collect::<Table>()
+        .with_attr([TableAttr::ColumnLines(vec![
+            ColumnLine::None,
+            ColumnLine::Solid,
+        ])]);
+
+    assert_eq!(
+        parse(r"\begin{array}{cc|r} 1 & 2 & 3 \end{array}"),
+        vec![Element::from(array)]
+    );
+}
+
+#[test]
+fn math() {
+    assert_eq!(
+        latex::parse("x^2"),
+        Ok(MathMl::with_content([SubSup::builder()
+            .base(Ident::from("x"))
+            .supscript(Num::from(2))
+            .build()]))
+    );
+}
+
+fn error(input: &str) -> (LatexErrorKind, std::ops::Range<usize>) {
+    let err = latex::parse(input).expect_err("Parsing should fail.");
+    (err.kind().clone(), err.span())
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        error(r"x + \foo"),
+        (LatexErrorKind::UnknownCommand("foo".into()), 4..8)
+    );
+
+    assert_eq!(error(r"\frac{a"), (LatexErrorKind::UnclosedGroup, 5..6));
+
+    assert_eq!(
+        error(r"\frac{a}"),
+        (LatexErrorKind::MissingArgument("frac".into()), 0..5)
+    );
+
+    assert_eq!(
+        error(r"a}"),
+        (LatexErrorKind::UnexpectedToken("}".into()), 1..2)
+    );
+
+    assert_eq!(error(r"x^1^2"), (LatexErrorKind::DoubleSuperscript, 3..4));
+
+    assert_eq!(error(r"\left( x"), (LatexErrorKind::UnmatchedLeft, 0..5));
+
+    assert_eq!(
+        error(r"\left\foo x \right)"),
+        (LatexErrorKind::InvalidDelimiter(r"\foo".into()), 5..9)
+    );
+
+    assert_eq!(
+        error(r"\begin{matrix} 1 \end{pmatrix}"),
+        (
+            LatexErrorKind::MismatchedEnvironment {
+                expected: "matrix".into(),
+                found: "pmatrix".into()
+            },
+            22..29
+        )
+    );
+
+    assert_eq!(
+        error(r"\begin{foo} 1 \end{foo}"),
+        (LatexErrorKind::UnknownEnvironment("foo".into()), 7..10)
+    );
+
+    assert_eq!(
+        error(r"\begin{matrix} 1 & 2"),
+        (LatexErrorKind::UnclosedEnvironment("matrix".into()), 0..6)
+    );
+
+    assert_eq!(
+        error(r"\begin{array}{cp} 1 \end{array}"),
+        (LatexErrorKind::InvalidColumnSpec('p'), 15..16)
+    );
+}
+
+fn render(input: &str) -> String {
+    let math = latex::parse(input).expect("Failed to parse LaTeX.");
+    let output = math
+        .render_with(&mut LatexFormatter)
+        .expect("Rendering is infallible.");
+
+    // the rendered output describes the same elements as the input
+    assert_eq!(
+        latex::parse(&output),
+        Ok(math),
+        "Rendered output {output:?} of {input:?} differs."
+    );
+
+    output
+}
+
+#[test]
+fn render_fractions_and_roots() {
+    assert_eq!(render(r"\frac{a+b}{c}"), r"\frac{a+b}{c}");
+    assert_eq!(
+        render(r"\dfrac12 \tfrac{x}{y}"),
+        r"\dfrac{1}{2}\tfrac{x}{y}"
+    );
+    assert_eq!(render(r"\binom{n}{k}"), r"\binom{n}{k}");
+    assert_eq!(render(r"\sqrt{x} \sqrt[3]{x+1}"), r"\sqrt{x}\sqrt[3]{x+1}");
+}
+
+#[test]
+fn render_scripts() {
+    assert_eq!(
+        render(r"x_i^2 x_{ij}^{n+1} {x^2}^3"),
+        r"x_i^2x_{ij}^{n+1}{x^2}^3"
+    );
+    assert_eq!(render(r"f'(x) f''_1^2"), r"f'(x)f''_1^2");
+    assert_eq!(
+        render(r"\sum_{i=0}^{n} \int_0^1 \int\limits_0^1"),
+        r"\sum_{i=0}^n\int_0^1\int\limits_0^1"
+    );
+    assert_eq!(render(r"\lim_{x \to 0} \sin x"), r"\lim_{x\to 0}\sin x");
+    assert_eq!(
+        render(r"\overset{!}{=} \underset{a}{\overset{b}{x}}"),
+        r"\overset{!}{=}\underset{a}{\overset{b}{x}}"
+    );
+}
+
+#[test]
+fn render_accents() {
+    assert_eq!(
+        render(r"\hat{x} \widehat{xy} \overline{z} \vec v"),
+        r"\hat{x}\widehat{xy}\overline{z}\vec{v}"
+    );
+    assert_eq!(
+        render(r"\underbrace{a+b}_{n} \overbrace{c}^{m}"),
+        r"\underbrace{a+b}_n\overbrace{c}^m"
+    );
+}
+
+#[test]
+fn render_fences() {
+    assert_eq!(
+        render(r"\left( \frac{a}{b} \right)"),
+        r"\left(\frac{a}{b}\right)"
+    );
+    assert_eq!(
+        render(r"\left\langle x \middle| y \right\rangle \left. x \right|"),
+        r"\left\langle x\middle|y\right\rangle\left.x\right|"
+    );
+    assert_eq!(render(r"\big( x \Big]"), r"\big(x\Big]");
+    assert_eq!(render(r"\{ x \}"), r"\{x\}");
+}
+
+#[test]
+fn render_operators() {
+    assert_eq!(
+        render(r"\alpha\beta \leq \geq \neq \times \cdot \pm \infty \partial \nabla"),
+        r"\alpha\beta\leq\geq\neq\times\cdot\pm\infty\partial\nabla"
+    );
+    assert_eq!(render(r"\% \# \not\subset"), r"\%\#\not\subset");
+
+    let math = MathMl::with_content(children![
+        Ident::from("x"),
+        Operator::le(),
+        Ident::from("y"),
+        Operator::rarrow(),
+        Operator::from("\u{2061}"),
+        Operator::from("\u{2295}"),
+    ]);
+
+    assert_eq!(
+        math.render_with(&mut LatexFormatter).unwrap(),
+        r"x\leq y\to\oplus"
+    );
+}
+
+#[test]
+fn render_fonts_and_text() {
+    assert_eq!(
+        render(r"\mathbf{x} \mathbb{R} \mathfrak{g} \mathcal{L} \mathrm{d}x \boldsymbol{\alpha}"),
+        r"\mathbf{x}\mathbb{R}\mathfrak{g}\mathcal{L}\mathrm{d}x\boldsymbol{\alpha}"
+    );
+    assert_eq!(
+        render(r"\text{if } x \textbf{bold} \text{a\%b\_c}"),
+        r"\text{if }x\textbf{bold}\text{a\%b\_c}"
+    );
+    assert_eq!(
+        render(r"\operatorname{foo} x \mathbf{12}"),
+        r"\operatorname{foo}x\mathbf{12}"
+    );
+    assert_eq!(
+        render(r"a \, b \quad c \! d \hspace{3pt} e"),
+        r"a\,b\quad c\!d\hspace{3pt}e"
+    );
+    assert_eq!(
+        render(r"\textcolor{blue}{y} {\displaystyle z}"),
+        r"{\color{blue}y}{\displaystyle z}"
+    );
+}
+
+#[test]
+fn render_then_parse() {
+    let math = MathMl::with_content(children![
+        Text::from(r"a\b~c^d{e}"),
+        Operator::from("\\"),
+        Ident::from(r"a\b^c"),
+    ]);
+    let output = math.render_with(&mut LatexFormatter).unwrap();
+
+    assert_eq!(
+        output,
+        concat!(
+            r"\text{a\textbackslash{}b\textasciitilde{}c\textasciicircum{}d\{e\}}\backslash",
+            r"\operatorname{a\textbackslash{}b\textasciicircum{}c}"
+        )
+    );
+    assert_eq!(latex::parse(&output), Ok(math));
+
+    // characters without a math mode command are written as text
+    let math = MathMl::with_content(children![Ident::from("~"), Operator::from("^")]);
+    let output = math.render_with(&mut LatexFormatter).unwrap();
+
+    assert_eq!(output, r"\text{\textasciitilde{}}\text{\textasciicircum{}}");
+    assert_eq!(
+        latex::parse(&output),
+        Ok(MathMl::with_content(children![
+            Text::from("~"),
+            Text::from("^")
+        ]))
+    );
+}
+
+#[test]
+fn render_environments() {
+    assert_eq!(
+        render(r"\begin{pmatrix} a & b \\ c & d \end{pmatrix}"),
+        r"\begin{pmatrix}a & b \\ c & d\end{pmatrix}"
+    );
+    assert_eq!(
+        render(r"\begin{Vmatrix} 1 \end{Vmatrix} \begin{matrix} 1 & 2 \end{matrix}"),
+        r"\begin{Vmatrix}1\end{Vmatrix}\begin{matrix}1 & 2\end{matrix}"
+    );
+    assert_eq!(
+        render(r"\begin{cases} x & y \\ z & w \end{cases}"),
+        r"\begin{cases}x & y \\ z & w\end{cases}"
+    );
+    assert_eq!(
+        render(r"\begin{aligned} a &= b \\ c &= d \end{aligned}"),
+        r"\begin{aligned}a & =b \\ c & =d\end{aligned}"
+    );
+
+    // augmented matrix
+    let table = Table::from([[Num::from(1), Num::from(2), Num::from(3)]]).with_attr([
+        TableAttr::ColumnLines(vec![ColumnLine::None, ColumnLine::Solid]),
+    ]);
+    let math = MathMl::with_content(children![Row::from(children![
+        fence(Operator::lbracket(), OpForm::Prefix),
+        table,
+        fence(Operator::rbracket(), OpForm::Postfix),
+    ])]);
+
+    assert_eq!(
+        math.render_with(&mut LatexFormatter).unwrap(),
+        r"\left[\begin{array}{cc|c}1 & 2 & 3\end{array}\right]"
+    );
+}
+
+#[test]
+fn nesting_depth() {
+    let groups = |depth: usize| format!("{}x{}", "{".repeat(depth), "}".repeat(depth));
+
+    assert!(latex::parse(&groups(255)).is_ok());
+
+    let err = latex::parse(&groups(256)).unwrap_err();
+    assert_eq!(err.kind(), &LatexErrorKind::NestingTooDeep(256));
+    assert_eq!(err.span(), 256..257);
+
+    for input in [
+        groups(200_000),
+        r"\sqrt".repeat(200_000),
+        r"\displaystyle ".repeat(200_000),
+        r"\left(".repeat(200_000),
+        "x^{".repeat(200_000),
+        r"\frac{".repeat(200_000),
+        r"\begin{matrix}".repeat(200_000),
+    ] {
+        let err = latex::parse(&input).unwrap_err();
+        assert_eq!(err.kind(), &LatexErrorKind::NestingTooDeep(256));
+    }
+}
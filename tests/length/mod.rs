@@ -0,0 +1,192 @@
+use alemat::{
+    attributes::{Attribute, Length, NamedSpace, Unit},
+    elements::{Frac, FracAttr, Num, Padded, PaddedAttr, Space, SpaceAttr},
+    BufMathMlWriter, MathMl, MathMlAttr, Writer,
+};
+
+#[test]
+fn parse_units() {
+    assert_eq!("0.5em".parse(), Ok(Length::em(0.5)));
+    assert_eq!("2ex".parse(), Ok(Length::ex(2.0)));
+    assert_eq!("-3px".parse(), Ok(Length::px(-3.0)));
+    assert_eq!("12pt".parse(), Ok(Length::pt(12.0)));
+    assert_eq!("50%".parse(), Ok(Length::percent(50.0)));
+    assert_eq!("1in".parse(), Ok(Length::new(1.0, Unit::In)));
+    assert_eq!(".5cm".parse(), Ok(Length::new(0.5, Unit::Cm)));
+    assert_eq!("0".parse(), Ok(Length::unitless(0.0)));
+}
+
+#[test]
+fn parse_named_spaces() {
+    assert_eq!("thinmathspace".parse(), Ok(Length::named(NamedSpace::Thin)));
+    assert_eq!(
+        "negativeveryverythickmathspace".parse(),
+        Ok(Length::named(NamedSpace::NegativeVeryVeryThick))
+    );
+    assert_eq!(NamedSpace::Medium.em(), 4.0 / 18.0);
+}
+
+#[test]
+fn parse_pseudo_units() {
+    assert_eq!("+1width".parse(), Ok(Length::relative(1.0, Unit::Width)));
+    assert_eq!("+0.5em".parse(), Ok(Length::relative(0.5, Unit::Em)));
+    assert_eq!(
+        "-0.5height".parse(),
+        Ok(Length::relative(-0.5, Unit::Height))
+    );
+    assert_eq!("-1width".parse(), Ok(Length::relative(-1.0, Unit::Width)));
+    assert_eq!("2depth".parse(), Ok(Length::new(2.0, Unit::Depth)));
+    assert_eq!(
+        "+10lspace".parse(),
+        Ok(Length::relative(10.0, Unit::LeftSpace))
+    );
+}
+
+#[test]
+fn parse_invalid() {
+    for invalid in [
+        "", "em", "2 em", " 2em", "2em ", "2EM", "2.em.", "+-2em", "1e3px", "thin", "NaNem",
+    ] {
+        assert!(invalid.parse::<Length>().is_err(), "{invalid:?} parsed");
+    }
+
+    let huge = format!("{}em", "9".repeat(400));
+    assert!(huge.parse::<Length>().is_err());
+
+    let err = "2 em".parse::<Length>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid length `2 em`");
+}
+
+#[test]
+fn display_round_trip() {
+    for valid in [
+        "0.5em",
+        "-2px",
+        "50%",
+        "0",
+        "+1width",
+        "-0.25lspace",
+        "thickmathspace",
+    ] {
+        assert_eq!(valid.parse::<Length>().unwrap().to_string(), valid);
+    }
+
+    assert_eq!(Length::relative(2.0, Unit::Em).to_string(), "+2em");
+    assert_eq!(Length::relative(-2.0, Unit::Em).to_string(), "-2em");
+}
+
+#[test]
+fn into_string() {
+    assert_eq!(String::from(Length::em(1.0)), "1em");
+    assert_eq!(String::from(Length::relative(-1.0, Unit::Width)), "-1width");
+    assert_eq!(
+        String::from(Length::named(NamedSpace::Thin)),
+        "thinmathspace"
+    );
+}
+
+#[test]
+fn is_zero() {
+    assert!(Length::unitless(0.0).is_zero());
+    assert!(Length::px(-0.0).is_zero());
+    assert!(!Length::em(0.5).is_zero());
+    assert!(!Length::relative(0.0, Unit::Width).is_zero());
+}
+
+#[test]
+fn accessors() {
+    let length = Length::relative(-1.0, Unit::Width);
+    assert_eq!(length.value(), Some(-1.0));
+    assert_eq!(length.unit(), Some(Unit::Width));
+    assert!(length.is_relative());
+    assert_eq!(length.named_space(), None);
+
+    let length = Length::named(NamedSpace::Thick);
+    assert_eq!(length.value(), None);
+    assert_eq!(length.unit(), None);
+    assert!(!length.is_relative());
+    assert_eq!(length.named_space(), Some(NamedSpace::Thick));
+
+    assert!(!"-2px".parse::<Length>().unwrap().is_relative());
+}
+
+#[test]
+#[should_panic(expected = "length `NaN` is not finite")]
+fn non_finite() {
+    Length::em(f64::NAN);
+}
+
+#[test]
+fn validate_lengths() {
+    let math = MathMl::with_content(alemat::children![
+        Space::default().with_attr([SpaceAttr::Width("2 em".into())]),
+        Padded::from([Num::from(1)]).with_attr([
+            PaddedAttr::Width("infem".into()),
+            PaddedAttr::LeftSpace("-1width".into()),
+        ]),
+        Space::default().with_attr([
+            SpaceAttr::Width("+1em".into()),
+            SpaceAttr::Height("2height".into()),
+            SpaceAttr::Depth("-1em".into()),
+        ]),
+    ])
+    .with_attr([MathMlAttr::Global(Attribute::MathSize("big".into()))]);
+
+    let diagnostics: Vec<_> = math.validate().iter().map(ToString::to_string).collect();
+
+    assert_eq!(
+        diagnostics,
+        [
+            "error: invalid length `big` of attribute `mathsize` at /math[0]",
+            "error: invalid length `2 em` of attribute `width` at /math[0]/mspace[0]",
+            "error: invalid length `infem` of attribute `width` at /math[0]/mpadded[1]",
+            "error: invalid length `+1em` of attribute `width` at /math[0]/mspace[2]",
+            "error: invalid length `2height` of attribute `height` at /math[0]/mspace[2]",
+        ]
+    );
+}
+
+#[test]
+fn write_lengths() {
+    let math = MathMl::with_content(alemat::children![
+        Space::default().with_attr([SpaceAttr::Width(Length::em(0.5).into())]),
+        Padded::from([Num::from(1)]).with_attr([
+            PaddedAttr::Width(Length::relative(1.0, Unit::Width).into()),
+            PaddedAttr::LeftSpace(Length::named(NamedSpace::Thin).into()),
+        ]),
+        Frac::builder()
+            .num(Num::from(1))
+            .denom(Num::from(2))
+            .attr([FracAttr::LineThickness(Length::px(2.0).into())])
+            .build(),
+    ])
+    .with_attr([MathMlAttr::Global(Attribute::MathSize(
+        Length::percent(120.0).into(),
+    ))]);
+
+    let out = math
+        .write(&mut BufMathMlWriter::default())
+        .unwrap()
+        .finish();
+
+    assert_eq!(
+        out,
+        concat!(
+            r#"<math mathsize="120%">"#,
+            r#"<mspace width="0.5em"/>"#,
+            r#"<mpadded width="+1width" lspace="thinmathspace"><mn>1</mn></mpadded>"#,
+            r#"<mfrac linethickness="2px"><mn>1</mn><mn>2</mn></mfrac>"#,
+            "</math>"
+        )
+    );
+}
+
+#[test]
+fn write_invalid_is_escaped() {
+    let space = Space::default().with_attr([SpaceAttr::Width("<1em>".into())]);
+
+    let mut writer = BufMathMlWriter::default();
+    writer.write_space(&space).unwrap();
+
+    assert_eq!(writer.finish(), r#"<mspace width="&lt;1em&gt;"/>"#);
+}
@@ -0,0 +1,218 @@
+use alemat::{
+    attributes::{Attribute, MathVariant},
+    children,
+    elements::{
+        grouping::{Phantom, Prescripts, Row},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, Frac, FracAttr, Ident, Num, Operator, Semantics, Text,
+    },
+    linear::{Fallback, LinearFormatter},
+    table, Element, MathMl,
+};
+
+fn unicode(content: impl Into<Element>) -> String {
+    MathMl::with_content([content.into()])
+        .render_with(&mut LinearFormatter::default())
+        .unwrap()
+}
+
+fn ascii(content: impl Into<Element>) -> String {
+    MathMl::with_content([content.into()])
+        .render_with(&mut LinearFormatter::with_fallback(Fallback::AsciiMath))
+        .unwrap()
+}
+
+fn sum() -> Row {
+    Row::from(children![
+        Ident::from("a"),
+        Operator::plus(),
+        Ident::from("b")
+    ])
+}
+
+#[test]
+fn scripts() {
+    let square = SubSup::builder()
+        .base(Ident::from("x"))
+        .subscript(Ident::from("i"))
+        .supscript(Num::from(2))
+        .build();
+    assert_eq!(unicode(square), "xᵢ²");
+
+    let nested = SubSup::builder()
+        .base(sum())
+        .supscript(
+            SubSup::builder()
+                .base(Ident::from("x"))
+                .supscript(Num::from(2))
+                .build(),
+        )
+        .build();
+    assert_eq!(unicode(nested), "(a+b)^(x²)");
+
+    let limits = UnderOver::builder()
+        .expr(Operator::sum())
+        .under(children![Ident::from("i"), Operator::eq(), Num::from(0)])
+        .over(Ident::from("n"))
+        .build();
+    let math = MathMl::with_content(children![limits, Ident::from("i")]);
+    assert_eq!(
+        math.render_with(&mut LinearFormatter::default()).unwrap(),
+        "∑_(i=0)^n i"
+    );
+
+    let multiscripts = Multiscripts::builder()
+        .content(children![
+            Ident::from("X"),
+            Num::from(1),
+            Row::default(),
+            Prescripts::default(),
+            Ident::from("k"),
+            Ident::from("q"),
+        ])
+        .build();
+    assert_eq!(unicode(multiscripts.clone()), "(_k^q)X₁");
+    assert_eq!(ascii(multiscripts), "{::}_k^qX₁");
+}
+
+#[test]
+fn fractions_and_roots() {
+    let frac = Frac::builder().num(sum()).denom(Ident::from("c")).build();
+    assert_eq!(unicode(frac), "(a+b)/c");
+
+    let half = Frac::builder()
+        .num(Num::from(1))
+        .denom(Num::from(2))
+        .build();
+    assert_eq!(unicode(half.clone()), "½");
+
+    // fractions after a letter or digit are grouped, and mixed numbers are avoided
+    let product = |factor: Element, frac: Frac| {
+        Row::from(children![factor, Operator::from("\u{2062}"), frac])
+    };
+
+    let frac = Frac::builder()
+        .num(Ident::from("b"))
+        .denom(Ident::from("c"))
+        .build();
+    assert_eq!(unicode(product(Ident::from("a").into(), frac)), "a(b/c)");
+    assert_eq!(
+        unicode(product(Num::from(2).into(), half.clone())),
+        "2(1/2)"
+    );
+    assert_eq!(unicode(product(Ident::from("a").into(), half)), "a½");
+
+    let binom = Row::from(children![
+        Operator::lparens(),
+        Frac::builder()
+            .num(Ident::from("n"))
+            .denom(Ident::from("k"))
+            .attr([FracAttr::LineThickness("0".into())])
+            .build(),
+        Operator::rparens(),
+    ]);
+    assert_eq!(unicode(binom.clone()), "(n¦k)");
+    assert_eq!(ascii(binom), "((n),(k))");
+
+    let sqrt = Radical::builder()
+        .index(Num::from(2))
+        .content(sum())
+        .build();
+    assert_eq!(unicode(sqrt), "√(a+b)");
+
+    let cbrt = Radical::builder()
+        .index(Num::from(3))
+        .content(Ident::from("x"))
+        .build();
+    assert_eq!(unicode(cbrt), "∛x");
+
+    let root = Radical::builder()
+        .index(Ident::from("n"))
+        .content(Ident::from("x"))
+        .build();
+    assert_eq!(unicode(root.clone()), "√(n&x)");
+    assert_eq!(ascii(root), "root(n)(x)");
+}
+
+#[test]
+fn accents() {
+    let hat = |expr: Element| {
+        UnderOver::builder()
+            .expr([expr])
+            .over(Operator::hat())
+            .attr([UnderOverAttr::AccentOver])
+            .build()
+    };
+
+    assert_eq!(unicode(hat(Ident::from("x").into())), "x\u{0302}");
+    assert_eq!(unicode(hat(sum().into())), "(a+b)\u{0302}");
+    assert_eq!(ascii(hat(sum().into())), "hat(a+b)");
+
+    let overset = UnderOver::builder()
+        .expr(Operator::eq())
+        .over(Operator::from("!"))
+        .build();
+    assert_eq!(unicode(overset.clone()), "=┴!");
+    assert_eq!(ascii(overset), "overset(!)(=)");
+}
+
+#[test]
+fn tables() {
+    let matrix = table![
+        [Ident::from("a"), Ident::from("b")],
+        [Ident::from("c"), Ident::from("d")]
+    ];
+
+    assert_eq!(unicode(matrix.clone()), "■(a&b@c&d)");
+    assert_eq!(ascii(matrix.clone()), "{:(a,b),(c,d):}");
+
+    let bracketed = Row::from(children![
+        Operator::lbracket(),
+        matrix,
+        Operator::rbracket()
+    ]);
+    assert_eq!(unicode(bracketed.clone()), "[■(a&b@c&d)]");
+    assert_eq!(ascii(bracketed), "[(a,b),(c,d)]");
+}
+
+#[test]
+fn variants_and_text() {
+    let reals = Ident::builder()
+        .ident("R")
+        .attr([Attribute::MathVariant(MathVariant::DoubleStruck)])
+        .build();
+    assert_eq!(unicode(reals), "ℝ");
+
+    let bold = Num::from_parts("12", vec![Attribute::MathVariant(MathVariant::Bold)]);
+    assert_eq!(unicode(bold), "𝟏𝟐");
+
+    let math = MathMl::with_content(children![
+        Ident::from("sin"),
+        Operator::from("\u{2061}"),
+        Ident::from("x"),
+        Text::from(" if "),
+        Ident::from("x"),
+        Operator::gt(),
+        Num::from(0),
+    ]);
+    assert_eq!(
+        math.render_with(&mut LinearFormatter::default()).unwrap(),
+        "sin x\" if \"x>0"
+    );
+}
+
+#[test]
+fn invisible_elements() {
+    let semantics = Semantics::builder()
+        .content(children![
+            Ident::from("x"),
+            Annotation::builder().content(String::from("x")).build(),
+        ])
+        .build();
+    assert_eq!(unicode(semantics), "x");
+
+    let phantom = Phantom::from([Ident::from("x")]);
+    assert_eq!(unicode(phantom.clone()), "⟡(x)");
+    assert_eq!(ascii(phantom), "");
+}
@@ -0,0 +1,115 @@
+use alemat::{
+    children,
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver},
+        Frac, Ident, Num, Operator, Text,
+    },
+    math, table, Element, MathMl,
+};
+
+#[test]
+fn scripts() {
+    let x_i = SubSup::builder()
+        .base(Ident::from("x"))
+        .subscript(Ident::from("i"))
+        .build();
+
+    assert_eq!(math! { x_i }, [Element::from(x_i.clone())]);
+    assert_eq!(math! { x _ i }, [Element::from(x_i)]);
+
+    let expected = SubSup::builder()
+        .base(Ident::from("x"))
+        .subscript(children![Ident::from("i"), Operator::plus(), Num::from(1)])
+        .supscript(Num::from(2))
+        .build();
+
+    assert_eq!(math! { x_(i+1)^2 }, [Element::from(expected.clone())]);
+    assert_eq!(math! { x^2_{i+1} }, [Element::from(expected)]);
+
+    let expected = UnderOver::builder()
+        .expr(Ident::from("lim"))
+        .under(children![
+            Ident::from("n"),
+            Operator::from("→"),
+            Ident::infinity()
+        ])
+        .build();
+
+    assert_eq!(math! { lim_(n -> infinity) }, [Element::from(expected)]);
+
+    let expected = SubSup::builder()
+        .base(Operator::integral())
+        .subscript(Num::from(0))
+        .supscript(Num::from(1))
+        .build();
+
+    assert_eq!(math! { int_0^1 }, [Element::from(expected)]);
+}
+
+#[test]
+fn layout() {
+    let expected = children![
+        Frac::builder()
+            .num(children![Num::from(1), Operator::plus(), Ident::from("x")])
+            .denom(
+                Radical::builder()
+                    .index(Num::from(2))
+                    .content(Ident::from("x"))
+                    .build()
+            )
+            .build(),
+        Operator::from("≤"),
+        Row::from(children![
+            Operator::lparens(),
+            Ident::alpha(),
+            Operator::from(","),
+            Ident::beta(),
+            Operator::rparens(),
+        ]),
+        Row::from(children![Text::from("if"), Ident::from("x")]),
+    ];
+
+    assert_eq!(
+        math! { frac(1 + x, sqrt(x)) <= (alpha, beta) {"if" x} },
+        expected
+    );
+
+    let expected = table![
+        [Ident::from("a"), Ident::from("b")],
+        [Ident::from("c"), Ident::from("d")]
+    ];
+
+    assert_eq!(math! { table[[a, b], [c, d]] }, [Element::from(expected)]);
+}
+
+#[test]
+fn interpolation() {
+    let values = [1, 2, 3];
+    let sum = values.iter().sum::<i32>();
+
+    let elements = math! { #{values[0]} + #{Ident::from("y")}^#{sum} };
+
+    assert_eq!(
+        elements,
+        children![
+            Num::from(1),
+            Operator::plus(),
+            SubSup::builder()
+                .base(Ident::from("y"))
+                .supscript(Num::from(6))
+                .build(),
+        ]
+    );
+
+    let math = MathMl::with_content(math! { e^(i pi) = -1 });
+
+    assert_eq!(
+        math.render().unwrap(),
+        concat!(
+            "<math><msup><mi>e</mi><mrow><mi>i</mi><mi>𝜋</mi></mrow></msup>",
+            "<mo>=</mo><mo>-</mo><mn>1</mn></math>",
+        )
+    );
+}
@@ -1,7 +1,33 @@
+#[cfg(feature = "asciimath")]
+mod asciimath;
+mod braille;
+mod color;
+mod content;
+mod editing;
+mod escaping;
+mod expr;
 mod grouping;
+mod io_writer;
+#[cfg(feature = "latex")]
+mod latex;
+mod length;
+mod linear;
+#[cfg(feature = "macros")]
+mod macros;
+mod omml;
+mod ops;
 mod others;
+mod parser;
+mod pretty_writer;
 mod radicals;
 mod scripted;
+mod speech;
+mod text_art;
+mod typst;
+#[cfg(feature = "unicodemath")]
+mod unicodemath;
+mod validate;
+mod visit;
 
 macro_rules! snap_test {
     ($input:expr $(, name: $name:expr)?) => {
@@ -0,0 +1,483 @@
+use alemat::{
+    attributes::{Attribute, MathVariant},
+    children,
+    elements::{
+        grouping::{Row, Style},
+        radicals::Radical,
+        scripted::{SubSup, UnderOver, UnderOverAttr},
+        Frac, FracAttr, Ident, Num, OpForm, Operator, OperatorAttr, Table, TableCell, TableRow,
+        Text,
+    },
+    omml::{self, OmmlFormatter},
+    table, DisplayAttr, Element, MathMl, MathMlAttr, ParseErrorKind,
+};
+
+/// Render the math and strip the `m:oMath` element around the content.
+fn omml(math: &MathMl) -> String {
+    let omml = math.render_with(&mut OmmlFormatter::default()).unwrap();
+
+    omml.strip_prefix(
+        r#"<m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">"#,
+    )
+    .and_then(|omml| omml.strip_suffix("</m:oMath>"))
+    .unwrap()
+    .to_string()
+}
+
+fn run(text: &str) -> String {
+    format!("<m:r><m:t>{text}</m:t></m:r>")
+}
+
+#[test]
+fn fractions_and_roots() {
+    let math = MathMl::with_content(children![
+        Frac::builder()
+            .num(Ident::from("a"))
+            .denom(Num::from(2))
+            .build(),
+        Frac::builder()
+            .num(Ident::from("n"))
+            .denom(Ident::from("k"))
+            .attr([FracAttr::LineThickness("0".into())])
+            .build(),
+        Radical::builder()
+            .index(Num::from(2))
+            .content(Ident::from("x"))
+            .build(),
+        Radical::builder()
+            .index(Num::from(3))
+            .content(Ident::from("y"))
+            .build(),
+    ]);
+
+    assert_eq!(
+        omml(&math),
+        [
+            format!("<m:f><m:num>{}</m:num><m:den>{}</m:den></m:f>", run("a"), run("2")),
+            format!(
+                r#"<m:f><m:fPr><m:type m:val="noBar"/></m:fPr><m:num>{}</m:num><m:den>{}</m:den></m:f>"#,
+                run("n"),
+                run("k")
+            ),
+            format!(
+                r#"<m:rad><m:radPr><m:degHide m:val="1"/></m:radPr><m:deg/><m:e>{}</m:e></m:rad>"#,
+                run("x")
+            ),
+            format!(
+                "<m:rad><m:deg>{}</m:deg><m:e>{}</m:e></m:rad>",
+                run("3"),
+                run("y")
+            ),
+        ]
+        .concat()
+    );
+}
+
+#[test]
+fn scripts_and_nary() {
+    let scripts = MathMl::with_content(children![
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .supscript(Num::from(2))
+            .build(),
+        SubSup::builder()
+            .base(Ident::from("a"))
+            .subscript(Ident::from("i"))
+            .supscript(Num::from(3))
+            .build(),
+    ]);
+
+    assert_eq!(
+        omml(&scripts),
+        format!(
+            "<m:sSup><m:e>{}</m:e><m:sup>{}</m:sup></m:sSup><m:sSubSup><m:e>{}</m:e><m:sub>{}</m:sub><m:sup>{}</m:sup></m:sSubSup>",
+            run("x"),
+            run("2"),
+            run("a"),
+            run("i"),
+            run("3"),
+        )
+    );
+
+    let sum = MathMl::with_content(children![
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(children![Ident::from("i"), Operator::eq(), Num::from(1)])
+            .over(Ident::from("n"))
+            .build(),
+        Ident::from("i"),
+        Operator::plus(),
+        Num::from(1),
+    ]);
+
+    assert_eq!(
+        omml(&sum),
+        format!(
+            r#"<m:nary><m:naryPr><m:chr m:val="∑"/><m:limLoc m:val="undOvr"/></m:naryPr><m:sub>{}{}{}</m:sub><m:sup>{}</m:sup><m:e>{}</m:e></m:nary>{}{}"#,
+            run("i"),
+            run("="),
+            run("1"),
+            run("n"),
+            run("i"),
+            run("+"),
+            run("1"),
+        )
+    );
+
+    let integral = MathMl::with_content(children![
+        SubSup::builder()
+            .base(Operator::integral())
+            .subscript(Num::from(0))
+            .build(),
+        Ident::from("f"),
+        Operator::from("("),
+        Ident::from("x"),
+        Operator::from(")"),
+    ]);
+
+    assert_eq!(
+        omml(&integral),
+        format!(
+            r#"<m:nary><m:naryPr><m:chr m:val="∫"/><m:limLoc m:val="subSup"/><m:supHide m:val="1"/></m:naryPr><m:sub>{}</m:sub><m:sup/><m:e>{}<m:d><m:dPr><m:begChr m:val="("/><m:endChr m:val=")"/></m:dPr><m:e>{}</m:e></m:d></m:e></m:nary>"#,
+            run("0"),
+            run("f"),
+            run("x"),
+        )
+    );
+}
+
+#[test]
+fn delimiters_and_matrices() {
+    let interval = MathMl::with_content(children![
+        Operator::from("["),
+        Num::from(0),
+        Operator::from(","),
+        Num::from(1),
+        Operator::from(")"),
+    ]);
+
+    assert_eq!(
+        omml(&interval),
+        format!(
+            r#"<m:d><m:dPr><m:begChr m:val="["/><m:endChr m:val=")"/></m:dPr><m:e>{}{}{}</m:e></m:d>"#,
+            run("0"),
+            run(","),
+            run("1"),
+        )
+    );
+
+    let matrix = MathMl::with_content(children![
+        Operator::from("("),
+        table![[Num::from(1), Num::from(0)], [Num::from(0), Num::from(1)]],
+        Operator::from(")"),
+    ]);
+
+    assert_eq!(
+        omml(&matrix),
+        format!(
+            r#"<m:d><m:dPr><m:begChr m:val="("/><m:endChr m:val=")"/></m:dPr><m:e><m:m><m:mPr><m:mcs><m:mc><m:mcPr><m:count m:val="2"/><m:mcJc m:val="center"/></m:mcPr></m:mc></m:mcs></m:mPr><m:mr><m:e>{}</m:e><m:e>{}</m:e></m:mr><m:mr><m:e>{}</m:e><m:e>{}</m:e></m:mr></m:m></m:e></m:d>"#,
+            run("1"),
+            run("0"),
+            run("0"),
+            run("1"),
+        )
+    );
+
+    // unmatched fences are written as text
+    let open = MathMl::with_content(children![Operator::from("("), Ident::from("x")]);
+    assert_eq!(omml(&open), [run("("), run("x")].concat());
+}
+
+#[test]
+fn variants_and_accents() {
+    let math = MathMl::with_content(children![
+        Ident::builder()
+            .ident("v")
+            .attr([Attribute::MathVariant(MathVariant::Bold)])
+            .build(),
+        Style::from([Ident::from("R")])
+            .with_attr([Attribute::MathVariant(MathVariant::DoubleStruck)]),
+        Ident::from("sin"),
+        Text::from(" if "),
+        UnderOver::builder()
+            .expr(Ident::from("x"))
+            .over(Operator::from("^"))
+            .build(),
+        UnderOver::builder()
+            .expr(Ident::from("z"))
+            .over(Operator::from("¯"))
+            .build(),
+    ]);
+
+    assert_eq!(
+        omml(&math),
+        format!(
+            concat!(
+                r#"<m:r><m:rPr><m:sty m:val="b"/></m:rPr><m:t>v</m:t></m:r>"#,
+                r#"<m:r><m:rPr><m:scr m:val="double-struck"/><m:sty m:val="p"/></m:rPr><m:t>R</m:t></m:r>"#,
+                r#"<m:r><m:rPr><m:sty m:val="p"/></m:rPr><m:t>sin</m:t></m:r>"#,
+                r#"<m:r><m:rPr><m:nor/></m:rPr><m:t xml:space="preserve"> if </m:t></m:r>"#,
+                "<m:acc><m:accPr><m:chr m:val=\"\u{302}\"/></m:accPr><m:e>{}</m:e></m:acc>",
+                r#"<m:bar><m:barPr><m:pos m:val="top"/></m:barPr><m:e>{}</m:e></m:bar>"#,
+            ),
+            run("x"),
+            run("z"),
+        )
+    );
+
+    let block = MathMl::with_content(children![Ident::from("x"), Operator::from("<")])
+        .with_attr([MathMlAttr::Display(DisplayAttr::Block)]);
+
+    assert_eq!(
+        block.render_with(&mut OmmlFormatter::default()).unwrap(),
+        format!(
+            r#"<m:oMathPara xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math"><m:oMath>{}{}</m:oMath></m:oMathPara>"#,
+            run("x"),
+            run("&lt;"),
+        )
+    );
+}
+
+/// Wrap the OMML content into an `m:oMath` element.
+fn equation(content: &str) -> String {
+    format!(
+        r#"<m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">{content}</m:oMath>"#
+    )
+}
+
+fn fence(op: &str, form: OpForm) -> Element {
+    Operator::builder()
+        .op(op)
+        .attr([
+            OperatorAttr::Stretchy,
+            OperatorAttr::Fence,
+            OperatorAttr::Form(form),
+        ])
+        .build()
+        .into()
+}
+
+#[test]
+fn parse_runs() {
+    let math = omml::parse(&equation(concat!(
+        "<m:r><m:t>2x+12.5</m:t></m:r>",
+        r#"<m:r><m:rPr><m:sty m:val="p"/></m:rPr><m:t>sin</m:t></m:r>"#,
+        r#"<m:r><m:rPr><m:sty m:val="b"/></m:rPr><m:t>v</m:t></m:r>"#,
+        r#"<m:r><m:rPr><m:scr m:val="double-struck"/><m:sty m:val="p"/></m:rPr><m:t>R</m:t></m:r>"#,
+        r#"<m:r><m:rPr><m:nor/></m:rPr><m:t xml:space="preserve"> if </m:t></m:r>"#,
+    )))
+    .unwrap();
+
+    let variant = |ident: &str, variant: MathVariant| {
+        Ident::builder()
+            .ident(ident)
+            .attr([Attribute::MathVariant(variant)])
+            .build()
+    };
+
+    assert_eq!(
+        math,
+        MathMl::with_content(children![
+            Num::from(2),
+            Ident::from("x"),
+            Operator::plus(),
+            Num::from("12.5"),
+            Ident::from("sin"),
+            variant("v", MathVariant::Bold),
+            variant("R", MathVariant::DoubleStruck),
+            Text::from(" if "),
+        ])
+    );
+}
+
+#[test]
+fn parse_structures() {
+    let elements = omml::parse_elements(&equation(concat!(
+        "<m:f><m:num><m:r><m:t>1</m:t></m:r></m:num><m:den><m:r><m:t>x</m:t></m:r></m:den></m:f>",
+        r#"<m:rad><m:radPr><m:degHide m:val="1"/></m:radPr><m:deg/><m:e><m:r><m:t>y</m:t></m:r></m:e></m:rad>"#,
+        "<m:rad><m:deg><m:r><m:t>3</m:t></m:r></m:deg><m:e><m:r><m:t>z</m:t></m:r></m:e></m:rad>",
+        "<m:sSubSup><m:e><m:r><m:t>a</m:t></m:r></m:e><m:sub><m:r><m:t>i</m:t></m:r></m:sub><m:sup><m:r><m:t>2</m:t></m:r></m:sup></m:sSubSup>",
+        r#"<m:acc><m:accPr><m:chr m:val="̂"/></m:accPr><m:e><m:r><m:t>u</m:t></m:r></m:e></m:acc>"#,
+        r#"<m:func><m:fName><m:r><m:rPr><m:sty m:val="p"/></m:rPr><m:t>log</m:t></m:r></m:fName><m:e><m:r><m:t>n</m:t></m:r></m:e></m:func>"#,
+    )))
+    .unwrap();
+
+    assert_eq!(
+        &elements[..],
+        children![
+            Frac::builder()
+                .num(Num::from(1))
+                .denom(Ident::from("x"))
+                .build(),
+            Radical::builder()
+                .index(Num::from(2))
+                .content(Ident::from("y"))
+                .build(),
+            Radical::builder()
+                .index(Num::from(3))
+                .content(Ident::from("z"))
+                .build(),
+            SubSup::builder()
+                .base(Ident::from("a"))
+                .subscript(Ident::from("i"))
+                .supscript(Num::from(2))
+                .build(),
+            UnderOver::builder()
+                .expr(Ident::from("u"))
+                .over(Operator::from("^"))
+                .attr([UnderOverAttr::AccentOver])
+                .build(),
+            Ident::from("log"),
+            Operator::from("\u{2061}"),
+            Ident::from("n"),
+        ]
+    );
+}
+
+#[test]
+fn parse_nary_delimiters_and_matrices() {
+    let sum = omml::parse_elements(concat!(
+        r#"<m:nary><m:naryPr><m:chr m:val="∑"/><m:limLoc m:val="undOvr"/></m:naryPr>"#,
+        "<m:sub><m:r><m:t>i=1</m:t></m:r></m:sub><m:sup><m:r><m:t>n</m:t></m:r></m:sup>",
+        "<m:e><m:r><m:t>i</m:t></m:r></m:e></m:nary>",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        &sum[..],
+        children![
+            UnderOver::builder()
+                .expr(Operator::sum())
+                .under(children![Ident::from("i"), Operator::eq(), Num::from(1)])
+                .over(Ident::from("n"))
+                .build(),
+            Ident::from("i"),
+        ]
+    );
+
+    // integrals default to limits placed as scripts
+    let integral = omml::parse_elements(concat!(
+        r#"<m:nary><m:naryPr><m:supHide m:val="1"/></m:naryPr>"#,
+        "<m:sub><m:r><m:t>0</m:t></m:r></m:sub><m:sup/><m:e><m:r><m:t>f</m:t></m:r></m:e></m:nary>",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        &integral[..],
+        children![
+            SubSup::builder()
+                .base(Operator::integral())
+                .subscript(Num::from(0))
+                .build(),
+            Ident::from("f"),
+        ]
+    );
+
+    let matrix = omml::parse_elements(concat!(
+        r#"<m:d><m:dPr><m:begChr m:val="["/><m:endChr m:val="]"/></m:dPr><m:e>"#,
+        "<m:m><m:mr><m:e><m:r><m:t>1</m:t></m:r></m:e><m:e><m:r><m:t>0</m:t></m:r></m:e></m:mr>",
+        "<m:mr><m:e><m:r><m:t>0</m:t></m:r></m:e><m:e><m:r><m:t>1</m:t></m:r></m:e></m:mr></m:m>",
+        "</m:e></m:d>",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        &matrix[..],
+        children![Row::from([
+            fence("[", OpForm::Prefix),
+            table![[Num::from(1), Num::from(0)], [Num::from(0), Num::from(1)]].into(),
+            fence("]", OpForm::Postfix),
+        ])]
+    );
+
+    // multiple arguments are separated with the separator character
+    let set = omml::parse_elements(concat!(
+        r#"<m:d><m:dPr><m:begChr m:val="{"/><m:sepChr m:val=","/><m:endChr m:val="}"/></m:dPr>"#,
+        "<m:e><m:r><m:t>a</m:t></m:r></m:e><m:e><m:r><m:t>b</m:t></m:r></m:e></m:d>",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        &set[..],
+        children![Row::from([
+            fence("{", OpForm::Prefix),
+            Ident::from("a").into(),
+            Operator::builder()
+                .op(",")
+                .attr([OperatorAttr::Separator])
+                .build()
+                .into(),
+            Ident::from("b").into(),
+            fence("}", OpForm::Postfix),
+        ])]
+    );
+}
+
+#[test]
+fn parse_equation_arrays() {
+    let math = omml::parse(concat!(
+        r#"<m:oMathPara xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math"><m:oMath>"#,
+        "<m:eqArr><m:e><m:r><m:t>x&amp;=1</m:t></m:r></m:e><m:e><m:r><m:t>y&amp;=2</m:t></m:r></m:e></m:eqArr>",
+        "</m:oMath></m:oMathPara>",
+    ))
+    .unwrap();
+
+    let row = |ident: &str, num: u32| {
+        TableRow::from_iter([
+            TableCell::from([Ident::from(ident)])
+                .with_attr([Attribute::Style("text-align: right".into())]),
+            TableCell::from([Element::from(Operator::eq()), Num::from(num).into()])
+                .with_attr([Attribute::Style("text-align: left".into())]),
+        ])
+    };
+
+    assert_eq!(
+        math,
+        MathMl::with_content(children![Table::from_iter([row("x", 1), row("y", 2)])])
+            .with_attr([MathMlAttr::Display(DisplayAttr::Block)])
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let error = omml::parse("<m:f/>").unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ParseErrorKind::UnexpectedElement { .. }
+    ));
+
+    let error = omml::parse(&equation("<m:unknown/>")).unwrap_err();
+    assert_eq!(
+        error.kind(),
+        &ParseErrorKind::UnknownElement(String::from("m:unknown"))
+    );
+
+    let error = omml::parse(&equation("text")).unwrap_err();
+    assert_eq!(
+        error.kind(),
+        &ParseErrorKind::UnexpectedText(String::from("text"))
+    );
+}
+
+#[test]
+fn round_trip() {
+    let math = MathMl::with_content(children![
+        Frac::builder()
+            .num(children![Ident::from("a"), Operator::plus(), Num::from(1)])
+            .denom(
+                SubSup::builder()
+                    .base(Ident::from("b"))
+                    .supscript(Num::from(2))
+                    .build()
+            )
+            .build(),
+        Radical::builder()
+            .index(Num::from(2))
+            .content(Ident::from("x"))
+            .build(),
+        Ident::from("sin"),
+    ]);
+
+    let omml = math.render_with(&mut OmmlFormatter::default()).unwrap();
+    assert_eq!(omml::parse(&omml).unwrap(), math);
+}
@@ -0,0 +1,135 @@
+use alemat::{
+    attributes::Attribute,
+    children,
+    elements::{grouping::Row, radicals::Radical, scripted::SubSup, Frac, Ident, Num, Operator},
+    ops::Compose,
+    Element,
+};
+
+fn x() -> Ident {
+    Ident::from("x")
+}
+
+fn y() -> Ident {
+    Ident::from("y")
+}
+
+#[test]
+fn arithmetic() {
+    // x + 1 - y
+    assert_eq!(
+        x() + 1 - y(),
+        Row::from(children![
+            Ident::from("x"),
+            Operator::plus(),
+            Num::from(1),
+            Operator::minus(),
+            Ident::from("y"),
+        ])
+    );
+
+    // 2x
+    assert_eq!(
+        Num::from(2) * x(),
+        Row::from(children![
+            Num::from(2),
+            Operator::from("\u{2062}"),
+            Ident::from("x")
+        ])
+    );
+
+    // -x
+    assert_eq!(
+        -Element::from(x()),
+        Row::from(children![Operator::minus(), Ident::from("x")])
+    );
+
+    // x - (x + y), the right operand is kept as nested row
+    assert_eq!(
+        x() - (x() + y()),
+        Row::from(children![
+            Ident::from("x"),
+            Operator::minus(),
+            Row::from(children![
+                Ident::from("x"),
+                Operator::plus(),
+                Ident::from("y")
+            ]),
+        ])
+    );
+
+    // rows with attributes are not extended
+    let styled = Row::from([x()]).with_attr(std::iter::once(Attribute::Id(String::from("a"))));
+
+    assert_eq!(
+        styled.clone() + 1,
+        Row::from(children![styled, Operator::plus(), Num::from(1)])
+    );
+}
+
+#[test]
+fn layout() {
+    // (x + 1) / y^2
+    assert_eq!(
+        (x() + 1).paren() / (y() ^ 2),
+        Frac::builder()
+            .num(Row::from(children![
+                Operator::lparens(),
+                Row::from(children![Ident::from("x"), Operator::plus(), Num::from(1)]),
+                Operator::rparens(),
+            ]))
+            .denom(
+                SubSup::builder()
+                    .base(Ident::from("y"))
+                    .supscript(Num::from(2))
+                    .build()
+            )
+            .build()
+    );
+
+    assert_eq!(
+        (x() + 1).sqrt(),
+        Radical::builder()
+            .index(Num::from(2))
+            .content(Row::from(children![
+                Ident::from("x"),
+                Operator::plus(),
+                Num::from(1)
+            ]))
+            .build()
+    );
+}
+
+#[test]
+fn scripts() {
+    let expected = SubSup::builder()
+        .base(Ident::from("x"))
+        .subscript(Ident::from("i"))
+        .supscript(Num::from(2))
+        .build();
+
+    assert_eq!(x().sub(Ident::from("i")).sup(2), expected);
+    assert_eq!((x() ^ 2).sub(Ident::from("i")), expected);
+
+    // a second superscript nests the scripts
+    let nested = (x() ^ 2).sup(3);
+
+    assert_eq!(
+        nested,
+        SubSup::builder()
+            .base(
+                SubSup::builder()
+                    .base(Ident::from("x"))
+                    .supscript(Num::from(2))
+                    .build()
+            )
+            .supscript(Num::from(3))
+            .build()
+    );
+
+    // the accessors of the element remain available through references
+    assert_eq!(
+        SubSup::sub(&expected),
+        Some(&[Element::from(Ident::from("i"))][..])
+    );
+}
@@ -0,0 +1,402 @@
+use alemat::{
+    attributes::{Attribute, Dir, MathVariant, ScriptLevel},
+    children,
+    elements::{
+        grouping::{Action, ActionAttr, Error, Phantom, Prescripts, Row, Style},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Annotation, AnnotationAttr, ColumnLine, Element, Frac, FracAttr, Ident, Num, OpForm,
+        Operator, OperatorAttr, Padded, PaddedAttr, Semantics, Space, SpaceAttr, StrLiteral,
+        TableAttr, TableCell, TableCellAttr, Text,
+    },
+    DisplayAttr, MathMl, MathMlAttr, ParseErrorKind,
+};
+
+fn roundtrip(math: MathMl) {
+    let rendered = math.render().expect("Failed to render MathMl.");
+    let parsed = MathMl::parse(&rendered).expect("Failed to parse MathMl.");
+
+    assert_eq!(parsed, math, "{rendered}");
+}
+
+#[test]
+fn roundtrip_elements() {
+    roundtrip(MathMl::with_content(children![
+        Action::builder()
+            .content(children![Ident::from("x"), Ident::from("y")])
+            .attr([
+                ActionAttr::ActionType("toggle".into()),
+                ActionAttr::Selection("2".into())
+            ])
+            .build(),
+        Error::builder().content(Text::from("oops")).build(),
+        Frac::builder()
+            .num(Ident::from("a"))
+            .denom(Num::from(2))
+            .attr([FracAttr::LineThickness("0".into())])
+            .build(),
+        Multiscripts::builder()
+            .content(children![
+                Ident::from("R"),
+                Ident::from("i"),
+                Row::default(),
+                Prescripts::default(),
+                Num::from(1),
+                Num::from(2),
+            ])
+            .build(),
+        Operator::builder()
+            .op("(")
+            .attr([
+                OperatorAttr::Form(OpForm::Prefix),
+                OperatorAttr::Fence,
+                OperatorAttr::Stretchy,
+                OperatorAttr::LeftSpace("0.1em".into()),
+            ])
+            .build(),
+        Padded::from([Ident::from("x")]).with_attr([
+            PaddedAttr::Width("+1em".into()),
+            PaddedAttr::VerticalOffset("-2px".into()),
+        ]),
+        Phantom::from([Num::from(0)]),
+        Radical::builder()
+            .index(Num::from(2))
+            .content(children![Num::from(1), Operator::plus(), Ident::from("x")])
+            .build(),
+        Radical::builder()
+            .index(Num::from(3))
+            .content(Ident::from("y"))
+            .build(),
+        Semantics::builder()
+            .content(children![
+                Ident::from("x"),
+                Annotation::builder()
+                    .content(String::from("x"))
+                    .attr([AnnotationAttr::Encoding("application/x-tex".into())])
+                    .build(),
+                Annotation::builder()
+                    .content(children![Ident::from("x")])
+                    .attr([AnnotationAttr::Encoding("MathML-Content".into())])
+                    .build(),
+            ])
+            .build(),
+        Space::default().with_attr([SpaceAttr::Width("1em".into())]),
+        StrLiteral::from("string"),
+        Style::from([Ident::from("s")]).with_attr([Attribute::MathColor("red".into())]),
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .subscript(Num::from(0))
+            .build(),
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .supscript(Num::from(1))
+            .build(),
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .subscript(Num::from(0))
+            .supscript(Num::from(1))
+            .build(),
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(Ident::from("i"))
+            .over(Ident::from("n"))
+            .build(),
+        UnderOver::builder()
+            .expr(Ident::from("x"))
+            .over(Operator::hat())
+            .attr([UnderOverAttr::AccentOver])
+            .build(),
+        UnderOver::builder()
+            .expr(Ident::from("x"))
+            .under(Operator::ubrace())
+            .build(),
+        alemat::table![[Num::from(1), Num::from(2)], [Num::from(3), Num::from(4)]]
+            .with_attr([TableAttr::ColumnLines(vec![ColumnLine::Solid])]),
+    ]));
+}
+
+#[test]
+fn roundtrip_attributes() {
+    let global = [
+        Attribute::Class("cls".into()),
+        Attribute::Data {
+            name: "key".into(),
+            value: "value".into(),
+        },
+        Attribute::Dir(Dir::RightToLeft),
+        Attribute::DisplayStyle(true),
+        Attribute::DisplayStyle(false),
+        Attribute::Id("id".into()),
+        Attribute::MathBackground("#fff".into()),
+        Attribute::MathColor("blue".into()),
+        Attribute::MathSize("2em".into()),
+        Attribute::MathVariant(MathVariant::DoubleStruck),
+        Attribute::Nonce("abc".into()),
+        Attribute::ScriptLevel(ScriptLevel::Add(1)),
+        Attribute::ScriptLevel(ScriptLevel::Sub(2)),
+        Attribute::ScriptLevel(ScriptLevel::Num(3)),
+        Attribute::Style("color: red".into()),
+        Attribute::TabIndex(-1),
+        Attribute::OnHandler {
+            name: "click".into(),
+            handler: "alert(\"<hi>\")".into(),
+        },
+    ];
+
+    // Duplicate attributes are not allowed in XML, so every attribute is put on its own element.
+    let content = global
+        .into_iter()
+        .map(|attr| Element::from(Ident::builder().ident("x").attr([attr]).build()))
+        .collect::<Vec<_>>();
+
+    roundtrip(MathMl::with_content(content).with_attr([
+        MathMlAttr::Display(DisplayAttr::Block),
+        MathMlAttr::AltText("x & y".into()),
+    ]));
+
+    let attr = [
+        UnderOverAttr::AccentOver,
+        UnderOverAttr::AccentUnder,
+        UnderOverAttr::Global(Attribute::Class("c".into())),
+    ];
+
+    roundtrip(MathMl::with_content(children![
+        UnderOver::builder()
+            .expr(Ident::from("x"))
+            .over(Operator::from("^"))
+            .attr(attr.clone())
+            .build(),
+        UnderOver::builder()
+            .expr(Ident::from("x"))
+            .under(Operator::from("_"))
+            .attr(attr.clone())
+            .build(),
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(Ident::from("i"))
+            .over(Ident::from("n"))
+            .attr(attr)
+            .build(),
+    ]));
+}
+
+#[test]
+fn roundtrip_table_cells() {
+    let cell = TableCell::from(Num::from(1)).with_attr([
+        TableCellAttr::ColumnSpan("2".into()),
+        TableCellAttr::RowSpan("3".into()),
+    ]);
+
+    roundtrip(MathMl::with_content(alemat::table![[cell]]));
+}
+
+#[test]
+fn roundtrip_whitespace() {
+    roundtrip(MathMl::with_content(children![
+        Text::from("a  b\nc"),
+        Text::from(" padded "),
+        StrLiteral::from("x\t y"),
+        Operator::from("  "),
+        Semantics::builder()
+            .content(children![
+                Ident::from("x"),
+                Annotation::builder()
+                    .content(String::from("\\frac{a}{b}\n  + c"))
+                    .attr([AnnotationAttr::Encoding("application/x-tex".into())])
+                    .build(),
+            ])
+            .build(),
+    ]));
+
+    // leading and trailing whitespace of `mi`, `mn` and `mo` is not part of the content
+    let parsed = MathMl::parse("<math><mi> x </mi><mn>\n 1 </mn><mo> + </mo></math>");
+
+    assert_eq!(
+        parsed,
+        Ok(MathMl::with_content(children![
+            Ident::from("x"),
+            Num::from(1),
+            Operator::plus()
+        ]))
+    );
+}
+
+#[test]
+fn pretty_printed() {
+    let input = r#"<?xml version="1.0"?>
+<!-- a comment -->
+<math xmlns="http://www.w3.org/1998/Math/MathML" display="block">
+  <mfrac>
+    <mi>
+      x
+    </mi>
+    <mn>2</mn>
+  </mfrac>
+  <mo stretchy="false">&#x2211;</mo>
+  <mtext><![CDATA[a < b]]>  and  &lt;</mtext>
+</math>
+"#;
+
+    let expected = MathMl::with_content(children![
+        Frac::builder()
+            .num(Ident::from("x"))
+            .denom(Num::from(2))
+            .build(),
+        Operator::sum(),
+        Text::from("a < b  and  <"),
+    ])
+    .with_attr([MathMlAttr::Display(DisplayAttr::Block)]);
+
+    assert_eq!(MathMl::parse(input), Ok(expected));
+}
+
+#[test]
+fn element_from_str() {
+    let element: Element = "<msup><mi>x</mi><mn>2</mn></msup>".parse().unwrap();
+
+    let expected = SubSup::builder()
+        .base(Ident::from("x"))
+        .supscript(Num::from(2))
+        .build();
+
+    assert_eq!(element, Element::from(expected));
+}
+
+fn error(input: &str) -> (ParseErrorKind, usize, usize) {
+    let err = MathMl::parse(input).expect_err("Parsing should fail.");
+    (err.kind().clone(), err.line(), err.column())
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        error("<math>\n  <mx>1</mx>\n</math>"),
+        (ParseErrorKind::UnknownElement("mx".into()), 2, 3)
+    );
+
+    assert_eq!(
+        error("<math><mi>x</mn></math>"),
+        (
+            ParseErrorKind::MismatchedClosingTag {
+                expected: "mi".into(),
+                found: "mn".into()
+            },
+            1,
+            12
+        )
+    );
+
+    assert_eq!(
+        error("<math><mfrac><mi>x</mi></mfrac></math>"),
+        (
+            ParseErrorKind::ChildCount {
+                element: "mfrac".into(),
+                expected: 2,
+                found: 1
+            },
+            1,
+            7
+        )
+    );
+
+    assert_eq!(
+        error("<math>\n<mi foo=\"bar\">x</mi></math>"),
+        (
+            ParseErrorKind::UnknownAttribute {
+                element: "mi".into(),
+                attribute: "foo".into()
+            },
+            2,
+            5
+        )
+    );
+
+    assert_eq!(
+        error("<math><mo stretchy=\"maybe\">x</mo></math>"),
+        (
+            ParseErrorKind::InvalidAttributeValue {
+                attribute: "stretchy".into(),
+                value: "maybe".into()
+            },
+            1,
+            11
+        )
+    );
+
+    assert_eq!(
+        error("<math><mi>&alpha;</mi></math>"),
+        (ParseErrorKind::UnknownReference("alpha".into()), 1, 11)
+    );
+
+    // signs are not digits of character references
+    for reference in ["#x+41", "#+65", "#-65", "#x", "#"] {
+        let input = format!("<math><mi>&{reference};</mi></math>");
+        assert_eq!(
+            error(&input),
+            (ParseErrorKind::UnknownReference(reference.into()), 1, 11)
+        );
+    }
+
+    let element: Element = "<mi>&#x41;&#65;</mi>".parse().unwrap();
+    assert_eq!(element, Element::from(Ident::from("AA")));
+
+    assert_eq!(
+        error("<math><mrow>x</mrow></math>"),
+        (ParseErrorKind::UnexpectedText("x".into()), 1, 13)
+    );
+
+    assert_eq!(
+        error("<math><mtd></mtd></math>"),
+        (
+            ParseErrorKind::UnexpectedElement {
+                parent: "math".into(),
+                element: "mtd".into()
+            },
+            1,
+            7
+        )
+    );
+
+    assert_eq!(
+        error("<math></math><math></math>"),
+        (ParseErrorKind::TrailingContent, 1, 14)
+    );
+
+    assert_eq!(
+        error("<math><mi>x</mi>"),
+        (ParseErrorKind::UnexpectedEof, 1, 17)
+    );
+
+    assert_eq!(
+        error("<mi>x</mi>"),
+        (
+            ParseErrorKind::UnexpectedElement {
+                parent: "#document".into(),
+                element: "mi".into()
+            },
+            1,
+            1
+        )
+    );
+}
+
+#[test]
+fn nesting_depth() {
+    let nested = |depth: usize| {
+        format!(
+            "<math>{}<mi>x</mi>{}</math>",
+            "<mrow>".repeat(depth),
+            "</mrow>".repeat(depth)
+        )
+    };
+
+    // the `math` and `mi` elements count as well
+    assert!(MathMl::parse(&nested(254)).is_ok());
+
+    let err = MathMl::parse(&nested(255)).unwrap_err();
+    assert_eq!(err.kind(), &ParseErrorKind::NestingTooDeep(256));
+
+    let err = MathMl::parse(&nested(200_000)).unwrap_err();
+    assert_eq!(err.kind(), &ParseErrorKind::NestingTooDeep(256));
+    assert_eq!(err.offset(), "<math>".len() + 255 * "<mrow>".len());
+}
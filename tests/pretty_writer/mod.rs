@@ -0,0 +1,140 @@
+use alemat::{
+    children,
+    elements::{
+        grouping::{Prescripts, Row},
+        scripted::{Multiscripts, SubSup},
+        Frac, Ident, Num, Operator, Space, Text,
+    },
+    Indent, MathMl, Newline, PrettyMathMlWriter, Writer,
+};
+
+fn pretty(math: &MathMl, mut writer: PrettyMathMlWriter) -> String {
+    math.write(&mut writer)
+        .map(|w| w.finish())
+        .expect("Failed to write MathMl.")
+}
+
+fn math() -> MathMl {
+    MathMl::with_content(children![
+        Frac::builder()
+            .num(
+                SubSup::builder()
+                    .base(Ident::from("x"))
+                    .supscript(Num::from(2))
+                    .build()
+            )
+            .denom(Num::from(2))
+            .build(),
+        Operator::plus(),
+        Text::from("a & b"),
+    ])
+}
+
+#[test]
+fn default_indentation() {
+    assert_eq!(
+        pretty(&math(), PrettyMathMlWriter::default()),
+        "<math>
+  <mfrac>
+    <msup>
+      <mi>x</mi>
+      <mn>2</mn>
+    </msup>
+    <mn>2</mn>
+  </mfrac>
+  <mo>+</mo>
+  <mtext>a &amp; b</mtext>
+</math>"
+    );
+}
+
+#[test]
+fn tabs_and_crlf() {
+    let math = MathMl::with_content([Row::from([Ident::from("x")])]);
+
+    assert_eq!(
+        pretty(
+            &math,
+            PrettyMathMlWriter::default()
+                .with_indent(Indent::Tabs(1))
+                .with_newline(Newline::CrLf)
+        ),
+        "<math>\r\n\t<mrow>\r\n\t\t<mi>x</mi>\r\n\t</mrow>\r\n</math>"
+    );
+
+    assert_eq!(
+        pretty(
+            &math,
+            PrettyMathMlWriter::default().with_indent(Indent::Spaces(4))
+        ),
+        "<math>\n    <mrow>\n        <mi>x</mi>\n    </mrow>\n</math>"
+    );
+}
+
+#[test]
+fn multiline_tokens() {
+    let math = MathMl::with_content([Row::from([Ident::from("x")])]);
+
+    assert_eq!(
+        pretty(
+            &math,
+            PrettyMathMlWriter::default().with_inline_tokens(false)
+        ),
+        "<math>
+  <mrow>
+    <mi>
+      x
+    </mi>
+  </mrow>
+</math>"
+    );
+}
+
+#[test]
+fn empty_elements() {
+    let math = MathMl::with_content(children![
+        Row::from([] as [Ident; 0]),
+        Multiscripts::builder()
+            .content(children![Ident::from("x"), Prescripts::default()])
+            .build(),
+        Space::default(),
+    ]);
+
+    assert_eq!(
+        pretty(&math, PrettyMathMlWriter::default()),
+        "<math>
+  <mrow></mrow>
+  <mmultiscripts>
+    <mi>x</mi>
+    <mprescripts/>
+  </mmultiscripts>
+  <mspace/>
+</math>"
+    );
+}
+
+#[test]
+fn only_adds_whitespace() {
+    let math = math();
+
+    let out = pretty(&math, PrettyMathMlWriter::default());
+    let compact: String = out.lines().map(str::trim).collect();
+
+    assert_eq!(compact, math.render().expect("Failed to render MathMl."));
+}
+
+#[test]
+fn writes_multiple_formulas() {
+    let mut writer = PrettyMathMlWriter::default();
+
+    for i in 0..2 {
+        MathMl::with_content([Num::from(i)])
+            .write(&mut writer)
+            .expect("Failed to write MathMl.");
+    }
+
+    assert_eq!(
+        writer.finish(),
+        "<math>\n  <mn>0</mn>\n</math>\n<math>\n  <mn>1</mn>\n</math>"
+    );
+}
@@ -0,0 +1,283 @@
+use alemat::{
+    children,
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver},
+        Frac, FracAttr, Ident, Num, Operator,
+    },
+    speech::{SpeechFormatter, SpeechStyle, Verbosity},
+    table, BufMathMlWriter, Element, GeneratedAltText, MathMl, MathMlAttr, Writer,
+};
+
+fn speak<const N: usize>(
+    content: [Element; N],
+    style: SpeechStyle,
+    verbosity: Verbosity,
+) -> String {
+    MathMl::with_content(content)
+        .render_with(&mut SpeechFormatter::new(style, verbosity))
+        .unwrap()
+}
+
+fn clearspeak<const N: usize>(content: [Element; N]) -> String {
+    speak(content, SpeechStyle::ClearSpeak, Verbosity::Medium)
+}
+
+fn mathspeak<const N: usize>(content: [Element; N]) -> String {
+    speak(content, SpeechStyle::MathSpeak, Verbosity::Verbose)
+}
+
+#[test]
+fn fractions_and_roots() {
+    let quadratic = children![
+        Ident::from("x"),
+        Operator::eq(),
+        Frac::builder()
+            .num(children![
+                Operator::minus(),
+                Ident::from("b"),
+                Operator::plus_minus(),
+                Radical::builder()
+                    .index(Num::from(2))
+                    .content(children![
+                        SubSup::builder()
+                            .base(Ident::from("b"))
+                            .supscript(Num::from(2))
+                            .build(),
+                        Operator::minus(),
+                        Num::from(4),
+                        Ident::from("a"),
+                        Ident::from("c"),
+                    ])
+                    .build(),
+            ])
+            .denom(children![Num::from(2), Ident::from("a")])
+            .build(),
+    ];
+
+    assert_eq!(
+        clearspeak(quadratic.clone()),
+        "x equals the fraction negative b plus or minus the square root of b squared minus 4 a c, \
+         end root over 2 a, end fraction"
+    );
+
+    assert_eq!(
+        speak(quadratic.clone(), SpeechStyle::ClearSpeak, Verbosity::Brief),
+        "x equals fraction negative b plus or minus square root of b squared minus 4 a c over 2 a, \
+         end fraction"
+    );
+
+    assert_eq!(
+        mathspeak(quadratic),
+        "x equals StartFraction negative b plus or minus StartRoot b Superscript 2 Baseline minus \
+         4 a c EndRoot Over 2 a EndFraction"
+    );
+
+    let simple = children![
+        Frac::builder()
+            .num(Ident::from("a"))
+            .denom(Ident::from("b"))
+            .build(),
+        Operator::plus(),
+        Radical::builder()
+            .index(Num::from(3))
+            .content(Ident::from("x"))
+            .build(),
+    ];
+
+    assert_eq!(
+        clearspeak(simple.clone()),
+        "the fraction a over b plus the cube root of x"
+    );
+
+    assert_eq!(
+        speak(simple, SpeechStyle::ClearSpeak, Verbosity::Verbose),
+        "the fraction with numerator a and denominator b, end fraction plus the cube root of x"
+    );
+
+    let nested = children![Frac::builder()
+        .num(Num::from(1))
+        .denom(children![
+            Num::from(1),
+            Operator::plus(),
+            Frac::builder()
+                .num(Num::from(1))
+                .denom(Ident::from("x"))
+                .build(),
+        ])
+        .build()];
+
+    assert_eq!(
+        mathspeak(nested),
+        "StartStartFraction 1 OverOver 1 plus StartFraction 1 Over x EndFraction EndEndFraction"
+    );
+}
+
+#[test]
+fn scripts_and_limits() {
+    let sum = children![
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(children![Ident::from("i"), Operator::eq(), Num::from(0)])
+            .over(Ident::from("n"))
+            .build(),
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .subscript(Ident::from("i"))
+            .supscript(children![Ident::from("n"), Operator::plus(), Num::from(1)])
+            .build(),
+    ];
+
+    assert_eq!(
+        clearspeak(sum.clone()),
+        "the sum from i equals 0 to n x sub i raised to the n plus 1 power"
+    );
+
+    assert_eq!(
+        mathspeak(sum),
+        "sum Underscript i equals 0 Overscript n Endscripts x Subscript i Superscript n plus 1 \
+         Baseline"
+    );
+
+    let limit = children![
+        UnderOver::builder()
+            .expr(Ident::from("lim"))
+            .under(children![
+                Ident::from("x"),
+                Operator::rarrow(),
+                Num::from(0)
+            ])
+            .build(),
+        Ident::from("f"),
+        Operator::from("\u{2061}"),
+        Operator::lparens(),
+        Ident::from("x"),
+        Operator::rparens(),
+        Operator::plus(),
+        Ident::from("sin"),
+        Operator::from("\u{2061}"),
+        Ident::alpha(),
+    ];
+
+    assert_eq!(
+        speak(limit, SpeechStyle::ClearSpeak, Verbosity::Verbose),
+        "the limit as x approaches 0 of f of x plus sine alpha"
+    );
+
+    let nested = children![
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .supscript(
+                SubSup::builder()
+                    .base(Ident::from("y"))
+                    .supscript(Num::from(2))
+                    .build()
+            )
+            .build(),
+        Operator::plus(),
+        UnderOver::builder()
+            .expr(Ident::from("v"))
+            .over(Operator::rarrow())
+            .build(),
+    ];
+
+    assert_eq!(
+        clearspeak(nested.clone()),
+        "x raised to the y squared power plus vector v"
+    );
+
+    assert_eq!(
+        speak(nested, SpeechStyle::MathSpeak, Verbosity::Brief),
+        "x Sup y Sup Sup 2 Base plus ModifyingAbove v With right arrow"
+    );
+}
+
+#[test]
+fn tables_and_binomials() {
+    let content = children![
+        Row::from(children![
+            Operator::lparens(),
+            table![
+                [Ident::from("a"), Ident::from("b")],
+                [Ident::from("c"), Ident::from("d")]
+            ],
+            Operator::rparens(),
+        ]),
+        Operator::eq(),
+        Row::from(children![
+            Operator::lparens(),
+            Frac::builder()
+                .num(Ident::from("n"))
+                .denom(Ident::from("k"))
+                .attr([FracAttr::LineThickness("0".into())])
+                .build(),
+            Operator::rparens(),
+        ]),
+    ];
+
+    assert_eq!(
+        clearspeak(content.clone()),
+        "the 2 by 2 matrix. row 1: a, b. row 2: c, d. end matrix equals n choose k"
+    );
+
+    assert_eq!(
+        mathspeak(content),
+        "Start 2 By 2 Matrix 1st Row 1st Column a 2nd Column b 2nd Row 1st Column c 2nd Column d \
+         EndMatrix equals StartBinomial n Choose k EndBinomial"
+    );
+}
+
+#[test]
+fn spoken_names() {
+    assert_eq!(
+        Operator::le().spoken_name(),
+        Some("is less than or equal to")
+    );
+    assert_eq!(Operator::from("\u{2212}").spoken_name(), Some("minus"));
+    assert_eq!(Operator::from("@").spoken_name(), None);
+}
+
+#[test]
+fn generated_alttext() {
+    let math = MathMl::with_content(children![
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .supscript(Num::from(2))
+            .build(),
+        Operator::lt(),
+        Num::from(1),
+    ]);
+
+    let mut generated = math.clone();
+    generated.generate_alttext(GeneratedAltText::AltTextAndAriaLabel);
+    assert_eq!(
+        generated.attributes(),
+        [
+            MathMlAttr::AltText(String::from("x squared is less than 1")),
+            MathMlAttr::AriaLabel(String::from("x squared is less than 1")),
+        ]
+    );
+
+    // text alternatives written by hand are kept
+    let manual = math
+        .clone()
+        .with_attr([MathMlAttr::AltText(String::from("manual"))])
+        .with_generated_alttext();
+    assert_eq!(
+        manual.attributes(),
+        [MathMlAttr::AltText(String::from("manual"))]
+    );
+
+    let mut writer =
+        BufMathMlWriter::default().with_generated_alttext(GeneratedAltText::AltTextAndAriaLabel);
+    let out = math.write(&mut writer).unwrap().finish();
+    assert_eq!(
+        out,
+        "<math alttext=\"x squared is less than 1\" aria-label=\"x squared is less than 1\">\
+         <msup><mi>x</mi><mn>2</mn></msup><mo>&lt;</mo><mn>1</mn></math>"
+    );
+
+    let parsed = MathMl::parse(&out).unwrap();
+    assert_eq!(parsed.attributes(), generated.attributes());
+}
@@ -0,0 +1,273 @@
+use alemat::{
+    children,
+    elements::{
+        grouping::{Phantom, Row},
+        radicals::Radical,
+        scripted::{SubSup, UnderOver},
+        Frac, Ident, Num, Operator, OperatorAttr, Table,
+    },
+    table,
+    text_art::TextArtFormatter,
+    MathMl,
+};
+
+fn unicode(math: &MathMl) -> String {
+    math.render_with(&mut TextArtFormatter::default()).unwrap()
+}
+
+fn ascii(math: &MathMl) -> String {
+    math.render_with(&mut TextArtFormatter::ascii()).unwrap()
+}
+
+#[test]
+fn fractions_and_roots() {
+    let discriminant = Radical::builder()
+        .index(Num::from(2))
+        .content(children![
+            SubSup::builder()
+                .base(Ident::from("b"))
+                .supscript(Num::from(2))
+                .build(),
+            Operator::minus(),
+            Num::from(4),
+            Ident::from("a"),
+            Ident::from("c"),
+        ])
+        .build();
+
+    let math = MathMl::with_content(children![
+        Ident::from("x"),
+        Operator::eq(),
+        Frac::builder()
+            .num(children![
+                Operator::minus(),
+                Ident::from("b"),
+                Operator::plus_minus(),
+                discriminant,
+            ])
+            .denom(children![Num::from(2), Ident::from("a")])
+            .build(),
+    ]);
+
+    assert_eq!(
+        unicode(&math),
+        [
+            "           ────────",
+            "          │ 2",
+            "     -b ± √b  - 4ac",
+            "x = ────────────────",
+            "           2a",
+        ]
+        .join("\n")
+    );
+
+    assert_eq!(
+        ascii(&math),
+        [
+            "             ________",
+            "            | 2",
+            "     -b +- \\/b  - 4ac",
+            "x = ------------------",
+            "            2a",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn scripts() {
+    let math = MathMl::with_content(children![
+        Radical::builder()
+            .index(Ident::from("n"))
+            .content(
+                Frac::builder()
+                    .num(Ident::from("x"))
+                    .denom(Ident::from("y"))
+                    .build()
+            )
+            .build(),
+        Operator::plus(),
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(children![Ident::from("i"), Operator::eq(), Num::from(0)])
+            .over(Ident::from("n"))
+            .build(),
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .subscript(Ident::from("i"))
+            .supscript(Num::from(2))
+            .build(),
+    ]);
+
+    assert_eq!(
+        unicode(&math),
+        [
+            "n ───",
+            " │ x      n   2",
+            " │─── +   ∑  x",
+            " √ y    i = 0 i",
+        ]
+        .join("\n")
+    );
+
+    assert_eq!(
+        ascii(&math),
+        [
+            "n  ___",
+            "  | x      n   2",
+            "  |--- +  sum x",
+            " \\/ y    i = 0 i",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn tables() {
+    let matrix = Row::from(children![
+        Operator::from("("),
+        table![
+            [Ident::from("a"), Num::from(10)],
+            [
+                Frac::builder()
+                    .num(Num::from(1))
+                    .denom(Num::from(2))
+                    .build(),
+                Ident::from("d")
+            ]
+        ],
+        Operator::from(")"),
+    ]);
+
+    let math = MathMl::with_content(children![Ident::from("A"), Operator::eq(), matrix]);
+
+    assert_eq!(
+        unicode(&math),
+        [
+            "    ⎛ a   10⎞",
+            "A = ⎜ 1     ⎟",
+            "    ⎜───  d ⎟",
+            "    ⎝ 2     ⎠",
+        ]
+        .join("\n")
+    );
+
+    assert_eq!(
+        ascii(&math),
+        [
+            "    / a   10\\",
+            "A = | 1     |",
+            "    |---  d |",
+            "    \\ 2     /",
+        ]
+        .join("\n")
+    );
+
+    let cases = MathMl::with_content(children![Row::from(children![
+        Operator::from("{"),
+        table![[Ident::from("a")], [Ident::from("b")], [Ident::from("c")]],
+    ])]);
+
+    assert_eq!(unicode(&cases), "⎧a\n⎨b\n⎩c");
+    assert_eq!(ascii(&cases), "/a\n<b\n\\c");
+
+    let empty = MathMl::with_content(children![
+        Ident::from("x"),
+        Table::default(),
+        Phantom::from([Table::default()]),
+    ]);
+    assert_eq!(unicode(&empty), "x");
+
+    let parsed = MathMl::parse("<math><mo>(</mo><mtable></mtable><mo>)</mo></math>").unwrap();
+    assert_eq!(unicode(&parsed), "()");
+}
+
+#[test]
+fn spacing() {
+    let apply = || Operator::from("\u{2061}");
+
+    let math = MathMl::with_content(children![
+        Ident::from("sin"),
+        apply(),
+        Ident::from("x"),
+        Operator::plus(),
+        Ident::from("a"),
+        Ident::from("mod"),
+        apply(),
+        Ident::from("b"),
+        Operator::plus(),
+        Ident::from("f"),
+        apply(),
+        Row::from(children![
+            Operator::from("("),
+            Ident::from("x"),
+            Operator::from(")")
+        ]),
+    ]);
+
+    assert_eq!(unicode(&math), "sin x + a mod b + f(x)");
+
+    let math = MathMl::with_content(children![
+        Ident::from("a"),
+        Operator::from("mod"),
+        Ident::from("b"),
+        Operator::builder()
+            .op(",")
+            .attr([OperatorAttr::RightSpace("1em".into())])
+            .build(),
+        Ident::from("c"),
+        Operator::builder()
+            .op("=")
+            .attr([
+                OperatorAttr::LeftSpace("0".into()),
+                OperatorAttr::RightSpace("0".into()),
+            ])
+            .build(),
+        Num::from(1),
+    ]);
+
+    assert_eq!(unicode(&math), "a mod b,  c=1");
+}
+
+#[test]
+fn stretchy_brackets() {
+    let frac = || {
+        Frac::builder()
+            .num(Ident::from("a"))
+            .denom(Ident::from("b"))
+            .build()
+    };
+
+    // brackets in the middle of a row are only stretched if they are marked as stretchy
+    let math = MathMl::with_content(children![
+        Ident::from("f"),
+        Operator::from("("),
+        Ident::from("x"),
+        Operator::from(")"),
+        Operator::eq(),
+        Operator::from("["),
+        frac(),
+        Operator::builder()
+            .op("]")
+            .attr([OperatorAttr::Stretchy])
+            .build(),
+    ]);
+
+    assert_eq!(
+        unicode(&math),
+        ["         a ⎤", "f(x) = [───⎥", "         b ⎦"].join("\n")
+    );
+}
+
+#[test]
+fn ascii_transliteration() {
+    let math = MathMl::with_content(children![
+        Ident::alpha(),
+        Ident::from("β"),
+        Operator::from("≤"),
+        Ident::from("∞"),
+    ]);
+
+    assert_eq!(unicode(&math), "𝛼β ≤ ∞");
+    assert_eq!(ascii(&math), "alpha beta <= oo");
+}
@@ -0,0 +1,336 @@
+use alemat::{
+    attributes::{Attribute, MathVariant},
+    children,
+    elements::{
+        grouping::{Prescripts, Row},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver, UnderOverAttr},
+        Frac, FracAttr, Ident, Num, Operator, OperatorAttr, Table, TableAttr, TableCell,
+        TableCellAttr, TableRow, Text,
+    },
+    table,
+    typst::TypstFormatter,
+    DisplayAttr, Element, MathMl, MathMlAttr,
+};
+
+fn typst(math: MathMl) -> String {
+    math.render_with(&mut TypstFormatter::default()).unwrap()
+}
+
+#[test]
+fn fractions_and_roots() {
+    let math = MathMl::with_content(children![
+        Frac::builder()
+            .num(children![
+                Ident::from("a"),
+                Operator::plus(),
+                Ident::from("b")
+            ])
+            .denom(Num::from(2))
+            .build(),
+        Radical::builder()
+            .index(Num::from(2))
+            .content(Ident::from("x"))
+            .build(),
+        Radical::builder()
+            .index(Ident::from("n"))
+            .content(children![
+                Ident::from("x"),
+                Operator::from(","),
+                Ident::from("y")
+            ])
+            .build(),
+    ]);
+
+    assert_eq!(typst(math), r"frac(a+b, 2)sqrt(x)root(n, x\,y)");
+
+    let binom = Row::from([
+        Element::from(Operator::lparens()),
+        Frac::builder()
+            .num(Ident::from("n"))
+            .denom(Ident::from("k"))
+            .attr([FracAttr::LineThickness("0".into())])
+            .build()
+            .into(),
+        Operator::rparens().into(),
+    ]);
+
+    assert_eq!(typst(MathMl::with_content(children![binom])), "binom(n, k)");
+}
+
+#[test]
+fn scripts_and_limits() {
+    let sum = UnderOver::builder()
+        .expr(Operator::sum())
+        .under(children![
+            Ident::from("i"),
+            Operator::from("="),
+            Num::from(0)
+        ])
+        .over(Ident::from("n"))
+        .build();
+
+    let power = SubSup::builder()
+        .base(Row::from([
+            Element::from(Operator::lparens()),
+            Ident::from("a").into(),
+            Operator::plus().into(),
+            Ident::from("b").into(),
+            Operator::rparens().into(),
+        ]))
+        .supscript(Num::from(2))
+        .build();
+
+    let indexed = SubSup::builder()
+        .base(Ident::from("x"))
+        .subscript(children![
+            Ident::from("i"),
+            Operator::from(","),
+            Ident::from("j")
+        ])
+        .supscript(Ident::from("α"))
+        .build();
+
+    assert_eq!(
+        typst(MathMl::with_content(children![sum, power, indexed])),
+        "sum_(i=0)^n(a+b)^2 x_(i,j)^alpha"
+    );
+
+    let lim = UnderOver::builder()
+        .expr(Ident::from("lim"))
+        .under(children![
+            Ident::from("x"),
+            Operator::from("→"),
+            Num::from(0)
+        ])
+        .build();
+
+    let integral = SubSup::builder()
+        .base(Operator::integral())
+        .subscript(Num::from(0))
+        .supscript(Ident::from("∞"))
+        .build();
+
+    let limits = UnderOver::builder()
+        .expr(Ident::from("x"))
+        .over(Ident::from("n"))
+        .build();
+
+    assert_eq!(
+        typst(MathMl::with_content(children![lim, integral, limits])),
+        "lim_(x->0)integral_0^infinity limits(x)^n"
+    );
+
+    let prime = SubSup::builder()
+        .base(Ident::from("f"))
+        .supscript(Operator::from("′"))
+        .build();
+
+    let prescripts = Multiscripts::builder()
+        .content(children![
+            Ident::from("C"),
+            Ident::from("k"),
+            Row::default(),
+            Prescripts::default(),
+            Ident::from("n"),
+            Row::default(),
+        ])
+        .build();
+
+    assert_eq!(
+        typst(MathMl::with_content(children![prime, prescripts])),
+        "f'attach(C, bl: n, br: k)"
+    );
+}
+
+#[test]
+fn matrices_and_cases() {
+    let matrix = table![[Num::from(1), Num::from(0)], [Num::from(0), Num::from(1)]];
+
+    let pmatrix = Row::from([
+        Element::from(Operator::lparens()),
+        matrix.clone().into(),
+        Operator::rparens().into(),
+    ]);
+
+    let bmatrix = Row::from([
+        Element::from(Operator::lbracket()),
+        matrix.clone().into(),
+        Operator::rbracket().into(),
+    ]);
+
+    assert_eq!(
+        typst(MathMl::with_content(children![pmatrix, bmatrix])),
+        r#"mat(1, 0; 0, 1)mat(delim: "[", 1, 0; 0, 1)"#
+    );
+
+    let cases = Row::from([
+        Element::from(Operator::lbrace()),
+        table![
+            [Ident::from("x"), Text::from("if x > 0")],
+            [
+                children![Operator::minus(), Ident::from("x")],
+                Text::from("otherwise")
+            ]
+        ]
+        .into(),
+    ]);
+
+    assert_eq!(
+        typst(MathMl::with_content(children![cases])),
+        r#"cases(x & "if x > 0", -x & "otherwise")"#
+    );
+
+    let right = TableCellAttr::Global(Attribute::Style(String::from("text-align: right")));
+    let left = TableCellAttr::Global(Attribute::Style(String::from("text-align: left")));
+
+    let aligned = Table::from_iter([
+        TableRow::from_iter([
+            TableCell::with_content(children![Ident::from("x")]).with_attr([right.clone()]),
+            TableCell::with_content(children![Operator::from("="), Num::from(1)])
+                .with_attr([left.clone()]),
+        ]),
+        TableRow::from_iter([
+            TableCell::with_content(children![Ident::from("y")]).with_attr([right]),
+            TableCell::with_content(children![Operator::from("="), Num::from(2)]).with_attr([left]),
+        ]),
+    ])
+    .with_attr([TableAttr::Global(Attribute::DisplayStyle(true))]);
+
+    assert_eq!(
+        typst(MathMl::with_content(children![aligned])),
+        r"x &=1 \ y &=2"
+    );
+}
+
+#[test]
+fn variants_and_accents() {
+    let math = MathMl::with_content(children![
+        Ident::builder()
+            .ident("R")
+            .attr([Attribute::MathVariant(MathVariant::DoubleStruck)])
+            .build(),
+        Ident::builder()
+            .ident("x")
+            .attr([Attribute::MathVariant(MathVariant::Bold)])
+            .build(),
+        Ident::builder()
+            .ident("v")
+            .attr([Attribute::MathVariant(MathVariant::BoldItalic)])
+            .build(),
+        Ident::builder()
+            .ident("d")
+            .attr([Attribute::MathVariant(MathVariant::Normal)])
+            .build(),
+        Ident::from("sin"),
+        Ident::from("grad"),
+    ]);
+
+    assert_eq!(
+        typst(math),
+        r#"bb(R)bold(x)bold(italic(v))upright(d)sin op("grad")"#
+    );
+
+    let hat = UnderOver::builder()
+        .expr(Ident::from("x"))
+        .over(Operator::hat())
+        .attr([UnderOverAttr::AccentOver])
+        .build();
+
+    let overline = UnderOver::builder()
+        .expr(children![Ident::from("a"), Ident::from("b")])
+        .over(
+            Operator::builder()
+                .op(Operator::bar().op())
+                .attr([OperatorAttr::Stretchy])
+                .build(),
+        )
+        .attr([UnderOverAttr::AccentOver])
+        .build();
+
+    let underbrace = UnderOver::builder()
+        .expr(
+            UnderOver::builder()
+                .expr(children![
+                    Ident::from("a"),
+                    Operator::plus(),
+                    Ident::from("b")
+                ])
+                .under(Operator::ubrace())
+                .build(),
+        )
+        .under(Ident::from("n"))
+        .build();
+
+    assert_eq!(
+        typst(MathMl::with_content(children![hat, overline, underbrace])),
+        "hat(x)overline(a b)underbrace(a+b, n)"
+    );
+}
+
+#[test]
+fn numbers() {
+    let power = SubSup::builder()
+        .base(Ident::from("x"))
+        .supscript(Num::from(10))
+        .build();
+
+    let bold = Num::from_parts("12", vec![Attribute::MathVariant(MathVariant::Bold)]);
+
+    assert_eq!(
+        typst(MathMl::with_content(children![
+            power,
+            Operator::from("+"),
+            Num::from("2.5"),
+            Ident::from("y"),
+            bold,
+        ])),
+        "x^10+2.5 y bold(12)"
+    );
+
+    let frac = Frac::builder()
+        .num(Num::from("3.14"))
+        .denom(Num::from(100))
+        .build();
+
+    assert_eq!(
+        typst(MathMl::with_content([Element::from(frac)])),
+        "frac(3.14, 100)"
+    );
+}
+
+#[test]
+fn escaping() {
+    let math = MathMl::with_content(children![
+        Ident::from("a"),
+        Operator::from("/"),
+        Ident::from("b"),
+        Operator::from("<"),
+        Operator::from("-"),
+        Ident::from("c"),
+        Operator::from("_"),
+        Operator::from("#"),
+        Operator::from("("),
+        Text::from(r#"say "hi""#),
+    ])
+    .with_attr([MathMlAttr::Display(DisplayAttr::Block)]);
+
+    assert_eq!(typst(math), r#"a\/b< -c\_\#\("say \"hi\"""#);
+
+    let frac = Frac::builder()
+        .num(children![
+            Ident::from("f"),
+            Operator::from("("),
+            Ident::from("a"),
+            Operator::from(";"),
+            Ident::from("b"),
+            Operator::from(")"),
+        ])
+        .denom(children![Operator::from(")"), Num::from(2)])
+        .build();
+
+    assert_eq!(
+        typst(MathMl::with_content([Element::from(frac)])),
+        r"frac(f(a\;b), \)2)"
+    );
+}
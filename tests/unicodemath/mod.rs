@@ -0,0 +1,286 @@
+use alemat::{
+    attributes::Attribute,
+    children,
+    elements::{
+        grouping::Row,
+        radicals::Radical,
+        scripted::{SubSup, UnderOver, UnderOverAttr},
+        Frac, Ident, Num, Operator, Table, TableCell, TableCellAttr, TableRow,
+    },
+    table,
+    unicodemath::{self, UnicodeMathErrorKind},
+    Element, MathMl,
+};
+
+#[test]
+fn fractions() {
+    let expected = MathMl::with_content([Frac::builder()
+        .num(children![
+            Ident::from("a"),
+            Operator::plus(),
+            Ident::from("b")
+        ])
+        .denom(Ident::from("c"))
+        .build()]);
+
+    assert_eq!(unicodemath::parse("(a+b)/c"), Ok(expected));
+
+    let expected = MathMl::with_content(children![
+        Num::from(1),
+        Operator::plus(),
+        Frac::builder()
+            .num(children![Num::from(2), Ident::from("x")])
+            .denom(Num::from(3))
+            .build(),
+    ]);
+
+    assert_eq!(unicodemath::parse("1 + 2x/3"), Ok(expected));
+}
+
+#[test]
+fn scripts() {
+    let expected = MathMl::with_content([SubSup::builder()
+        .base(Ident::from("x"))
+        .subscript(Num::from(1))
+        .supscript(Num::from(2))
+        .build()]);
+
+    assert_eq!(unicodemath::parse("x_1^2"), Ok(expected.clone()));
+    assert_eq!(unicodemath::parse("x^2_1"), Ok(expected));
+
+    let expected = MathMl::with_content([SubSup::builder()
+        .base(Ident::from("e"))
+        .supscript(children![
+            Operator::minus(),
+            Ident::from("i"),
+            Ident::from("t")
+        ])
+        .build()]);
+
+    assert_eq!(unicodemath::parse("e^(-it)"), Ok(expected));
+
+    let expected = MathMl::with_content(children![
+        SubSup::builder()
+            .base(Ident::from("f"))
+            .supscript(Operator::from("′"))
+            .build(),
+        Row::from([
+            Element::from(Operator::lparens()),
+            Ident::from("x").into(),
+            Operator::rparens().into(),
+        ]),
+    ]);
+
+    assert_eq!(unicodemath::parse("f'(x)"), Ok(expected));
+
+    let expected = MathMl::with_content([SubSup::builder()
+        .base(Ident::from("x"))
+        .supscript(
+            SubSup::builder()
+                .base(Num::from(2))
+                .supscript(Num::from(3))
+                .build(),
+        )
+        .build()]);
+
+    assert_eq!(unicodemath::parse("x^2^3"), Ok(expected));
+
+    let expected = MathMl::with_content([SubSup::builder()
+        .base(Ident::from("a"))
+        .subscript(
+            SubSup::builder()
+                .base(Ident::from("i"))
+                .subscript(Ident::from("j"))
+                .build(),
+        )
+        .supscript(Num::from(2))
+        .build()]);
+
+    assert_eq!(unicodemath::parse("a_i_j^2"), Ok(expected));
+}
+
+#[test]
+fn radicals() {
+    let expected = MathMl::with_content([Radical::builder()
+        .index(Num::from(2))
+        .content(children![Ident::from("x"), Operator::plus(), Num::from(1)])
+        .build()]);
+
+    assert_eq!(unicodemath::parse("√(x+1)"), Ok(expected.clone()));
+    assert_eq!(unicodemath::parse(r"\sqrt (x+1)"), Ok(expected));
+
+    let expected = MathMl::with_content([Radical::builder()
+        .index(Ident::from("n"))
+        .content(Ident::from("x"))
+        .build()]);
+
+    assert_eq!(unicodemath::parse("√(n&x)"), Ok(expected));
+
+    let expected = MathMl::with_content([Radical::builder()
+        .index(Num::from(3))
+        .content(Num::from(8))
+        .build()]);
+
+    assert_eq!(unicodemath::parse("∛8"), Ok(expected));
+}
+
+#[test]
+fn nary_and_functions() {
+    let expected = MathMl::with_content(children![
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(children![
+                Ident::from("i"),
+                Operator::from("="),
+                Num::from(1)
+            ])
+            .over(Ident::from("n"))
+            .build(),
+        Ident::from("i"),
+    ]);
+
+    assert_eq!(unicodemath::parse("∑_(i=1)^n▒i"), Ok(expected));
+
+    let expected = MathMl::with_content(children![
+        SubSup::builder()
+            .base(Operator::integral())
+            .subscript(Num::from(0))
+            .supscript(Num::from(1))
+            .build(),
+        Ident::from("x"),
+        Ident::from("d"),
+        Ident::from("x"),
+    ]);
+
+    assert_eq!(unicodemath::parse(r"\int_0^1 x dx"), Ok(expected));
+
+    let expected = MathMl::with_content(children![
+        UnderOver::builder()
+            .expr(Ident::from("lim"))
+            .under(children![
+                Ident::from("n"),
+                Operator::from("→"),
+                Ident::from("∞")
+            ])
+            .build(),
+        Operator::from("\u{2061}"),
+        Ident::from("sin"),
+        Operator::from("\u{2061}"),
+        Ident::from("x"),
+    ]);
+
+    assert_eq!(unicodemath::parse(r"lim_(n\to\infty) sin x"), Ok(expected));
+
+    let expected = MathMl::with_content([UnderOver::builder()
+        .expr(Ident::from("x"))
+        .over(Operator::hat())
+        .attr([UnderOverAttr::AccentOver])
+        .build()]);
+
+    assert_eq!(unicodemath::parse("x\u{302}"), Ok(expected.clone()));
+    assert_eq!(unicodemath::parse(r"x\hat"), Ok(expected));
+}
+
+#[test]
+fn matrices_and_fences() {
+    let matrix = table![
+        [Ident::from("a"), Ident::from("b")],
+        [Ident::from("c"), Ident::from("d")]
+    ];
+
+    let expected = MathMl::with_content([Row::from([
+        Element::from(Operator::lparens()),
+        matrix.clone().into(),
+        Operator::rparens().into(),
+    ])]);
+
+    assert_eq!(unicodemath::parse("(■(a&b@c&d))"), Ok(expected));
+    assert_eq!(
+        unicodemath::parse(r"\matrix(a&b@c&d)"),
+        Ok(MathMl::with_content([matrix]))
+    );
+
+    let right = TableCellAttr::Global(Attribute::Style(String::from("text-align: right")));
+    let left = TableCellAttr::Global(Attribute::Style(String::from("text-align: left")));
+
+    let expected = MathMl::with_content([Table::from_iter([
+        TableRow::from_iter([
+            TableCell::with_content(children![Ident::from("x")]).with_attr([right.clone()]),
+            TableCell::with_content(children![Operator::from("="), Num::from(1)])
+                .with_attr([left.clone()]),
+        ]),
+        TableRow::from_iter([
+            TableCell::with_content(children![Ident::from("y")]).with_attr([right]),
+            TableCell::with_content(children![Operator::from("="), Num::from(2)]).with_attr([left]),
+        ]),
+    ])]);
+
+    assert_eq!(unicodemath::parse("█(x&=1@y&=2)"), Ok(expected));
+
+    let expected = MathMl::with_content([Row::from([
+        Element::from(Operator::lbracket()),
+        Num::from(0).into(),
+        Operator::from(",").into(),
+        Num::from(1).into(),
+        Operator::rparens().into(),
+    ])]);
+
+    assert_eq!(unicodemath::parse("[0,1)"), Ok(expected));
+
+    let expected = MathMl::with_content([Row::from([
+        Element::from(Operator::from("|")),
+        Ident::from("x").into(),
+        Operator::from("|").into(),
+    ])]);
+
+    assert_eq!(unicodemath::parse("├|x┤|"), Ok(expected));
+}
+
+#[test]
+fn errors() {
+    let err = unicodemath::parse("a/").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        &UnicodeMathErrorKind::MissingArgument(String::from("/"))
+    );
+    assert_eq!(err.span(), 1..2);
+
+    let err = unicodemath::parse("x+√").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        &UnicodeMathErrorKind::MissingArgument(String::from("√"))
+    );
+
+    let err = unicodemath::parse("■(a&b").unwrap_err();
+    assert_eq!(err.kind(), &UnicodeMathErrorKind::UnclosedBracket);
+
+    let err = unicodemath::parse(r"\foo x").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        &UnicodeMathErrorKind::UnknownControlWord(String::from("foo"))
+    );
+    assert_eq!(err.to_string(), r"unknown control word `\foo` at 0..4");
+
+    let err = unicodemath::parse(r#""text"#).unwrap_err();
+    assert_eq!(err.kind(), &UnicodeMathErrorKind::UnclosedText);
+}
+
+#[test]
+fn nesting_too_deep() {
+    let brackets = |depth: usize| format!("{}x{}", "(".repeat(depth), ")".repeat(depth));
+    assert!(unicodemath::parse(&brackets(256)).is_ok());
+
+    let err = unicodemath::parse(&brackets(257)).unwrap_err();
+    assert_eq!(err.kind(), &UnicodeMathErrorKind::NestingTooDeep(256));
+    assert_eq!(err.span(), 256..257);
+
+    for input in [
+        "(".repeat(600),
+        "√".repeat(5000),
+        "■(".repeat(5000),
+        "⏟".repeat(5000),
+    ] {
+        let err = unicodemath::parse(&input).unwrap_err();
+        assert_eq!(err.kind(), &UnicodeMathErrorKind::NestingTooDeep(256));
+    }
+}
@@ -0,0 +1,303 @@
+use alemat::{
+    attributes::Attribute,
+    children,
+    elements::{
+        grouping::{Prescripts, Row},
+        radicals::Radical,
+        scripted::{Multiscripts, SubSup, UnderOver},
+        Annotation, Frac, Ident, Num, Operator, OperatorAttr, Semantics,
+    },
+    table,
+    validate::{Diagnostic, DiagnosticKind, Severity},
+    Element, MathMl, MathMlAttr,
+};
+
+fn kinds(diagnostics: &[Diagnostic]) -> Vec<(String, DiagnosticKind)> {
+    diagnostics
+        .iter()
+        .map(|d| (d.path().to_string(), d.kind().clone()))
+        .collect()
+}
+
+fn annotation() -> Annotation {
+    Annotation::builder().content(String::from("x")).build()
+}
+
+#[test]
+fn valid() {
+    let math = MathMl::with_content(children![
+        Multiscripts::builder()
+            .content(children![
+                Ident::from("R"),
+                Ident::from("i"),
+                Ident::from("j"),
+                Prescripts::default(),
+                Ident::from("k"),
+                Ident::from("l"),
+            ])
+            .build(),
+        Semantics::builder()
+            .content(children![Ident::from("x"), annotation(), annotation()])
+            .build(),
+        table![[Num::from(1), Num::from(2)]],
+        Frac::builder()
+            .num(Num::from(1))
+            .denom(Num::from(2))
+            .build(),
+        SubSup::builder()
+            .base(Ident::from("x"))
+            .subscript(Ident::from("i"))
+            .supscript(Num::from(2))
+            .build(),
+        Radical::builder()
+            .index(Num::from(3))
+            .content(Ident::from("x"))
+            .build(),
+        Radical::builder()
+            .index(Num::from(2))
+            .content(children![Ident::from("x"), Ident::from("y")])
+            .build(),
+    ])
+    .with_attr([MathMlAttr::Global(Attribute::Id("math".into()))]);
+
+    assert_eq!(math.validate(), []);
+}
+
+#[test]
+fn multiscripts() {
+    let math = MathMl::with_content(children![
+        Multiscripts::builder()
+            .content(children![Prescripts::default(), Ident::from("i")])
+            .build(),
+        Multiscripts::builder()
+            .content(children![
+                Ident::from("R"),
+                Ident::from("i"),
+                Prescripts::default(),
+                Ident::from("j"),
+                Ident::from("k"),
+            ])
+            .build(),
+        Multiscripts::builder()
+            .content(children![
+                Ident::from("R"),
+                Prescripts::default(),
+                Prescripts::default(),
+            ])
+            .build(),
+    ]);
+
+    assert_eq!(
+        kinds(&math.validate()),
+        [
+            (
+                "/math[0]/mmultiscripts[0]".into(),
+                DiagnosticKind::MissingBase
+            ),
+            (
+                "/math[0]/mmultiscripts[1]".into(),
+                DiagnosticKind::OddScripts
+            ),
+            (
+                "/math[0]/mmultiscripts[2]".into(),
+                DiagnosticKind::MultiplePrescripts
+            ),
+        ]
+    );
+}
+
+#[test]
+fn misplaced_prescripts() {
+    let row: Element = Row::from(children![Ident::from("x"), Prescripts::default()]).into();
+
+    assert_eq!(
+        kinds(&row.validate()),
+        [(
+            "/mrow[0]/mprescripts[1]".into(),
+            DiagnosticKind::MisplacedPrescripts
+        )]
+    );
+}
+
+#[test]
+fn semantics() {
+    let math = MathMl::with_content(children![
+        Semantics::builder()
+            .content(children![annotation(), Ident::from("x")])
+            .build(),
+        Semantics::builder()
+            .content(children![Ident::from("x"), Ident::from("y")])
+            .build(),
+    ]);
+
+    let diagnostics = math.validate();
+
+    assert_eq!(
+        kinds(&diagnostics),
+        [
+            (
+                "/math[0]/semantics[0]".into(),
+                DiagnosticKind::MissingPresentation
+            ),
+            (
+                "/math[0]/semantics[0]/mi[1]".into(),
+                DiagnosticKind::UnexpectedSemanticsChild
+            ),
+            (
+                "/math[0]/semantics[1]/mi[1]".into(),
+                DiagnosticKind::UnexpectedSemanticsChild
+            ),
+        ]
+    );
+    assert_eq!(diagnostics[1].severity(), Severity::Warning);
+    assert!(!diagnostics[1].is_error());
+}
+
+#[test]
+fn misplaced_annotation() {
+    let math = MathMl::with_content(children![Frac::builder()
+        .num(Num::from(1))
+        .denom(annotation())
+        .build()]);
+
+    let diagnostics = math.validate();
+
+    assert_eq!(
+        kinds(&diagnostics),
+        [(
+            "/math[0]/mfrac[0]/annotation[1]".into(),
+            DiagnosticKind::MisplacedAnnotation
+        )]
+    );
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "error: annotation outside of semantics at /math[0]/mfrac[0]/annotation[1]"
+    );
+}
+
+#[test]
+fn child_count() {
+    // the builders wrap empty arguments in a row, so the children are removed afterwards
+    let mut sup = SubSup::builder()
+        .base(Ident::from("x"))
+        .supscript(Num::from(2))
+        .build();
+    sup.base_mut().clear();
+    sup.sup_mut().unwrap().clear();
+
+    let mut sub_sup = SubSup::builder()
+        .base(Ident::from("x"))
+        .subscript(Ident::from("i"))
+        .supscript(Num::from(2))
+        .build();
+    sub_sup.sub_mut().unwrap().clear();
+
+    let mut frac = Frac::builder()
+        .num(Num::from(1))
+        .denom(Num::from(2))
+        .build();
+    frac.denom_mut().clear();
+
+    let mut under = UnderOver::builder()
+        .expr(Operator::sum())
+        .under(Ident::from("i"))
+        .build();
+    under.under_mut().unwrap().clear();
+
+    let mut root = Radical::builder()
+        .index(Num::from(3))
+        .content(Ident::from("x"))
+        .build();
+    root.index_mut().clear();
+
+    let math = MathMl::with_content(children![
+        sup,
+        sub_sup,
+        frac,
+        under,
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(Ident::from("i"))
+            .over(Ident::from("n"))
+            .build(),
+        root,
+    ]);
+
+    let count = |expected, found| DiagnosticKind::ChildCount { expected, found };
+
+    assert_eq!(
+        kinds(&math.validate()),
+        [
+            ("/math[0]/msup[0]".into(), count(2, 0)),
+            ("/math[0]/msubsup[1]".into(), count(3, 2)),
+            ("/math[0]/mfrac[2]".into(), count(2, 1)),
+            ("/math[0]/munder[3]".into(), count(2, 1)),
+            ("/math[0]/mroot[5]".into(), count(2, 1)),
+        ]
+    );
+
+    assert_eq!(
+        math.validate()[0].to_string(),
+        "error: expected 2 children, found 0 at /math[0]/msup[0]"
+    );
+}
+
+#[test]
+fn duplicate_attributes() {
+    let math = MathMl::with_content(children![
+        Operator::from_parts(
+            "+",
+            vec![
+                OperatorAttr::Stretchy,
+                OperatorAttr::Global(Attribute::Class("a".into())),
+                OperatorAttr::Stretchy,
+            ]
+        ),
+        Ident::from_parts(
+            "x",
+            vec![
+                Attribute::Class("a".into()),
+                Attribute::Class("b".into()),
+                Attribute::Data {
+                    name: "x".into(),
+                    value: "1".into(),
+                },
+                Attribute::Data {
+                    name: "y".into(),
+                    value: "2".into(),
+                },
+            ]
+        ),
+    ]);
+
+    assert_eq!(
+        kinds(&math.validate()),
+        [
+            (
+                "/math[0]/mo[0]".into(),
+                DiagnosticKind::DuplicateAttribute("stretchy".into())
+            ),
+            (
+                "/math[0]/mi[1]".into(),
+                DiagnosticKind::DuplicateAttribute("class".into())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn duplicate_ids() {
+    let math = MathMl::with_content(children![
+        Ident::from_parts("x", vec![Attribute::Id("a".into())]),
+        table![[Num::from_parts("1", vec![Attribute::Id("a".into())])]],
+    ])
+    .with_attr([MathMlAttr::Global(Attribute::Id("b".into()))]);
+
+    assert_eq!(
+        kinds(&math.validate()),
+        [(
+            "/math[0]/mtable[1]/mtr[0]/mtd[0]/mn[0]".into(),
+            DiagnosticKind::DuplicateId("a".into())
+        )]
+    );
+}
@@ -0,0 +1,184 @@
+use alemat::{
+    children,
+    elements::{
+        grouping::{Phantom, Row},
+        radicals::Radical,
+        scripted::{SubSup, UnderOver},
+        Annotation, Element, Elements, Frac, Ident, Num, Operator, Semantics, Text,
+    },
+    table,
+    visit::{self, Visit, VisitMut},
+    MathMl,
+};
+
+/// Collects the content of all token elements in the order they are visited.
+#[derive(Default)]
+struct Tokens(Vec<String>);
+
+impl Visit for Tokens {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.0.push(ident.ident().into());
+    }
+
+    fn visit_num(&mut self, num: &Num) {
+        self.0.push(num.num().into());
+    }
+
+    fn visit_operator(&mut self, operator: &Operator) {
+        self.0.push(operator.op().into());
+    }
+
+    fn visit_text(&mut self, text: &Text) {
+        self.0.push(text.text().into());
+    }
+}
+
+fn tokens(math: &MathMl) -> Vec<String> {
+    let mut tokens = Tokens::default();
+    tokens.visit_mathml(math);
+    tokens.0
+}
+
+fn math() -> MathMl {
+    MathMl::with_content(children![
+        Frac::builder()
+            .num(
+                SubSup::builder()
+                    .base(Ident::from("a"))
+                    .subscript(Ident::from("b"))
+                    .supscript(Ident::from("c"))
+                    .build()
+            )
+            .denom(
+                Radical::builder()
+                    .index(Num::from(3))
+                    .content(Ident::from("d"))
+                    .build()
+            )
+            .build(),
+        UnderOver::builder()
+            .expr(Operator::sum())
+            .under(Ident::from("e"))
+            .over(Ident::from("f"))
+            .build(),
+        table![
+            [Ident::from("g"), Ident::from("h")],
+            [Ident::from("i"), Ident::from("j")]
+        ],
+        Semantics::builder()
+            .content(children![
+                Phantom::from([Ident::from("k")]),
+                Annotation::builder()
+                    .content(Elements::from_iter([Element::from(Text::from("l"))]))
+                    .build(),
+                Annotation::builder().content(String::from("m")).build(),
+            ])
+            .build(),
+    ])
+}
+
+#[test]
+fn visits_all_children() {
+    assert_eq!(
+        tokens(&math()),
+        ["a", "b", "c", "d", "3", "∑", "e", "f", "g", "h", "i", "j", "k", "l"]
+    );
+}
+
+#[test]
+fn skips_square_root_index() {
+    let math = MathMl::with_content([Radical::builder()
+        .index(Num::from(2))
+        .content(Ident::from("x"))
+        .build()]);
+
+    assert_eq!(tokens(&math), ["x"]);
+}
+
+/// Counts rows, but does not recurse into their children.
+#[derive(Default)]
+struct Rows(usize);
+
+impl Visit for Rows {
+    fn visit_row(&mut self, _row: &Row) {
+        self.0 += 1;
+    }
+}
+
+#[test]
+fn overridden_methods_stop_recursion() {
+    let math = MathMl::with_content(children![
+        Row::from([Row::from([Ident::from("x")])]),
+        Frac::builder()
+            .num(Row::from([Ident::from("y")]))
+            .denom(Num::from(1))
+            .build(),
+    ]);
+
+    let mut rows = Rows::default();
+    rows.visit_mathml(&math);
+
+    assert_eq!(rows.0, 2);
+}
+
+/// Replaces all identifiers `x` with `y` and removes all operators.
+struct Rewrite;
+
+impl VisitMut for Rewrite {
+    fn visit_elements_mut(&mut self, elements: &mut Elements) {
+        elements.retain(|element| !matches!(element, Element::Operator(_)));
+        visit::visit_elements_mut(self, elements);
+    }
+
+    fn visit_element_mut(&mut self, element: &mut Element) {
+        if matches!(element, Element::Ident(ident) if ident.ident() == "x") {
+            *element = Ident::from("y").into();
+        }
+
+        visit::visit_element_mut(self, element);
+    }
+}
+
+#[test]
+fn rewrites_elements() {
+    let mut math = MathMl::with_content(children![
+        Ident::from("x"),
+        Operator::plus(),
+        Frac::builder()
+            .num(children![Ident::from("x"), Operator::minus(), Num::from(1)])
+            .denom(table![[Ident::from("x")]])
+            .build(),
+    ]);
+
+    Rewrite.visit_mathml_mut(&mut math);
+
+    assert_eq!(
+        math,
+        MathMl::with_content(children![
+            Ident::from("y"),
+            Frac::builder()
+                .num(children![Ident::from("y"), Num::from(1)])
+                .denom(table![[Ident::from("y")]])
+                .build(),
+        ])
+    );
+}
+
+#[test]
+fn visit_mut_visits_all_children() {
+    struct Upper;
+
+    impl VisitMut for Upper {
+        fn visit_ident_mut(&mut self, ident: &mut Ident) {
+            *ident = Ident::from(ident.ident().to_uppercase());
+        }
+    }
+
+    let mut math = math();
+    Upper.visit_mathml_mut(&mut math);
+
+    assert_eq!(
+        tokens(&math),
+        ["A", "B", "C", "D", "3", "∑", "E", "F", "G", "H", "I", "J", "K", "l"]
+    );
+}